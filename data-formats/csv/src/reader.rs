@@ -167,6 +167,14 @@ pub struct Row {
 }
 
 impl Row {
+    ///
+    /// Creates a row pairing each of the keys with the value at the same position.  Any keys or
+    /// values without a counterpart are ignored.
+    #[must_use]
+    pub fn new(keys: Vec<String>, data: Vec<String>) -> Row {
+        Row { keys, data }
+    }
+
     ///
     /// Converts this row into a BTreeMap<String, String>.
     ///
@@ -14,6 +14,7 @@ pub enum ErrorType {
     ParseInt,
     MissingValue,
     BadValue,
+    ChecksumMismatch,
 }
 
 #[derive(Debug, Clone)]
@@ -85,7 +86,7 @@ impl From<Error> for BitsError {
             ErrorType::IOError => BitsErrorKind::Other,
             ErrorType::ParseInt => BitsErrorKind::InvalidInput,
             ErrorType::MissingValue => BitsErrorKind::UnexpectedEof,
-            ErrorType::BadValue => BitsErrorKind::InvalidData,
+            ErrorType::BadValue | ErrorType::ChecksumMismatch => BitsErrorKind::InvalidData,
         }
         .into()
     }
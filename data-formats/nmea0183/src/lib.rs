@@ -110,6 +110,7 @@ impl PacketBuilder<Frame> for NMEAParser {
         let packet = NMEAPacketizer::new().read_next_packet(input)?;
         let raw = String::from_utf8_lossy(&packet).to_string();
         trace!("PKT: {}", raw);
+        validate_checksum(&packet)?;
 
         let key = packet.as_slice().read_until(&[b','])?;
         let mut pkt = packet.as_slice();
@@ -176,6 +177,35 @@ pub fn calculate_checksum<T: AsRef<[u8]>>(data: &T) -> u8 {
     out
 }
 
+///
+/// Validates the checksum at the end of the sentence (the two hex digits after the `*`) against
+/// the calculated checksum.  Sentences without a checksum are accepted.
+pub fn validate_checksum<T: AsRef<[u8]>>(data: &T) -> Result<(), Error> {
+    let data = data.as_ref();
+    let Some(idx) = data.iter().position(|v| *v == b'*') else {
+        return Ok(());
+    };
+    let expected = data
+        .get(idx + 1..)
+        .and_then(|v| v.get(..2))
+        .and_then(|v| core::str::from_utf8(v).ok())
+        .and_then(|v| u8::from_str_radix(v, 16).ok());
+    let Some(expected) = expected else {
+        return Err(Error::new_str(
+            ErrorType::ChecksumMismatch,
+            "Checksum is not two hex digits",
+        ));
+    };
+    let actual = calculate_checksum(&data);
+    if actual != expected {
+        return Err(Error::new(
+            ErrorType::ChecksumMismatch,
+            format!("Expected checksum {expected:02X} but was {actual:02X}"),
+        ));
+    }
+    Ok(())
+}
+
 #[allow(clippy::match_same_arms)]
 pub(crate) fn maybe_latitude(val: Option<&str>, ns: Option<&str>) -> Option<Latitude> {
     let val = val?;
@@ -246,8 +276,9 @@ pub(crate) fn maybe_timestamp(val: Option<&str>) -> Option<Time> {
 
 pub(crate) fn maybe_date(val: Option<&str>) -> Option<Date> {
     let val = val?;
-    let (dd, rest) = val.split_at(2);
-    let (mm, yy) = rest.split_at(2);
+    let dd = val.get(0..2)?;
+    let mm = val.get(2..4)?;
+    let yy = val.get(4..)?;
 
     let year = i32::from_str(yy).ok()? + 2000;
     let mm = u8::from_str(mm).ok()?;
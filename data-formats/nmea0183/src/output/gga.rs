@@ -6,7 +6,8 @@ use std::time::Duration;
 
 use irox_bits::{Bits, BitsError};
 use irox_carto::altitude::{Altitude, AltitudeReferenceFrame};
-use irox_carto::coordinate::{Latitude, Longitude};
+use irox_carto::coordinate::{EllipticalCoordinate, Latitude, Longitude};
+use irox_carto::geo::standards::wgs84::WGS84_SHAPE;
 use irox_enums::EnumName;
 use irox_time::Time;
 use irox_tools::fmt::DecimalFormatF64;
@@ -149,6 +150,17 @@ impl GGA {
     pub fn stn_id(&self) -> Option<u16> {
         self.stn_id
     }
+
+    ///
+    /// Returns the WGS84 position of this fix, if both the latitude and longitude are present.
+    /// The coordinate includes the antenna altitude, if available.
+    pub fn coordinate(&self) -> Option<EllipticalCoordinate> {
+        let coord = EllipticalCoordinate::new(self.latitude?, self.longitude?, WGS84_SHAPE);
+        match self.ant_alt {
+            Some(alt) => Some(coord.with_altitude(alt)),
+            None => Some(coord),
+        }
+    }
 }
 
 impl Display for GGA {
@@ -8,7 +8,9 @@ use crate::{
     MessageType, ModeIndicator,
 };
 use irox_bits::{Bits, Error};
-use irox_carto::coordinate::{Latitude, Longitude};
+use irox_carto::coordinate::{EllipticalCoordinate, Latitude, Longitude};
+use irox_carto::geo::standards::wgs84::WGS84_SHAPE;
+use irox_time::datetime::UTCDateTime;
 use irox_time::gregorian::Date;
 use irox_time::Time;
 use irox_tools::packetio::{Packet, PacketBuilder};
@@ -51,6 +53,59 @@ pub struct RMC {
     faa_mode: ModeIndicator,
     nav_mode: ModeIndicator,
 }
+impl RMC {
+    pub fn system_id(&self) -> GNSSSystemID {
+        self.system_id
+    }
+    pub fn timestamp(&self) -> Option<Time> {
+        self.timestamp
+    }
+    pub fn status(&self) -> RMCStatus {
+        self.status
+    }
+    pub fn latitude(&self) -> Option<Latitude> {
+        self.latitude
+    }
+    pub fn longitude(&self) -> Option<Longitude> {
+        self.longitude
+    }
+    pub fn speed(&self) -> Option<Speed> {
+        self.speed
+    }
+    pub fn track(&self) -> Option<Track> {
+        self.track
+    }
+    pub fn date(&self) -> Option<Date> {
+        self.date
+    }
+    pub fn magvar(&self) -> Option<Angle> {
+        self.magvar
+    }
+    pub fn faa_mode(&self) -> ModeIndicator {
+        self.faa_mode
+    }
+    pub fn nav_mode(&self) -> ModeIndicator {
+        self.nav_mode
+    }
+
+    ///
+    /// Returns the combined date and time of this report, if both are present
+    pub fn date_time(&self) -> Option<UTCDateTime> {
+        Some(UTCDateTime::new(self.date?, self.timestamp?))
+    }
+
+    ///
+    /// Returns the WGS84 position of this report, if both the latitude and longitude are
+    /// present.  The coordinate is stamped with [`RMC::date_time`], if available.
+    pub fn coordinate(&self) -> Option<EllipticalCoordinate> {
+        let coord = EllipticalCoordinate::new(self.latitude?, self.longitude?, WGS84_SHAPE);
+        match self.date_time() {
+            Some(ts) => Some(coord.with_timestamp(ts)),
+            None => Some(coord),
+        }
+    }
+}
+
 impl Display for RMC {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "SYSTEM[{}] ", self.system_id)?;
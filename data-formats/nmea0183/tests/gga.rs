@@ -3,14 +3,31 @@
 //
 
 use irox_carto::altitude::{Altitude, AltitudeReferenceFrame};
-use irox_carto::coordinate::{Latitude, Longitude};
+use irox_carto::coordinate::{EllipticalCoordinate, Latitude, Longitude};
 use irox_nmea0183::gga::GPSQualityIndicator;
-use irox_nmea0183::{Error, FramePayload, NMEAParser};
+use irox_nmea0183::{Error, ErrorType, FramePayload, NMEAParser};
 use irox_time::Time;
 use irox_tools::packetio::{Packet, PacketBuilder};
 use irox_units::units::angle::Angle;
 use irox_units::units::length::Length;
 
+fn assert_near(expected: f64, actual: Option<f64>) {
+    let diff = actual.map(|a| (expected - a).abs());
+    assert!(
+        diff.is_some_and(|d| d < 1e-9),
+        "expected {expected} but was {actual:?}"
+    );
+}
+
+/// Parses the GGA sentence, returning its coordinate
+fn parse_coordinate(sentence: &str) -> Result<Option<EllipticalCoordinate>, Error> {
+    let out = NMEAParser.build_from(&mut sentence.as_bytes())?;
+    match out.payload {
+        FramePayload::GGA(gga) => Ok(gga.coordinate()),
+        _ => Err(Error::new_str(ErrorType::BadValue, "Expecting GGA")),
+    }
+}
+
 #[test]
 pub fn test_gga() -> Result<(), Error> {
     let test = "$GNGGA,001043.00,4404.14036,N,12118.85961,W,1,12,0.98,1113.0,M,-21.3,M*47\r\n";
@@ -59,3 +76,54 @@ pub fn test_gga() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[allow(clippy::panic_in_result_fn)]
+#[test]
+pub fn test_gga_coordinate() -> Result<(), Error> {
+    // north and west
+    let coord = parse_coordinate(
+        "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76\r\n",
+    )?;
+    assert_near(
+        53.361_336_666_666_67,
+        coord.map(|c| c.get_latitude().0.as_degrees().value()),
+    );
+    assert_near(
+        -6.505_62,
+        coord.map(|c| c.get_longitude().0.as_degrees().value()),
+    );
+    let alt = coord.and_then(|c| *c.get_altitude());
+    assert_near(61.7, alt.map(|a| a.value().as_meters().value()));
+    assert_eq!(
+        Some(AltitudeReferenceFrame::Geoid),
+        alt.map(|a| a.reference_frame())
+    );
+
+    // south and east
+    let coord = parse_coordinate(
+        "$GPGGA,002153.000,3342.6618,S,15113.4815,E,1,10,1.2,27.0,M,-34.2,M,,0000*5B\r\n",
+    )?;
+    assert_near(
+        -33.711_03,
+        coord.map(|c| c.get_latitude().0.as_degrees().value()),
+    );
+    assert_near(
+        151.224_691_666_666_67,
+        coord.map(|c| c.get_longitude().0.as_degrees().value()),
+    );
+    assert_near(
+        27.0,
+        coord.and_then(|c| c.get_altitude().map(|a| a.value().as_meters().value())),
+    );
+
+    // no fix, and no longitude
+    assert_eq!(
+        None,
+        parse_coordinate("$GPGGA,002154.000,,,,,0,00,99.99,,,,,,*54\r\n")?
+    );
+    assert_eq!(
+        None,
+        parse_coordinate("$GPGGA,002155.000,3342.6618,S,,,1,04,2.5,,,,,,*25\r\n")?
+    );
+    Ok(())
+}
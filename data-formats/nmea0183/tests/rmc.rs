@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+use irox_nmea0183::rmc::RMCStatus;
+use irox_nmea0183::{Error, ErrorType, FramePayload, ModeIndicator, NMEAParser};
+use irox_time::datetime::UTCDateTime;
+use irox_tools::packetio::PacketBuilder;
+use irox_units::units::speed::SpeedUnits;
+
+fn assert_near(expected: f64, actual: Option<f64>) {
+    let diff = actual.map(|a| (expected - a).abs());
+    assert!(
+        diff.is_some_and(|d| d < 1e-9),
+        "expected {expected} but was {actual:?}"
+    );
+}
+
+#[allow(clippy::panic_in_result_fn)]
+#[test]
+pub fn test_rmc() -> Result<(), Error> {
+    let test = "$GNRMC,183004.50,A,3351.90742,S,15112.36508,E,0.052,271.5,150324,12.6,E,A,V*77\r\n";
+    let out = NMEAParser.build_from(&mut test.as_bytes())?;
+    let FramePayload::RMC(rmc) = out.payload else {
+        assert!(false, "Expecting RMC but was {:?}", out.payload);
+        return Ok(());
+    };
+
+    assert_eq!(RMCStatus::Valid, rmc.status());
+    assert_eq!(ModeIndicator::Autonomous, rmc.faa_mode());
+    assert_near(
+        -33.865_123_666_666_67,
+        rmc.latitude().map(|l| l.0.as_degrees().value()),
+    );
+    assert_near(
+        151.206_084_666_666_67,
+        rmc.longitude().map(|l| l.0.as_degrees().value()),
+    );
+    assert_eq!(Some(SpeedUnits::Knots), rmc.speed().map(|s| s.units()));
+    assert_near(0.052, rmc.speed().map(|s| s.value()));
+    assert_near(271.5, rmc.track().map(|t| t.angle().as_degrees().value()));
+
+    let expected = UTCDateTime::try_from_values_f64(2024, 3, 15, 18, 30, 4.5).ok();
+    assert_eq!(expected, rmc.date_time());
+
+    let coord = rmc.coordinate();
+    assert_near(
+        -33.865_123_666_666_67,
+        coord.map(|c| c.get_latitude().0.as_degrees().value()),
+    );
+    assert_near(
+        151.206_084_666_666_67,
+        coord.map(|c| c.get_longitude().0.as_degrees().value()),
+    );
+    assert_eq!(expected, coord.and_then(|c| *c.get_timestamp()));
+    Ok(())
+}
+
+#[allow(clippy::panic_in_result_fn)]
+#[test]
+pub fn test_rmc_empty_fields() -> Result<(), Error> {
+    let test = "$GPRMC,,V,,,,,,,,,,N*53\r\n";
+    let out = NMEAParser.build_from(&mut test.as_bytes())?;
+    let FramePayload::RMC(rmc) = out.payload else {
+        assert!(false, "Expecting RMC but was {:?}", out.payload);
+        return Ok(());
+    };
+    assert_eq!(RMCStatus::Warning, rmc.status());
+    assert_eq!(ModeIndicator::NoValidFix, rmc.faa_mode());
+    assert_eq!(None, rmc.timestamp());
+    assert_eq!(None, rmc.date());
+    assert_eq!(None, rmc.date_time());
+    assert_eq!(None, rmc.latitude());
+    assert!(rmc.speed().is_none());
+    assert!(rmc.coordinate().is_none());
+    Ok(())
+}
+
+#[test]
+pub fn test_bad_checksum() {
+    let test = "$GNRMC,183004.50,A,3351.90742,S,15112.36508,E,0.052,271.5,150324,12.6,E,A,V*78\r\n";
+    let res = NMEAParser.build_from(&mut test.as_bytes());
+    assert!(matches!(
+        res,
+        Err(Error {
+            error_type: ErrorType::ChecksumMismatch,
+            ..
+        })
+    ));
+}
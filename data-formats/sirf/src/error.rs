@@ -4,6 +4,7 @@
 use irox_bits::BitsError;
 use std::fmt::{Display, Formatter};
 use std::io::ErrorKind;
+use std::sync::Arc;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum ErrorType {
@@ -16,6 +17,7 @@ pub enum ErrorType {
 pub struct Error {
     error_type: ErrorType,
     error: String,
+    source: Option<Arc<dyn std::error::Error + Send + Sync>>,
 }
 
 impl Display for Error {
@@ -24,7 +26,13 @@ impl Display for Error {
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
 
 impl Error {
     pub fn kind(&self) -> ErrorType {
@@ -34,7 +42,22 @@ impl Error {
         Error::new_str(error_type, String::from(error))
     }
     pub(crate) fn new_str(error_type: ErrorType, error: String) -> Error {
-        Error { error, error_type }
+        Error {
+            error,
+            error_type,
+            source: None,
+        }
+    }
+    /// Creates a new error, retaining the underlying cause as the [`std::error::Error::source`]
+    fn with_source<E: std::error::Error + Send + Sync + 'static>(
+        error_type: ErrorType,
+        source: E,
+    ) -> Error {
+        Error {
+            error: format!("{source:?}"),
+            error_type,
+            source: Some(Arc::new(source)),
+        }
     }
     pub(crate) fn unsupported<T>(msg: &'static str) -> Result<T, Error> {
         Err(Error::new(ErrorType::UnimplementedMessage, msg))
@@ -43,13 +66,13 @@ impl Error {
 
 impl From<std::io::Error> for Error {
     fn from(value: std::io::Error) -> Self {
-        Error::new_str(ErrorType::IOError, format!("{value:?}"))
+        Error::with_source(ErrorType::IOError, value)
     }
 }
 
 impl From<BitsError> for Error {
     fn from(value: BitsError) -> Self {
-        Error::new_str(ErrorType::IOError, format!("{value:?}"))
+        Error::with_source(ErrorType::IOError, value)
     }
 }
 
@@ -63,3 +86,14 @@ impl From<ErrorType> for Error {
         Error::new_str(value, format!("{value:?}"))
     }
 }
+
+impl From<Error> for std::io::Error {
+    fn from(value: Error) -> Self {
+        let kind = match value.error_type {
+            ErrorType::IOError => ErrorKind::Other,
+            ErrorType::InvalidData | ErrorType::StructError => ErrorKind::InvalidData,
+            ErrorType::UnimplementedMessage => ErrorKind::Unsupported,
+        };
+        std::io::Error::new(kind, value)
+    }
+}
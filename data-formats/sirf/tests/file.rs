@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+use irox_bits::{MutBits, ReadBits, WriteBits};
+use irox_sirf::error::Error;
+use irox_sirf::packet::{PacketParser, PacketType, END_SEQ, START_SEQ};
+use irox_tools::packetio::{Packet, PacketBuilder};
+
+fn write_frame<T: MutBits>(out: &mut T, payload: &[u8]) -> Result<(), Error> {
+    let checksum = payload
+        .iter()
+        .fold(0_u16, |acc, v| acc.wrapping_add(u16::from(*v)));
+    out.write_all_bytes(&START_SEQ)?;
+    out.write_be_u16(payload.len() as u16)?;
+    out.write_all_bytes(payload)?;
+    out.write_be_u16(checksum)?;
+    out.write_all_bytes(&END_SEQ)?;
+    Ok(())
+}
+
+#[allow(clippy::panic_in_result_fn)]
+#[test]
+pub fn test_parse_from_file() -> Result<(), Error> {
+    let path = std::env::temp_dir().join(format!("irox-sirf-test-{}.bin", std::process::id()));
+    let first = [0x09, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04];
+    let second = [0x09, 0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80];
+    {
+        // a tiny buffer to exercise the flushes, the remainder is written on drop.
+        let mut out = WriteBits::with_capacity(4, std::fs::File::create(&path)?);
+        write_frame(&mut out, &first)?;
+        write_frame(&mut out, &second)?;
+    }
+
+    let mut input = ReadBits::new(std::fs::File::open(&path)?);
+    for expected in [first, second] {
+        let PacketType::CPUThroughput(cpu) = PacketParser.build_from(&mut input)? else {
+            std::fs::remove_file(&path)?;
+            return Err(Error::from(std::io::ErrorKind::InvalidData));
+        };
+        assert_eq!(expected.get(1..), Some(cpu.get_bytes()?.as_slice()));
+    }
+    // nothing left, EOF is an error.
+    assert!(PacketParser.build_from(&mut input).is_err());
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -2,23 +2,31 @@
 // Copyright 2023 IROX Contributors
 
 use std::fmt::Display;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub enum ErrorType {
     UrlParseError,
     RequestTransportError,
     RequestErrorCode(u16),
+    /// The server rejected the request's credentials (HTTP 401)
+    Unauthorized,
     IOError,
     CSVError,
     MissingKeyError(String),
     NameKeyMismatch,
     UnsupportedType(String),
+    /// A point can't be represented in the line protocol
+    InvalidPoint,
+    /// A query can't be represented in InfluxQL
+    InvalidQuery,
 }
 
 #[derive(Debug, Clone)]
 pub struct Error {
     pub(crate) error_type: ErrorType,
     pub(crate) error: String,
+    pub(crate) source: Option<Arc<dyn std::error::Error + Send + Sync>>,
 }
 
 impl Error {
@@ -27,6 +35,7 @@ impl Error {
         Error {
             error_type,
             error: String::from(error),
+            source: None,
         }
     }
 
@@ -35,7 +44,28 @@ impl Error {
     }
 
     pub fn err_str<T>(error_type: ErrorType, error: String) -> Result<T, Error> {
-        Err(Error { error_type, error })
+        Err(Error {
+            error_type,
+            error,
+            source: None,
+        })
+    }
+
+    /// Creates a new error, retaining the underlying cause as the [`std::error::Error::source`]
+    fn with_source<E: std::error::Error + Send + Sync + 'static>(
+        error_type: ErrorType,
+        source: E,
+    ) -> Error {
+        Error {
+            error_type,
+            error: format!("{source:?}"),
+            source: Some(Arc::new(source)),
+        }
+    }
+
+    #[must_use]
+    pub fn error_type(&self) -> &ErrorType {
+        &self.error_type
     }
 }
 
@@ -45,47 +75,120 @@ impl Display for Error {
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
 
 impl From<url::ParseError> for Error {
     fn from(value: url::ParseError) -> Self {
-        Error {
-            error_type: ErrorType::UrlParseError,
-            error: format!("{value:?}"),
-        }
+        Error::with_source(ErrorType::UrlParseError, value)
     }
 }
 
 impl From<ureq::Error> for Error {
     fn from(value: ureq::Error) -> Self {
-        let error = format!("{value:?}");
-        match value {
-            ureq::Error::Status(code, _resp) => Error {
-                error_type: ErrorType::RequestErrorCode(code),
-                error,
-            },
-            ureq::Error::Transport(_resp) => Error {
-                error_type: ErrorType::RequestTransportError,
-                error,
-            },
-        }
+        let error_type = match &value {
+            ureq::Error::Status(code, _resp) => ErrorType::RequestErrorCode(*code),
+            ureq::Error::Transport(_resp) => ErrorType::RequestTransportError,
+        };
+        Error::with_source(error_type, value)
     }
 }
 
 impl From<std::io::Error> for Error {
     fn from(value: std::io::Error) -> Self {
-        Error {
-            error_type: ErrorType::IOError,
-            error: format!("{value:?}"),
-        }
+        Error::with_source(ErrorType::IOError, value)
     }
 }
 
 impl From<irox_csv::CSVError> for Error {
     fn from(value: irox_csv::CSVError) -> Self {
-        Error {
-            error_type: ErrorType::CSVError,
-            error: format!("{value:?}"),
+        Error::with_source(ErrorType::CSVError, value)
+    }
+}
+
+impl From<Error> for std::io::Error {
+    /// Retains the [`std::io::ErrorKind`] of any underlying io error (including those within a
+    /// ureq transport error), otherwise picks the kind from the [`ErrorType`].
+    fn from(value: Error) -> Self {
+        let mut source = std::error::Error::source(&value);
+        while let Some(err) = source {
+            if let Some(io) = err.downcast_ref::<std::io::Error>() {
+                return std::io::Error::new(io.kind(), value);
+            }
+            source = err.source();
         }
+        let kind = match value.error_type {
+            ErrorType::UrlParseError
+            | ErrorType::MissingKeyError(_)
+            | ErrorType::NameKeyMismatch
+            | ErrorType::UnsupportedType(_)
+            | ErrorType::InvalidPoint
+            | ErrorType::InvalidQuery
+            | ErrorType::CSVError => std::io::ErrorKind::InvalidData,
+            ErrorType::Unauthorized => std::io::ErrorKind::PermissionDenied,
+            ErrorType::RequestTransportError
+            | ErrorType::RequestErrorCode(_)
+            | ErrorType::IOError => std::io::ErrorKind::Other,
+        };
+        std::io::Error::new(kind, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+    use std::net::TcpListener;
+
+    use crate::error::{Error, ErrorType};
+
+    fn request(url: &str) -> Result<(), Error> {
+        ureq::get(url).call()?;
+        Ok(())
+    }
+
+    #[test]
+    pub fn ureq_source_preserved() {
+        // grab a free port, then close it so the connection is refused.
+        let port = TcpListener::bind("127.0.0.1:0")
+            .and_then(|l| l.local_addr())
+            .map(|a| a.port())
+            .unwrap_or(1);
+        let result = request(&format!("http://127.0.0.1:{port}/ping"));
+        assert!(result.is_err());
+        let Err(err) = result else {
+            return;
+        };
+        assert!(matches!(err.error_type(), ErrorType::RequestTransportError));
+        let source = err.source().and_then(|s| s.downcast_ref::<ureq::Error>());
+        assert!(matches!(source, Some(ureq::Error::Transport(_))));
+
+        // and the chain survives conversion into an io error
+        let io: std::io::Error = err.into();
+        assert_eq!(std::io::ErrorKind::ConnectionRefused, io.kind());
+        let inner = io.get_ref().and_then(|e| e.downcast_ref::<Error>());
+        assert!(inner.and_then(|e| e.source()).is_some());
+    }
+
+    #[test]
+    pub fn io_kind_preserved() {
+        let err = Error::from(std::io::Error::from(std::io::ErrorKind::TimedOut));
+        assert_eq!(
+            std::io::ErrorKind::TimedOut,
+            std::io::Error::from(err).kind()
+        );
+
+        let err = Error::new(ErrorType::RequestTransportError, "no source");
+        assert_eq!(std::io::ErrorKind::Other, std::io::Error::from(err).kind());
+
+        let err = Error::new(ErrorType::InvalidQuery, "bad query");
+        assert_eq!(
+            std::io::ErrorKind::InvalidData,
+            std::io::Error::from(err).kind()
+        );
     }
 }
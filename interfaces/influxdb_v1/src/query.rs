@@ -0,0 +1,471 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! A builder for InfluxQL `SELECT` queries, that quotes identifiers and escapes literals.
+//!
+
+use std::fmt::Write;
+
+use irox_time::datetime::UTCDateTime;
+use irox_time::format::iso8601::EXTENDED_DATE_TIME_FORMAT;
+use irox_time::format::Format;
+use irox_time::Duration;
+use irox_units::units::duration::HUMAN_UNITS;
+
+use crate::error::{Error, ErrorType};
+
+/// Appends the identifier double-quoted, with any `"` or `\` escaped.
+fn push_identifier(out: &mut String, ident: &str) {
+    out.push('"');
+    for ch in ident.chars() {
+        if ch == '"' || ch == '\\' {
+            out.push('\\');
+        }
+        out.push(ch);
+    }
+    out.push('"');
+}
+
+/// Appends the value single-quoted, with any `'` or `\` escaped.
+fn push_string_literal(out: &mut String, value: &str) {
+    out.push('\'');
+    for ch in value.chars() {
+        if ch == '\'' || ch == '\\' {
+            out.push('\\');
+        }
+        out.push(ch);
+    }
+    out.push('\'');
+}
+
+/// Appends the time as an RFC3339 string literal
+fn push_time_literal(out: &mut String, time: &UTCDateTime) {
+    out.push('\'');
+    let _ = EXTENDED_DATE_TIME_FORMAT.format_to(time, out);
+    out.push('\'');
+}
+
+///
+/// Formats the duration as an InfluxQL duration literal like `90m`, using the largest of the
+/// [`HUMAN_UNITS`] that exactly represents it, after rounding to the nearest nanosecond.
+/// Negative durations are prefixed with `-`, like `-90m`.
+#[must_use]
+pub fn duration_literal(duration: &Duration) -> String {
+    let nanos = duration.as_nanos_rounded();
+    let sign = if nanos < 0 { "-" } else { "" };
+    let nanos = nanos.unsigned_abs();
+    for (size, unit) in HUMAN_UNITS {
+        if nanos >= size && nanos % size == 0 {
+            // InfluxQL only accepts `u` (or `µ`) for microseconds.
+            let unit = if unit == "us" { "u" } else { unit };
+            return format!("{sign}{}{unit}", nanos / size);
+        }
+    }
+    String::from("0s")
+}
+
+///
+/// Aggregation functions that can be applied to a field
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Aggregate {
+    Count,
+    Distinct,
+    Mean,
+    Median,
+    Mode,
+    Spread,
+    Stddev,
+    Sum,
+    First,
+    Last,
+    Max,
+    Min,
+}
+
+impl Aggregate {
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Aggregate::Count => "count",
+            Aggregate::Distinct => "distinct",
+            Aggregate::Mean => "mean",
+            Aggregate::Median => "median",
+            Aggregate::Mode => "mode",
+            Aggregate::Spread => "spread",
+            Aggregate::Stddev => "stddev",
+            Aggregate::Sum => "sum",
+            Aggregate::First => "first",
+            Aggregate::Last => "last",
+            Aggregate::Max => "max",
+            Aggregate::Min => "min",
+        }
+    }
+}
+
+///
+/// What to report for `GROUP BY time(...)` intervals that have no data
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Fill {
+    /// Report a null value, the default
+    Null,
+    /// Don't report the interval at all
+    None,
+    /// Report the value from the previous interval
+    Previous,
+    /// Linearly interpolate between the surrounding intervals
+    Linear,
+    /// Report the provided value, which must be finite
+    Value(f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Column {
+    Field(String),
+    Aggregate(Aggregate, String),
+}
+
+///
+/// Builds an InfluxQL `SELECT` query.  All identifiers are double-quoted and all values are
+/// single-quoted and escaped, so measurement, field and tag names or values can't alter the query.
+///
+/// # Example
+/// ```
+/// # use irox_influxdb_v1::query::{Aggregate, Fill, Select};
+/// # use irox_time::datetime::UTCDateTime;
+/// # use irox_time::Duration;
+/// let start = UTCDateTime::try_from_values(2024, 1, 1, 0, 0, 0).unwrap();
+/// let end = UTCDateTime::try_from_values(2024, 1, 2, 0, 0, 0).unwrap();
+/// let query = Select::from("cpu")
+///     .aggregate(Aggregate::Mean, "usage")
+///     .where_tag("host", "server01")
+///     .where_time(start, end)
+///     .group_by_time(Duration::from_minutes(5))
+///     .fill(Fill::Null)
+///     .build()?;
+/// assert_eq!(
+///     "SELECT mean(\"usage\") FROM \"cpu\" WHERE \"host\" = 'server01' AND \
+///     time >= '2024-01-01T00:00:00Z' AND time < '2024-01-02T00:00:00Z' \
+///     GROUP BY time(5m) fill(null)",
+///     query
+/// );
+/// # Ok::<(), irox_influxdb_v1::error::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Select {
+    measurement: String,
+    retention_policy: Option<String>,
+    columns: Vec<Column>,
+    tags: Vec<(String, String)>,
+    start: Option<UTCDateTime>,
+    end: Option<UTCDateTime>,
+    group_by_time: Option<Duration>,
+    group_by_tags: Vec<String>,
+    fill: Option<Fill>,
+    descending: bool,
+    limit: Option<u64>,
+}
+
+impl Select {
+    ///
+    /// Starts a query of the measurement.  With no fields or aggregates, selects all (`*`).
+    #[must_use]
+    pub fn from<T: Into<String>>(measurement: T) -> Select {
+        Select {
+            measurement: measurement.into(),
+            retention_policy: None,
+            columns: Vec::new(),
+            tags: Vec::new(),
+            start: None,
+            end: None,
+            group_by_time: None,
+            group_by_tags: Vec::new(),
+            fill: None,
+            descending: false,
+            limit: None,
+        }
+    }
+
+    /// Queries the measurement in the named retention policy, rather than the default.
+    #[must_use]
+    pub fn retention_policy<T: Into<String>>(mut self, policy: T) -> Self {
+        self.retention_policy = Some(policy.into());
+        self
+    }
+
+    /// Selects the fields (or tags)
+    #[must_use]
+    pub fn fields<T: AsRef<str>>(mut self, fields: &[T]) -> Self {
+        self.columns
+            .extend(fields.iter().map(|f| Column::Field(f.as_ref().to_string())));
+        self
+    }
+
+    /// Selects the aggregate of the field, like `mean("field")`
+    #[must_use]
+    pub fn aggregate<T: Into<String>>(mut self, aggregate: Aggregate, field: T) -> Self {
+        self.columns
+            .push(Column::Aggregate(aggregate, field.into()));
+        self
+    }
+
+    /// Only selects the points where the tag has the value.  Multiple filters must all match.
+    #[must_use]
+    pub fn where_tag<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.tags.push((key.into(), value.into()));
+        self
+    }
+
+    /// Only selects the points at or after `start` and before `end`
+    #[must_use]
+    pub fn where_time(mut self, start: UTCDateTime, end: UTCDateTime) -> Self {
+        self.start = Some(start);
+        self.end = Some(end);
+        self
+    }
+
+    /// Only selects the points at or after `start`
+    #[must_use]
+    pub fn where_time_since(mut self, start: UTCDateTime) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Groups the results into intervals of the provided duration
+    #[must_use]
+    pub fn group_by_time(mut self, interval: Duration) -> Self {
+        self.group_by_time = Some(interval);
+        self
+    }
+
+    /// Groups the results by the tag
+    #[must_use]
+    pub fn group_by_tag<T: Into<String>>(mut self, tag: T) -> Self {
+        self.group_by_tags.push(tag.into());
+        self
+    }
+
+    /// What to report for intervals without data, only used with [`Select::group_by_time`]
+    #[must_use]
+    pub fn fill(mut self, fill: Fill) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Returns the newest points first
+    #[must_use]
+    pub fn descending(mut self) -> Self {
+        self.descending = true;
+        self
+    }
+
+    /// Returns at most `limit` points (per series)
+    #[must_use]
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    ///
+    /// Builds the InfluxQL query string.  Returns an error if the [`Fill::Value`] isn't finite,
+    /// which InfluxQL can't represent.
+    pub fn build(&self) -> Result<String, Error> {
+        let mut out = String::from("SELECT ");
+        if self.columns.is_empty() {
+            out.push('*');
+        }
+        for (idx, column) in self.columns.iter().enumerate() {
+            if idx > 0 {
+                out.push_str(", ");
+            }
+            match column {
+                Column::Field(field) => push_identifier(&mut out, field),
+                Column::Aggregate(aggregate, field) => {
+                    out.push_str(aggregate.name());
+                    out.push('(');
+                    push_identifier(&mut out, field);
+                    out.push(')');
+                }
+            }
+        }
+
+        out.push_str(" FROM ");
+        if let Some(policy) = &self.retention_policy {
+            push_identifier(&mut out, policy);
+            out.push('.');
+        }
+        push_identifier(&mut out, &self.measurement);
+
+        let mut conditions = 0;
+        let mut next_condition = |out: &mut String| {
+            out.push_str(if conditions == 0 { " WHERE " } else { " AND " });
+            conditions += 1;
+        };
+        for (key, value) in &self.tags {
+            next_condition(&mut out);
+            push_identifier(&mut out, key);
+            out.push_str(" = ");
+            push_string_literal(&mut out, value);
+        }
+        if let Some(start) = &self.start {
+            next_condition(&mut out);
+            out.push_str("time >= ");
+            push_time_literal(&mut out, start);
+        }
+        if let Some(end) = &self.end {
+            next_condition(&mut out);
+            out.push_str("time < ");
+            push_time_literal(&mut out, end);
+        }
+
+        if self.group_by_time.is_some() || !self.group_by_tags.is_empty() {
+            out.push_str(" GROUP BY ");
+            let mut first = true;
+            if let Some(interval) = &self.group_by_time {
+                let _ = write!(out, "time({})", duration_literal(interval));
+                first = false;
+            }
+            for tag in &self.group_by_tags {
+                if !first {
+                    out.push_str(", ");
+                }
+                push_identifier(&mut out, tag);
+                first = false;
+            }
+            if let (Some(fill), Some(_)) = (&self.fill, &self.group_by_time) {
+                match fill {
+                    Fill::Null => out.push_str(" fill(null)"),
+                    Fill::None => out.push_str(" fill(none)"),
+                    Fill::Previous => out.push_str(" fill(previous)"),
+                    Fill::Linear => out.push_str(" fill(linear)"),
+                    Fill::Value(value) => {
+                        if !value.is_finite() {
+                            return Error::err_str(
+                                ErrorType::InvalidQuery,
+                                format!("Fill value is not finite: {value}"),
+                            );
+                        }
+                        let _ = write!(out, " fill({value})");
+                    }
+                }
+            }
+        }
+        if self.descending {
+            out.push_str(" ORDER BY time DESC");
+        }
+        if let Some(limit) = self.limit {
+            let _ = write!(out, " LIMIT {limit}");
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_time::datetime::UTCDateTime;
+    use irox_time::{Duration, GreaterThanEqualToValueError};
+
+    use crate::error::{Error, ErrorType};
+    use crate::query::{duration_literal, Aggregate, Fill, Select};
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn select_all() -> Result<(), Error> {
+        assert_eq!("SELECT * FROM \"cpu\"", Select::from("cpu").build()?);
+        assert_eq!(
+            "SELECT \"usage\", \"host\" FROM \"autogen\".\"cpu\" ORDER BY time DESC LIMIT 10",
+            Select::from("cpu")
+                .retention_policy("autogen")
+                .fields(&["usage", "host"])
+                .descending()
+                .limit(10)
+                .build()?
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn grouped() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        let query = Select::from("weather")
+            .aggregate(Aggregate::Max, "temp")
+            .aggregate(Aggregate::Count, "temp")
+            .where_time_since(UTCDateTime::try_from_values(2024, 3, 1, 12, 30, 0)?)
+            .group_by_time(Duration::from_hours(1))
+            .group_by_tag("station")
+            .fill(Fill::Value(-1.5));
+        assert_eq!(
+            Some(
+                "SELECT max(\"temp\"), count(\"temp\") FROM \"weather\" \
+                WHERE time >= '2024-03-01T12:30:00Z' GROUP BY time(1h), \"station\" fill(-1.5)"
+            ),
+            query.build().as_deref().ok()
+        );
+
+        // fill is only valid with a time grouping
+        assert_eq!(
+            Some("SELECT * FROM \"weather\" GROUP BY \"station\""),
+            Select::from("weather")
+                .group_by_tag("station")
+                .fill(Fill::Linear)
+                .build()
+                .as_deref()
+                .ok()
+        );
+
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let err = Select::from("weather")
+                .group_by_time(Duration::from_hours(1))
+                .fill(Fill::Value(value))
+                .build()
+                .err();
+            assert!(
+                matches!(
+                    err.as_ref().map(Error::error_type),
+                    Some(ErrorType::InvalidQuery)
+                ),
+                "{err:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn escaping() -> Result<(), Error> {
+        assert_eq!(
+            "SELECT \"my \\\"field\\\"\" FROM \"cpu\\\\load\" \
+            WHERE \"host\" = 'a\\' OR 1=1 --' AND \"region\" = 'us\\\\west'",
+            Select::from("cpu\\load")
+                .fields(&["my \"field\""])
+                .where_tag("host", "a' OR 1=1 --")
+                .where_tag("region", "us\\west")
+                .build()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn durations() {
+        assert_eq!("2w", duration_literal(&Duration::from_days(14)));
+        assert_eq!("3d", duration_literal(&Duration::from_days(3)));
+        assert_eq!("90m", duration_literal(&Duration::from_minutes(90)));
+        assert_eq!("45s", duration_literal(&Duration::from_seconds(45)));
+        assert_eq!("1500ms", duration_literal(&Duration::from_millis(1500)));
+        assert_eq!("10u", duration_literal(&Duration::from_micros(10)));
+        assert_eq!("7ns", duration_literal(&Duration::from_nanos(7)));
+        assert_eq!("0s", duration_literal(&Duration::from_nanos(0)));
+        // not exactly representable in floating point seconds
+        assert_eq!("300ms", duration_literal(&Duration::from_millis(300)));
+        assert_eq!("100ms", duration_literal(&Duration::from_seconds_f64(0.1)));
+        assert_eq!(
+            "-90m",
+            duration_literal(&Duration::from_seconds_f64(-5400.))
+        );
+        assert_eq!(
+            "-1500ms",
+            duration_literal(&Duration::from_seconds_f64(-1.5))
+        );
+    }
+}
@@ -3,7 +3,11 @@
 //
 
 use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::Read;
 
+use irox_csv::UNIX_DIALECT;
+use irox_time::datetime::UTCDateTime;
 use irox_types::{NamedVariable, Primitives, VariableType};
 
 use crate::error::{self, Error, ErrorType};
@@ -151,3 +155,571 @@ impl MeasurementDescriptor {
         Ok(())
     }
 }
+
+///
+/// The value of a field in a [`LineProtocol`] point
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Float(f64),
+    Integer(i64),
+    Boolean(bool),
+    String(String),
+}
+
+impl From<f64> for FieldValue {
+    fn from(value: f64) -> Self {
+        FieldValue::Float(value)
+    }
+}
+
+impl From<i64> for FieldValue {
+    fn from(value: i64) -> Self {
+        FieldValue::Integer(value)
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        FieldValue::Boolean(value)
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        FieldValue::String(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        FieldValue::String(value.to_string())
+    }
+}
+
+/// Appends the value, escaping each of the characters with a backslash
+fn push_escaped(out: &mut String, value: &str, escaped: &[char]) {
+    for c in value.chars() {
+        if escaped.contains(&c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
+
+///
+/// Appends the measurement name, tag or field key, or tag value, escaping each of the
+/// characters with a backslash.  The line protocol has no escape for line breaks, so returns an
+/// error if the value contains one.
+fn push_escaped_name(
+    out: &mut String,
+    value: &str,
+    escaped: &[char],
+    what: &str,
+) -> Result<(), Error> {
+    if value.contains(['\n', '\r']) {
+        return Error::err_str(
+            ErrorType::InvalidPoint,
+            format!("{what} contains a line break: {value:?}"),
+        );
+    }
+    push_escaped(out, value, escaped);
+    Ok(())
+}
+
+///
+/// A single point to write, serialized into the InfluxDB line protocol:
+/// `measurement,tag=value field=value timestamp`
+///
+/// ```
+/// # use irox_influxdb_v1::types::LineProtocol;
+/// let line = LineProtocol::new("cpu")
+///     .with_tag("host", "server 01")
+///     .with_field("load", 0.5)
+///     .with_field("procs", 12i64)
+///     .to_line();
+/// assert_eq!("cpu,host=server\\ 01 load=0.5,procs=12i", line.unwrap_or_default());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LineProtocol {
+    measurement: String,
+    tags: BTreeMap<String, String>,
+    fields: BTreeMap<String, FieldValue>,
+    timestamp: Option<UTCDateTime>,
+}
+
+impl LineProtocol {
+    #[must_use]
+    pub fn new<T: Into<String>>(measurement: T) -> LineProtocol {
+        LineProtocol {
+            measurement: measurement.into(),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_tag<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.tags.insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds all the tags, replacing any existing tags with the same keys
+    #[must_use]
+    pub fn with_tags(mut self, tags: BTreeMap<String, String>) -> Self {
+        self.tags.extend(tags);
+        self
+    }
+
+    #[must_use]
+    pub fn with_field<K: Into<String>, V: Into<FieldValue>>(mut self, key: K, value: V) -> Self {
+        self.fields.insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds all the fields, replacing any existing fields with the same keys
+    #[must_use]
+    pub fn with_fields(mut self, fields: BTreeMap<String, FieldValue>) -> Self {
+        self.fields.extend(fields);
+        self
+    }
+
+    /// Sets the time of the point, if not set the server's time on receipt is used.
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: UTCDateTime) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    #[must_use]
+    pub fn measurement(&self) -> &str {
+        &self.measurement
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> &BTreeMap<String, String> {
+        &self.tags
+    }
+
+    #[must_use]
+    pub fn fields(&self) -> &BTreeMap<String, FieldValue> {
+        &self.fields
+    }
+
+    #[must_use]
+    pub fn timestamp(&self) -> Option<UTCDateTime> {
+        self.timestamp
+    }
+
+    ///
+    /// Serializes this point into a single line of the line protocol, with the timestamp in
+    /// nanoseconds.  Tags with empty values are omitted.  Returns an error if the measurement
+    /// name is empty, there are no fields, a float field isn't finite, or the measurement name,
+    /// a tag or a field key contains a line break, none of which can be represented.
+    pub fn to_line(&self) -> Result<String, Error> {
+        if self.measurement.is_empty() {
+            return Error::err(ErrorType::InvalidPoint, "Measurement name is empty");
+        }
+        if self.fields.is_empty() {
+            return Error::err_str(
+                ErrorType::InvalidPoint,
+                format!("Point in {} has no fields", self.measurement),
+            );
+        }
+        let mut out = String::new();
+        push_escaped_name(&mut out, &self.measurement, &[',', ' '], "Measurement name")?;
+        for (key, value) in &self.tags {
+            if value.is_empty() {
+                continue;
+            }
+            out.push(',');
+            push_escaped_name(&mut out, key, &[',', '=', ' '], "Tag key")?;
+            out.push('=');
+            push_escaped_name(&mut out, value, &[',', '=', ' '], "Tag value")?;
+        }
+        let mut separator = ' ';
+        for (key, value) in &self.fields {
+            out.push(separator);
+            separator = ',';
+            push_escaped_name(&mut out, key, &[',', '=', ' '], "Field key")?;
+            out.push('=');
+            match value {
+                FieldValue::Float(v) => {
+                    if !v.is_finite() {
+                        return Error::err_str(
+                            ErrorType::InvalidPoint,
+                            format!("Field {key} is not finite: {v}"),
+                        );
+                    }
+                    let _ = write!(out, "{v}");
+                }
+                FieldValue::Integer(v) => {
+                    let _ = write!(out, "{v}i");
+                }
+                FieldValue::Boolean(v) => {
+                    let _ = write!(out, "{v}");
+                }
+                FieldValue::String(v) => {
+                    out.push('"');
+                    push_escaped(&mut out, v, &['"', '\\']);
+                    out.push('"');
+                }
+            }
+        }
+        if let Some(timestamp) = &self.timestamp {
+            let _ = write!(out, " {}", timestamp.as_unix_nanos());
+        }
+        Ok(out)
+    }
+}
+
+///
+/// A single value in a [`Series`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Float(f64),
+    Integer(i64),
+    Boolean(bool),
+    String(String),
+}
+
+/// The type of a column, inferred from all of its non-empty values
+#[derive(Copy, Clone)]
+enum ColumnType {
+    Integer,
+    Float,
+    Boolean,
+    String,
+}
+
+impl ColumnType {
+    fn infer<'a, T: Iterator<Item = &'a str>>(values: T) -> ColumnType {
+        let (mut integer, mut float, mut boolean) = (true, true, true);
+        for value in values.filter(|v| !v.is_empty()) {
+            integer &= value.parse::<i64>().is_ok();
+            float &= value.parse::<f64>().is_ok();
+            boolean &= value == "true" || value == "false";
+        }
+        if integer {
+            ColumnType::Integer
+        } else if float {
+            ColumnType::Float
+        } else if boolean {
+            ColumnType::Boolean
+        } else {
+            ColumnType::String
+        }
+    }
+
+    fn parse(self, value: String) -> Value {
+        if value.is_empty() {
+            return Value::Null;
+        }
+        let parsed = match self {
+            ColumnType::Integer => value.parse().ok().map(Value::Integer),
+            ColumnType::Float => value.parse().ok().map(Value::Float),
+            ColumnType::Boolean => Some(Value::Boolean(value == "true")),
+            ColumnType::String => None,
+        };
+        parsed.unwrap_or(Value::String(value))
+    }
+}
+
+///
+/// The results of a query for a single measurement and set of tag values.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Series {
+    pub(crate) name: String,
+    pub(crate) tags: BTreeMap<String, String>,
+    pub(crate) columns: Vec<String>,
+    pub(crate) rows: Vec<Vec<Value>>,
+}
+
+impl Series {
+    /// The name of the measurement
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The tag values shared by all rows of this series, populated when grouping by tags
+    #[must_use]
+    pub fn tags(&self) -> &BTreeMap<String, String> {
+        &self.tags
+    }
+
+    /// The names of the columns, in the order of the values in each row
+    #[must_use]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    #[must_use]
+    pub fn rows(&self) -> &[Vec<Value>] {
+        &self.rows
+    }
+
+    /// Returns the index of the named column, if present
+    #[must_use]
+    pub fn column_index(&self, column: &str) -> Option<usize> {
+        self.columns.iter().position(|c| c == column)
+    }
+
+    ///
+    /// Parses the CSV results of a query into a list of series.  The CSV doesn't carry any type
+    /// information, so the type of each column is inferred from its values: integers, then
+    /// floats, then booleans, falling back to strings.  Empty values are [`Value::Null`].  A float
+    /// field that only has whole-number values will be inferred as an integer.
+    pub fn parse_csv<T: Read>(mut reader: T) -> Result<Vec<Series>, Error> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        if data.iter().all(u8::is_ascii_whitespace) {
+            // no results
+            return Ok(Vec::new());
+        }
+        let mut reader = irox_csv::CSVMapReader::dialect(data.as_slice(), UNIX_DIALECT)?;
+
+        // (series, raw rows)
+        let mut out: Vec<(Series, Vec<Vec<String>>)> = Vec::new();
+        while let Some(row) = reader.next_row()? {
+            let mut items = row
+                .into_items()
+                .into_iter()
+                .map(|(key, value)| (key, unquote(value)));
+            let (Some((name_key, name)), Some((tags_key, tags))) = (items.next(), items.next())
+            else {
+                return Error::err(
+                    ErrorType::MissingKeyError("name".to_string()),
+                    "Missing key",
+                );
+            };
+            if name_key != "name" || tags_key != "tags" {
+                return Error::err_str(
+                    ErrorType::MissingKeyError("name".to_string()),
+                    format!("Expected name,tags columns but found {name_key},{tags_key}"),
+                );
+            }
+            let tags = parse_tags(&tags);
+            let (columns, values): (Vec<String>, Vec<String>) = items.unzip();
+            match out.last_mut() {
+                Some((series, rows))
+                    if series.name == name && series.tags == tags && series.columns == columns =>
+                {
+                    rows.push(values);
+                }
+                _ => out.push((
+                    Series {
+                        name,
+                        tags,
+                        columns,
+                        rows: Vec::new(),
+                    },
+                    vec![values],
+                )),
+            }
+        }
+
+        Ok(out
+            .into_iter()
+            .map(|(mut series, rows)| {
+                let types: Vec<ColumnType> = (0..series.columns.len())
+                    .map(|idx| {
+                        ColumnType::infer(
+                            rows.iter()
+                                .filter_map(|row| row.get(idx).map(String::as_str)),
+                        )
+                    })
+                    .collect();
+                series.rows = rows
+                    .into_iter()
+                    .map(|row| {
+                        row.into_iter()
+                            .zip(&types)
+                            .map(|(value, ty)| ty.parse(value))
+                            .collect()
+                    })
+                    .collect();
+                series
+            })
+            .collect())
+    }
+}
+
+///
+/// The CSV reader splits quoted fields correctly, but leaves the quotes in place.
+fn unquote(value: String) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(inner) => inner.replace("\"\"", "\""),
+        None => value,
+    }
+}
+
+///
+/// Parses the `tags` column of the CSV results, like `host=a,region=us\,west`.
+fn parse_tags(tags: &str) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+    let (mut key, mut value) = (String::new(), String::new());
+    let mut in_value = false;
+    let mut chars = tags.chars();
+    while let Some(c) = chars.next() {
+        let target = if in_value { &mut value } else { &mut key };
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    target.push(next);
+                }
+            }
+            '=' if !in_value => in_value = true,
+            ',' => {
+                if in_value {
+                    out.insert(core::mem::take(&mut key), core::mem::take(&mut value));
+                }
+                key.clear();
+                in_value = false;
+            }
+            c => target.push(c),
+        }
+    }
+    if in_value {
+        out.insert(key, value);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use irox_time::datetime::UTCDateTime;
+
+    use crate::error::{Error, ErrorType};
+    use crate::types::{FieldValue, LineProtocol, Series, Value};
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn line_protocol() -> Result<(), Error> {
+        let time = UTCDateTime::from_unix_nanos(1_700_000_000_123_456_789);
+        let mut point = LineProtocol::new("weather")
+            .with_tag("location", "us-midwest")
+            .with_tag("season", "summer")
+            .with_field("temperature", 82.5)
+            .with_field("humid", true)
+            .with_field("count", -3i64)
+            .with_field("note", "it's \"hot\"");
+        if let Some(time) = time {
+            point = point.with_timestamp(time);
+        }
+        assert_eq!(
+            "weather,location=us-midwest,season=summer count=-3i,humid=true,note=\"it's \\\"hot\\\"\",temperature=82.5 1700000000123456789",
+            point.to_line()?
+        );
+
+        let tags = BTreeMap::from([
+            ("host name".to_string(), "a,b=c".to_string()),
+            ("empty".to_string(), String::new()),
+        ]);
+        let fields = BTreeMap::from([
+            ("path=".to_string(), FieldValue::from("C:\\temp")),
+            ("one".to_string(), FieldValue::from(1.0)),
+        ]);
+        let point = LineProtocol::new("disk usage,all")
+            .with_tags(tags)
+            .with_fields(fields);
+        assert_eq!(
+            "disk\\ usage\\,all,host\\ name=a\\,b\\=c one=1,path\\==\"C:\\\\temp\"",
+            point.to_line()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn invalid_points() {
+        assert!(LineProtocol::new("cpu").to_line().is_err());
+        assert!(LineProtocol::new("")
+            .with_field("a", 1i64)
+            .to_line()
+            .is_err());
+        assert!(LineProtocol::new("cpu")
+            .with_field("a", f64::NAN)
+            .to_line()
+            .is_err());
+        for line_break in ["\n", "\r"] {
+            let name = format!("c{line_break}pu");
+            assert!(LineProtocol::new(name.as_str())
+                .with_field("a", 1i64)
+                .to_line()
+                .is_err());
+            assert!(LineProtocol::new("cpu")
+                .with_tag(name.as_str(), "a")
+                .with_field("a", 1i64)
+                .to_line()
+                .is_err());
+            assert!(LineProtocol::new("cpu")
+                .with_tag("host", name.as_str())
+                .with_field("a", 1i64)
+                .to_line()
+                .is_err());
+            assert!(LineProtocol::new("cpu")
+                .with_field(name.as_str(), 1i64)
+                .to_line()
+                .is_err());
+        }
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn parse_series() -> Result<(), Error> {
+        let csv = "name,tags,time,mean,host_up,note\n\
+            cpu,\"host=a,region=us\\,west\",1700000000000000000,0.5,true,ok\n\
+            cpu,\"host=a,region=us\\,west\",1700000060000000000,,false,\n\
+            cpu,host=b,1700000000000000000,2,true,12\n\
+            cpu,host=b,1700000060000000000,3,true,x\n";
+        let series = Series::parse_csv(csv.as_bytes())?;
+        let [first, second] = series.as_slice() else {
+            return Err(Error::new(ErrorType::NameKeyMismatch, "expected 2 series"));
+        };
+        assert_eq!("cpu", first.name());
+        assert_eq!(
+            &BTreeMap::from([
+                ("host".to_string(), "a".to_string()),
+                ("region".to_string(), "us,west".to_string()),
+            ]),
+            first.tags()
+        );
+        assert_eq!(["time", "mean", "host_up", "note"], first.columns());
+        assert_eq!(Some(1), first.column_index("mean"));
+        assert_eq!(
+            vec![
+                vec![
+                    Value::Integer(1_700_000_000_000_000_000),
+                    Value::Float(0.5),
+                    Value::Boolean(true),
+                    Value::String("ok".to_string()),
+                ],
+                vec![
+                    Value::Integer(1_700_000_060_000_000_000),
+                    Value::Null,
+                    Value::Boolean(false),
+                    Value::Null,
+                ],
+            ],
+            first.rows()
+        );
+
+        // types are inferred per series, whole-number floats look like integers.
+        assert_eq!(Some("b"), second.tags().get("host").map(String::as_str));
+        let values: Vec<&Value> = second.rows().iter().filter_map(|r| r.get(1)).collect();
+        assert_eq!(vec![&Value::Integer(2), &Value::Integer(3)], values);
+        let notes: Vec<&Value> = second.rows().iter().filter_map(|r| r.get(3)).collect();
+        assert_eq!(
+            vec![
+                &Value::String("12".to_string()),
+                &Value::String("x".to_string())
+            ],
+            notes
+        );
+
+        assert!(Series::parse_csv("\n".as_bytes())?.is_empty());
+        assert!(Series::parse_csv("time,value\n1,2\n".as_bytes()).is_err());
+        Ok(())
+    }
+}
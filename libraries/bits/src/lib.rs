@@ -35,6 +35,8 @@
 //! * `std`:
 //!     * Enables the implementation of [`Bits`] and [`MutBits`] on the types from the `std` crate:
 //!         * [`std::fs::File`]
+//!     * Provides the [`BitsWrapper`], [`ReadBits`] and [`WriteBits`] adapters, which implement
+//!       [`Bits`] and [`MutBits`] on any [`std::io::Read`] or [`std::io::Write`]
 //!     * Also enables the [`SeekRead`] and [`SeekWrite`] traits, which wrap and normalize:
 //!         * [`std::os::windows::fs::FileExt::seek_read`] and [`std::os::windows::fs::FileExt::seek_write`]
 //!         * [`std::os::unix::fs::FileExt:read_at`] and [`std::os::unix::fs::FileExt::write_at`]
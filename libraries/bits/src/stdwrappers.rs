@@ -5,6 +5,10 @@
 use crate::bits::Bits;
 use crate::mutbits::MutBits;
 use crate::Error;
+use std::io::{ErrorKind, Read, Write};
+
+/// Default size of the internal buffers of [`ReadBits`] and [`WriteBits`]
+pub const DEFAULT_BUFFER_SIZE: usize = 8192;
 
 ///
 /// Wraps a borrowed [`std::io::Read`] or [`std::io::Write`] and provides a basic implementation
@@ -33,3 +37,156 @@ where
         Ok(self.0.write_all(&[val])?)
     }
 }
+
+///
+/// Owns a [`std::io::Read`] and provides a buffered implementation of [`Bits`], so any std IO
+/// source (files, sockets, etc) can be handed to a [`Bits`]-based parser.  Reaching the end of
+/// the underlying reader returns `None` from [`Bits::next_u8`], and
+/// [`ErrorKind::UnexpectedEof`](crate::ErrorKind::UnexpectedEof) from the `read_*` methods.
+pub struct ReadBits<R> {
+    reader: R,
+    buf: Box<[u8]>,
+    /// Position of the next byte to be returned within the buffer
+    pos: usize,
+    /// Number of valid bytes in the buffer
+    filled: usize,
+}
+
+impl<R: Read> ReadBits<R> {
+    ///
+    /// Wraps the reader with a buffer of [`DEFAULT_BUFFER_SIZE`]
+    pub fn new(reader: R) -> Self {
+        Self::with_capacity(DEFAULT_BUFFER_SIZE, reader)
+    }
+
+    ///
+    /// Wraps the reader with a buffer of the specified size
+    pub fn with_capacity(capacity: usize, reader: R) -> Self {
+        ReadBits {
+            reader,
+            buf: vec![0; capacity.max(1)].into_boxed_slice(),
+            pos: 0,
+            filled: 0,
+        }
+    }
+
+    /// Returns a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns the underlying reader.  Any buffered data that hasn't been read is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Returns the unread buffered data, refilling from the reader if it's empty.  An empty
+    /// slice is returned at EOF.
+    fn fill_buf(&mut self) -> Result<&[u8], Error> {
+        if self.pos >= self.filled {
+            self.pos = 0;
+            self.filled = loop {
+                match self.reader.read(&mut self.buf) {
+                    Ok(read) => break read,
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    Err(e) => return Err(e.into()),
+                }
+            };
+        }
+        Ok(self.buf.get(self.pos..self.filled).unwrap_or_default())
+    }
+}
+
+impl<R: Read> Bits for ReadBits<R> {
+    fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+        let Some(val) = self.fill_buf()?.first().copied() else {
+            return Ok(None);
+        };
+        self.pos += 1;
+        Ok(Some(val))
+    }
+
+    fn read_some_into<T: MutBits>(&mut self, into: &mut T) -> Result<usize, Error> {
+        let wrote = into.write_some_bytes(self.fill_buf()?);
+        self.pos += wrote;
+        Ok(wrote)
+    }
+}
+
+///
+/// Owns a [`std::io::Write`] and provides a buffered implementation of [`MutBits`].  The buffer
+/// is written to the underlying writer when it's full, on [`WriteBits::flush`], and on drop -
+/// any errors while flushing on drop are ignored, so call [`WriteBits::flush`] to observe them.
+pub struct WriteBits<W: Write> {
+    writer: W,
+    buf: Vec<u8>,
+    capacity: usize,
+}
+
+impl<W: Write> WriteBits<W> {
+    ///
+    /// Wraps the writer with a buffer of [`DEFAULT_BUFFER_SIZE`]
+    pub fn new(writer: W) -> Self {
+        Self::with_capacity(DEFAULT_BUFFER_SIZE, writer)
+    }
+
+    ///
+    /// Wraps the writer with a buffer of the specified size
+    pub fn with_capacity(capacity: usize, writer: W) -> Self {
+        let capacity = capacity.max(1);
+        WriteBits {
+            writer,
+            buf: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    ///
+    /// Writes any buffered data to the underlying writer, and flushes it.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.flush_buf()?;
+        Ok(self.writer.flush()?)
+    }
+
+    fn flush_buf(&mut self) -> Result<(), Error> {
+        if !self.buf.is_empty() {
+            self.writer.write_all(&self.buf)?;
+            self.buf.clear();
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> MutBits for WriteBits<W> {
+    fn write_u8(&mut self, val: u8) -> Result<(), Error> {
+        if self.buf.len() >= self.capacity {
+            self.flush_buf()?;
+        }
+        self.buf.push(val);
+        Ok(())
+    }
+
+    fn write_all_bytes(&mut self, val: &[u8]) -> Result<(), Error> {
+        if self.buf.len() + val.len() > self.capacity {
+            self.flush_buf()?;
+        }
+        if val.len() >= self.capacity {
+            // too big to buffer, write it directly.
+            self.writer.write_all(val)?;
+        } else {
+            self.buf.extend_from_slice(val);
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for WriteBits<W> {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+
+//!
+//! Axis-aligned bounding boxes
+
+use irox_units::units::length::Length;
+
+use crate::coordinate::CartesianCoordinate;
+
+///
+/// A coordinate type that can be compared and combined axis-by-axis, and so used as the corners
+/// of a [`Bounds`].
+pub trait BoundedCoordinate: Copy {
+    /// Returns true if each axis of this coordinate is less than or equal to the same axis of
+    /// the other coordinate
+    fn all_axes_le(&self, other: &Self) -> bool;
+
+    /// Returns a coordinate made of the smaller of the two values on each axis
+    #[must_use]
+    fn min_axes(&self, other: &Self) -> Self;
+
+    /// Returns a coordinate made of the larger of the two values on each axis
+    #[must_use]
+    fn max_axes(&self, other: &Self) -> Self;
+}
+
+impl BoundedCoordinate for f64 {
+    fn all_axes_le(&self, other: &Self) -> bool {
+        self <= other
+    }
+
+    fn min_axes(&self, other: &Self) -> Self {
+        self.min(*other)
+    }
+
+    fn max_axes(&self, other: &Self) -> Self {
+        self.max(*other)
+    }
+}
+
+fn min_length(first: &Length, second: &Length) -> Length {
+    if second < first {
+        *second
+    } else {
+        *first
+    }
+}
+
+fn max_length(first: &Length, second: &Length) -> Length {
+    if second > first {
+        *second
+    } else {
+        *first
+    }
+}
+
+///
+/// Compares the X, Y and Z axes.  The combined coordinates only carry the axes, the altitude,
+/// uncertainties and timestamp are dropped.
+impl BoundedCoordinate for CartesianCoordinate {
+    fn all_axes_le(&self, other: &Self) -> bool {
+        self.get_x() <= other.get_x()
+            && self.get_y() <= other.get_y()
+            && self.get_z() <= other.get_z()
+    }
+
+    fn min_axes(&self, other: &Self) -> Self {
+        CartesianCoordinate::new(
+            min_length(self.get_x(), other.get_x()),
+            min_length(self.get_y(), other.get_y()),
+            min_length(self.get_z(), other.get_z()),
+        )
+    }
+
+    fn max_axes(&self, other: &Self) -> Self {
+        CartesianCoordinate::new(
+            max_length(self.get_x(), other.get_x()),
+            max_length(self.get_y(), other.get_y()),
+            max_length(self.get_z(), other.get_z()),
+        )
+    }
+}
+
+///
+/// An axis-aligned bounding box from the minimum corner to the maximum corner, inclusive of its
+/// edges.
+///
+/// # Example
+/// ```
+/// use irox_carto::bounds::Bounds;
+/// use irox_carto::coordinate::CartesianCoordinate;
+///
+/// let mut bounds = Bounds::new(
+///     CartesianCoordinate::new_meters(0., 0., 0.),
+///     CartesianCoordinate::new_meters(10., 10., 0.),
+/// );
+/// let point = CartesianCoordinate::new_meters(15., 5., 0.);
+/// assert!(!bounds.contains(&point));
+///
+/// bounds.expand_to_include(&point);
+/// assert!(bounds.contains(&point));
+/// assert_eq!(&CartesianCoordinate::new_meters(15., 10., 0.), bounds.max());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bounds<T> {
+    min: T,
+    max: T,
+}
+
+impl<T> Bounds<T> {
+    ///
+    /// Creates a new bounding box between the two corners.  Each axis of `min` should be less
+    /// than or equal to the same axis of `max`, otherwise the bounds are empty.
+    #[must_use]
+    pub const fn new(min: T, max: T) -> Bounds<T> {
+        Bounds { min, max }
+    }
+
+    /// Returns the minimum corner of the bounds
+    #[must_use]
+    pub const fn min(&self) -> &T {
+        &self.min
+    }
+
+    /// Returns the maximum corner of the bounds
+    #[must_use]
+    pub const fn max(&self) -> &T {
+        &self.max
+    }
+}
+
+impl<T: BoundedCoordinate> Bounds<T> {
+    ///
+    /// Creates the smallest bounding box containing both coordinates, in any order.
+    #[must_use]
+    pub fn from_corners(first: &T, second: &T) -> Bounds<T> {
+        Bounds::new(first.min_axes(second), first.max_axes(second))
+    }
+
+    /// Returns true if the coordinate is within (or on the edge of) the bounds
+    #[must_use]
+    pub fn contains(&self, coord: &T) -> bool {
+        self.min.all_axes_le(coord) && coord.all_axes_le(&self.max)
+    }
+
+    /// Returns true if the bounds overlap, including if they only share an edge
+    #[must_use]
+    pub fn intersects(&self, other: &Bounds<T>) -> bool {
+        self.min.all_axes_le(&other.max) && other.min.all_axes_le(&self.max)
+    }
+
+    /// Grows the bounds, if required, so that they contain the coordinate
+    pub fn expand_to_include(&mut self, coord: &T) {
+        self.min = self.min.min_axes(coord);
+        self.max = self.max.max_axes(coord);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounds::Bounds;
+    use crate::coordinate::CartesianCoordinate;
+
+    #[test]
+    pub fn cartesian() {
+        let point = |x: f64, y: f64| CartesianCoordinate::new_meters(x, y, 0.);
+        let mut bounds = Bounds::from_corners(&point(10., -5.), &point(-10., 5.));
+        assert_eq!(&point(-10., -5.), bounds.min());
+        assert_eq!(&point(10., 5.), bounds.max());
+
+        assert!(bounds.contains(&point(0., 0.)));
+        assert!(bounds.contains(&point(-10., 5.)));
+        assert!(!bounds.contains(&point(10.1, 0.)));
+        assert!(!bounds.contains(&CartesianCoordinate::new_meters(0., 0., 1.)));
+
+        assert!(bounds.intersects(&bounds));
+        assert!(bounds.intersects(&Bounds::new(point(5., 5.), point(20., 20.))));
+        assert!(bounds.intersects(&Bounds::new(point(10., -20.), point(20., -5.))));
+        assert!(bounds.intersects(&Bounds::new(point(-1., -1.), point(1., 1.))));
+        assert!(!bounds.intersects(&Bounds::new(point(11., 0.), point(20., 1.))));
+        assert!(!bounds.intersects(&Bounds::new(point(0., 6.), point(1., 7.))));
+
+        bounds.expand_to_include(&point(0., 0.));
+        assert_eq!(Bounds::new(point(-10., -5.), point(10., 5.)), bounds);
+        bounds.expand_to_include(&point(20., -8.));
+        assert_eq!(Bounds::new(point(-10., -8.), point(20., 5.)), bounds);
+        assert!(bounds.contains(&point(15., -6.)));
+    }
+
+    #[test]
+    pub fn scalar() {
+        let mut bounds = Bounds::new(1.0f64, 2.0);
+        assert!(bounds.contains(&1.5));
+        assert!(!bounds.contains(&2.5));
+        assert!(bounds.intersects(&Bounds::new(2.0, 3.0)));
+        assert!(!bounds.intersects(&Bounds::new(-1.0, 0.5)));
+        bounds.expand_to_include(&-1.0);
+        assert!(bounds.intersects(&Bounds::new(-1.0, 0.5)));
+    }
+}
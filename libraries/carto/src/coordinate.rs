@@ -315,17 +315,20 @@ impl Display for CartesianCoordinate {
 
 impl CartesianCoordinate {
     #[must_use]
-    pub fn new(x: Length, y: Length, z: Length) -> CartesianCoordinate {
+    pub const fn new(x: Length, y: Length, z: Length) -> CartesianCoordinate {
         CartesianCoordinate {
             x,
             y,
             z,
-            ..CartesianCoordinate::default()
+            altitude: None,
+            altitude_uncertainty: None,
+            position_uncertainty: None,
+            timestamp: None,
         }
     }
 
     #[must_use]
-    pub fn new_meters(x_meters: f64, y_meters: f64, z_meters: f64) -> CartesianCoordinate {
+    pub const fn new_meters(x_meters: f64, y_meters: f64, z_meters: f64) -> CartesianCoordinate {
         Self::new(
             Length::new_meters(x_meters),
             Length::new_meters(y_meters),
@@ -482,6 +485,64 @@ impl Display for PositionUncertainty {
     }
 }
 
+///
+/// A geographic bounding box, described by its north-west (upper left) and south-east (lower
+/// right) corners.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CoordinateBounds {
+    north_west: EllipticalCoordinate,
+    south_east: EllipticalCoordinate,
+}
+
+impl CoordinateBounds {
+    #[must_use]
+    pub const fn new(
+        north_west: EllipticalCoordinate,
+        south_east: EllipticalCoordinate,
+    ) -> CoordinateBounds {
+        CoordinateBounds {
+            north_west,
+            south_east,
+        }
+    }
+
+    /// Returns the north-west (upper left) corner of the bounds
+    #[must_use]
+    pub fn north_west(&self) -> &EllipticalCoordinate {
+        &self.north_west
+    }
+
+    /// Returns the south-east (lower right) corner of the bounds
+    #[must_use]
+    pub fn south_east(&self) -> &EllipticalCoordinate {
+        &self.south_east
+    }
+
+    /// Returns the northern-most latitude of the bounds
+    #[must_use]
+    pub fn north(&self) -> &Latitude {
+        self.north_west.get_latitude()
+    }
+
+    /// Returns the southern-most latitude of the bounds
+    #[must_use]
+    pub fn south(&self) -> &Latitude {
+        self.south_east.get_latitude()
+    }
+
+    /// Returns the western-most longitude of the bounds
+    #[must_use]
+    pub fn west(&self) -> &Longitude {
+        self.north_west.get_longitude()
+    }
+
+    /// Returns the eastern-most longitude of the bounds
+    #[must_use]
+    pub fn east(&self) -> &Longitude {
+        self.south_east.get_longitude()
+    }
+}
+
 ///
 /// A coordinate type that represents an Azimuth/Elevation look angle from a particular
 /// refernece point.
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+
+//!
+//! Earth-Centered, Earth-Fixed (ECEF) conversions, and the look angles (azimuth, elevation and
+//! slant range) from an observer to a target in the observer's local East-North-Up (ENU) frame.
+
+use irox_units::units::angle::Angle;
+use irox_units::units::length::Length;
+
+use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate};
+use crate::error::ConvertError;
+use crate::geo::ellipsoid::Ellipsoid;
+use crate::geo::standards::StandardShapes;
+use crate::geo::EllipticalShape;
+use crate::position_type::ECEFPosition;
+
+/// Returns the ellipsoid the coordinate is relative to.  The only supported EPSG datum is WGS84.
+fn ellipsoid(coord: &EllipticalCoordinate) -> Result<Ellipsoid, ConvertError> {
+    let shape = coord.get_reference_frame();
+    match shape {
+        EllipticalShape::Ellipse(ellipse) => Ok(Ellipsoid::from(*ellipse)),
+        EllipticalShape::EpsgDatum(_) if shape.is_wgs84() => {
+            Ok(StandardShapes::WGS84.as_ellipsoid())
+        }
+        EllipticalShape::EpsgDatum(code) => Err(ConvertError::InvalidValue(format!(
+            "Unsupported datum EPSG({code})"
+        ))),
+    }
+}
+
+impl EllipticalCoordinate {
+    ///
+    /// Converts this coordinate into Earth-Centered, Earth-Fixed cartesian coordinates.  The
+    /// altitude is taken as the height above the ellipsoid, and is required.
+    pub fn to_ecef(&self) -> Result<ECEFPosition, ConvertError> {
+        let Some(altitude) = self.get_altitude() else {
+            return Err(ConvertError::MissingValue(
+                "Altitude is required to convert to ECEF".to_string(),
+            ));
+        };
+        let shape = ellipsoid(self)?;
+        let a = shape.semi_major_axis_a().as_meters().value();
+        let e2 = shape.first_eccentricity_squared();
+        let h = altitude.value().as_meters().value();
+
+        let (sin_lat, cos_lat) = self.get_latitude().0.as_radians().value().sin_cos();
+        let (sin_lon, cos_lon) = self.get_longitude().0.as_radians().value().sin_cos();
+        let radius_prime_vertical = a / (1. - e2 * sin_lat * sin_lat).sqrt();
+
+        Ok(ECEFPosition(CartesianCoordinate::new_meters(
+            (radius_prime_vertical + h) * cos_lat * cos_lon,
+            (radius_prime_vertical + h) * cos_lat * sin_lon,
+            (radius_prime_vertical * (1. - e2) + h) * sin_lat,
+        )))
+    }
+
+    ///
+    /// Computes the look angles from this coordinate (the observer) to the target, returning
+    /// `(azimuth, elevation, slant_range)`.  The azimuth is clockwise from true north in
+    /// `[0, 360)` degrees, and the elevation is above the observer's local horizon plane in
+    /// `[-90, 90]` degrees - a target below the horizon has a negative elevation.  The slant range
+    /// is the straight-line distance between the two.
+    ///
+    /// Both coordinates must have an altitude, taken as the height above their ellipsoid.
+    pub fn look_angles_to(
+        &self,
+        target: &EllipticalCoordinate,
+    ) -> Result<(Angle, Angle, Length), ConvertError> {
+        let observer = self.to_ecef()?.0;
+        let target = target.to_ecef()?.0;
+        let dx = (*target.get_x() - *observer.get_x()).as_meters().value();
+        let dy = (*target.get_y() - *observer.get_y()).as_meters().value();
+        let dz = (*target.get_z() - *observer.get_z()).as_meters().value();
+
+        // rotate the ECEF delta into the observer's ENU frame.
+        let (sin_lat, cos_lat) = self.get_latitude().0.as_radians().value().sin_cos();
+        let (sin_lon, cos_lon) = self.get_longitude().0.as_radians().value().sin_cos();
+        let east = -sin_lon * dx + cos_lon * dy;
+        let north = -sin_lat * cos_lon * dx - sin_lat * sin_lon * dy + cos_lat * dz;
+        let up = cos_lat * cos_lon * dx + cos_lat * sin_lon * dy + sin_lat * dz;
+
+        let horizontal = east.hypot(north);
+        let azimuth = east.atan2(north).to_degrees().rem_euclid(360.);
+        let elevation = up.atan2(horizontal).to_degrees();
+        let slant_range = horizontal.hypot(up);
+
+        Ok((
+            Angle::new_degrees(azimuth),
+            Angle::new_degrees(elevation),
+            Length::new_meters(slant_range),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_tools::assert_eq_eps;
+    use irox_units::units::length::Length;
+
+    use crate::altitude::{Altitude, AltitudeReferenceFrame};
+    use crate::coordinate::EllipticalCoordinate;
+    use crate::error::ConvertError;
+
+    const GEO_ALTITUDE_METERS: f64 = 35_786_000.;
+
+    fn coord(lat: f64, lon: f64, alt: f64) -> EllipticalCoordinate {
+        EllipticalCoordinate::new_degrees_wgs84(lat, lon).with_altitude(Altitude::new(
+            Length::new_meters(alt),
+            AltitudeReferenceFrame::Ellipsoid,
+        ))
+    }
+
+    fn look(
+        observer: &EllipticalCoordinate,
+        target: &EllipticalCoordinate,
+    ) -> Result<(f64, f64, f64), ConvertError> {
+        let (az, el, range) = observer.look_angles_to(target)?;
+        Ok((
+            az.as_degrees().value(),
+            el.as_degrees().value(),
+            range.as_meters().value(),
+        ))
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn geostationary() -> Result<(), ConvertError> {
+        // directly overhead
+        let (_, el, range) = look(&coord(0., 0., 0.), &coord(0., 0., GEO_ALTITUDE_METERS))?;
+        assert_eq_eps!(90., el, 1e-9);
+        assert_eq_eps!(GEO_ALTITUDE_METERS, range, 1e-6);
+
+        // due south from the northern hemisphere
+        let (az, el, range) = look(&coord(40., 0., 0.), &coord(0., 0., GEO_ALTITUDE_METERS))?;
+        assert_eq_eps!(180., az, 1e-9);
+        assert_eq_eps!(43.755_924, el, 1e-6);
+        assert_eq_eps!(37_493_858.375, range, 1e-3);
+
+        // Washington DC to a satellite at 75°W
+        let (az, el, range) = look(
+            &coord(38.9, -77.0, 100.),
+            &coord(0., -75., GEO_ALTITUDE_METERS),
+        )?;
+        assert_eq_eps!(176.814_573, az, 1e-6);
+        assert_eq_eps!(44.937_984, el, 1e-6);
+        assert_eq_eps!(37_409_902.802, range, 1e-3);
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn below_horizon() -> Result<(), ConvertError> {
+        // along the equator the ellipsoid is a circle, the chord dips by half the central angle.
+        let (az, el, range) = look(&coord(0., 0., 0.), &coord(0., 1., 0.))?;
+        assert_eq_eps!(90., az, 1e-9);
+        assert_eq_eps!(-0.5, el, 1e-9);
+        assert_eq_eps!(2. * 6_378_137. * 0.5_f64.to_radians().sin(), range, 1e-6);
+
+        let (az, el, _) = look(&coord(0., 0., 0.), &coord(1., 0., 0.))?;
+        assert_eq_eps!(0., az, 1e-9);
+        assert!(el < 0.);
+
+        // straight through the earth
+        let (_, el, range) = look(&coord(0., 0., 0.), &coord(0., 180., 0.))?;
+        assert_eq_eps!(-90., el, 1e-9);
+        assert_eq_eps!(2. * 6_378_137., range, 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    pub fn requires_altitude() {
+        let observer = EllipticalCoordinate::new_degrees_wgs84(0., 0.);
+        let target = coord(0., 0., GEO_ALTITUDE_METERS);
+        assert!(observer.look_angles_to(&target).is_err());
+        assert!(target.look_angles_to(&observer).is_err());
+    }
+}
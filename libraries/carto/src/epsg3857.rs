@@ -8,7 +8,10 @@ use std::f64::consts::{PI, TAU};
 
 use irox_units::units::angle::{self, Angle};
 
-use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate, Latitude, Longitude};
+use crate::bounds::Bounds;
+use crate::coordinate::{
+    CartesianCoordinate, CoordinateBounds, EllipticalCoordinate, Latitude, Longitude,
+};
 use crate::geo::standards::wgs84::WGS84_SHAPE;
 use crate::geo::EllipticalShape;
 use crate::proj::Projection;
@@ -33,9 +36,16 @@ impl SphericalMercatorProjection {
         offset * max_tile
     }
 
+    ///
+    /// Returns the fractional tile row of the coordinate, `0` at the north edge of the map.  The
+    /// latitude is clamped to the mercator limit of +/- [`UPPER_LEFT_COORDINATE_Y`] degrees, so
+    /// coordinates nearer the poles (which project to infinity) return the top or bottom edge.
     #[must_use]
     pub fn tile_y_index(&self, coordinate: &EllipticalCoordinate) -> f64 {
-        let lat_rad = coordinate.get_latitude().0.as_radians().value();
+        let lat_deg = coordinate.get_latitude().0.as_degrees().value();
+        let lat_rad = lat_deg
+            .clamp(LOWER_LEFT_COORDINATE_Y, UPPER_LEFT_COORDINATE_Y)
+            .to_radians();
 
         let y = lat_rad.tan().asinh();
         let y = (1. - (y / PI)) / 2.;
@@ -55,10 +65,95 @@ impl SphericalMercatorProjection {
         Longitude(Angle::new_radians(offset * TAU - PI))
     }
 
+    ///
+    /// Returns the tile containing the coordinate, and the pixel within that
+    /// [`TILE_SIZE_PIXELS`]-square tile, as `(tile_x, tile_y, pixel_x, pixel_y)`.  Pixels are
+    /// counted from the north-west corner of the tile.  Coordinates on (or beyond) the eastern
+    /// and southern edges of the map are placed in the last pixel.
+    #[must_use]
+    pub fn to_tile_pixel(&self, coordinate: &EllipticalCoordinate) -> (u32, u32, u16, u16) {
+        let size = u64::from(TILE_SIZE_PIXELS);
+        let max_pixel = (self.max_tile_index() + 1) * size - 1;
+        let pixel = |tile_index: f64| {
+            let pixel = (tile_index * f64::from(TILE_SIZE_PIXELS)).floor().max(0.) as u64;
+            let pixel = pixel.min(max_pixel);
+            ((pixel / size) as u32, (pixel % size) as u16)
+        };
+        let (tile_x, pixel_x) = pixel(self.tile_x_index(coordinate));
+        let (tile_y, pixel_y) = pixel(self.tile_y_index(coordinate));
+        (tile_x, tile_y, pixel_x, pixel_y)
+    }
+
+    ///
+    /// Returns the coordinate at the center of the pixel within the tile, the inverse of
+    /// [`SphericalMercatorProjection::to_tile_pixel`].
+    #[must_use]
+    pub fn from_tile_pixel(
+        &self,
+        tile_x: u32,
+        tile_y: u32,
+        pixel_x: u16,
+        pixel_y: u16,
+    ) -> EllipticalCoordinate {
+        let index = |tile: u32, pixel: u16| {
+            f64::from(tile) + (f64::from(pixel) + 0.5) / f64::from(TILE_SIZE_PIXELS)
+        };
+        let lat = self.latitude(index(tile_y, pixel_y));
+        let lon = self.longitude(index(tile_x, pixel_x));
+        EllipticalCoordinate::new(lat, lon, WGS84_SHAPE)
+    }
+
     #[must_use]
     pub fn max_tile_index(&self) -> u64 {
         (1 << self.zoom_level) - 1
     }
+
+    ///
+    /// Returns the geographic extent of the tile `zoom/x/y`, from the north-west corner of the
+    /// tile to the south-east corner.  The latitudes of the top and bottom rows of tiles are
+    /// clamped to the mercator limit of +/- [`UPPER_LEFT_COORDINATE_Y`] degrees.
+    #[must_use]
+    pub fn tile_bounds(zoom: u8, x: u64, y: u64) -> CoordinateBounds {
+        let proj = SphericalMercatorProjection::new(zoom);
+        let lat = |tile_y: u64| {
+            let lat = proj.latitude(tile_y as f64).0.as_degrees().value();
+            Latitude(Angle::new_degrees(
+                lat.clamp(LOWER_LEFT_COORDINATE_Y, UPPER_LEFT_COORDINATE_Y),
+            ))
+        };
+        let north_west = EllipticalCoordinate::new(lat(y), proj.longitude(x as f64), WGS84_SHAPE);
+        let south_east =
+            EllipticalCoordinate::new(lat(y + 1), proj.longitude((x + 1) as f64), WGS84_SHAPE);
+        CoordinateBounds::new(north_west, south_east)
+    }
+
+    ///
+    /// Projects the coordinate to EPSG:3857 (Web Mercator) meters, the units of [`BOUNDS`], with
+    /// X increasing to the east and Y to the north from `(0, 0)` at the center of the map.  The
+    /// latitude is not clamped, coordinates beyond the mercator limit of +/-
+    /// [`UPPER_LEFT_COORDINATE_Y`] degrees project outside of [`BOUNDS`].
+    #[must_use]
+    pub fn to_web_mercator_meters(coordinate: &EllipticalCoordinate) -> CartesianCoordinate {
+        let lat = coordinate.get_latitude().0.as_radians().value();
+        let lon = coordinate.get_longitude().0.as_radians().value();
+        let x = WEB_MERCATOR_RADIUS_METERS * lon;
+        let y = WEB_MERCATOR_RADIUS_METERS * lat.tan().asinh();
+        CartesianCoordinate::new_meters(x, y, 0.)
+    }
+
+    ///
+    /// Converts EPSG:3857 (Web Mercator) meters back to a coordinate, the inverse of
+    /// [`SphericalMercatorProjection::to_web_mercator_meters`]
+    #[must_use]
+    pub fn from_web_mercator_meters(coordinate: &CartesianCoordinate) -> EllipticalCoordinate {
+        let x = coordinate.get_x().as_meters().value() / WEB_MERCATOR_RADIUS_METERS;
+        let y = coordinate.get_y().as_meters().value() / WEB_MERCATOR_RADIUS_METERS;
+        EllipticalCoordinate::new(
+            Latitude(Angle::new_radians(y.sinh().atan())),
+            Longitude(Angle::new_radians(x)),
+            WGS84_SHAPE,
+        )
+    }
 }
 
 impl Projection for SphericalMercatorProjection {
@@ -75,11 +170,19 @@ impl Projection for SphericalMercatorProjection {
     }
 
     fn project_to_elliptical(&self, coord: &CartesianCoordinate) -> EllipticalCoordinate {
-        let lat = self.latitude(coord.get_y().as_meters().value());
-        let lon = self.longitude(coord.get_x().as_meters().value());
+        let lat = self.latitude(coord.get_y().as_meters().value() / TILE_TO_PIXEL);
+        let lon = self.longitude(coord.get_x().as_meters().value() / TILE_TO_PIXEL);
 
         EllipticalCoordinate::new(lat, lon, WGS84_SHAPE)
     }
+
+    ///
+    /// Valid between the mercator latitude limits of +/- [`UPPER_LEFT_COORDINATE_Y`] degrees,
+    /// where the map becomes square.  The poles project to infinity.
+    fn is_valid_for(&self, coord: &EllipticalCoordinate) -> bool {
+        let lat = coord.get_latitude().0.as_degrees().value();
+        (LOWER_LEFT_COORDINATE_Y..=UPPER_LEFT_COORDINATE_Y).contains(&lat)
+    }
 }
 
 pub const UPPER_LEFT_COORDINATE_X: f64 = -180.0;
@@ -97,15 +200,44 @@ pub const LOWER_RIGHT_COORDINATE_Y: f64 = LOWER_LEFT_COORDINATE_Y;
 pub static CENTER_COORDS: EllipticalCoordinate =
     EllipticalCoordinate::new(Latitude(angle::ZERO), Longitude(angle::ZERO), WGS84_SHAPE);
 
-// pub const BOUNDS: Bounds<CartesianCoordinate> = Bounds::new()
-
-const TILE_TO_PIXEL: f64 = 40.743_665_431_525_21;
+/// Radius of the sphere EPSG:3857 projects onto, the WGS84 semi-major axis
+const WEB_MERCATOR_RADIUS_METERS: f64 = 6_378_137.0;
+
+/// Distance from the center of the map to each edge in EPSG:3857 meters, half the circumference
+/// of the WGS84 equator.  The mercator latitude limit of [`UPPER_LEFT_COORDINATE_Y`] degrees is
+/// where the map becomes square.
+pub const WORLD_EXTENT_METERS: f64 = PI * WEB_MERCATOR_RADIUS_METERS;
+
+///
+/// The whole map in EPSG:3857 (Web Mercator) meters, with X increasing to the east and Y to the
+/// north, as returned by [`SphericalMercatorProjection::to_web_mercator_meters`].  These are the
+/// projected coordinates used by tile servers and web maps - not the tile-scaled coordinates of
+/// [`SphericalMercatorProjection::project_to_cartesian`].
+pub const BOUNDS: Bounds<CartesianCoordinate> = Bounds::new(
+    CartesianCoordinate::new_meters(-WORLD_EXTENT_METERS, -WORLD_EXTENT_METERS, 0.),
+    CartesianCoordinate::new_meters(WORLD_EXTENT_METERS, WORLD_EXTENT_METERS, 0.),
+);
+
+/// Width and height of a map tile, in pixels.
+pub const TILE_SIZE_PIXELS: u16 = 256;
+
+/// Scale from the tile indices to the coordinates of
+/// [`SphericalMercatorProjection::project_to_cartesian`]: one tile is [`TILE_SIZE_PIXELS`] / 2π
+/// units across, so the whole map at zoom level `z` is `2^z * 256` pixels per 2π radians of
+/// longitude.
+const TILE_TO_PIXEL: f64 = TILE_SIZE_PIXELS as f64 / TAU;
 
 #[cfg(test)]
 mod test {
-    use crate::coordinate::EllipticalCoordinate;
+    use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate};
+    use crate::proj::Projection;
 
-    use super::SphericalMercatorProjection;
+    use super::{
+        SphericalMercatorProjection, BOUNDS, CENTER_COORDS, LOWER_LEFT_COORDINATE_X,
+        LOWER_LEFT_COORDINATE_Y, LOWER_RIGHT_COORDINATE_X, LOWER_RIGHT_COORDINATE_Y,
+        TILE_SIZE_PIXELS, TILE_TO_PIXEL, UPPER_LEFT_COORDINATE_X, UPPER_LEFT_COORDINATE_Y,
+        UPPER_RIGHT_COORDINATE_X, UPPER_RIGHT_COORDINATE_Y,
+    };
 
     #[test]
     pub fn test1() {
@@ -130,4 +262,206 @@ mod test {
         let invy = max_tile - 439 - 1;
         assert_eq!(invy, 584);
     }
+
+    #[test]
+    pub fn tile_bounds_world() {
+        let bounds = SphericalMercatorProjection::tile_bounds(0, 0, 0);
+        let north = bounds.north().0.as_degrees().value();
+        let south = bounds.south().0.as_degrees().value();
+        let west = bounds.west().0.as_degrees().value();
+        let east = bounds.east().0.as_degrees().value();
+        assert!((north - UPPER_LEFT_COORDINATE_Y).abs() < 1e-9);
+        assert!((south - LOWER_LEFT_COORDINATE_Y).abs() < 1e-9);
+        assert!((west - UPPER_LEFT_COORDINATE_X).abs() < 1e-9);
+        assert!((east - UPPER_RIGHT_COORDINATE_X).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn tile_bounds_adjacent_share_edges() {
+        let zoom = 10;
+        let (x, y) = (279, 439);
+        let tile = SphericalMercatorProjection::tile_bounds(zoom, x, y);
+        let east = SphericalMercatorProjection::tile_bounds(zoom, x + 1, y);
+        let south = SphericalMercatorProjection::tile_bounds(zoom, x, y + 1);
+
+        assert_eq!(tile.east(), east.west());
+        assert_eq!(tile.north(), east.north());
+        assert_eq!(tile.south(), south.north());
+        assert_eq!(tile.west(), south.west());
+
+        // the coordinate used in test2 lies within this tile
+        let coord = EllipticalCoordinate::new_degrees_wgs84(24.846_562, -81.914);
+        let lat = coord.get_latitude().0.as_degrees().value();
+        let lon = coord.get_longitude().0.as_degrees().value();
+        assert!(lat < tile.north().0.as_degrees().value());
+        assert!(lat > tile.south().0.as_degrees().value());
+        assert!(lon > tile.west().0.as_degrees().value());
+        assert!(lon < tile.east().0.as_degrees().value());
+    }
+
+    #[test]
+    pub fn round_trip_near_limits() {
+        let sm = SphericalMercatorProjection::new(10);
+        for lat in [
+            0.0,
+            45.0,
+            -60.0,
+            85.0,
+            -85.0,
+            UPPER_LEFT_COORDINATE_Y,
+            LOWER_LEFT_COORDINATE_Y,
+        ] {
+            for lon in [-180.0, -81.914, 0.0, 179.999] {
+                let coord = EllipticalCoordinate::new_degrees_wgs84(lat, lon);
+                assert!(sm.is_valid_for(&coord), "{lat},{lon} should be valid");
+                let error = sm.round_trip_error(&coord).as_meters().value();
+                assert!(error < 1e-6, "{lat},{lon} round trip error was {error}m");
+            }
+        }
+    }
+
+    #[test]
+    pub fn invalid_beyond_limits() {
+        let sm = SphericalMercatorProjection::new(10);
+        for lat in [
+            UPPER_LEFT_COORDINATE_Y + 1e-9,
+            LOWER_LEFT_COORDINATE_Y - 1e-9,
+            89.0,
+            -90.0,
+        ] {
+            let coord = EllipticalCoordinate::new_degrees_wgs84(lat, 0.0);
+            assert!(!sm.is_valid_for(&coord), "{lat} should be invalid");
+        }
+    }
+
+    #[test]
+    pub fn tile_y_near_poles() {
+        let sm = SphericalMercatorProjection::new(10);
+        let max_tile = f64::from(1 << 10);
+        let tile_y = |lat: f64| sm.tile_y_index(&EllipticalCoordinate::new_degrees_wgs84(lat, 0.0));
+
+        let mut previous = f64::INFINITY;
+        for lat in [85.0, 85.05, 89.0, 90.0] {
+            let y = tile_y(lat);
+            assert!(y.is_finite(), "{lat} was {y}");
+            assert!((0.0..=max_tile).contains(&y), "{lat} was {y}");
+            assert!(y <= previous, "{lat} was {y}, not moving north");
+            previous = y;
+        }
+        assert!(tile_y(85.05) < tile_y(85.0));
+        assert!(tile_y(89.0).abs() < 1e-9);
+        assert!(tile_y(90.0).abs() < 1e-9);
+        assert!((tile_y(-90.0) - max_tile).abs() < 1e-9);
+        assert!((tile_y(-89.0) - max_tile).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn tile_pixels() {
+        assert_eq!(40.743_665_431_525_21_f64.to_bits(), TILE_TO_PIXEL.to_bits());
+
+        let center = EllipticalCoordinate::new_degrees_wgs84(0.0, 0.0);
+        assert_eq!(
+            (0, 0, 128, 128),
+            SphericalMercatorProjection::new(0).to_tile_pixel(&center)
+        );
+        assert_eq!(
+            (1, 1, 0, 0),
+            SphericalMercatorProjection::new(1).to_tile_pixel(&center)
+        );
+        // the far corners of the map are in the last pixel
+        let sm = SphericalMercatorProjection::new(2);
+        let corner = EllipticalCoordinate::new_degrees_wgs84(-90.0, 180.0);
+        assert_eq!((3, 3, 255, 255), sm.to_tile_pixel(&corner));
+        let corner = EllipticalCoordinate::new_degrees_wgs84(90.0, -180.0);
+        assert_eq!((0, 0, 0, 0), sm.to_tile_pixel(&corner));
+
+        // same tile as test2
+        let coord = EllipticalCoordinate::new_degrees_wgs84(24.846_562, -81.914);
+        let (tx, ty, _, _) = SphericalMercatorProjection::new(10).to_tile_pixel(&coord);
+        assert_eq!((279, 439), (tx, ty));
+    }
+
+    #[test]
+    pub fn tile_pixel_round_trip() {
+        for zoom in [0, 3, 10, 18] {
+            let sm = SphericalMercatorProjection::new(zoom);
+            let pixels = f64::from(1 << zoom) * f64::from(TILE_SIZE_PIXELS);
+            // the size of a pixel in degrees of longitude, which is larger than in latitude.
+            let pixel_degrees = 360. / pixels;
+            for (lat, lon) in [
+                (0.0, 0.0),
+                (24.846_562, -81.914),
+                (-33.856_784, 151.215_297),
+                (51.477_928, -0.001_545),
+                (84.9, 179.9),
+                (-84.9, -179.9),
+            ] {
+                let coord = EllipticalCoordinate::new_degrees_wgs84(lat, lon);
+                let (tx, ty, px, py) = sm.to_tile_pixel(&coord);
+                let center = sm.from_tile_pixel(tx, ty, px, py);
+                assert_eq!(
+                    (tx, ty, px, py),
+                    sm.to_tile_pixel(&center),
+                    "{zoom}: {lat},{lon}"
+                );
+
+                let dlat = center.get_latitude().0.as_degrees().value() - lat;
+                let dlon = center.get_longitude().0.as_degrees().value() - lon;
+                assert!(dlat.abs() <= pixel_degrees, "{zoom}: {lat} off by {dlat}");
+                assert!(dlon.abs() <= pixel_degrees, "{zoom}: {lon} off by {dlon}");
+            }
+        }
+    }
+
+    #[test]
+    pub fn world_bounds() {
+        let meters = |lat: f64, lon: f64| {
+            SphericalMercatorProjection::to_web_mercator_meters(
+                &EllipticalCoordinate::new_degrees_wgs84(lat, lon),
+            )
+        };
+        let assert_near = |expected: &CartesianCoordinate, actual: &CartesianCoordinate| {
+            let dx = actual.get_x().as_meters().value() - expected.get_x().as_meters().value();
+            let dy = actual.get_y().as_meters().value() - expected.get_y().as_meters().value();
+            assert!(dx.abs() < 1e-6 && dy.abs() < 1e-6, "{expected} {actual}");
+        };
+        // the corner constants project onto the corners of the bounds.
+        assert_near(
+            &CartesianCoordinate::new_meters(
+                BOUNDS.min().get_x().as_meters().value(),
+                BOUNDS.max().get_y().as_meters().value(),
+                0.,
+            ),
+            &meters(UPPER_LEFT_COORDINATE_Y, UPPER_LEFT_COORDINATE_X),
+        );
+        assert_near(
+            &CartesianCoordinate::new_meters(
+                BOUNDS.max().get_x().as_meters().value(),
+                BOUNDS.min().get_y().as_meters().value(),
+                0.,
+            ),
+            &meters(LOWER_RIGHT_COORDINATE_Y, LOWER_RIGHT_COORDINATE_X),
+        );
+        assert_near(
+            BOUNDS.min(),
+            &meters(LOWER_LEFT_COORDINATE_Y, LOWER_LEFT_COORDINATE_X),
+        );
+        assert_near(
+            BOUNDS.max(),
+            &meters(UPPER_RIGHT_COORDINATE_Y, UPPER_RIGHT_COORDINATE_X),
+        );
+
+        let center = SphericalMercatorProjection::to_web_mercator_meters(&CENTER_COORDS);
+        assert_near(&CartesianCoordinate::new_meters(0., 0., 0.), &center);
+        assert!(BOUNDS.contains(&center));
+        assert!(BOUNDS.contains(&meters(51.5074, -0.1278)));
+        assert!(BOUNDS.contains(&meters(-85., 179.9)));
+        assert!(!BOUNDS.contains(&meters(86., 0.)));
+        assert!(!BOUNDS.contains(&meters(-86., 0.)));
+
+        let london = meters(51.5074, -0.1278);
+        let back = SphericalMercatorProjection::from_web_mercator_meters(&london);
+        assert!((back.get_latitude().0.as_degrees().value() - 51.5074).abs() < 1e-9);
+        assert!((back.get_longitude().0.as_degrees().value() + 0.1278).abs() < 1e-9);
+    }
 }
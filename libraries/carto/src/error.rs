@@ -12,12 +12,13 @@ use irox_enums::EnumName;
 #[derive(Debug, Clone, EnumName)]
 pub enum ConvertError {
     MissingValue(String),
+    InvalidValue(String),
 }
 
 impl ConvertError {
     fn error(&self) -> &String {
         match self {
-            ConvertError::MissingValue(e) => e,
+            ConvertError::MissingValue(e) | ConvertError::InvalidValue(e) => e,
         }
     }
 }
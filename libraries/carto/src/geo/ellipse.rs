@@ -71,35 +71,56 @@ impl Ellipse {
         self.name
     }
 
+    ///
+    /// Returns the semi-minor axis (b), the polar radius
     #[must_use]
     pub fn semi_minor_axis_b(&self) -> Length {
         self.semi_major_axis * (1.0 - self.flattening_f())
     }
 
+    ///
+    /// Returns the flattening (f), `(a - b) / a`.  Zero for a sphere.
     #[must_use]
     pub fn flattening_f(&self) -> f64 {
+        if self.inverse_flattening == 0.0 {
+            // spheres are defined with an inverse flattening of zero, not infinity.
+            return 0.0;
+        }
         1.0 / self.inverse_flattening
     }
 
+    ///
+    /// Returns e^2 - the first eccentricity squared, `(a^2 - b^2) / a^2`
     #[must_use]
     pub fn first_eccentricity_squared(&self) -> f64 {
         let f = self.flattening_f();
         f * (2.0 - f)
     }
 
+    /// Returns e - the first eccentricity
     #[must_use]
     pub fn first_eccentricity(&self) -> f64 {
         self.first_eccentricity_squared().sqrt()
     }
 
+    ///
+    /// Returns e'^2 - the second eccentricity (e prime) squared, `(a^2 - b^2) / b^2`
     #[must_use]
     pub fn second_eccentricity_squared(&self) -> f64 {
         self.first_eccentricity_squared() / (1.0 - self.first_eccentricity_squared())
     }
 
+    /// Returns e' - the second eccentricity (e prime)
     #[must_use]
     pub fn second_eccentricity(&self) -> f64 {
-        self.first_eccentricity_squared().sqrt()
+        self.second_eccentricity_squared().sqrt()
+    }
+
+    ///
+    /// Returns the IUGG mean radius (R1), `(2a + b) / 3`
+    #[must_use]
+    pub fn mean_radius(&self) -> Length {
+        (self.semi_major_axis * 2.0 + self.semi_minor_axis_b()) / 3.0
     }
 }
 
@@ -108,3 +129,98 @@ impl From<Ellipsoid> for Ellipse {
         Ellipse::new(value.semi_major_axis, value.inverse_flattening)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use irox_tools::assert_eq_eps;
+
+    use crate::geo::ellipse::Ellipse;
+    use crate::geo::ellipsoid::Ellipsoid;
+    use crate::geo::standards::grs80::GRS80_PARAMS;
+    use crate::geo::standards::wgs84::{WGS84_ELLIPSOID, WGS84_MEAN_RADIUS_PARAMS, WGS84_PARAMS};
+
+    #[test]
+    pub fn grs80_derived() {
+        // Moritz, H. (2000) "Geodetic Reference System 1980", Journal of Geodesy 74 (1)
+        let grs80 = GRS80_PARAMS;
+        assert_eq_eps!(
+            6_356_752.314_1,
+            grs80.semi_minor_axis_b().as_meters().value(),
+            1e-4
+        );
+        assert_eq_eps!(0.003_352_810_681, grs80.flattening_f(), 1e-12);
+        assert_eq_eps!(
+            0.006_694_380_022_90,
+            grs80.first_eccentricity_squared(),
+            1e-14
+        );
+        assert_eq_eps!(0.081_819_191_042_8, grs80.first_eccentricity(), 1e-12);
+        assert_eq_eps!(
+            0.006_739_496_775_48,
+            grs80.second_eccentricity_squared(),
+            1e-14
+        );
+        assert_eq_eps!(0.082_094_438_151_9, grs80.second_eccentricity(), 1e-12);
+        assert_eq_eps!(
+            6_371_008.771_4,
+            grs80.mean_radius().as_meters().value(),
+            1e-4
+        );
+    }
+
+    #[test]
+    pub fn wgs84_derived() {
+        let derived: Ellipsoid = WGS84_PARAMS.into();
+        let wgs84 = WGS84_ELLIPSOID;
+        assert_eq_eps!(
+            wgs84.semi_minor_axis_b().as_meters().value(),
+            derived.semi_minor_axis_b().as_meters().value(),
+            1e-3
+        );
+        assert_eq_eps!(
+            wgs84.first_eccentricity(),
+            derived.first_eccentricity(),
+            1e-12
+        );
+        assert_eq_eps!(
+            wgs84.first_eccentricity_squared(),
+            derived.first_eccentricity_squared(),
+            1e-13
+        );
+        assert_eq_eps!(
+            wgs84.second_eccentricity(),
+            derived.second_eccentricity(),
+            1e-12
+        );
+        assert_eq_eps!(
+            wgs84.second_eccentricity_sq(),
+            derived.second_eccentricity_sq(),
+            1e-13
+        );
+        assert_eq_eps!(
+            WGS84_MEAN_RADIUS_PARAMS
+                .semi_major_axis_a()
+                .as_meters()
+                .value(),
+            WGS84_PARAMS.mean_radius().as_meters().value(),
+            1e-3
+        );
+    }
+
+    #[test]
+    pub fn sphere() {
+        let sphere = Ellipse::new_sphere_meters(6_371_000.0);
+        assert_eq!(0.0f64.to_bits(), sphere.flattening_f().to_bits());
+        assert_eq!(
+            0.0f64.to_bits(),
+            sphere.first_eccentricity_squared().to_bits()
+        );
+        assert_eq!(0.0f64.to_bits(), sphere.second_eccentricity().to_bits());
+        assert_eq_eps!(
+            6_371_000.0,
+            sphere.semi_minor_axis_b().as_meters().value(),
+            1e-9
+        );
+        assert_eq_eps!(6_371_000.0, sphere.mean_radius().as_meters().value(), 1e-9);
+    }
+}
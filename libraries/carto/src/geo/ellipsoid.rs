@@ -8,8 +8,9 @@ use irox_units::units::angle::Angle;
 use irox_units::units::compass::{Azimuth, Compass, CompassReference, RotationDirection};
 use irox_units::units::length::Length;
 
-use crate::coordinate::Latitude;
+use crate::coordinate::{EllipticalCoordinate, Latitude, Longitude};
 use crate::geo::ellipse::Ellipse;
+use crate::geo::geodesic::{normalize_bearing, spherical_inverse, GeodesicSolution};
 use crate::geo::EllipticalShape;
 
 impl From<Ellipse> for Ellipsoid {
@@ -218,6 +219,191 @@ impl Ellipsoid {
         let offset = 1. - e2 / 6. - 5. / 72. * e4 - 55. / 1296. * e6;
         self.semi_major_axis * offset
     }
+
+    ///
+    /// Computes the geodesic (shortest path) distance along the surface of this ellipsoid between
+    /// the two coordinates, using Vincenty's inverse formula.  See [`Ellipsoid::geodesic_inverse`]
+    #[must_use]
+    pub fn geodesic_distance(
+        &self,
+        first: &EllipticalCoordinate,
+        second: &EllipticalCoordinate,
+    ) -> Length {
+        self.geodesic_inverse(first, second).distance()
+    }
+
+    ///
+    /// Solves the inverse geodesic problem between the two coordinates on this ellipsoid using
+    /// Vincenty's inverse formula, returning the distance and the initial and final bearings.
+    /// Vincenty's method may fail to converge for nearly antipodal points, in which case the
+    /// great-circle solution on a sphere of equal area is returned instead, and
+    /// [`GeodesicSolution::is_converged`] returns false.
+    /// Vincenty, T. (1975) "Direct and Inverse Solutions of Geodesics on the Ellipsoid with
+    /// application of nested equations", Survey Review 23 (176)
+    #[must_use]
+    pub fn geodesic_inverse(
+        &self,
+        first: &EllipticalCoordinate,
+        second: &EllipticalCoordinate,
+    ) -> GeodesicSolution {
+        let a = self.semi_major_axis.as_meters().value();
+        let b = self.semi_minor_axis.as_meters().value();
+        let f = self.flattening_f();
+
+        let phi1 = first.get_latitude().0.as_radians().value();
+        let phi2 = second.get_latitude().0.as_radians().value();
+        let delta_lon = (second.get_longitude().0 - first.get_longitude().0)
+            .as_radians()
+            .value();
+
+        let (sin_u1, cos_u1) = ((1. - f) * phi1.tan()).atan().sin_cos();
+        let (sin_u2, cos_u2) = ((1. - f) * phi2.tan()).atan().sin_cos();
+
+        let mut lambda = delta_lon;
+        for _ in 0..200 {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+            if sin_sigma == 0.0 {
+                // coincident points
+                return GeodesicSolution::new(
+                    Length::new_meters(0.0),
+                    Angle::new_degrees(0.0),
+                    Angle::new_degrees(0.0),
+                    true,
+                );
+            }
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos2_alpha = 1. - sin_alpha.powi(2);
+            let cos_2sigma_m = if cos2_alpha == 0.0 {
+                // equatorial line
+                0.0
+            } else {
+                cos_sigma - 2. * sin_u1 * sin_u2 / cos2_alpha
+            };
+            let c = f / 16. * cos2_alpha * (4. + f * (4. - 3. * cos2_alpha));
+            let prev_lambda = lambda;
+            lambda = delta_lon
+                + (1. - c)
+                    * f
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m + c * cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))));
+            if (lambda - prev_lambda).abs() < 1e-12 {
+                let u2 = cos2_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+                let big_a = 1. + u2 / 16384. * (4096. + u2 * (-768. + u2 * (320. - 175. * u2)));
+                let big_b = u2 / 1024. * (256. + u2 * (-128. + u2 * (74. - 47. * u2)));
+                let delta_sigma = big_b
+                    * sin_sigma
+                    * (cos_2sigma_m
+                        + big_b / 4.
+                            * (cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))
+                                - big_b / 6.
+                                    * cos_2sigma_m
+                                    * (-3. + 4. * sin_sigma.powi(2))
+                                    * (-3. + 4. * cos_2sigma_m.powi(2))));
+                let alpha1 =
+                    (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+                let alpha2 =
+                    (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+                return GeodesicSolution::new(
+                    Length::new_meters(b * big_a * (sigma - delta_sigma)),
+                    normalize_bearing(alpha1),
+                    normalize_bearing(alpha2),
+                    true,
+                );
+            }
+        }
+
+        // didn't converge, fall back to the great-circle solution.
+        let mut solution = spherical_inverse(
+            &self.spherical_radius_equal_area_approximation(),
+            first,
+            second,
+        );
+        solution.converged = false;
+        solution
+    }
+
+    ///
+    /// Solves the direct geodesic problem on this ellipsoid using Vincenty's direct formula,
+    /// returning the coordinate reached by travelling the distance along the geodesic leaving the
+    /// start coordinate at the initial bearing (clockwise from true north).  The returned
+    /// coordinate has the same reference frame as the start coordinate.
+    /// Vincenty, T. (1975) "Direct and Inverse Solutions of Geodesics on the Ellipsoid with
+    /// application of nested equations", Survey Review 23 (176)
+    #[must_use]
+    pub fn geodesic_direct(
+        &self,
+        start: &EllipticalCoordinate,
+        bearing: &Angle,
+        distance: &Length,
+    ) -> EllipticalCoordinate {
+        let a = self.semi_major_axis.as_meters().value();
+        let b = self.semi_minor_axis.as_meters().value();
+        let f = self.flattening_f();
+        let s = distance.as_meters().value();
+
+        let phi1 = start.get_latitude().0.as_radians().value();
+        let (sin_alpha1, cos_alpha1) = bearing.as_radians().value().sin_cos();
+
+        let tan_u1 = (1. - f) * phi1.tan();
+        let (sin_u1, cos_u1) = tan_u1.atan().sin_cos();
+        let sigma1 = tan_u1.atan2(cos_alpha1);
+        let sin_alpha = cos_u1 * sin_alpha1;
+        let cos2_alpha = 1. - sin_alpha.powi(2);
+        let u2 = cos2_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+        let big_a = 1. + u2 / 16384. * (4096. + u2 * (-768. + u2 * (320. - 175. * u2)));
+        let big_b = u2 / 1024. * (256. + u2 * (-128. + u2 * (74. - 47. * u2)));
+
+        let mut sigma = s / (b * big_a);
+        let mut cos_2sigma_m = (2. * sigma1 + sigma).cos();
+        for _ in 0..200 {
+            cos_2sigma_m = (2. * sigma1 + sigma).cos();
+            let sin_sigma = sigma.sin();
+            let cos_sigma = sigma.cos();
+            let delta_sigma = big_b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + big_b / 4.
+                        * (cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))
+                            - big_b / 6.
+                                * cos_2sigma_m
+                                * (-3. + 4. * sin_sigma.powi(2))
+                                * (-3. + 4. * cos_2sigma_m.powi(2))));
+            let prev_sigma = sigma;
+            sigma = s / (b * big_a) + delta_sigma;
+            if (sigma - prev_sigma).abs() < 1e-12 {
+                break;
+            }
+        }
+
+        let (sin_sigma, cos_sigma) = sigma.sin_cos();
+        let tmp = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+        let phi2 = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+            .atan2((1. - f) * (sin_alpha.powi(2) + tmp.powi(2)).sqrt());
+        let lambda =
+            (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+        let c = f / 16. * cos2_alpha * (4. + f * (4. - 3. * cos2_alpha));
+        let delta_lon = lambda
+            - (1. - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m + c * cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))));
+
+        let lon2 = start.get_longitude().0.as_degrees().value() + delta_lon.to_degrees();
+        EllipticalCoordinate::new(
+            Latitude(Angle::new_degrees(phi2.to_degrees())),
+            Longitude(Angle::new_degrees((lon2 + 180.).rem_euclid(360.) - 180.)),
+            *start.get_reference_frame(),
+        )
+    }
 }
 
 ///
@@ -360,3 +546,44 @@ impl MeridianCalculator for BesselMeridianCalculator {
         self.prefix * (a - b + c - d)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use irox_tools::assert_eq_eps;
+
+    use crate::coordinate::EllipticalCoordinate;
+    use crate::geo::standards::StandardShapes;
+
+    #[test]
+    pub fn vincenty_inverse() {
+        // Flinders Peak to Buninyong, from Vincenty's paper, on GRS80.
+        let ellipsoid = StandardShapes::GRS80.as_ellipsoid();
+        let flinders = EllipticalCoordinate::new_degrees_wgs84(
+            -(37. + 57. / 60. + 3.720_30 / 3600.),
+            144. + 25. / 60. + 29.524_40 / 3600.,
+        );
+        let buninyong = EllipticalCoordinate::new_degrees_wgs84(
+            -(37. + 39. / 60. + 10.156_10 / 3600.),
+            143. + 55. / 60. + 35.383_90 / 3600.,
+        );
+        let solution = ellipsoid.geodesic_inverse(&flinders, &buninyong);
+        assert_eq_eps!(54_972.271, solution.distance().as_meters().value(), 1e-3);
+        // 306°52'05.37", and the reverse azimuth of 127°10'25.07"
+        assert_eq_eps!(306.868_158, solution.initial_bearing().value(), 1e-5);
+        assert_eq_eps!(307.173_631, solution.final_bearing().value(), 1e-5);
+        let dist = ellipsoid.geodesic_distance(&buninyong, &flinders);
+        assert_eq_eps!(54_972.271, dist.as_meters().value(), 1e-3);
+
+        let dist = ellipsoid.geodesic_distance(&flinders, &flinders);
+        assert_eq_eps!(0.0, dist.as_meters().value(), 1e-12);
+
+        // nearly antipodal points fall back to the spherical distance
+        let wgs84 = StandardShapes::WGS84.as_ellipsoid();
+        let first = EllipticalCoordinate::new_degrees_wgs84(0., 0.);
+        let second = EllipticalCoordinate::new_degrees_wgs84(0.5, 179.7);
+        let solution = wgs84.geodesic_inverse(&first, &second);
+        assert!(!solution.is_converged());
+        let dist = solution.distance().as_meters().value();
+        assert!(dist > 19_900_000. && dist < 20_050_000., "{dist}");
+    }
+}
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+
+//!
+//! Distances and bearings between coordinates - great-circle (Haversine) solutions on a sphere,
+//! and geodesic (Vincenty) solutions on the WGS84 ellipsoid.
+
+use irox_units::units::angle::Angle;
+use irox_units::units::length::Length;
+
+use crate::coordinate::EllipticalCoordinate;
+use crate::geo::standards::wgs84::{WGS84_ELLIPSOID, WGS84_MEAN_RADIUS_PARAMS};
+
+///
+/// The solution of the inverse geodesic problem: the distance between two coordinates, and the
+/// bearings (clockwise from true north, 0 to 360 degrees) of the path at each end.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeodesicSolution {
+    distance: Length,
+    initial_bearing: Angle,
+    final_bearing: Angle,
+    pub(crate) converged: bool,
+}
+
+impl GeodesicSolution {
+    pub(crate) const fn new(
+        distance: Length,
+        initial_bearing: Angle,
+        final_bearing: Angle,
+        converged: bool,
+    ) -> GeodesicSolution {
+        GeodesicSolution {
+            distance,
+            initial_bearing,
+            final_bearing,
+            converged,
+        }
+    }
+
+    /// Returns the distance along the path between the coordinates
+    #[must_use]
+    pub const fn distance(&self) -> Length {
+        self.distance
+    }
+
+    /// Returns the bearing of the path leaving the first coordinate
+    #[must_use]
+    pub const fn initial_bearing(&self) -> Angle {
+        self.initial_bearing
+    }
+
+    /// Returns the bearing of the path arriving at the second coordinate
+    #[must_use]
+    pub const fn final_bearing(&self) -> Angle {
+        self.final_bearing
+    }
+
+    ///
+    /// Returns false if the ellipsoidal solution failed to converge (nearly antipodal points),
+    /// and this is the less accurate spherical solution instead.
+    #[must_use]
+    pub const fn is_converged(&self) -> bool {
+        self.converged
+    }
+}
+
+/// Converts a bearing in radians to degrees in the range 0 to 360
+pub(crate) fn normalize_bearing(radians: f64) -> Angle {
+    Angle::new_degrees(radians.to_degrees().rem_euclid(360.))
+}
+
+///
+/// Solves the inverse problem on a sphere of the provided radius with the Haversine formula.
+pub(crate) fn spherical_inverse(
+    radius: &Length,
+    first: &EllipticalCoordinate,
+    second: &EllipticalCoordinate,
+) -> GeodesicSolution {
+    let phi1 = first.get_latitude().0.as_radians().value();
+    let phi2 = second.get_latitude().0.as_radians().value();
+    let delta_lon = (second.get_longitude().0 - first.get_longitude().0)
+        .as_radians()
+        .value();
+    let (sin_phi1, cos_phi1) = phi1.sin_cos();
+    let (sin_phi2, cos_phi2) = phi2.sin_cos();
+    let (sin_dlon, cos_dlon) = delta_lon.sin_cos();
+
+    let hav =
+        ((phi2 - phi1) / 2.).sin().powi(2) + cos_phi1 * cos_phi2 * (delta_lon / 2.).sin().powi(2);
+    let central_angle = 2. * hav.sqrt().min(1.).asin();
+
+    let initial = (sin_dlon * cos_phi2).atan2(cos_phi1 * sin_phi2 - sin_phi1 * cos_phi2 * cos_dlon);
+    // the final bearing is the reverse of the initial bearing from the second coordinate.
+    let reverse =
+        (-sin_dlon * cos_phi1).atan2(cos_phi2 * sin_phi1 - sin_phi2 * cos_phi1 * cos_dlon);
+    GeodesicSolution::new(
+        *radius * central_angle,
+        normalize_bearing(initial),
+        normalize_bearing(reverse + core::f64::consts::PI),
+        true,
+    )
+}
+
+///
+/// Computes the great-circle distance between the two coordinates using the Haversine formula on
+/// a sphere of the WGS84 mean radius.  Fast, but in error by up to about 0.5% from the true
+/// (ellipsoidal) distance - see [`geodesic_distance`] for that.
+///
+/// # Example
+/// ```
+/// use irox_carto::coordinate::EllipticalCoordinate;
+/// use irox_carto::geo::geodesic::great_circle_distance;
+///
+/// let london = EllipticalCoordinate::new_degrees_wgs84(51.5074, -0.1278);
+/// let paris = EllipticalCoordinate::new_degrees_wgs84(48.8566, 2.3522);
+/// let dist = great_circle_distance(&london, &paris);
+/// assert_eq!(343, (dist.as_meters().value() / 1000.) as u32);
+/// ```
+#[must_use]
+pub fn great_circle_distance(
+    first: &EllipticalCoordinate,
+    second: &EllipticalCoordinate,
+) -> Length {
+    spherical_inverse(&WGS84_MEAN_RADIUS_PARAMS.semi_major_axis_a(), first, second).distance()
+}
+
+///
+/// Computes the geodesic distance and bearings between the two coordinates on the WGS84
+/// ellipsoid using Vincenty's inverse formula.  For nearly antipodal points, where Vincenty's
+/// method fails to converge, the spherical solution is returned and flagged with
+/// [`GeodesicSolution::is_converged`].  See [`crate::geo::ellipsoid::Ellipsoid::geodesic_inverse`]
+/// for other ellipsoids.
+#[must_use]
+pub fn geodesic_distance(
+    first: &EllipticalCoordinate,
+    second: &EllipticalCoordinate,
+) -> GeodesicSolution {
+    WGS84_ELLIPSOID.geodesic_inverse(first, second)
+}
+
+///
+/// Computes the coordinate reached by travelling the distance from the start coordinate along
+/// the geodesic leaving at the bearing (clockwise from true north) on the WGS84 ellipsoid, using
+/// Vincenty's direct formula.  See [`crate::geo::ellipsoid::Ellipsoid::geodesic_direct`] for
+/// other ellipsoids.
+#[must_use]
+pub fn destination(
+    start: &EllipticalCoordinate,
+    bearing: &Angle,
+    distance: &Length,
+) -> EllipticalCoordinate {
+    WGS84_ELLIPSOID.geodesic_direct(start, bearing, distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_tools::assert_eq_eps;
+    use irox_units::units::angle::Angle;
+    use irox_units::units::length::Length;
+
+    use crate::coordinate::EllipticalCoordinate;
+    use crate::geo::geodesic::{destination, geodesic_distance, great_circle_distance};
+    use crate::geo::standards::StandardShapes;
+
+    #[test]
+    pub fn city_pairs() {
+        let london = EllipticalCoordinate::new_degrees_wgs84(51.5074, -0.1278);
+        let paris = EllipticalCoordinate::new_degrees_wgs84(48.8566, 2.3522);
+        let new_york = EllipticalCoordinate::new_degrees_wgs84(40.7128, -74.0060);
+        let los_angeles = EllipticalCoordinate::new_degrees_wgs84(34.0522, -118.2437);
+        let jfk = EllipticalCoordinate::new_degrees_wgs84(40.6413, -73.7781);
+        let lhr = EllipticalCoordinate::new_degrees_wgs84(51.4700, -0.4543);
+
+        let dist = great_circle_distance(&london, &paris).as_meters().value();
+        assert_eq_eps!(343_556.53, dist, 1e-2);
+        let dist = great_circle_distance(&new_york, &los_angeles)
+            .as_meters()
+            .value();
+        assert_eq_eps!(3_935_751.67, dist, 1e-2);
+        let dist = great_circle_distance(&los_angeles, &new_york)
+            .as_meters()
+            .value();
+        assert_eq_eps!(3_935_751.67, dist, 1e-2);
+
+        let solution = geodesic_distance(&jfk, &lhr);
+        assert!(solution.is_converged());
+        assert_eq_eps!(5_554_908.79, solution.distance().as_meters().value(), 1e-2);
+        assert_eq_eps!(51.381_65, solution.initial_bearing().value(), 1e-5);
+        assert_eq_eps!(107.982_83, solution.final_bearing().value(), 1e-5);
+
+        // and the ellipsoidal distance is within 0.5% of the spherical one
+        let sphere = great_circle_distance(&jfk, &lhr).as_meters().value();
+        let ratio = solution.distance().as_meters().value() / sphere;
+        assert!((ratio - 1.).abs() < 0.005, "{ratio}");
+
+        let arrived = destination(&jfk, &solution.initial_bearing(), &solution.distance());
+        let lat = arrived.get_latitude().0.as_degrees().value();
+        let lon = arrived.get_longitude().0.as_degrees().value();
+        assert_eq_eps!(51.4700, lat, 1e-9);
+        assert_eq_eps!(-0.4543, lon, 1e-9);
+    }
+
+    #[test]
+    pub fn vincenty_direct() {
+        // Flinders Peak to Buninyong, from Vincenty's paper, on GRS80.
+        let ellipsoid = StandardShapes::GRS80.as_ellipsoid();
+        let flinders = EllipticalCoordinate::new_degrees_wgs84(
+            -(37. + 57. / 60. + 3.720_30 / 3600.),
+            144. + 25. / 60. + 29.524_40 / 3600.,
+        );
+        let bearing = Angle::new_degrees(306. + 52. / 60. + 5.37 / 3600.);
+        let buninyong =
+            ellipsoid.geodesic_direct(&flinders, &bearing, &Length::new_meters(54_972.271));
+        let lat = buninyong.get_latitude().0.as_degrees().value();
+        let lon = buninyong.get_longitude().0.as_degrees().value();
+        assert_eq_eps!(-(37. + 39. / 60. + 10.156_10 / 3600.), lat, 1e-7);
+        assert_eq_eps!(143. + 55. / 60. + 35.383_90 / 3600., lon, 1e-7);
+
+        // crosses the antimeridian
+        let start = EllipticalCoordinate::new_degrees_wgs84(0., 179.5);
+        let end = destination(
+            &start,
+            &Angle::new_degrees(90.),
+            &Length::new_meters(111_319.49),
+        );
+        assert_eq_eps!(-179.5, end.get_longitude().0.as_degrees().value(), 1e-6);
+        assert_eq_eps!(0., end.get_latitude().0.as_degrees().value(), 1e-9);
+    }
+}
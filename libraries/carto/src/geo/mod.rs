@@ -5,11 +5,14 @@
 //! Geodesy types and math, Ellipses, Ellipsoids, Elliptical Shapes
 
 use ellipse::Ellipse;
+pub use polygon::point_in_polygon;
 
 use crate::geo::standards::wgs84::{WGS84_EPSG_SHAPE, WGS84_SHAPE};
 
 pub mod ellipse;
 pub mod ellipsoid;
+pub mod geodesic;
+pub mod polygon;
 pub mod standards;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Containment tests for polygons of [`EllipticalCoordinate`]s
+
+use crate::coordinate::EllipticalCoordinate;
+
+/// Distance (in degrees) within which a point is considered to lie on an edge of the polygon
+const EDGE_TOLERANCE_DEG: f64 = 1e-9;
+
+///
+/// Returns true if the point lies within the polygon, using the ray-casting (even-odd) method.
+///
+/// The polygon is a series of vertices, implicitly closed from the last vertex back to the
+/// first (repeating the first vertex at the end is also fine).  Edges are treated as straight
+/// lines in latitude/longitude space, which is a good approximation for small polygons but will
+/// diverge from the true great-circle edges for large ones.  Polygons with fewer than 3 vertices
+/// contain no points.
+///
+/// * Points exactly on an edge or vertex (within ~1e-9 degrees) are considered *inside*.
+/// * Each edge is assumed to take the shorter way around the globe, so a polygon whose edges
+///   cross the antimeridian (+/-180 degrees longitude) is handled correctly, as long as no single
+///   edge spans more than 180 degrees of longitude.
+///
+/// # Example
+/// ```
+/// use irox_carto::coordinate::EllipticalCoordinate;
+/// use irox_carto::geo::point_in_polygon;
+///
+/// let square = [
+///     EllipticalCoordinate::new_degrees_wgs84(0.0, 0.0),
+///     EllipticalCoordinate::new_degrees_wgs84(0.0, 10.0),
+///     EllipticalCoordinate::new_degrees_wgs84(10.0, 10.0),
+///     EllipticalCoordinate::new_degrees_wgs84(10.0, 0.0),
+/// ];
+/// assert!(point_in_polygon(&EllipticalCoordinate::new_degrees_wgs84(5.0, 5.0), &square));
+/// assert!(!point_in_polygon(&EllipticalCoordinate::new_degrees_wgs84(5.0, 15.0), &square));
+/// ```
+#[must_use]
+pub fn point_in_polygon(point: &EllipticalCoordinate, polygon: &[EllipticalCoordinate]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+    let vertices = unwrapped_vertices(polygon);
+    let lat = point.get_latitude().0.as_degrees().value();
+    let lon = point.get_longitude().0.as_degrees().value();
+
+    // the unwrapped polygon may extend past +/-180, so check the point in each of those spaces.
+    [lon, lon + 360.0, lon - 360.0]
+        .into_iter()
+        .any(|lon| contains(&vertices, lon, lat))
+}
+
+///
+/// Converts the polygon into (lon, lat) degree pairs, shifting each longitude by a multiple of
+/// 360 such that no edge is longer than 180 degrees.
+fn unwrapped_vertices(polygon: &[EllipticalCoordinate]) -> Vec<(f64, f64)> {
+    let mut out: Vec<(f64, f64)> = Vec::with_capacity(polygon.len());
+    let mut prev_lon: Option<f64> = None;
+    for vertex in polygon {
+        let lat = vertex.get_latitude().0.as_degrees().value();
+        let mut lon = vertex.get_longitude().0.as_degrees().value();
+        if let Some(prev) = prev_lon {
+            lon = prev + (lon - prev + 180.0).rem_euclid(360.0) - 180.0;
+        }
+        prev_lon = Some(lon);
+        out.push((lon, lat));
+    }
+    out
+}
+
+/// Planar even-odd test, with points on an edge considered inside.
+fn contains(vertices: &[(f64, f64)], x: f64, y: f64) -> bool {
+    let mut inside = false;
+    let mut prev = vertices.last().copied().unwrap_or_default();
+    for &cur in vertices {
+        if on_segment(prev, cur, x, y) {
+            return true;
+        }
+        let ((x1, y1), (x2, y2)) = (prev, cur);
+        if (y1 > y) != (y2 > y) {
+            let cross_x = x1 + (y - y1) * (x2 - x1) / (y2 - y1);
+            if x < cross_x {
+                inside = !inside;
+            }
+        }
+        prev = cur;
+    }
+    inside
+}
+
+/// Returns true if the point lies on the segment from `a` to `b`
+fn on_segment(a: (f64, f64), b: (f64, f64), x: f64, y: f64) -> bool {
+    let ((x1, y1), (x2, y2)) = (a, b);
+    if x < x1.min(x2) - EDGE_TOLERANCE_DEG
+        || x > x1.max(x2) + EDGE_TOLERANCE_DEG
+        || y < y1.min(y2) - EDGE_TOLERANCE_DEG
+        || y > y1.max(y2) + EDGE_TOLERANCE_DEG
+    {
+        return false;
+    }
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let len = dx.hypot(dy);
+    if len < EDGE_TOLERANCE_DEG {
+        // degenerate edge, the bounds check above is sufficient.
+        return true;
+    }
+    // perpendicular distance from the line through a and b.
+    let dist = (dx * (y - y1) - dy * (x - x1)).abs() / len;
+    dist <= EDGE_TOLERANCE_DEG
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::EllipticalCoordinate;
+    use crate::geo::point_in_polygon;
+
+    fn poly(points: &[(f64, f64)]) -> Vec<EllipticalCoordinate> {
+        points
+            .iter()
+            .map(|(lat, lon)| EllipticalCoordinate::new_degrees_wgs84(*lat, *lon))
+            .collect()
+    }
+
+    fn pt(lat: f64, lon: f64) -> EllipticalCoordinate {
+        EllipticalCoordinate::new_degrees_wgs84(lat, lon)
+    }
+
+    #[test]
+    pub fn convex() {
+        let triangle = poly(&[(0.0, 0.0), (10.0, 5.0), (0.0, 10.0)]);
+        assert!(point_in_polygon(&pt(2.0, 5.0), &triangle));
+        assert!(point_in_polygon(&pt(1.0, 1.0), &triangle));
+        assert!(!point_in_polygon(&pt(9.0, 1.0), &triangle));
+        assert!(!point_in_polygon(&pt(-1.0, 5.0), &triangle));
+        assert!(!point_in_polygon(&pt(5.0, 11.0), &triangle));
+    }
+
+    #[test]
+    pub fn concave() {
+        // a "U" shape, open to the north.
+        let u = poly(&[
+            (0.0, 0.0),
+            (0.0, 30.0),
+            (30.0, 30.0),
+            (30.0, 20.0),
+            (10.0, 20.0),
+            (10.0, 10.0),
+            (30.0, 10.0),
+            (30.0, 0.0),
+        ]);
+        assert!(point_in_polygon(&pt(5.0, 15.0), &u));
+        assert!(point_in_polygon(&pt(20.0, 5.0), &u));
+        assert!(point_in_polygon(&pt(20.0, 25.0), &u));
+        // inside the notch of the U
+        assert!(!point_in_polygon(&pt(20.0, 15.0), &u));
+        assert!(!point_in_polygon(&pt(35.0, 15.0), &u));
+        // on the same latitude as a horizontal edge, but outside
+        assert!(!point_in_polygon(&pt(10.0, 35.0), &u));
+    }
+
+    #[test]
+    pub fn boundary() {
+        let square = poly(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)]);
+        // edges
+        assert!(point_in_polygon(&pt(0.0, 5.0), &square));
+        assert!(point_in_polygon(&pt(5.0, 10.0), &square));
+        // vertex
+        assert!(point_in_polygon(&pt(10.0, 10.0), &square));
+        // just outside
+        assert!(!point_in_polygon(&pt(5.0, 10.000_001), &square));
+        // closed polygons (repeated first vertex) behave the same
+        let closed = poly(&[
+            (0.0, 0.0),
+            (0.0, 10.0),
+            (10.0, 10.0),
+            (10.0, 0.0),
+            (0.0, 0.0),
+        ]);
+        assert!(point_in_polygon(&pt(5.0, 5.0), &closed));
+        assert!(point_in_polygon(&pt(0.0, 5.0), &closed));
+    }
+
+    #[test]
+    pub fn antimeridian() {
+        let fiji = poly(&[
+            (-15.0, 175.0),
+            (-15.0, -175.0),
+            (-20.0, -175.0),
+            (-20.0, 175.0),
+        ]);
+        assert!(point_in_polygon(&pt(-17.0, 179.0), &fiji));
+        assert!(point_in_polygon(&pt(-17.0, -179.0), &fiji));
+        assert!(point_in_polygon(&pt(-17.0, 180.0), &fiji));
+        assert!(!point_in_polygon(&pt(-17.0, 170.0), &fiji));
+        assert!(!point_in_polygon(&pt(-17.0, -170.0), &fiji));
+        assert!(!point_in_polygon(&pt(-17.0, 0.0), &fiji));
+    }
+
+    #[test]
+    pub fn degenerate() {
+        let line = poly(&[(0.0, 0.0), (10.0, 10.0)]);
+        assert!(!point_in_polygon(&pt(5.0, 5.0), &line));
+        assert!(!point_in_polygon(&pt(5.0, 5.0), &[]));
+    }
+}
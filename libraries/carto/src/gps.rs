@@ -90,11 +90,89 @@ pub struct DOPs {
     pub vertical: Option<DilutionOfPrecision>,
 }
 
+///
+/// Qualitative rating of a Dilution of Precision value, using the common buckets:
+///
+/// | DOP         | Rating    |
+/// |-------------|-----------|
+/// | < 1         | Ideal     |
+/// | 1 to 2      | Excellent |
+/// | 2 to 5      | Good      |
+/// | 5 to 10     | Moderate  |
+/// | 10 to 20    | Fair      |
+/// | > 20        | Poor      |
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum DopRating {
+    /// No (valid) DOP value was available
+    #[default]
+    Unknown,
+    Ideal,
+    Excellent,
+    Good,
+    Moderate,
+    Fair,
+    Poor,
+}
+
+impl From<DilutionOfPrecision> for DopRating {
+    fn from(value: DilutionOfPrecision) -> Self {
+        match value.0 {
+            v if v.is_nan() || v < 0. => DopRating::Unknown,
+            v if v < 1. => DopRating::Ideal,
+            v if v <= 2. => DopRating::Excellent,
+            v if v <= 5. => DopRating::Good,
+            v if v <= 10. => DopRating::Moderate,
+            v if v <= 20. => DopRating::Fair,
+            _ => DopRating::Poor,
+        }
+    }
+}
+
+impl Display for DopRating {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DopRating::Unknown => "Unknown",
+            DopRating::Ideal => "Ideal",
+            DopRating::Excellent => "Excellent",
+            DopRating::Good => "Good",
+            DopRating::Moderate => "Moderate",
+            DopRating::Fair => "Fair",
+            DopRating::Poor => "Poor",
+        };
+        f.write_str(name)
+    }
+}
+
 impl DOPs {
     #[must_use]
     pub fn new() -> DOPs {
         Default::default()
     }
+
+    ///
+    /// Rates the overall quality of the fix.  Keys off the position DOP (PDOP) if present,
+    /// otherwise the horizontal DOP (HDOP) - returns [`DopRating::Unknown`] if neither is present.
+    #[must_use]
+    pub fn rating(&self) -> DopRating {
+        self.position
+            .or(self.horizontal)
+            .map(DopRating::from)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns a copy of these DOPs, with any missing values filled in from `other`.  Values
+    /// present in `self` take precedence.
+    #[must_use]
+    pub fn merge(&self, other: &DOPs) -> DOPs {
+        DOPs {
+            geometric: self.geometric.or(other.geometric),
+            horizontal: self.horizontal.or(other.horizontal),
+            position: self.position.or(other.position),
+            time: self.time.or(other.time),
+            vertical: self.vertical.or(other.vertical),
+        }
+    }
 }
 
 impl Display for DOPs {
@@ -147,3 +225,62 @@ pub mod windows {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::gps::{DOPs, DilutionOfPrecision, DopRating};
+
+    #[test]
+    pub fn rating() {
+        for (dop, rating) in [
+            (0.8, DopRating::Ideal),
+            (1.0, DopRating::Excellent),
+            (2.0, DopRating::Excellent),
+            (3.5, DopRating::Good),
+            (7.0, DopRating::Moderate),
+            (15.0, DopRating::Fair),
+            (20.1, DopRating::Poor),
+            (f64::NAN, DopRating::Unknown),
+        ] {
+            assert_eq!(rating, DilutionOfPrecision(dop).into(), "{dop}");
+        }
+
+        let mut dops = DOPs::new();
+        assert_eq!(DopRating::Unknown, dops.rating());
+        dops.vertical = Some(DilutionOfPrecision(1.5));
+        assert_eq!(DopRating::Unknown, dops.rating());
+        dops.horizontal = Some(DilutionOfPrecision(1.5));
+        assert_eq!(DopRating::Excellent, dops.rating());
+        dops.position = Some(DilutionOfPrecision(6.));
+        assert_eq!(DopRating::Moderate, dops.rating());
+    }
+
+    #[test]
+    pub fn merge() {
+        let gsa = DOPs {
+            horizontal: Some(DilutionOfPrecision(1.2)),
+            position: Some(DilutionOfPrecision(2.1)),
+            vertical: Some(DilutionOfPrecision(1.7)),
+            ..Default::default()
+        };
+        let vendor = DOPs {
+            geometric: Some(DilutionOfPrecision(2.5)),
+            horizontal: Some(DilutionOfPrecision(1.0)),
+            time: Some(DilutionOfPrecision(1.1)),
+            ..Default::default()
+        };
+        let merged = gsa.merge(&vendor);
+        assert_eq!(
+            DOPs {
+                geometric: Some(DilutionOfPrecision(2.5)),
+                horizontal: Some(DilutionOfPrecision(1.2)),
+                position: Some(DilutionOfPrecision(2.1)),
+                time: Some(DilutionOfPrecision(1.1)),
+                vertical: Some(DilutionOfPrecision(1.7)),
+            },
+            merged
+        );
+        assert_eq!(gsa, gsa.merge(&DOPs::new()));
+        assert_eq!(vendor, DOPs::new().merge(&vendor));
+    }
+}
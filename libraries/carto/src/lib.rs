@@ -9,14 +9,18 @@
 pub use irox_units;
 
 pub mod altitude;
+pub mod bounds;
 pub mod coordinate;
+pub mod ecef;
 pub mod epsg3857;
 pub mod error;
 pub mod geo;
 pub mod gps;
+pub mod mgrs;
 pub mod position_type;
 pub mod proj;
 pub mod tm;
+pub mod utm;
 
 /// ISO 3166-1 Country Codes
 pub mod countrycodes {
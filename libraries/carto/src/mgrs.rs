@@ -0,0 +1,468 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+
+//!
+//! Military Grid Reference System (MGRS) and US National Grid (USNG) references, like
+//! `18SUJ2348306479`.  Between 80°S and 84°N, references are on the Universal Transverse Mercator
+//! (UTM) grid, and around the poles, on the Universal Polar Stereographic (UPS) grid.
+
+use std::f64::consts::FRAC_PI_2;
+
+use irox_units::units::length::Length;
+
+use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate};
+use crate::error::ConvertError;
+use crate::geo::ellipsoid::Ellipsoid;
+use crate::geo::standards::StandardShapes;
+use crate::proj::Projection;
+use crate::utm::{utm_zone, Hemisphere, UTMProjection, UTM_MAX_LATITUDE_DEG, UTM_MIN_LATITUDE_DEG};
+
+/// Latitude band letters, 8 degrees each, starting at 80°S.  Band X is 12 degrees.
+const BANDS: &[u8] = b"CDEFGHJKLMNPQRSTUVWX";
+/// 100km square column letters, the set used repeats every three UTM zones
+const COLUMN_SETS: [&[u8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+/// 100km square row letters, repeating every 2,000km.  Even UTM zones start at `F`.
+const ROWS: &[u8] = b"ABCDEFGHJKLMNPQRSTUV";
+
+/// UPS zone letters, (south-west, south-east, north-west, north-east)
+const UPS_BANDS: &[u8] = b"ABYZ";
+/// UPS 100km square column letters, indexed like [`UPS_BANDS`]
+const UPS_COLUMNS: [&[u8]; 4] = [b"JKLPQRSTUXYZ", b"ABCFGHJKLPQR", b"RSTUXYZ", b"ABCFGHJ"];
+/// UPS 100km square row letters in the south zone
+const UPS_SOUTH_ROWS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ";
+/// UPS 100km square row letters in the north zone
+const UPS_NORTH_ROWS: &[u8] = b"ABCDEFGHJKLMNP";
+/// The first UPS 100km column/row index in the south zone
+const UPS_SOUTH_MIN_INDEX: i64 = 8;
+/// The first UPS 100km column/row index in the north zone
+const UPS_NORTH_MIN_INDEX: i64 = 13;
+/// The UPS 100km column index of the east half of the zone
+const UPS_EAST_INDEX: i64 = 20;
+
+const GRID_SQUARE_METERS: f64 = 100_000.;
+const UPS_SCALE_FACTOR: f64 = 0.994;
+const UPS_FALSE_ORIGIN_METERS: f64 = 2_000_000.;
+
+///
+/// The precision of the easting and northing of a MGRS reference, the size of the grid square it
+/// refers to.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum MGRSPrecision {
+    /// 1 digit each of easting and northing
+    TenKilometers,
+    /// 2 digits each of easting and northing
+    Kilometer,
+    /// 3 digits each of easting and northing
+    HundredMeters,
+    /// 4 digits each of easting and northing
+    TenMeters,
+    /// 5 digits each of easting and northing
+    #[default]
+    Meter,
+}
+
+impl MGRSPrecision {
+    /// Returns the number of digits used for each of the easting and northing
+    #[must_use]
+    pub const fn digits(&self) -> usize {
+        match self {
+            MGRSPrecision::TenKilometers => 1,
+            MGRSPrecision::Kilometer => 2,
+            MGRSPrecision::HundredMeters => 3,
+            MGRSPrecision::TenMeters => 4,
+            MGRSPrecision::Meter => 5,
+        }
+    }
+
+    /// Returns the size of the grid square at this precision
+    #[must_use]
+    pub fn size(&self) -> Length {
+        Length::new_meters(self.meters())
+    }
+
+    fn meters(self) -> f64 {
+        10_f64.powi(5 - self.digits() as i32)
+    }
+
+    const fn from_digits(digits: usize) -> Option<MGRSPrecision> {
+        Some(match digits {
+            1 => MGRSPrecision::TenKilometers,
+            2 => MGRSPrecision::Kilometer,
+            3 => MGRSPrecision::HundredMeters,
+            4 => MGRSPrecision::TenMeters,
+            5 => MGRSPrecision::Meter,
+            _ => return None,
+        })
+    }
+}
+
+/// Returns `sqrt((1+e)^(1+e) * (1-e)^(1-e))`, the polar stereographic scaling term
+fn ups_scale_term(shape: &Ellipsoid) -> f64 {
+    let e = shape.first_eccentricity;
+    ((1. + e).powf(1. + e) * (1. - e).powf(1. - e)).sqrt()
+}
+
+/// Projects into UPS (easting, northing) meters, as per Snyder (21-33) to (21-38)
+fn ups_forward(lat: f64, lon: f64) -> (f64, f64) {
+    let shape = StandardShapes::WGS84.as_ellipsoid();
+    let e = shape.first_eccentricity;
+    let a = shape.semi_major_axis.as_meters().value();
+    let phi = lat.abs().to_radians();
+    let lam = lon.to_radians();
+    let esin = e * phi.sin();
+    let t = (FRAC_PI_2 / 2. - phi / 2.).tan() / ((1. - esin) / (1. + esin)).powf(e / 2.);
+    let rho = 2. * a * UPS_SCALE_FACTOR * t / ups_scale_term(&shape);
+    let easting = UPS_FALSE_ORIGIN_METERS + rho * lam.sin();
+    let northing = if lat >= 0. {
+        UPS_FALSE_ORIGIN_METERS - rho * lam.cos()
+    } else {
+        UPS_FALSE_ORIGIN_METERS + rho * lam.cos()
+    };
+    (easting, northing)
+}
+
+/// Projects UPS (easting, northing) meters back into (lat, lon) degrees
+fn ups_inverse(north: bool, easting: f64, northing: f64) -> (f64, f64) {
+    let shape = StandardShapes::WGS84.as_ellipsoid();
+    let e = shape.first_eccentricity;
+    let a = shape.semi_major_axis.as_meters().value();
+    let dx = easting - UPS_FALSE_ORIGIN_METERS;
+    let dy = northing - UPS_FALSE_ORIGIN_METERS;
+    let rho = dx.hypot(dy);
+    let t = rho * ups_scale_term(&shape) / (2. * a * UPS_SCALE_FACTOR);
+    let mut phi = FRAC_PI_2 - 2. * t.atan();
+    for _ in 0..20 {
+        let esin = e * phi.sin();
+        let next = FRAC_PI_2 - 2. * (t * ((1. - esin) / (1. + esin)).powf(e / 2.)).atan();
+        let done = (next - phi).abs() < 1e-14;
+        phi = next;
+        if done {
+            break;
+        }
+    }
+    if north {
+        (phi.to_degrees(), dx.atan2(-dy).to_degrees())
+    } else {
+        (-phi.to_degrees(), dx.atan2(dy).to_degrees())
+    }
+}
+
+/// Returns the letter at the index, or `?` if it's out of range
+fn letter(letters: &[u8], index: i64) -> char {
+    usize::try_from(index)
+        .ok()
+        .and_then(|i| letters.get(i))
+        .map_or('?', |l| char::from(*l))
+}
+
+/// Returns the index of the letter, if it's in the set.
+fn index_of(letters: &[u8], letter: u8) -> Option<i64> {
+    letters
+        .iter()
+        .position(|l| *l == letter)
+        .and_then(|i| i64::try_from(i).ok())
+}
+
+/// Returns the (rows, min index) of the north or south UPS zone
+fn ups_rows(north: bool) -> (&'static [u8], i64) {
+    if north {
+        (UPS_NORTH_ROWS, UPS_NORTH_MIN_INDEX)
+    } else {
+        (UPS_SOUTH_ROWS, UPS_SOUTH_MIN_INDEX)
+    }
+}
+
+fn invalid(reference: &str, reason: &str) -> ConvertError {
+    ConvertError::InvalidValue(format!("Invalid MGRS reference '{reference}': {reason}"))
+}
+
+impl EllipticalCoordinate {
+    ///
+    /// Converts this coordinate into a MGRS reference, like `18SUJ2348306479`, truncating the
+    /// easting and northing to the precision.  The reference is of the south-west corner of the
+    /// grid square containing the coordinate.  The coordinate is assumed to be WGS84 - no datum
+    /// transformation is performed.
+    #[must_use]
+    pub fn to_mgrs(&self, precision: MGRSPrecision) -> String {
+        let lat = self.get_latitude().0.as_degrees().value().clamp(-90., 90.);
+        let lon = (self.get_longitude().0.as_degrees().value() + 180.).rem_euclid(360.) - 180.;
+
+        let (zone, easting, northing) = if (UTM_MIN_LATITUDE_DEG..UTM_MAX_LATITUDE_DEG)
+            .contains(&lat)
+        {
+            let zone = utm_zone(lat, lon);
+            let coord = EllipticalCoordinate::new_degrees_wgs84(lat, lon);
+            let hemisphere = if lat < 0. {
+                Hemisphere::Southern
+            } else {
+                Hemisphere::Northern
+            };
+            let projected = UTMProjection::for_zone(zone, hemisphere).project_to_cartesian(&coord);
+            let easting = projected.get_x().as_meters().value();
+            let northing = projected.get_y().as_meters().value();
+
+            // band X is extended up to 84°N
+            let band = letter(BANDS, (((lat + 80.) / 8.).floor() as i64).min(19));
+            let columns = COLUMN_SETS
+                .get(usize::from((zone - 1) % 3))
+                .copied()
+                .unwrap_or_default();
+            let column = letter(columns, (easting / GRID_SQUARE_METERS).floor() as i64 - 1);
+            let row_offset = if zone % 2 == 0 { 5 } else { 0 };
+            let row_index = (northing / GRID_SQUARE_METERS).floor() as i64 + row_offset;
+            let row = letter(ROWS, row_index.rem_euclid(20));
+            (format!("{zone}{band}{column}{row}"), easting, northing)
+        } else {
+            let north = lat > 0.;
+            let (easting, northing) = ups_forward(lat, lon);
+            let column_index = (easting / GRID_SQUARE_METERS).floor() as i64;
+            let row_index = (northing / GRID_SQUARE_METERS).floor() as i64;
+            let east = column_index >= UPS_EAST_INDEX;
+            let quadrant = usize::from(east) + if north { 2 } else { 0 };
+            let (rows, min_index) = ups_rows(north);
+
+            let band = letter(UPS_BANDS, quadrant as i64);
+            let columns = UPS_COLUMNS.get(quadrant).copied().unwrap_or_default();
+            let column = if east {
+                letter(columns, column_index - UPS_EAST_INDEX)
+            } else {
+                letter(columns, column_index - min_index)
+            };
+            let row = letter(rows, row_index - min_index);
+            (format!("{band}{column}{row}"), easting, northing)
+        };
+
+        let digits = precision.digits();
+        let size = precision.meters();
+        let easting = (easting.rem_euclid(GRID_SQUARE_METERS) / size).floor() as u32;
+        let northing = (northing.rem_euclid(GRID_SQUARE_METERS) / size).floor() as u32;
+        format!("{zone}{easting:0digits$}{northing:0digits$}")
+    }
+
+    ///
+    /// Parses a MGRS reference, like `18SUJ2348306479` or `18S UJ 23483 06479`, returning the
+    /// WGS84 coordinate at the center of the referenced grid square.  The easting and northing can
+    /// be anywhere from 0 digits (the 100km square) up to 5 digits (1m) each.
+    pub fn from_mgrs(reference: &str) -> Result<EllipticalCoordinate, ConvertError> {
+        let chars: Vec<u8> = reference
+            .bytes()
+            .filter(|b| !b.is_ascii_whitespace())
+            .map(|b| b.to_ascii_uppercase())
+            .collect();
+        let zone_digits = chars.iter().take_while(|c| c.is_ascii_digit()).count();
+        let (Some(zone), Some(&[band, column, row]), Some(position)) = (
+            chars.get(..zone_digits),
+            chars.get(zone_digits..zone_digits + 3),
+            chars.get(zone_digits + 3..),
+        ) else {
+            return Err(invalid(reference, "missing the grid zone or 100km square"));
+        };
+        if position.len() % 2 != 0 || !position.iter().all(u8::is_ascii_digit) {
+            return Err(invalid(
+                reference,
+                "easting and northing must be an equal number of digits",
+            ));
+        }
+        let digits = position.len() / 2;
+        let (easting, northing) = if digits == 0 {
+            // just the 100km square.
+            (GRID_SQUARE_METERS / 2., GRID_SQUARE_METERS / 2.)
+        } else {
+            let Some(precision) = MGRSPrecision::from_digits(digits) else {
+                return Err(invalid(reference, "more than 5 digits of easting/northing"));
+            };
+            let parse =
+                |v: &[u8]| -> f64 { v.iter().fold(0., |acc, d| acc * 10. + f64::from(d - b'0')) };
+            let size = precision.meters();
+            let (e, n) = position.split_at(digits);
+            let easting = parse(e) * size + size / 2.;
+            let northing = parse(n) * size + size / 2.;
+            (easting, northing)
+        };
+
+        let (lat, lon) = if zone.is_empty() {
+            let Some(quadrant) = index_of(UPS_BANDS, band) else {
+                return Err(invalid(
+                    reference,
+                    "polar zones must be one of A, B, Y or Z",
+                ));
+            };
+            let north = quadrant >= 2;
+            let east = quadrant % 2 == 1;
+            let (rows, min_index) = ups_rows(north);
+            let columns = UPS_COLUMNS
+                .get(quadrant as usize)
+                .copied()
+                .unwrap_or_default();
+            let Some(column) = index_of(columns, column) else {
+                return Err(invalid(reference, "invalid 100km square column"));
+            };
+            let Some(row) = index_of(rows, row) else {
+                return Err(invalid(reference, "invalid 100km square row"));
+            };
+            let column = column + if east { UPS_EAST_INDEX } else { min_index };
+            let row = row + min_index;
+            ups_inverse(
+                north,
+                column as f64 * GRID_SQUARE_METERS + easting,
+                row as f64 * GRID_SQUARE_METERS + northing,
+            )
+        } else {
+            let zone = zone
+                .iter()
+                .fold(0_u32, |acc, d| acc * 10 + u32::from(d - b'0'));
+            let Some(zone) = u8::try_from(zone).ok().filter(|z| (1..=60).contains(z)) else {
+                return Err(invalid(reference, "zone must be between 1 and 60"));
+            };
+            let Some(band) = index_of(BANDS, band) else {
+                return Err(invalid(reference, "invalid latitude band"));
+            };
+            let columns = COLUMN_SETS
+                .get(usize::from((zone - 1) % 3))
+                .copied()
+                .unwrap_or_default();
+            let Some(column) = index_of(columns, column) else {
+                return Err(invalid(
+                    reference,
+                    "invalid 100km square column for the zone",
+                ));
+            };
+            let Some(row) = index_of(ROWS, row) else {
+                return Err(invalid(reference, "invalid 100km square row"));
+            };
+            let row_offset = if zone % 2 == 0 { 5 } else { 0 };
+            let easting = (column + 1) as f64 * GRID_SQUARE_METERS + easting;
+            let northing = (row - row_offset).rem_euclid(20) as f64 * GRID_SQUARE_METERS + northing;
+
+            // the row letters repeat every 2000km, so find the repeat that lands in the band.
+            let hemisphere = if band < 10 {
+                Hemisphere::Southern
+            } else {
+                Hemisphere::Northern
+            };
+            let proj = UTMProjection::for_zone(zone, hemisphere);
+            let band_lat = f64::from(band as u8) * 8. + UTM_MIN_LATITUDE_DEG;
+            let central_meridian = proj
+                .get_center_coords()
+                .get_longitude()
+                .0
+                .as_degrees()
+                .value();
+            let band_bottom = EllipticalCoordinate::new_degrees_wgs84(band_lat, central_meridian);
+            let min_northing = proj
+                .project_to_cartesian(&band_bottom)
+                .get_y()
+                .as_meters()
+                .value()
+                - 5. * GRID_SQUARE_METERS;
+            let cycle = 20. * GRID_SQUARE_METERS;
+            let repeats = ((min_northing - northing) / cycle).ceil().max(0.);
+            let northing = northing + repeats * cycle;
+
+            let coord =
+                proj.project_to_elliptical(&CartesianCoordinate::new_meters(easting, northing, 0.));
+            (
+                coord.get_latitude().0.as_degrees().value(),
+                coord.get_longitude().0.as_degrees().value(),
+            )
+        };
+        let lon = (lon + 180.).rem_euclid(360.) - 180.;
+        Ok(EllipticalCoordinate::new_degrees_wgs84(lat, lon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::EllipticalCoordinate;
+    use crate::error::ConvertError;
+    use crate::geo::standards::StandardShapes;
+    use crate::mgrs::MGRSPrecision;
+
+    #[test]
+    pub fn reference_points() {
+        for (lat, lon, expected) in [
+            // GeographicLib GeoConvert: 38n 444140.54 3684706.36
+            (33.3, 44.4, "38SMB4414084706"),
+            (-33.8568, 151.2153, "56HLH3490052288"),
+            // southwest Norway and Svalbard exceptions
+            (60., 5., "32VKM7697958157"),
+            (78., 15., "33XWG0000058369"),
+            (-79.9, -100., "14CMS8042329407"),
+            // polar
+            (90., 0., "ZAH0000000000"),
+            (-90., 0., "BAN0000000000"),
+        ] {
+            let coord = EllipticalCoordinate::new_degrees_wgs84(lat, lon);
+            assert_eq!(expected, coord.to_mgrs(MGRSPrecision::Meter));
+        }
+
+        let coord = EllipticalCoordinate::new_degrees_wgs84(33.3, 44.4);
+        for (precision, expected) in [
+            (MGRSPrecision::TenKilometers, "38SMB48"),
+            (MGRSPrecision::Kilometer, "38SMB4484"),
+            (MGRSPrecision::HundredMeters, "38SMB441847"),
+            (MGRSPrecision::TenMeters, "38SMB44148470"),
+        ] {
+            assert_eq!(expected, coord.to_mgrs(precision));
+        }
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn round_trip() -> Result<(), ConvertError> {
+        let wgs84 = StandardShapes::WGS84.as_ellipsoid();
+        let mut lat = -89.45;
+        while lat < 90. {
+            let mut lon = -179.;
+            while lon < 180. {
+                let coord = EllipticalCoordinate::new_degrees_wgs84(lat, lon);
+                let mgrs = coord.to_mgrs(MGRSPrecision::Meter);
+                let parsed = EllipticalCoordinate::from_mgrs(&mgrs)?;
+                // truncated to the 1m square, and parsed to the center of it.
+                let error = wgs84.geodesic_distance(&coord, &parsed).as_meters().value();
+                assert!(error < 1.0, "{lat},{lon} -> {mgrs} was off by {error}m");
+                assert_eq!(mgrs, parsed.to_mgrs(MGRSPrecision::Meter));
+                lon += 7.3;
+            }
+            lat += 3.7;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn parse_precisions() -> Result<(), ConvertError> {
+        let wgs84 = StandardShapes::WGS84.as_ellipsoid();
+        let coord = EllipticalCoordinate::new_degrees_wgs84(-33.8568, 151.2153);
+        for (reference, max_error) in [
+            ("56H LH 34900 52288", 1.),
+            ("56hlh34905228", 10.),
+            ("56HLH349522", 100.),
+            ("56HLH3452", 1_000.),
+            ("56HLH35", 10_000.),
+            ("56HLH", 100_000.),
+        ] {
+            let parsed = EllipticalCoordinate::from_mgrs(reference)?;
+            let error = wgs84.geodesic_distance(&coord, &parsed).as_meters().value();
+            assert!(error < max_error, "{reference} was off by {error}m");
+        }
+
+        for invalid in [
+            "",
+            "56H",
+            "56HLH123",
+            "56HLH123456123456",
+            "61HLH1234",
+            "56ILH1234",
+            "56HAH1234",
+            "CAH1234",
+            "ZZZ1234",
+        ] {
+            assert!(
+                EllipticalCoordinate::from_mgrs(invalid).is_err(),
+                "{invalid} should be invalid"
+            );
+        }
+        Ok(())
+    }
+}
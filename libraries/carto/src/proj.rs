@@ -4,7 +4,12 @@
 //!
 //! Map Projection Traits
 
+use irox_units::units::length::Length;
+
 use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate};
+use crate::geo::ellipsoid::Ellipsoid;
+use crate::geo::standards::StandardShapes;
+use crate::geo::EllipticalShape;
 
 ///
 /// Allows a projection from Elliptical to Cartesian coordinates
@@ -17,4 +22,25 @@ pub trait Projection {
 
     /// Projects the cartesian coordinate to an equivalent elliptical coordinate
     fn project_to_elliptical(&self, coord: &CartesianCoordinate) -> EllipticalCoordinate;
+
+    ///
+    /// Returns true if the coordinate is within the usable domain of this projection.  Coordinates
+    /// outside of the domain may still project, but with unbounded (or undefined) error.
+    fn is_valid_for(&self, _coord: &EllipticalCoordinate) -> bool {
+        true
+    }
+
+    ///
+    /// Projects the coordinate to cartesian and back again, and returns the geodesic distance
+    /// between the input coordinate and the result.  This is a self-check of the fidelity of the
+    /// projection at the provided location.  The distance is measured on the ellipsoid of the
+    /// input coordinate, or WGS84 if it's referenced by EPSG datum.
+    fn round_trip_error(&self, coord: &EllipticalCoordinate) -> Length {
+        let result = self.project_to_elliptical(&self.project_to_cartesian(coord));
+        let ellipsoid = match coord.get_reference_frame() {
+            EllipticalShape::Ellipse(ellipse) => Ellipsoid::from(*ellipse),
+            EllipticalShape::EpsgDatum(_) => StandardShapes::WGS84.as_ellipsoid(),
+        };
+        ellipsoid.geodesic_distance(coord, &result)
+    }
 }
@@ -4,6 +4,8 @@
 //!
 //! Transverse Mercator Map Projection.
 
+use std::f64::consts::{PI, TAU};
+
 use irox_units::units::angle::Angle;
 use irox_units::units::length;
 use irox_units::units::length::Length;
@@ -13,6 +15,12 @@ use crate::geo::ellipsoid::{Ellipsoid, MeridianCalculators};
 use crate::geo::standards::StandardShapes;
 use crate::proj::Projection;
 
+///
+/// The maximum offset in longitude from the central meridian that the projection is considered
+/// valid for - one full UTM zone width either side of the center.  Past this, the series
+/// expansions diverge quickly.
+pub const MAX_CENTRAL_MERIDIAN_OFFSET_DEG: f64 = 6.0;
+
 ///
 /// An implementation of the Transverse Mercator map projection.
 ///
@@ -134,12 +142,27 @@ impl Projection for TransverseMercator {
         &self.center
     }
 
+    ///
+    /// Valid within [`MAX_CENTRAL_MERIDIAN_OFFSET_DEG`] degrees of longitude from the center of the
+    /// projection, and away from the poles.
+    fn is_valid_for(&self, coord: &EllipticalCoordinate) -> bool {
+        let lat = coord.get_latitude().0.as_degrees().value();
+        let offset = (coord.get_longitude().0 - self.center.get_longitude().0)
+            .as_degrees()
+            .value();
+        // wrap to [-180, 180)
+        let offset = (offset + 180.).rem_euclid(360.) - 180.;
+        lat.abs() < 90. && offset.abs() <= MAX_CENTRAL_MERIDIAN_OFFSET_DEG
+    }
+
     ///
     /// Projects (Lat, Lon, Alt) into TM (X-East, Y-North, Z-Up)
     fn project_to_cartesian(&self, coord: &EllipticalCoordinate) -> CartesianCoordinate {
         let w = (coord.get_longitude().0 - self.center.get_longitude().0)
             .as_radians()
             .value();
+        // wrap across the antimeridian to [-PI, PI)
+        let w = (w + PI).rem_euclid(TAU) - PI;
         let w2 = w.powi(2);
         let w3 = w.powi(3);
         let w4 = w.powi(4);
@@ -227,7 +250,9 @@ impl Projection for TransverseMercator {
         let scaled_axis = self.shape.semi_major_axis;
         let phi0 = self.center.get_latitude().0.as_radians().value();
         let mut phi_prime = (northing - &self.false_northing) / scaled_axis + phi0;
-        loop {
+        // bounded, as at large northings the tolerance is close to the float resolution, and the
+        // correction can oscillate around it without ever dropping below.
+        for _ in 0..100 {
             let dphi = phi_prime - phi0;
             let m = MeridianCalculators::DeakinHunterKarney
                 .get(&self.shape)
@@ -452,4 +477,24 @@ mod test {
             assert!(deltalon.abs() < 1e-10)
         }
     }
+
+    #[test]
+    pub fn validity_and_round_trip() {
+        let center = EllipticalCoordinate::new_degrees_wgs84(0., 177.);
+        let tm = TransverseMercator::builder().with_center(center).build();
+
+        for (lat, lon) in [(0., 177.), (45., 171.), (-60., -177.), (80., 179.5)] {
+            let coord = EllipticalCoordinate::new_degrees_wgs84(lat, lon);
+            assert!(tm.is_valid_for(&coord), "{lat},{lon} should be valid");
+            let error = tm.round_trip_error(&coord).as_meters().value();
+            assert!(error < 1e-3, "{lat},{lon} round trip error was {error}m");
+        }
+        for (lat, lon) in [(0., 170.), (10., -170.), (90., 177.)] {
+            let coord = EllipticalCoordinate::new_degrees_wgs84(lat, lon);
+            assert!(!tm.is_valid_for(&coord), "{lat},{lon} should be invalid");
+        }
+        // outside of the domain, the error grows quickly.
+        let coord = EllipticalCoordinate::new_degrees_wgs84(10., -170.);
+        assert!(tm.round_trip_error(&coord).as_meters().value() > 1e-2);
+    }
 }
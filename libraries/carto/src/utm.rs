@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+
+//!
+//! Universal Transverse Mercator (UTM) Map Projection.  The world between 80°S and 84°N is split
+//! into 60 zones, each 6 degrees of longitude wide, and each projected with its own
+//! [`TransverseMercator`].
+
+use irox_units::units::angle::Angle;
+use irox_units::units::length::Length;
+
+use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate, Longitude};
+use crate::error::ConvertError;
+use crate::proj::Projection;
+use crate::tm::TransverseMercator;
+
+pub(crate) const UTM_FALSE_EASTING_METERS: f64 = 500_000.;
+pub(crate) const UTM_SOUTH_FALSE_NORTHING_METERS: f64 = 10_000_000.;
+pub(crate) const UTM_MIN_LATITUDE_DEG: f64 = -80.;
+pub(crate) const UTM_MAX_LATITUDE_DEG: f64 = 84.;
+/// The scale factor along the central meridian of each zone
+const UTM_SCALE_FACTOR: f64 = 0.9996;
+
+///
+/// Hemisphere of a UTM zone, north or south of the equator.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+/// Returns the UTM zone number, including the exceptions for southwest Norway and Svalbard.
+pub(crate) fn utm_zone(lat: f64, lon: f64) -> u8 {
+    if (56. ..64.).contains(&lat) && (3. ..12.).contains(&lon) {
+        return 32;
+    }
+    if (72. ..=UTM_MAX_LATITUDE_DEG).contains(&lat) && (0. ..42.).contains(&lon) {
+        return match lon {
+            l if l < 9. => 31,
+            l if l < 21. => 33,
+            l if l < 33. => 35,
+            _ => 37,
+        };
+    }
+    (((lon + 180.) / 6.).floor() as u8).clamp(0, 59) + 1
+}
+
+///
+/// The Universal Transverse Mercator projection of a single zone, projecting WGS84 coordinates to
+/// (Easting, Northing) in meters.  Eastings are offset by a false easting of `500_000m` from the
+/// central meridian of the zone, and in the southern hemisphere, northings are offset by a false
+/// northing of `10_000_000m` from the equator.
+///
+/// # Example
+/// ```
+/// use irox_carto::coordinate::EllipticalCoordinate;
+/// use irox_carto::proj::Projection;
+/// use irox_carto::utm::UTMProjection;
+///
+/// let coord = EllipticalCoordinate::new_degrees_wgs84(33.3, 44.4);
+/// let utm = UTMProjection::for_coordinate(&coord);
+/// assert_eq!(38, utm.zone());
+///
+/// let projected = utm.project_to_cartesian(&coord);
+/// assert_eq!(444_140, projected.get_x().as_meters().value() as u32);
+/// assert_eq!(3_684_706, projected.get_y().as_meters().value() as u32);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UTMProjection {
+    zone: u8,
+    hemisphere: Hemisphere,
+    tm: TransverseMercator,
+}
+
+impl UTMProjection {
+    ///
+    /// Creates the projection for the zone (1 to 60) in the hemisphere
+    pub fn new(zone: u8, hemisphere: Hemisphere) -> Result<UTMProjection, ConvertError> {
+        if !(1..=60).contains(&zone) {
+            return Err(ConvertError::InvalidValue(format!(
+                "UTM zone must be between 1 and 60, but was {zone}"
+            )));
+        }
+        Ok(UTMProjection::for_zone(zone, hemisphere))
+    }
+
+    /// Creates the projection for an already-validated zone
+    pub(crate) fn for_zone(zone: u8, hemisphere: Hemisphere) -> UTMProjection {
+        let false_northing = match hemisphere {
+            Hemisphere::Northern => 0.,
+            Hemisphere::Southern => UTM_SOUTH_FALSE_NORTHING_METERS,
+        };
+        let tm = TransverseMercator::builder()
+            .with_scale_factor(UTM_SCALE_FACTOR)
+            .with_false_easting(Length::new_meters(UTM_FALSE_EASTING_METERS))
+            .with_false_northing(Length::new_meters(false_northing))
+            .with_center(EllipticalCoordinate::new_degrees_wgs84(
+                0.,
+                f64::from(zone) * 6. - 183.,
+            ))
+            .build();
+        UTMProjection {
+            zone,
+            hemisphere,
+            tm,
+        }
+    }
+
+    ///
+    /// Creates the projection for the zone and hemisphere containing the coordinate, including
+    /// the exceptions for southwest Norway and Svalbard.  Coordinates outside of the UTM latitude
+    /// limits get the zone they would have been in.
+    #[must_use]
+    pub fn for_coordinate(coord: &EllipticalCoordinate) -> UTMProjection {
+        let lat = coord.get_latitude().0.as_degrees().value();
+        let lon = (coord.get_longitude().0.as_degrees().value() + 180.).rem_euclid(360.) - 180.;
+        let hemisphere = if lat < 0. {
+            Hemisphere::Southern
+        } else {
+            Hemisphere::Northern
+        };
+        UTMProjection::for_zone(utm_zone(lat, lon), hemisphere)
+    }
+
+    /// Returns the zone number, 1 to 60
+    #[must_use]
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// Returns the hemisphere, which determines the false northing
+    #[must_use]
+    pub fn hemisphere(&self) -> Hemisphere {
+        self.hemisphere
+    }
+
+    /// Returns the central meridian of the zone
+    #[must_use]
+    pub fn central_meridian(&self) -> Longitude {
+        Longitude(Angle::new_degrees(f64::from(self.zone) * 6. - 183.))
+    }
+}
+
+impl Projection for UTMProjection {
+    fn get_center_coords(&self) -> &EllipticalCoordinate {
+        self.tm.get_center_coords()
+    }
+
+    fn project_to_cartesian(&self, coord: &EllipticalCoordinate) -> CartesianCoordinate {
+        self.tm.project_to_cartesian(coord)
+    }
+
+    fn project_to_elliptical(&self, coord: &CartesianCoordinate) -> EllipticalCoordinate {
+        self.tm.project_to_elliptical(coord)
+    }
+
+    ///
+    /// Valid between 80°S and 84°N, within [`crate::tm::MAX_CENTRAL_MERIDIAN_OFFSET_DEG`] degrees
+    /// of the central meridian of the zone.
+    fn is_valid_for(&self, coord: &EllipticalCoordinate) -> bool {
+        let lat = coord.get_latitude().0.as_degrees().value();
+        (UTM_MIN_LATITUDE_DEG..=UTM_MAX_LATITUDE_DEG).contains(&lat) && self.tm.is_valid_for(coord)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::EllipticalCoordinate;
+    use crate::error::ConvertError;
+    use crate::proj::Projection;
+    use crate::utm::{Hemisphere, UTMProjection};
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn control_points() -> Result<(), ConvertError> {
+        for (lat, lon, zone, hemisphere, easting, northing) in [
+            // GeographicLib GeoConvert, and Karney's Krüger series
+            (
+                33.3,
+                44.4,
+                38,
+                Hemisphere::Northern,
+                444_140.54,
+                3_684_706.36,
+            ),
+            (
+                -33.3,
+                44.4,
+                38,
+                Hemisphere::Southern,
+                444_140.54,
+                6_315_293.64,
+            ),
+            (0., 0., 31, Hemisphere::Northern, 166_021.44, 0.),
+            (0., -75., 18, Hemisphere::Northern, 500_000., 0.),
+            // southwest Norway and Svalbard exceptions
+            (60., 5., 32, Hemisphere::Northern, 276_979.93, 6_658_157.20),
+            (78., 15., 33, Hemisphere::Northern, 500_000., 8_658_369.59),
+        ] {
+            let coord = EllipticalCoordinate::new_degrees_wgs84(lat, lon);
+            let utm = UTMProjection::for_coordinate(&coord);
+            assert_eq!(zone, utm.zone(), "{lat},{lon}");
+            assert_eq!(hemisphere, utm.hemisphere(), "{lat},{lon}");
+            assert!(utm.is_valid_for(&coord));
+
+            let projected = utm.project_to_cartesian(&coord);
+            let de = projected.get_x().as_meters().value() - easting;
+            let dn = projected.get_y().as_meters().value() - northing;
+            assert!(de.abs() < 0.01, "{lat},{lon} easting off by {de}m");
+            assert!(dn.abs() < 0.01, "{lat},{lon} northing off by {dn}m");
+
+            let error = utm.round_trip_error(&coord).as_meters().value();
+            assert!(error < 1e-4, "{lat},{lon} round trip error was {error}m");
+
+            let same = UTMProjection::new(zone, hemisphere)?;
+            assert_eq!(projected, same.project_to_cartesian(&coord));
+        }
+
+        assert!(UTMProjection::new(0, Hemisphere::Northern).is_err());
+        assert!(UTMProjection::new(61, Hemisphere::Southern).is_err());
+        let utm = UTMProjection::new(18, Hemisphere::Northern)?;
+        assert_eq!(
+            (-75f64).to_bits(),
+            utm.central_meridian().0.as_degrees().value().to_bits()
+        );
+        assert!(!utm.is_valid_for(&EllipticalCoordinate::new_degrees_wgs84(85., -75.)));
+        assert!(!utm.is_valid_for(&EllipticalCoordinate::new_degrees_wgs84(40., -60.)));
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Shows the progress of a long-running background task.  While the task is still discovering
+//! how much work there is, the bar is indeterminate.  Once the total is known, the bar switches
+//! to showing the completed fraction.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use eframe::{App, Frame};
+use egui::{CentralPanel, Context, Vec2, ViewportBuilder, Widget};
+use irox_egui_extras::progressbar::ProgressBar;
+use log::error;
+
+pub fn main() {
+    let viewport = ViewportBuilder::default().with_inner_size(Vec2::new(400., 100.));
+
+    let native_options = eframe::NativeOptions {
+        viewport,
+        ..Default::default()
+    };
+    if let Err(e) = eframe::run_native(
+        "progress-task",
+        native_options,
+        Box::new(|cc| {
+            let app = TaskApp::default();
+            app.start(cc.egui_ctx.clone());
+            Ok(Box::new(app))
+        }),
+    ) {
+        error!("{e:?}");
+    };
+}
+
+///
+/// Progress of the task, shared between the worker thread and the UI.
+#[derive(Default)]
+struct TaskProgress {
+    /// Total number of elements to process, zero until they've all been discovered.
+    total: AtomicU64,
+    /// Number of elements processed so far.
+    completed: AtomicU64,
+}
+
+#[derive(Default)]
+struct TaskApp {
+    progress: Arc<TaskProgress>,
+}
+
+impl TaskApp {
+    ///
+    /// Starts the worker thread, which requests a repaint whenever the progress changes.
+    fn start(&self, ctx: Context) {
+        let progress = self.progress.clone();
+        std::thread::spawn(move || {
+            // first phase, finding the work to do - the total isn't known yet.
+            let mut found = 0;
+            for _ in 0..30 {
+                std::thread::sleep(Duration::from_millis(100));
+                found += 17;
+            }
+            progress.total.store(found, Ordering::Relaxed);
+            ctx.request_repaint();
+
+            // second phase, doing the work.
+            for _ in 0..found {
+                std::thread::sleep(Duration::from_millis(10));
+                progress.completed.fetch_add(1, Ordering::Relaxed);
+                ctx.request_repaint();
+            }
+        });
+    }
+}
+
+impl App for TaskApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        CentralPanel::default().show(ctx, |ui| {
+            let total = self.progress.total.load(Ordering::Relaxed);
+            let completed = self.progress.completed.load(Ordering::Relaxed);
+            if total == 0 {
+                // the indeterminate bar keeps repainting itself to animate
+                ProgressBar::indeterminate()
+                    .text_center("Discovering work...".to_string())
+                    .ui(ui);
+            } else {
+                let frac = completed as f32 / total as f32;
+                ProgressBar::new(frac)
+                    .text_left(format!("{:.0}%", frac * 100.))
+                    .text_right(format!("{completed}/{total}"))
+                    .ui(ui);
+            }
+        });
+    }
+}
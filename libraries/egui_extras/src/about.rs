@@ -2,7 +2,10 @@
 // Copyright 2023 IROX Contributors
 //
 
-use egui::Ui;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use egui::{ScrollArea, TextEdit, Ui};
 
 pub const IMPORTANT_NAMES: &[(&str, &str)] = &[
     ("CARGO_PKG_NAME", "Package Name"),
@@ -89,3 +92,167 @@ impl AboutWindow {
         }
     }
 }
+
+///
+/// Version and build information about an app, to show with [`show_about`] and to include in bug
+/// reports.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct AboutInfo {
+    /// Name of the app
+    pub name: String,
+    /// Semantic version of the app
+    pub version: String,
+    /// Full hash of the git commit the app was built from
+    pub git_hash: Option<String>,
+    /// Timestamp of the build.  The `build` module doesn't record the wall-clock time of the
+    /// build (so builds are reproducible), so this defaults to the date of the git commit.
+    pub build_timestamp: Option<String>,
+    /// Target triple the app was built for
+    pub target: Option<String>,
+    /// Cargo profile the app was built with
+    pub profile: Option<String>,
+    /// Names and versions of the dependencies of the app
+    pub dependencies: Vec<(String, String)>,
+}
+
+impl AboutInfo {
+    ///
+    /// Pulls the info from the items of a generated `build` module, like
+    /// `AboutInfo::from_build(build::get_ALL_ITEMS())`.  Missing items (like the git info, when
+    /// built outside of a repository) are left empty.
+    #[must_use]
+    pub fn from_build(items: &BTreeMap<&str, &str>) -> AboutInfo {
+        let get = |key: &str| {
+            items
+                .get(key)
+                .filter(|v| !v.is_empty())
+                .map(ToString::to_string)
+        };
+        AboutInfo {
+            name: get("CARGO_PKG_NAME").unwrap_or_default(),
+            version: get("CARGO_PKG_VERSION").unwrap_or_default(),
+            git_hash: get("GIT_COMMIT_FULLHASH"),
+            build_timestamp: get("GIT_COMMIT_DATETIME"),
+            target: get("TARGET"),
+            profile: get("PROFILE"),
+            dependencies: Vec::new(),
+        }
+    }
+
+    ///
+    /// Appends a dependency to the list of dependencies
+    #[must_use]
+    pub fn with_dependency<N: Into<String>, V: Into<String>>(
+        mut self,
+        name: N,
+        version: V,
+    ) -> Self {
+        self.dependencies.push((name.into(), version.into()));
+        self
+    }
+
+    fn details(&self) -> [(&'static str, Option<&str>); 5] {
+        [
+            ("Version", Some(self.version.as_str())),
+            ("Git Commit Hash", self.git_hash.as_deref()),
+            ("Build Timestamp", self.build_timestamp.as_deref()),
+            ("Target", self.target.as_deref()),
+            ("Build Profile", self.profile.as_deref()),
+        ]
+    }
+
+    fn dependency_list(&self) -> String {
+        let mut out = String::new();
+        for (name, version) in &self.dependencies {
+            let _ = writeln!(out, "{name} {version}");
+        }
+        out
+    }
+
+    ///
+    /// Formats the info as plain text, for bug reports.
+    #[must_use]
+    pub fn diagnostics(&self) -> String {
+        let mut out = format!("{} {}\n", self.name, self.version);
+        for (name, value) in self.details().into_iter().skip(1) {
+            if let Some(value) = value {
+                let _ = writeln!(out, "{name}: {value}");
+            }
+        }
+        if !self.dependencies.is_empty() {
+            out.push_str("Dependencies:\n");
+            for (name, version) in &self.dependencies {
+                let _ = writeln!(out, "  {name} {version}");
+            }
+        }
+        out
+    }
+}
+
+///
+/// Draws the app name and version, the build details, a scrollable (and copyable) list of the
+/// dependencies, and a button to copy the [`AboutInfo::diagnostics`] to the clipboard.
+pub fn show_about(ui: &mut Ui, info: &AboutInfo) {
+    ui.heading(format!("{} {}", info.name, info.version));
+    egui::Grid::new("about_info_grid")
+        .num_columns(2)
+        .striped(true)
+        .spacing([40.0, 4.0])
+        .show(ui, |ui| {
+            for (name, value) in info.details() {
+                if let Some(value) = value {
+                    ui.label(name);
+                    ui.label(value);
+                    ui.end_row();
+                }
+            }
+        });
+    if !info.dependencies.is_empty() {
+        ui.collapsing("Dependencies", |ui| {
+            let list = info.dependency_list();
+            ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                ui.add(TextEdit::multiline(&mut list.as_str()).code_editor());
+            });
+        });
+    }
+    if ui.button("Copy diagnostics to clipboard").clicked() {
+        ui.ctx().copy_text(info.diagnostics());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::about::AboutInfo;
+
+    #[test]
+    pub fn from_build() {
+        let items = BTreeMap::from([
+            ("CARGO_PKG_NAME", "irox-test"),
+            ("CARGO_PKG_VERSION", "1.2.3"),
+            ("GIT_COMMIT_FULLHASH", "0123abcd"),
+            ("GIT_COMMIT_DATETIME", "2024-05-06T07:08:09Z"),
+            ("PROFILE", "release"),
+            ("TARGET", ""),
+        ]);
+        let info = AboutInfo::from_build(&items)
+            .with_dependency("egui", "0.28.1")
+            .with_dependency("irox-tools", "0.8.6");
+        assert_eq!("irox-test", info.name);
+        assert_eq!(None, info.target);
+        assert_eq!(
+            "irox-test 1.2.3\n\
+            Git Commit Hash: 0123abcd\n\
+            Build Timestamp: 2024-05-06T07:08:09Z\n\
+            Build Profile: release\n\
+            Dependencies:\n  \
+            egui 0.28.1\n  \
+            irox-tools 0.8.6\n",
+            info.diagnostics()
+        );
+
+        let info = AboutInfo::from_build(crate::build::get_ALL_ITEMS());
+        assert_eq!(env!("CARGO_PKG_VERSION"), info.version);
+    }
+}
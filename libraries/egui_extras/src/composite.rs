@@ -9,12 +9,49 @@ use std::time::Duration;
 
 use eframe::{App, Frame, Storage};
 use egui::Context;
+use log::warn;
+
+/// Key the layout of the [`CompositeApp`] is persisted under in the [`Storage`]
+#[cfg(feature = "serde")]
+pub const LAYOUT_STORAGE_KEY: &str = "composite_layout";
+
+///
+/// The state of a single sub-app (tab) of a [`CompositeApp`]
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TabState {
+    /// The id the sub-app was added with
+    pub id: String,
+    /// Whether the sub-app is open (drawn)
+    pub open: bool,
+}
+
+///
+/// The layout of a [`CompositeApp`] - which sub-apps are open, the order they're drawn in, and
+/// the active one.  See [`crate::serde::save_layout`] and [`crate::serde::load_layout`] to
+/// persist it.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeState {
+    /// The sub-apps in the order they're drawn
+    pub tabs: Vec<TabState>,
+    /// The id of the active sub-app, if any
+    pub active: Option<String>,
+}
+
+struct Tab {
+    id: String,
+    open: bool,
+    app: Box<dyn App>,
+}
 
 ///
 /// An implementation of `eframe::App` that allows the composition of multiple sub-apps.
-/// Each app is called in the order added to this structure
+/// Each open app is called in the order added to this structure, or the order restored with
+/// [`CompositeApp::restore_state`]
 pub struct CompositeApp {
-    apps: Vec<Box<dyn App>>,
+    tabs: Vec<Tab>,
+    active: Option<String>,
 
     persist_egui_memory: bool,
 }
@@ -22,42 +59,153 @@ pub struct CompositeApp {
 impl Default for CompositeApp {
     fn default() -> Self {
         CompositeApp {
-            apps: Vec::new(),
+            tabs: Vec::new(),
+            active: None,
             persist_egui_memory: true,
         }
     }
 }
 
 impl CompositeApp {
+    ///
+    /// Adds the app, with an id of its position in the list of apps.
     pub fn add(&mut self, app: Box<dyn App>) {
+        let id = self.tabs.len().to_string();
+        self.add_named(id, app);
+    }
+
+    ///
+    /// Adds the app with the provided id, which identifies it in the [`CompositeState`].
+    pub fn add_named<T: Into<String>>(&mut self, id: T, app: Box<dyn App>) {
         self.persist_egui_memory &= app.persist_egui_memory();
 
-        self.apps.push(app);
+        self.tabs.push(Tab {
+            id: id.into(),
+            open: true,
+            app,
+        });
+    }
+
+    #[must_use]
+    pub fn is_open(&self, id: &str) -> bool {
+        self.tabs.iter().any(|tab| tab.id == id && tab.open)
+    }
+
+    ///
+    /// Opens or closes the sub-app, a closed app is not drawn.
+    pub fn set_open(&mut self, id: &str, open: bool) {
+        for tab in &mut self.tabs {
+            if tab.id == id {
+                tab.open = open;
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn active(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    ///
+    /// Sets the active sub-app, ignored if there is no sub-app with that id.
+    pub fn set_active(&mut self, id: &str) {
+        if self.tabs.iter().any(|tab| tab.id == id) {
+            self.active = Some(id.to_string());
+        }
+    }
+
+    ///
+    /// Returns the current layout of the sub-apps
+    #[must_use]
+    pub fn state(&self) -> CompositeState {
+        CompositeState {
+            tabs: self
+                .tabs
+                .iter()
+                .map(|tab| TabState {
+                    id: tab.id.clone(),
+                    open: tab.open,
+                })
+                .collect(),
+            active: self.active.clone(),
+        }
+    }
+
+    ///
+    /// Restores a layout from [`CompositeApp::state`].  The sub-apps are reordered to match the
+    /// layout, and any sub-apps missing from the layout keep their state and are moved to the end.
+    /// Ids in the layout that don't match any sub-app (like an app that's since been removed) are
+    /// dropped with a warning.
+    pub fn restore_state(&mut self, state: CompositeState) {
+        let mut remaining = core::mem::take(&mut self.tabs);
+        for tab_state in state.tabs {
+            let Some(idx) = remaining.iter().position(|tab| tab.id == tab_state.id) else {
+                warn!("Dropping unknown tab '{}' from the layout", tab_state.id);
+                continue;
+            };
+            let mut tab = remaining.remove(idx);
+            tab.open = tab_state.open;
+            self.tabs.push(tab);
+        }
+        self.tabs.append(&mut remaining);
+
+        self.active = None;
+        if let Some(active) = state.active {
+            self.set_active(&active);
+            if self.active.is_none() {
+                warn!("Dropping unknown active tab '{active}' from the layout");
+            }
+        }
+    }
+
+    ///
+    /// Restores the layout saved in the storage by a previous run, if any.  Call after all
+    /// the sub-apps have been added.
+    #[cfg(feature = "serde")]
+    pub fn restore_from_storage(&mut self, storage: &dyn Storage) {
+        let Some(layout) = storage.get_string(LAYOUT_STORAGE_KEY) else {
+            return;
+        };
+        match crate::serde::load_layout(&layout) {
+            Ok(state) => self.restore_state(state),
+            Err(e) => warn!("Unable to restore the saved layout: {e}"),
+        }
     }
 }
 
 impl App for CompositeApp {
     fn update(&mut self, ctx: &Context, frame: &mut Frame) {
-        for app in &mut self.apps {
-            app.update(ctx, frame)
+        for tab in &mut self.tabs {
+            if tab.open {
+                tab.app.update(ctx, frame)
+            }
         }
     }
 
     fn save(&mut self, storage: &mut dyn Storage) {
-        for app in &mut self.apps {
-            app.save(storage)
+        for tab in &mut self.tabs {
+            tab.app.save(storage)
+        }
+        #[cfg(feature = "serde")]
+        match crate::serde::save_layout(&self.state()) {
+            Ok(layout) => storage.set_string(LAYOUT_STORAGE_KEY, layout),
+            Err(e) => warn!("Unable to save the layout: {e}"),
         }
     }
 
     #[cfg(feature = "eframe/glow")]
     fn on_exit(&mut self, gl: Option<&eframe::glow::Context>) {
-        for app in &mut self.apps {
-            app.on_exit(gl)
+        for tab in &mut self.tabs {
+            tab.app.on_exit(gl)
         }
     }
 
     fn auto_save_interval(&self) -> Duration {
-        let min = self.apps.iter().map(|app| app.auto_save_interval()).min();
+        let min = self
+            .tabs
+            .iter()
+            .map(|tab| tab.app.auto_save_interval())
+            .min();
         if let Some(min) = min {
             return min;
         }
@@ -68,3 +216,67 @@ impl App for CompositeApp {
         self.persist_egui_memory
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use eframe::{App, Frame};
+    use egui::Context;
+
+    use crate::composite::{CompositeApp, CompositeState, TabState};
+
+    struct Empty;
+    impl App for Empty {
+        fn update(&mut self, _ctx: &Context, _frame: &mut Frame) {}
+    }
+
+    fn tab(id: &str, open: bool) -> TabState {
+        TabState {
+            id: id.to_string(),
+            open,
+        }
+    }
+
+    #[test]
+    pub fn restore_state() {
+        let mut app = CompositeApp::default();
+        app.add_named("a", Box::new(Empty));
+        app.add_named("b", Box::new(Empty));
+        app.add_named("c", Box::new(Empty));
+        app.add(Box::new(Empty));
+        assert_eq!(
+            vec![
+                tab("a", true),
+                tab("b", true),
+                tab("c", true),
+                tab("3", true)
+            ],
+            app.state().tabs
+        );
+
+        app.restore_state(CompositeState {
+            tabs: vec![tab("c", true), tab("removed", true), tab("a", false)],
+            active: Some("c".to_string()),
+        });
+        let state = app.state();
+        assert_eq!(
+            vec![
+                tab("c", true),
+                tab("a", false),
+                tab("b", true),
+                tab("3", true)
+            ],
+            state.tabs
+        );
+        assert_eq!(Some("c"), app.active());
+        assert!(!app.is_open("a"));
+
+        app.set_open("a", true);
+        app.restore_state(CompositeState {
+            tabs: Vec::new(),
+            active: Some("removed".to_string()),
+        });
+        assert!(app.is_open("a"));
+        assert_eq!(None, app.active());
+        assert_eq!(4, app.state().tabs.len());
+    }
+}
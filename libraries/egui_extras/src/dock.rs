@@ -0,0 +1,633 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! A dock layout of named panels, that users can rearrange into splits and tabbed groups by
+//! dragging the tabs, and resize by dragging the separators between them.
+
+use egui::{
+    Align, CentralPanel, Context, CursorIcon, Id, Layout, Pos2, Rect, Sense, Stroke, Ui, Vec2,
+};
+
+/// Width of the draggable separator between the two sides of a split
+const SEPARATOR_WIDTH: f32 = 6.0;
+/// Smallest fraction of a split that either side can be resized down to
+const MIN_FRACTION: f32 = 0.1;
+
+///
+/// Where a dragged panel will be dropped, relative to the hovered tab group
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DropZone {
+    /// Split the group, and put the panel on the left
+    Left,
+    /// Split the group, and put the panel on the right
+    Right,
+    /// Split the group, and put the panel on top
+    Top,
+    /// Split the group, and put the panel on the bottom
+    Bottom,
+    /// Add the panel as another tab of the group
+    Tab,
+}
+
+impl DropZone {
+    ///
+    /// Returns the zone for the position within the rect - the middle half is [`DropZone::Tab`],
+    /// otherwise the zone of the nearest edge.
+    #[must_use]
+    pub fn from_position(rect: Rect, pos: Pos2) -> DropZone {
+        let x = (pos.x - rect.left()) / rect.width().max(1.0);
+        let y = (pos.y - rect.top()) / rect.height().max(1.0);
+        if (0.25..=0.75).contains(&x) && (0.25..=0.75).contains(&y) {
+            return DropZone::Tab;
+        }
+        let edges = [
+            (x, DropZone::Left),
+            (1.0 - x, DropZone::Right),
+            (y, DropZone::Top),
+            (1.0 - y, DropZone::Bottom),
+        ];
+        edges
+            .into_iter()
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map_or(DropZone::Tab, |(_, zone)| zone)
+    }
+
+    /// Returns the part of the rect the panel will occupy if dropped in this zone
+    #[must_use]
+    pub fn preview_rect(&self, rect: Rect) -> Rect {
+        let half = rect.size() / 2.0;
+        match self {
+            DropZone::Left => Rect::from_min_size(rect.min, Vec2::new(half.x, rect.height())),
+            DropZone::Right => Rect::from_min_max(Pos2::new(rect.center().x, rect.top()), rect.max),
+            DropZone::Top => Rect::from_min_size(rect.min, Vec2::new(rect.width(), half.y)),
+            DropZone::Bottom => {
+                Rect::from_min_max(Pos2::new(rect.left(), rect.center().y), rect.max)
+            }
+            DropZone::Tab => rect,
+        }
+    }
+}
+
+///
+/// The direction of a split
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SplitDirection {
+    /// Side by side, first on the left
+    Horizontal,
+    /// Stacked, first on top
+    Vertical,
+}
+
+///
+/// A node in the layout tree
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DockNode {
+    /// A group of panels shown as tabs, with the index of the visible tab
+    Tabs { panels: Vec<String>, active: usize },
+    /// Two nodes split horizontally or vertically, with `fraction` of the space given to the first
+    Split {
+        direction: SplitDirection,
+        fraction: f32,
+        first: Box<DockNode>,
+        second: Box<DockNode>,
+    },
+}
+
+impl DockNode {
+    ///
+    /// Creates a new tab group of the panels
+    #[must_use]
+    pub fn tabs<T: Into<String>, I: IntoIterator<Item = T>>(panels: I) -> DockNode {
+        DockNode::Tabs {
+            panels: panels.into_iter().map(Into::into).collect(),
+            active: 0,
+        }
+    }
+
+    ///
+    /// Creates a new split of the two nodes, evenly sized
+    #[must_use]
+    pub fn split(direction: SplitDirection, first: DockNode, second: DockNode) -> DockNode {
+        DockNode::Split {
+            direction,
+            fraction: 0.5,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Returns true if the panel is within this node, or any of its children
+    #[must_use]
+    pub fn contains(&self, panel: &str) -> bool {
+        match self {
+            DockNode::Tabs { panels, .. } => panels.iter().any(|p| p == panel),
+            DockNode::Split { first, second, .. } => {
+                first.contains(panel) || second.contains(panel)
+            }
+        }
+    }
+
+    fn collect_panels<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            DockNode::Tabs { panels, .. } => out.extend(panels.iter().map(String::as_str)),
+            DockNode::Split { first, second, .. } => {
+                first.collect_panels(out);
+                second.collect_panels(out);
+            }
+        }
+    }
+
+    /// Removes the panel, returning [`None`] if this node is left empty.  Splits with an empty
+    /// side collapse into the other side.
+    fn remove(self, panel: &str) -> Option<DockNode> {
+        match self {
+            DockNode::Tabs { mut panels, active } => {
+                let idx = panels.iter().position(|p| p == panel);
+                if let Some(idx) = idx {
+                    panels.remove(idx);
+                }
+                if panels.is_empty() {
+                    return None;
+                }
+                let active = match idx {
+                    Some(idx) if idx < active => active - 1,
+                    _ => active,
+                };
+                let active = active.min(panels.len() - 1);
+                Some(DockNode::Tabs { panels, active })
+            }
+            DockNode::Split {
+                direction,
+                fraction,
+                first,
+                second,
+            } => match (first.remove(panel), second.remove(panel)) {
+                (Some(first), Some(second)) => Some(DockNode::Split {
+                    direction,
+                    fraction,
+                    first: Box::new(first),
+                    second: Box::new(second),
+                }),
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            },
+        }
+    }
+
+    /// Inserts the panel into the zone of the tab group containing the target.
+    fn insert(&mut self, target: &str, panel: String, zone: DropZone) -> bool {
+        match self {
+            DockNode::Split { first, second, .. } => {
+                if first.contains(target) {
+                    first.insert(target, panel, zone)
+                } else {
+                    second.insert(target, panel, zone)
+                }
+            }
+            DockNode::Tabs { panels, active } => {
+                if !panels.iter().any(|p| p == target) {
+                    return false;
+                }
+                let (direction, panel_first) = match zone {
+                    DropZone::Tab => {
+                        panels.push(panel);
+                        *active = panels.len() - 1;
+                        return true;
+                    }
+                    DropZone::Left => (SplitDirection::Horizontal, true),
+                    DropZone::Right => (SplitDirection::Horizontal, false),
+                    DropZone::Top => (SplitDirection::Vertical, true),
+                    DropZone::Bottom => (SplitDirection::Vertical, false),
+                };
+                let existing = core::mem::replace(self, DockNode::tabs::<String, _>([]));
+                let new = DockNode::tabs([panel]);
+                *self = if panel_first {
+                    DockNode::split(direction, new, existing)
+                } else {
+                    DockNode::split(direction, existing, new)
+                };
+                true
+            }
+        }
+    }
+}
+
+///
+/// A layout of named panels, arranged in a tree of splits and tabbed groups.  The panels are drawn
+/// by the caller with [`DockLayout::show`], and the user can drag a tab onto the edge of another
+/// group to split it, or onto the middle of the group to add it as a tab.  The separators between
+/// the splits can be dragged to resize.
+///
+/// With the `serde` feature, the layout can be persisted and restored, like with [`ron`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DockLayout {
+    root: Option<DockNode>,
+}
+
+impl DockLayout {
+    ///
+    /// Creates a new layout with all the panels in a single tab group
+    #[must_use]
+    pub fn new<T: Into<String>, I: IntoIterator<Item = T>>(panels: I) -> DockLayout {
+        let root = DockNode::tabs(panels);
+        DockLayout::from_root(root)
+    }
+
+    ///
+    /// Creates a new layout from the provided tree
+    #[must_use]
+    pub fn from_root(root: DockNode) -> DockLayout {
+        DockLayout { root: Some(root) }
+    }
+
+    /// Returns the root of the layout tree, if there are any panels
+    #[must_use]
+    pub fn root(&self) -> Option<&DockNode> {
+        self.root.as_ref()
+    }
+
+    /// Returns the ids of all the panels in the layout
+    #[must_use]
+    pub fn panels(&self) -> Vec<&str> {
+        let mut out = Vec::new();
+        if let Some(root) = &self.root {
+            root.collect_panels(&mut out);
+        }
+        out
+    }
+
+    /// Returns true if the panel is in the layout
+    #[must_use]
+    pub fn contains(&self, panel: &str) -> bool {
+        self.root.as_ref().is_some_and(|r| r.contains(panel))
+    }
+
+    ///
+    /// Adds the panel as a new tab of the first group, if it's not already in the layout
+    pub fn add_panel<T: Into<String>>(&mut self, panel: T) {
+        let panel = panel.into();
+        if self.contains(&panel) {
+            return;
+        }
+        let Some(first) = self.panels().first().map(ToString::to_string) else {
+            self.root = Some(DockNode::tabs([panel]));
+            return;
+        };
+        if let Some(root) = &mut self.root {
+            root.insert(&first, panel, DropZone::Tab);
+        }
+    }
+
+    ///
+    /// Removes the panel from the layout, returning true if it was present.
+    pub fn remove_panel(&mut self, panel: &str) -> bool {
+        if !self.contains(panel) {
+            return false;
+        }
+        self.root = self.root.take().and_then(|r| r.remove(panel));
+        true
+    }
+
+    ///
+    /// Moves the panel into the zone of the group containing the target panel.  Returns false if
+    /// either panel isn't in the layout, or they're the same panel.
+    pub fn move_panel(&mut self, panel: &str, target: &str, zone: DropZone) -> bool {
+        if panel == target || !self.contains(panel) || !self.contains(target) {
+            return false;
+        }
+        self.root = self.root.take().and_then(|r| r.remove(panel));
+        if let Some(root) = &mut self.root {
+            root.insert(target, panel.to_string(), zone);
+        }
+        true
+    }
+
+    ///
+    /// Shows the layout in the central panel of the context, calling `add_contents` to draw the
+    /// contents of each visible panel.
+    pub fn show<F: FnMut(&str, &mut Ui)>(&mut self, ctx: &Context, add_contents: F) {
+        CentralPanel::default().show(ctx, |ui| self.ui(ui, add_contents));
+    }
+
+    ///
+    /// Shows the layout in all the available space of the ui, calling `add_contents` to draw the
+    /// contents of each visible panel.
+    pub fn ui<F: FnMut(&str, &mut Ui)>(&mut self, ui: &mut Ui, mut add_contents: F) {
+        let rect = ui.available_rect_before_wrap();
+        ui.allocate_rect(rect, Sense::hover());
+        let Some(root) = &mut self.root else {
+            return;
+        };
+        let drag_id = ui.id().with("irox_dock_drag");
+        let mut groups = Vec::new();
+        let root_id = ui.id().with("irox_dock_node");
+        show_node(
+            root,
+            ui,
+            rect,
+            root_id,
+            drag_id,
+            &mut groups,
+            &mut add_contents,
+        );
+
+        let Some(dragged) = ui.data(|d| d.get_temp::<String>(drag_id)) else {
+            return;
+        };
+        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+        let pointer = ui.ctx().input(|i| i.pointer.latest_pos());
+        let released = ui.ctx().input(|i| i.pointer.any_released());
+        let target = pointer.and_then(|pos| {
+            groups
+                .iter()
+                .find(|(_, rect)| rect.contains(pos))
+                .map(|(target, rect)| (target.clone(), *rect, DropZone::from_position(*rect, pos)))
+        });
+        if let Some((target, rect, zone)) = &target {
+            let color = ui.visuals().selection.bg_fill;
+            ui.painter().rect(
+                zone.preview_rect(*rect),
+                ui.visuals().widgets.noninteractive.rounding,
+                color.gamma_multiply(0.3),
+                Stroke::new(1.0, color),
+            );
+            if released {
+                self.move_panel(&dragged, target, *zone);
+            }
+        }
+        if released {
+            ui.data_mut(|d| d.remove::<String>(drag_id));
+        }
+    }
+}
+
+///
+/// Draws the node into the rect, recording the rect of each tab group and its active panel.
+/// `node_id` identifies the node by its path from the root, `id.with(0)` and `id.with(1)` for the
+/// two sides of a split, so nested splits that share a corner still have distinct separators.
+fn show_node<F: FnMut(&str, &mut Ui)>(
+    node: &mut DockNode,
+    ui: &mut Ui,
+    rect: Rect,
+    node_id: Id,
+    drag_id: Id,
+    groups: &mut Vec<(String, Rect)>,
+    add_contents: &mut F,
+) {
+    match node {
+        DockNode::Split {
+            direction,
+            fraction,
+            first,
+            second,
+        } => {
+            let horizontal = *direction == SplitDirection::Horizontal;
+            let extent = if horizontal {
+                rect.width()
+            } else {
+                rect.height()
+            };
+            let first_extent = (extent - SEPARATOR_WIDTH) * *fraction;
+            let (first_rect, separator, second_rect) = if horizontal {
+                let sep_left = rect.left() + first_extent;
+                (
+                    Rect::from_min_max(rect.min, Pos2::new(sep_left, rect.bottom())),
+                    Rect::from_x_y_ranges(sep_left..=sep_left + SEPARATOR_WIDTH, rect.y_range()),
+                    Rect::from_min_max(Pos2::new(sep_left + SEPARATOR_WIDTH, rect.top()), rect.max),
+                )
+            } else {
+                let sep_top = rect.top() + first_extent;
+                (
+                    Rect::from_min_max(rect.min, Pos2::new(rect.right(), sep_top)),
+                    Rect::from_x_y_ranges(rect.x_range(), sep_top..=sep_top + SEPARATOR_WIDTH),
+                    Rect::from_min_max(Pos2::new(rect.left(), sep_top + SEPARATOR_WIDTH), rect.max),
+                )
+            };
+
+            let response = ui.interact(separator, node_id.with("separator"), Sense::drag());
+            if response.hovered() || response.dragged() {
+                ui.ctx().set_cursor_icon(if horizontal {
+                    CursorIcon::ResizeHorizontal
+                } else {
+                    CursorIcon::ResizeVertical
+                });
+            }
+            if let Some(pos) = response
+                .interact_pointer_pos()
+                .filter(|_| response.dragged())
+            {
+                let offset = if horizontal {
+                    pos.x - rect.left()
+                } else {
+                    pos.y - rect.top()
+                };
+                *fraction = (offset / extent.max(1.0)).clamp(MIN_FRACTION, 1.0 - MIN_FRACTION);
+            }
+            let stroke = if response.hovered() || response.dragged() {
+                ui.visuals().widgets.hovered.fg_stroke
+            } else {
+                ui.visuals().widgets.noninteractive.bg_stroke
+            };
+            let (a, b) = if horizontal {
+                (separator.center_top(), separator.center_bottom())
+            } else {
+                (separator.left_center(), separator.right_center())
+            };
+            ui.painter().line_segment([a, b], stroke);
+
+            let (first_id, second_id) = (node_id.with(0), node_id.with(1));
+            show_node(
+                first,
+                ui,
+                first_rect,
+                first_id,
+                drag_id,
+                groups,
+                add_contents,
+            );
+            show_node(
+                second,
+                ui,
+                second_rect,
+                second_id,
+                drag_id,
+                groups,
+                add_contents,
+            );
+        }
+        DockNode::Tabs { panels, active } => {
+            let tab_height = ui.spacing().interact_size.y;
+            let tab_rect = Rect::from_min_size(rect.min, Vec2::new(rect.width(), tab_height));
+            let content_rect =
+                Rect::from_min_max(Pos2::new(rect.left(), tab_rect.bottom()), rect.max);
+
+            let mut tabs = ui.child_ui(tab_rect, Layout::left_to_right(Align::Center), None);
+            tabs.set_clip_rect(tab_rect.intersect(ui.clip_rect()));
+            for (idx, panel) in panels.iter().enumerate() {
+                let response = tabs
+                    .selectable_label(idx == *active, panel.as_str())
+                    .interact(Sense::click_and_drag());
+                if response.clicked() {
+                    *active = idx;
+                }
+                if response.drag_started() {
+                    tabs.data_mut(|d| d.insert_temp(drag_id, panel.clone()));
+                }
+            }
+            ui.painter().line_segment(
+                [tab_rect.left_bottom(), tab_rect.right_bottom()],
+                ui.visuals().widgets.noninteractive.bg_stroke,
+            );
+
+            let Some(panel) = panels.get(*active) else {
+                return;
+            };
+            groups.push((panel.clone(), rect));
+            let mut content = ui.child_ui(content_rect.shrink(2.0), Layout::default(), None);
+            content.set_clip_rect(content_rect.intersect(ui.clip_rect()));
+            content.push_id(panel.as_str(), |ui| add_contents(panel, ui));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dock::{DockLayout, DockNode, DropZone, SplitDirection};
+
+    #[test]
+    pub fn rearrange() {
+        let mut layout = DockLayout::new(["map", "log", "status"]);
+        assert_eq!(vec!["map", "log", "status"], layout.panels());
+
+        // drag the log to the right of the map, then the status below the log.
+        assert!(layout.move_panel("log", "map", DropZone::Right));
+        assert!(layout.move_panel("status", "log", DropZone::Bottom));
+        assert_eq!(
+            Some(&DockNode::split(
+                SplitDirection::Horizontal,
+                DockNode::tabs(["map"]),
+                DockNode::split(
+                    SplitDirection::Vertical,
+                    DockNode::tabs(["log"]),
+                    DockNode::tabs(["status"]),
+                )
+            )),
+            layout.root()
+        );
+
+        // and back into a tab, collapsing the vertical split.
+        assert!(layout.move_panel("status", "map", DropZone::Tab));
+        assert_eq!(
+            Some(&DockNode::split(
+                SplitDirection::Horizontal,
+                DockNode::Tabs {
+                    panels: vec!["map".to_string(), "status".to_string()],
+                    active: 1
+                },
+                DockNode::tabs(["log"]),
+            )),
+            layout.root()
+        );
+
+        assert!(!layout.move_panel("log", "log", DropZone::Left));
+        assert!(!layout.move_panel("missing", "log", DropZone::Left));
+        assert!(layout.remove_panel("log"));
+        assert!(!layout.remove_panel("log"));
+        layout.add_panel("log");
+        assert_eq!(vec!["map", "status", "log"], layout.panels());
+        for panel in ["map", "status", "log"] {
+            layout.remove_panel(panel);
+        }
+        assert_eq!(None, layout.root());
+    }
+
+    #[test]
+    pub fn nested_split_separators() {
+        use egui::{CentralPanel, Context, Event, Frame, Modifiers, PointerButton, Pos2, RawInput};
+        // both splits start at the same corner, but are dragged independently.
+        let mut layout = DockLayout::from_root(DockNode::split(
+            SplitDirection::Horizontal,
+            DockNode::split(
+                SplitDirection::Horizontal,
+                DockNode::tabs(["map"]),
+                DockNode::tabs(["log"]),
+            ),
+            DockNode::tabs(["status"]),
+        ));
+        let ctx = Context::default();
+        let button = |pos: Pos2, pressed: bool| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::default(),
+        };
+        // the inner separator is centered on the inner split, (800 - 6) / 2 wide.
+        let start = Pos2::new(198.0, 300.0);
+        let end = Pos2::new(300.0, 300.0);
+        let frames = [
+            vec![Event::PointerMoved(start)],
+            vec![button(start, true)],
+            vec![Event::PointerMoved(Pos2::new(250.0, 300.0))],
+            vec![Event::PointerMoved(end)],
+            vec![button(end, false)],
+        ];
+        for events in frames {
+            let input = RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(
+                    Pos2::ZERO,
+                    egui::vec2(800.0, 600.0),
+                )),
+                events,
+                ..RawInput::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default()
+                    .frame(Frame::none())
+                    .show(ctx, |ui| layout.ui(ui, |_, _| {}));
+            });
+        }
+
+        let fractions = match layout.root() {
+            Some(DockNode::Split {
+                fraction: outer,
+                first,
+                ..
+            }) => match first.as_ref() {
+                DockNode::Split {
+                    fraction: inner, ..
+                } => Some((*outer, *inner)),
+                DockNode::Tabs { .. } => None,
+            },
+            _ => None,
+        };
+        assert_eq!(
+            Some(0.5f32.to_bits()),
+            fractions.map(|(outer, _)| outer.to_bits())
+        );
+        let inner = fractions.map_or(0.0, |(_, inner)| inner);
+        assert!((inner - 300.0 / 397.0).abs() < 0.01, "{inner}");
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    #[cfg(feature = "serde")]
+    pub fn serialize_round_trip() -> Result<(), ron::Error> {
+        let mut layout = DockLayout::new(["map", "log", "status", "plot"]);
+        layout.move_panel("log", "map", DropZone::Left);
+        layout.move_panel("plot", "status", DropZone::Top);
+        if let Some(DockNode::Split { fraction, .. }) = &mut layout.root {
+            *fraction = 0.3;
+        }
+
+        let saved = ron::to_string(&layout)?;
+        let restored: DockLayout = ron::from_str(&saved).map_err(|e| e.code)?;
+        assert_eq!(layout, restored);
+        assert_eq!(vec!["log", "plot", "map", "status"], restored.panels());
+        Ok(())
+    }
+}
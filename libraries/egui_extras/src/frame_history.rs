@@ -54,10 +54,67 @@ impl FrameHistory {
         1.0 / self.mean_frame_time()
     }
 
+    ///
+    /// Returns the `p`th percentile (0 to 100) of the retained frame times, interpolating
+    /// between the closest frames.  Returns 0 if no frames have been recorded.
+    #[must_use]
+    pub fn percentile(&self, p: f64) -> f32 {
+        let mut times: Vec<f32> = self.frame_times.values().collect();
+        if times.is_empty() {
+            return 0.0;
+        }
+        times.sort_by(f32::total_cmp);
+        let rank = (p.clamp(0.0, 100.0) / 100.0) * (times.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let frac = (rank - rank.floor()) as f32;
+        let (Some(low), high) = (times.get(lower), times.get(lower + 1)) else {
+            return 0.0;
+        };
+        match high {
+            Some(high) => low + (high - low) * frac,
+            None => *low,
+        }
+    }
+
+    /// The median frame time
+    #[must_use]
+    pub fn p50(&self) -> f32 {
+        self.percentile(50.0)
+    }
+
+    #[must_use]
+    pub fn p95(&self) -> f32 {
+        self.percentile(95.0)
+    }
+
+    #[must_use]
+    pub fn p99(&self) -> f32 {
+        self.percentile(99.0)
+    }
+
+    /// The longest retained frame time, or 0 if no frames have been recorded.
+    #[must_use]
+    pub fn max_frame_time(&self) -> f32 {
+        self.frame_times
+            .values()
+            .reduce(f32::max)
+            .unwrap_or_default()
+    }
+
+    /// The shortest retained frame time, or 0 if no frames have been recorded.
+    #[must_use]
+    pub fn min_frame_time(&self) -> f32 {
+        self.frame_times
+            .values()
+            .reduce(f32::min)
+            .unwrap_or_default()
+    }
+
     pub fn ui(&mut self, ui: &mut egui::Ui) {
         let mean_frame_time = 1e3 * self.mean_frame_time();
         let mean_frame_interval = 1e3 * self.mean_time_interval();
         let avg_fps = self.fps();
+        let p99_frame_time = 1e3 * self.p99();
         let utilization =
             (1.0 - (mean_frame_interval - mean_frame_time).max(0.0) / mean_frame_interval) * 100.0;
 
@@ -65,6 +122,7 @@ impl FrameHistory {
             ui.label("Frame Stats:");
             ui.label(format!("Count {}", ui.ctx().frame_nr())).on_hover_text("Total number of frames rendered");
             ui.label(format!("Duration {mean_frame_time:.2} ms / frame")).on_hover_text("Single-thread CPU time for a single frame, excluding some GPU transfer times");
+            ui.label(format!("p99 {p99_frame_time:.2} ms")).on_hover_text("99th percentile frame duration, a spike above the mean indicates stutter");
             ui.label(format!("Avg FPS {avg_fps:.1}")).on_hover_text("Average frames per second. With VSync, will max out at the monitor's refresh rate, usually about 60 FPS");
             ui.label(format!("Util: {utilization:.2}%")).on_hover_text("Single-thread CPU Render thread utilization, lower is better");
         });
@@ -146,3 +204,53 @@ impl FrameHistory {
         response
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::frame_history::FrameHistory;
+
+    fn assert_ms(expected: f32, actual: f32) {
+        assert!(
+            (expected - actual * 1e3).abs() < 1e-3,
+            "expected {expected}ms but was {}ms",
+            actual * 1e3
+        );
+    }
+
+    #[test]
+    pub fn empty() {
+        let history = FrameHistory::default();
+        for stat in [
+            history.p50(),
+            history.p99(),
+            history.percentile(0.0),
+            history.min_frame_time(),
+            history.max_frame_time(),
+        ] {
+            assert!(stat.abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    pub fn percentiles() {
+        let mut history = FrameHistory::default();
+        history.on_new_frame(0.0, Some(0.004));
+        // a single (projected) frame
+        assert_ms(4.0, history.p50());
+        assert_ms(4.0, history.p99());
+
+        // the first frame time is rewritten with each following frame's, leaving 2..=100 ms
+        // and the projected 100ms.
+        let mut history = FrameHistory::default();
+        for ms in 1..=100 {
+            history.on_new_frame(f64::from(ms) * 1e-3, Some(ms as f32 * 1e-3));
+        }
+        assert_ms(2.0, history.min_frame_time());
+        assert_ms(100.0, history.max_frame_time());
+        assert_ms(2.0, history.percentile(-5.0));
+        assert_ms(51.5, history.p50());
+        assert_ms(96.05, history.p95());
+        assert_ms(100.0, history.p99());
+        assert_ms(100.0, history.percentile(100.0));
+    }
+}
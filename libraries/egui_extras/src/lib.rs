@@ -16,13 +16,22 @@ pub mod styles;
 pub mod composite;
 
 pub mod about;
+/// Draggable and resizable panel layouts
+pub mod dock;
 /// A customization of [`egui::widgets::ProgressBar`]
 pub mod progressbar;
+/// Frame-rate limiting and on-demand repainting
+pub mod repaint;
+/// Transient notification overlays
+pub mod toasts;
 
 #[cfg(feature = "plots")]
 pub mod logplot;
+#[cfg(feature = "map")]
+pub mod mapview;
 #[cfg(feature = "serde")]
 pub mod serde;
+pub mod stripchart;
 pub mod toolframe;
 pub mod visuals;
 
@@ -31,9 +40,22 @@ pub mod build {
 }
 
 pub trait WithAlpha {
+    /// Replaces the alpha of this color, from `0` (transparent) to `255` (opaque)
     #[must_use]
     fn with_alpha(self, alpha: u8) -> Self;
+
+    /// Replaces the alpha of this color, from `0.0` (transparent) to `1.0` (opaque).  By default
+    /// the alpha is clamped and rounded to the nearest step of [`WithAlpha::with_alpha`].
+    #[must_use]
+    fn with_alpha_f32(self, alpha: f32) -> Self
+    where
+        Self: Sized,
+    {
+        self.with_alpha((alpha.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
 }
+
+/// Operates in sRGB (gamma) space, on the unmultiplied color.
 impl WithAlpha for egui::Color32 {
     #[must_use]
     fn with_alpha(self, alpha: u8) -> Self {
@@ -41,3 +63,151 @@ impl WithAlpha for egui::Color32 {
         egui::Color32::from_rgba_unmultiplied(r, g, b, alpha)
     }
 }
+
+/// Operates in linear space, on the unmultiplied color.
+impl WithAlpha for egui::Rgba {
+    #[must_use]
+    fn with_alpha(self, alpha: u8) -> Self {
+        self.with_alpha_f32(f32::from(alpha) / 255.0)
+    }
+
+    #[must_use]
+    fn with_alpha_f32(self, alpha: f32) -> Self {
+        let [r, g, b, _] = self.to_rgba_unmultiplied();
+        egui::Rgba::from_rgba_unmultiplied(r, g, b, alpha.clamp(0.0, 1.0))
+    }
+}
+
+///
+/// Compositing of colors, in the same space as the color type's [`WithAlpha`] implementation.
+pub trait Blend {
+    /// Composites this color over the other (the "over" Porter-Duff operator)
+    #[must_use]
+    fn blend_over(self, other: Self) -> Self;
+
+    /// Linearly interpolates the red, green and blue channels of the unmultiplied colors, from
+    /// this color at `t = 0.0` to the other at `t = 1.0`, keeping this color's alpha.  `t` is
+    /// clamped to `[0, 1]`.
+    #[must_use]
+    fn lerp_rgb(self, other: Self, t: f32) -> Self;
+}
+
+impl Blend for egui::Color32 {
+    fn blend_over(self, other: Self) -> Self {
+        // both premultiplied, so: out = src + dst * (1 - src_alpha)
+        let inv_alpha = 255 - u16::from(self.a());
+        let channel = |src: u8, dst: u8| -> u8 {
+            let dst = (u16::from(dst) * inv_alpha + 127) / 255;
+            (u16::from(src) + dst).min(255) as u8
+        };
+        egui::Color32::from_rgba_premultiplied(
+            channel(self.r(), other.r()),
+            channel(self.g(), other.g()),
+            channel(self.b(), other.b()),
+            channel(self.a(), other.a()),
+        )
+    }
+
+    fn lerp_rgb(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let [r0, g0, b0, a] = self.to_srgba_unmultiplied();
+        let [r1, g1, b1, _] = other.to_srgba_unmultiplied();
+        let lerp = |from: u8, to: u8| -> u8 {
+            (f32::from(from) + (f32::from(to) - f32::from(from)) * t).round() as u8
+        };
+        egui::Color32::from_rgba_unmultiplied(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1), a)
+    }
+}
+
+impl Blend for egui::Rgba {
+    fn blend_over(self, other: Self) -> Self {
+        self + other * (1.0 - self.a())
+    }
+
+    fn lerp_rgb(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let [r0, g0, b0, a] = self.to_rgba_unmultiplied();
+        let [r1, g1, b1, _] = other.to_rgba_unmultiplied();
+        let lerp = |from: f32, to: f32| from + (to - from) * t;
+        egui::Rgba::from_rgba_unmultiplied(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1), a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{Color32, Rgba};
+
+    use crate::{Blend, WithAlpha};
+
+    fn assert_close(expected: [f32; 4], actual: [f32; 4]) {
+        assert!(
+            expected
+                .iter()
+                .zip(actual)
+                .all(|(e, a)| (e - a).abs() < 1e-6),
+            "expected {expected:?} but was {actual:?}"
+        );
+    }
+
+    #[test]
+    pub fn color32_alpha() {
+        let color = Color32::from_rgb(200, 100, 50);
+        // the premultiplied storage loses some precision
+        assert_eq!(
+            [201, 101, 50, 128],
+            color.with_alpha(128).to_srgba_unmultiplied()
+        );
+        assert_eq!([147, 72, 34, 128], color.with_alpha(128).to_array());
+        assert_eq!(color.with_alpha(128), color.with_alpha_f32(0.5));
+        assert_eq!(Color32::TRANSPARENT, color.with_alpha_f32(-1.0));
+        assert_eq!(color, color.with_alpha_f32(2.0));
+    }
+
+    #[test]
+    pub fn rgba_alpha() {
+        let color = Rgba::from_rgb(0.8, 0.4, 0.2);
+        let half = color.with_alpha_f32(0.5);
+        // linear space, so the premultiplied channels are exactly halved.
+        assert_close([0.4, 0.2, 0.1, 0.5], half.to_array());
+        assert_close([0.8, 0.4, 0.2, 0.5], half.to_rgba_unmultiplied());
+        assert_close(
+            color.with_alpha(51).to_array(),
+            color.with_alpha_f32(0.2).to_array(),
+        );
+        assert_eq!(
+            [231, 170, 123, 128],
+            Color32::from(color.with_alpha(128)).to_srgba_unmultiplied()
+        );
+    }
+
+    #[test]
+    pub fn blend() {
+        let red = Color32::from_rgb(255, 0, 0);
+        let blue = Color32::from_rgb(0, 0, 255);
+        assert_eq!(red, red.blend_over(blue));
+        assert_eq!(blue, Color32::TRANSPARENT.blend_over(blue));
+        assert_eq!(
+            [188, 0, 127, 255],
+            red.with_alpha(128).blend_over(blue).to_array()
+        );
+        assert_eq!(
+            [128, 64, 32, 255],
+            red.lerp_rgb(Color32::from_rgb(0, 128, 64), 0.5).to_array()
+        );
+        assert_eq!(red, red.lerp_rgb(blue, -1.0));
+
+        let red = Rgba::from_rgb(1.0, 0.0, 0.0);
+        let blue = Rgba::from_rgb(0.0, 0.0, 1.0);
+        assert_close(
+            [0.25, 0.0, 0.75, 1.0],
+            red.with_alpha_f32(0.25).blend_over(blue).to_array(),
+        );
+        assert_close([0.25, 0.0, 0.75, 1.0], red.lerp_rgb(blue, 0.75).to_array());
+        assert_close(
+            [0.5, 0.0, 0.5, 0.5],
+            red.with_alpha_f32(0.5)
+                .lerp_rgb(blue, 0.5)
+                .to_rgba_unmultiplied(),
+        );
+    }
+}
@@ -11,6 +11,9 @@ use egui::{
     TextStyle, Ui, Vec2,
 };
 use egui_plot::PlotPoint;
+use irox_time::datetime::UTCDateTime;
+use irox_time::epoch::UnixTimestamp;
+use irox_time::format::iso8601::EXTENDED_DATE_TIME_FORMAT;
 use std::fmt::{Display, Formatter, LowerExp};
 use std::sync::Arc;
 
@@ -82,13 +85,111 @@ impl PlotInteraction {
     }
 }
 
+///
+/// A single line of data drawn on a [`BasicPlot`]
+#[derive(Default, Clone)]
+pub struct PlotSeries {
+    /// Name of the series, shown in the cursor readout
+    pub name: String,
+    /// The data to plot each frame, sorted by X.
+    pub data: Arc<Vec<PlotPoint>>,
+    /// Optional unit label of the Y values, shown in the cursor readout
+    pub unit: Option<String>,
+    /// Whether the X values are unix timestamps (in seconds), shown as dates in the cursor readout
+    pub time_based: bool,
+    /// Color of the line, defaults to a color picked by the plot
+    pub color: Option<Color32>,
+}
+
+impl PlotSeries {
+    pub fn new<T: AsRef<str>>(name: T, data: Arc<Vec<PlotPoint>>) -> PlotSeries {
+        PlotSeries {
+            name: name.as_ref().to_string(),
+            data,
+            ..Default::default()
+        }
+    }
+    #[must_use]
+    pub fn with_unit<T: AsRef<str>>(mut self, unit: T) -> Self {
+        self.unit = Some(unit.as_ref().to_string());
+        self
+    }
+    ///
+    /// Marks the X values as unix timestamps (in seconds)
+    #[must_use]
+    pub fn with_time_x(mut self) -> Self {
+        self.time_based = true;
+        self
+    }
+    #[must_use]
+    pub fn with_color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    ///
+    /// Returns the sample with the X value closest to `x`, using a binary search of the (sorted)
+    /// data.
+    #[must_use]
+    pub fn nearest_sample(&self, x: f64) -> Option<&PlotPoint> {
+        let idx = self.data.partition_point(|p| p.x < x);
+        let after = self.data.get(idx);
+        let before = idx.checked_sub(1).and_then(|i| self.data.get(i));
+        match (before, after) {
+            (Some(before), Some(after)) => {
+                if x - before.x <= after.x - x {
+                    Some(before)
+                } else {
+                    Some(after)
+                }
+            }
+            (before, after) => before.or(after),
+        }
+    }
+
+    ///
+    /// Describes the sample for the cursor readout, with the series name, the X value (as a
+    /// date for time based series) and the Y value with the unit.
+    #[must_use]
+    pub fn describe_sample(&self, point: &PlotPoint) -> String {
+        let x = if self.time_based {
+            UTCDateTime::from(UnixTimestamp::from_seconds_f64(point.x))
+                .format(&EXTENDED_DATE_TIME_FORMAT)
+        } else {
+            format!("{}", PrettyDec(point.x))
+        };
+        let unit = self
+            .unit
+            .as_ref()
+            .map(|unit| format!(" {unit}"))
+            .unwrap_or_default();
+        let text = format!("x: {x}\ny: {}{unit}", PrettyDec(point.y));
+        if self.name.is_empty() {
+            text
+        } else {
+            format!("{}\n{text}", self.name)
+        }
+    }
+}
+
+///
+/// Picks a distinct color for each series, the first uses the provided default.
+fn series_color(index: usize, first: Color32) -> Color32 {
+    if index == 0 {
+        return first;
+    }
+    // golden ratio, to spread out the hues
+    let hue = (index as f32 * 0.618_034) % 1.0;
+    egui::ecolor::Hsva::new(hue, 0.85, 0.5, 1.0).into()
+}
+
 ///
 /// Basic plot, with ability to switch between linear and log axes.  This widget
 /// tracks state and is meant to be saved across multiple frames.
 #[derive(Default)]
 pub struct BasicPlot {
-    /// The data to plot each frame.
-    pub data: Arc<Vec<PlotPoint>>,
+    /// The series of data to plot each frame.
+    pub series: Vec<PlotSeries>,
     pub name: Arc<String>,
     /// The X-axis settings
     pub x_axis: Axis,
@@ -98,15 +199,32 @@ pub struct BasicPlot {
     pub interaction: PlotInteraction,
     /// Optional title for this plot.
     pub title: Option<String>,
+    /// Whether the cursor snaps to, and shows the values of, the nearest sample.
+    pub show_cursor_readout: bool,
 }
 
 impl BasicPlot {
     pub fn new(data: Arc<Vec<PlotPoint>>) -> BasicPlot {
         BasicPlot {
-            data,
+            series: vec![PlotSeries::new("", data)],
             ..Default::default()
         }
     }
+    ///
+    /// Adds another series of data to draw on this plot
+    #[must_use]
+    pub fn with_series(mut self, series: PlotSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+    ///
+    /// When enabled, the hover cursor snaps to the sample nearest to the mouse (in any series),
+    /// and shows that sample's values rather than the raw cursor coordinates.
+    #[must_use]
+    pub fn show_cursor_readout(mut self, show: bool) -> Self {
+        self.show_cursor_readout = show;
+        self
+    }
     #[must_use]
     pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
         self.title = Some(title.as_ref().to_string());
@@ -122,6 +240,14 @@ impl BasicPlot {
         self.y_axis.axis_label = Some(title.as_ref().to_string());
         self
     }
+    ///
+    /// Sets the scale of the Y-axis.  Values <= 0 can't be shown on the logarithmic scales, and
+    /// are skipped.
+    #[must_use]
+    pub fn with_y_scale(mut self, scale: LogScale) -> Self {
+        self.y_axis.scale_mode = scale.into();
+        self
+    }
     #[must_use]
     pub fn with_x_axis_formatter(mut self, fmtr: Box<FormatterFn>) -> Self {
         self.x_axis.axis_formatter = Some(fmtr);
@@ -164,6 +290,7 @@ impl BasicPlot {
     pub fn show(&mut self, ui: &mut Ui) {
         let major_stroke = Stroke::new(2.0, ui.visuals().widgets.inactive.fg_stroke.color);
         let minor_stroke = Stroke::new(1.0, ui.visuals().widgets.open.bg_stroke.color);
+        let faint_stroke = Stroke::new(0.5, minor_stroke.color.gamma_multiply(0.5));
         let caution_color = ui.visuals().warn_fg_color;
         let small_font = TextStyle::Small.resolve(ui.style());
         let large_font = TextStyle::Heading.resolve(ui.style());
@@ -186,6 +313,12 @@ impl BasicPlot {
             {
                 ui.close_menu();
             }
+            if ui
+                .selectable_value(&mut self.y_axis.scale_mode, ScaleMode::Ln, "Y-Ln")
+                .clicked()
+            {
+                ui.close_menu();
+            }
             if ui
                 .selectable_value(&mut self.y_axis.scale_mode, ScaleMode::DBScale, "Y-dB")
                 .clicked()
@@ -272,11 +405,43 @@ impl BasicPlot {
         self.y_axis.screen_range = x_axis_y_offset - y_axis_y_min;
         self.y_axis.incr_sign = -1.0;
 
-        let points = &self.data;
         // update and rescale the data based on this frame's painting window.
-        self.x_axis.update_range(points, |p| p.x);
-        self.y_axis.update_range(points, |p| p.y);
+        self.x_axis
+            .update_range(self.series.iter().flat_map(|s| s.data.iter()), |p| p.x);
+        self.y_axis
+            .update_range(self.series.iter().flat_map(|s| s.data.iter()), |p| p.y);
 
+        // draw the faint minor gridlines within each decade of the log axes
+        for x in &self.x_axis.minor_detents {
+            painter.line_segment(
+                [
+                    Pos2 {
+                        x: *x,
+                        y: y_axis_y_min,
+                    },
+                    Pos2 {
+                        x: *x,
+                        y: x_axis_y_offset,
+                    },
+                ],
+                faint_stroke,
+            );
+        }
+        for y in &self.y_axis.minor_detents {
+            painter.line_segment(
+                [
+                    Pos2 {
+                        x: x_axis_x_min,
+                        y: *y,
+                    },
+                    Pos2 {
+                        x: x_axis_x_max,
+                        y: *y,
+                    },
+                ],
+                faint_stroke,
+            );
+        }
         // draw the info across the bottom of the x axis
         for detent in &self.x_axis.detents {
             let pos = Pos2 {
@@ -351,25 +516,31 @@ impl BasicPlot {
         );
 
         // draw the points as individual line segments
-        for pnt in points.windows(2) {
-            let Some(first) = pnt.first() else {
-                continue;
-            };
-            let Some(second) = pnt.get(1) else {
-                continue;
-            };
-            let Some(first) = self.scale_point(first) else {
-                draw_log_warning = true;
-                self.draw_yellow_err_line(&mut painter, first, ui);
-                continue;
-            };
-            let Some(second) = self.scale_point(second) else {
-                draw_log_warning = true;
-                self.draw_yellow_err_line(&mut painter, second, ui);
-                continue;
-            };
-            // draw the actual line
-            painter.line_segment([first, second], major_stroke);
+        for (idx, series) in self.series.iter().enumerate() {
+            let color = series
+                .color
+                .unwrap_or_else(|| series_color(idx, major_stroke.color));
+            let stroke = Stroke::new(major_stroke.width, color);
+            for pnt in series.data.windows(2) {
+                let Some(first) = pnt.first() else {
+                    continue;
+                };
+                let Some(second) = pnt.get(1) else {
+                    continue;
+                };
+                let Some(first) = self.scale_point(first) else {
+                    draw_log_warning = true;
+                    self.draw_yellow_err_line(&mut painter, first, ui);
+                    continue;
+                };
+                let Some(second) = self.scale_point(second) else {
+                    draw_log_warning = true;
+                    self.draw_yellow_err_line(&mut painter, second, ui);
+                    continue;
+                };
+                // draw the actual line
+                painter.line_segment([first, second], stroke);
+            }
         }
 
         self.draw_cursor(ui, &mut response, &mut painter);
@@ -378,7 +549,7 @@ impl BasicPlot {
             painter.text(
                 rect.center_bottom(),
                 Align2::CENTER_BOTTOM,
-                "Warning: some points <= 0 were skipped in log10/dB mode.".to_string(),
+                "Warning: some points <= 0 were skipped in log/dB mode.".to_string(),
                 small_font.clone(),
                 caution_color,
             );
@@ -394,6 +565,21 @@ impl BasicPlot {
         }
     }
 
+    ///
+    /// Finds the sample closest to the screen position.  The sample nearest in X of each series
+    /// is found, then whichever of those is closest in screen space is picked.
+    fn nearest_sample(&self, pos: Pos2) -> Option<(&PlotSeries, &PlotPoint, Pos2)> {
+        let x = self.x_axis.unscale_value(pos.x);
+        self.series
+            .iter()
+            .filter_map(|series| {
+                let point = series.nearest_sample(x)?;
+                let screen = self.scale_point(point)?;
+                Some((series, point, screen))
+            })
+            .min_by(|a, b| a.2.distance_sq(pos).total_cmp(&b.2.distance_sq(pos)))
+    }
+
     fn scale_point(&self, pos: &PlotPoint) -> Option<Pos2> {
         Some(Pos2 {
             x: self.x_axis.scale_value(pos.x)?,
@@ -432,19 +618,32 @@ impl BasicPlot {
 
     fn draw_cursor(&mut self, ui: &mut Ui, response: &mut Response, painter: &mut Painter) {
         // draw the hover cursors
-        if let Some(hover) = response.hover_pos() {
+        if let Some(mut hover) = response.hover_pos() {
             let rect = response.rect;
             let xrng = rect.min.x..=rect.max.x;
             let yrng = rect.min.y..=rect.max.y;
             let color = ui.visuals().widgets.noninteractive.fg_stroke;
+
+            let nearest = if self.show_cursor_readout {
+                self.nearest_sample(hover)
+            } else {
+                None
+            };
+            let text = if let Some((series, point, screen)) = nearest {
+                // snap to the sample
+                hover = screen;
+                painter.circle_stroke(hover, 4.0, color);
+                series.describe_sample(point)
+            } else {
+                let mod_x = self.x_axis.describe_screen_pos(hover.x);
+                let mod_y = self.y_axis.describe_screen_pos(hover.y);
+                format!("x: {mod_x}\ny: {mod_y}")
+            };
             // paint the crosshair lines
             painter.hline(xrng, hover.y, color);
             painter.vline(hover.x, yrng, color);
 
             // paint the text
-            let mod_x = self.x_axis.describe_screen_pos(hover.x);
-            let mod_y = self.y_axis.describe_screen_pos(hover.y);
-            let text = format!("x: {mod_x}\ny: {mod_y}");
             let color = ui.visuals().text_cursor.stroke.color;
             let font_id = TextStyle::Monospace.resolve(ui.style());
             let mut align = Align2::LEFT_BOTTOM;
@@ -476,8 +675,31 @@ pub enum ScaleMode {
     #[default]
     Linear,
     Log10,
+    Ln,
     DBScale,
 }
+
+///
+/// The scale of the values along an axis of a [`BasicPlot`]
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LogScale {
+    #[default]
+    Linear,
+    /// Base-10 logarithmic scale, with gridlines at each decade
+    Log10,
+    /// Natural logarithmic scale, with gridlines at each decade
+    Ln,
+}
+
+impl From<LogScale> for ScaleMode {
+    fn from(value: LogScale) -> Self {
+        match value {
+            LogScale::Linear => ScaleMode::Linear,
+            LogScale::Log10 => ScaleMode::Log10,
+            LogScale::Ln => ScaleMode::Ln,
+        }
+    }
+}
 #[derive(Default)]
 pub struct Axis {
     pub name: Arc<String>,
@@ -499,6 +721,8 @@ pub struct Axis {
     pub screen_limit: f32,
     /// The list of detents to draw on this axis, position and label
     pub detents: Vec<(f32, String)>,
+    /// The positions of the unlabeled minor gridlines on this axis, only used by the log scales
+    pub minor_detents: Vec<f32>,
     /// Whether or not to draw a warning that the values have been clipped to
     /// positive values for `log` drawing
     pub draw_log_clip_warning: bool,
@@ -514,16 +738,21 @@ pub struct Axis {
 }
 
 impl Axis {
-    pub fn update_range<F: Fn(&PlotPoint) -> f64>(&mut self, vals: &[PlotPoint], accessor: F) {
+    pub fn update_range<'a, I: IntoIterator<Item = &'a PlotPoint>, F: Fn(&PlotPoint) -> f64>(
+        &mut self,
+        vals: I,
+        accessor: F,
+    ) {
         self.draw_log_clip_warning = false;
+        self.minor_detents.clear();
         self.min_val = f64::INFINITY;
         self.max_val = f64::NEG_INFINITY;
         for val in vals {
             let v = match self.scale_mode {
                 ScaleMode::Linear => accessor(val),
-                ScaleMode::Log10 | ScaleMode::DBScale => {
+                ScaleMode::Log10 | ScaleMode::Ln | ScaleMode::DBScale => {
                     let v = accessor(val);
-                    if v <= 0.0 {
+                    if v <= 0.0 || !v.is_finite() {
                         self.draw_log_clip_warning = true;
                         continue;
                     }
@@ -540,9 +769,20 @@ impl Axis {
             }
             self.min_val = 10. * self.min_val.log10();
             self.max_val = 10. * self.max_val.log10();
-        } else if self.scale_mode == ScaleMode::Log10 && self.min_val <= 0.0 {
-            self.draw_log_clip_warning = true;
-            self.min_val = f64::MIN_POSITIVE;
+        } else if self.is_log() {
+            if !self.min_val.is_finite() || !self.max_val.is_finite() {
+                // nothing plottable, show a single decade.
+                self.min_val = 1.0;
+                self.max_val = 10.0;
+            }
+            // autoscale out to the enclosing decades
+            let min_decade = self.min_val.log10().floor();
+            let mut max_decade = self.max_val.log10().ceil();
+            if max_decade <= min_decade {
+                max_decade = min_decade + 1.0;
+            }
+            self.min_val = self.log_scale(10f64.powf(min_decade));
+            self.max_val = self.log_scale(10f64.powf(max_decade));
         }
         if let Some((min, max)) = self.zoomed_range {
             self.min_val = min;
@@ -582,67 +822,53 @@ impl Axis {
                     self.detents.push((drawpnt, label));
                 }
             }
-            ScaleMode::Log10 => {
-                self.detents = Vec::new();
-                let range = self.max_val - self.min_val;
-                let mut range_exp = if range < 1.0 {
-                    range.log10().floor() as i32
-                } else {
-                    range.log10().ceil() as i32
-                };
+            ScaleMode::Log10 | ScaleMode::Ln => self.update_log_detents(),
+        }
+    }
 
-                let mut scalef = 10f64.powi(range_exp);
-                let mut mv = (self.min_val / scalef).floor() * scalef;
-                if self.min_val < 1.0 {
-                    range_exp = self.min_val.log10().floor() as i32;
-                    scalef = 10f64.powi(range_exp);
-                    mv = (self.min_val / scalef).floor() * scalef;
-                }
-                self.min_val = mv;
-                self.max_val = (self.max_val / scalef).ceil() * scalef;
-                let mut current_exp = range_exp - 1;
-                let mut current = self.min_val;
-                let stop = self.max_val;
-                // major detents
-                let drawpnt = self.model_to_screen(self.log_scale(current));
-                let label = if let Some(fmtr) = &self.axis_formatter {
-                    fmtr(current)
-                } else {
-                    format!("{current:.4}: 1e{current_exp:.4}")
-                };
-                self.detents.push((drawpnt, label));
-                while current <= stop {
-                    let incr = 10f64.powi(current_exp);
-                    let next = current + 10f64 * incr;
-                    current = (current / incr).round() * incr;
-                    let drawpnt = self.model_to_screen(self.log_scale(current));
-                    let label = if let Some(fmtr) = &self.axis_formatter {
-                        fmtr(current)
-                    } else {
-                        format!("{current:.4}: 1e{current_exp:.4}")
-                    };
-                    self.detents.push((drawpnt, label));
+    fn is_log(&self) -> bool {
+        matches!(self.scale_mode, ScaleMode::Log10 | ScaleMode::Ln)
+    }
 
-                    // minor detents
-                    for _idx in 1..10 {
-                        if current >= next || current >= stop {
-                            break;
-                        }
-                        current += incr;
-                        let drawpnt = self.model_to_screen(self.log_scale(current));
-                        self.detents.push((drawpnt, String::default()));
-                    }
+    ///
+    /// Places a labeled major detent at each decade (`10^n`) in the range, and the unlabeled minor
+    /// detents at 2,3,...,9 times each decade.
+    fn update_log_detents(&mut self) {
+        self.detents = Vec::new();
+        let (lo, hi) = if self.min_val <= self.max_val {
+            (self.min_val, self.max_val)
+        } else {
+            (self.max_val, self.min_val)
+        };
+        // allow for the rounding error of the log/exp round trip at the bounds.
+        let tolerance = (hi - lo) * 1e-9;
+        let in_range = |model: f64| model >= lo - tolerance && model <= hi + tolerance;
 
-                    current_exp += 1;
-                }
-                let drawpnt = self.model_to_screen(self.log_scale(self.max_val));
+        let min_decade = self.log_unscale(lo).log10().floor() as i32;
+        let max_decade = self.log_unscale(hi).log10().ceil() as i32;
+        // only label every n'th decade on a very wide range, and skip the minor detents.
+        let step = ((max_decade - min_decade) / 10).max(1);
+        let mut decade = min_decade;
+        while decade <= max_decade {
+            let major = 10f64.powi(decade);
+            let model = self.log_scale(major);
+            if in_range(model) {
                 let label = if let Some(fmtr) = &self.axis_formatter {
-                    fmtr(self.max_val)
+                    fmtr(major)
                 } else {
-                    format!("{:.4}: 1e{current_exp:.4}", self.max_val)
+                    format!("10^{decade}")
                 };
-                self.detents.push((drawpnt, label));
+                self.detents.push((self.model_to_screen(model), label));
+            }
+            if step == 1 {
+                for mult in 2..=9 {
+                    let model = self.log_scale(major * f64::from(mult));
+                    if in_range(model) {
+                        self.minor_detents.push(self.model_to_screen(model));
+                    }
+                }
             }
+            decade += step;
         }
     }
 
@@ -653,23 +879,26 @@ impl Axis {
         val as f64 * self.range + self.min_val
     }
 
+    ///
+    /// Converts the data value into the model coordinates of the log scale (`log10` or `ln`).
+    /// Values <= 0 are clamped to the smallest positive value.
     pub fn log_scale(&self, mut val: f64) -> f64 {
         if val <= 0.0 {
             val = f64::MIN_POSITIVE;
         }
-        let min_val = self.min_val.log10();
-        let max_val = self.max_val.log10();
-        let frac = (val.log10() - min_val) / (max_val - min_val);
-        frac * self.range + self.min_val
+        match self.scale_mode {
+            ScaleMode::Ln => val.ln(),
+            _ => val.log10(),
+        }
     }
 
+    ///
+    /// Converts the model coordinates of the log scale back into the data value.
     pub fn log_unscale(&self, val: f64) -> f64 {
-        let frac = (val - self.min_val) / self.range;
-        let min_val = self.min_val.log10();
-        let max_val = self.max_val.log10();
-        let frac = frac * (max_val - min_val) + min_val;
-
-        10f64.powf(frac)
+        match self.scale_mode {
+            ScaleMode::Ln => val.exp(),
+            _ => 10f64.powf(val),
+        }
     }
 
     pub fn db_scale(&self, mut val: f64) -> f64 {
@@ -686,8 +915,8 @@ impl Axis {
     pub fn scale_value(&self, val: f64) -> Option<f32> {
         match self.scale_mode {
             ScaleMode::Linear => Some(self.model_to_screen(val)),
-            ScaleMode::Log10 => {
-                if val <= 0.0 {
+            ScaleMode::Log10 | ScaleMode::Ln => {
+                if val <= 0.0 || !val.is_finite() {
                     None
                 } else {
                     let v = self.log_scale(val);
@@ -707,7 +936,7 @@ impl Axis {
     pub fn unscale_value(&self, val: f32) -> f64 {
         match self.scale_mode {
             ScaleMode::Linear => self.screen_to_model(val),
-            ScaleMode::Log10 => {
+            ScaleMode::Log10 | ScaleMode::Ln => {
                 let val = self.screen_to_model(val);
                 self.log_unscale(val)
             }
@@ -745,8 +974,11 @@ impl Axis {
             ScaleMode::Linear => format!("{}", PrettyDec(v)),
             ScaleMode::Log10 => {
                 let orig = self.log_unscale(v);
-                // let scaled = orig.log10();
-                format!("{orig}")
+                format!("{}", PrettyDec(orig))
+            }
+            ScaleMode::Ln => {
+                let orig = self.log_unscale(v);
+                format!("{}, ln={}", PrettyDec(orig), PrettyDec(v))
             }
             ScaleMode::DBScale => {
                 let orig = self.db_unscale(v);
@@ -766,3 +998,164 @@ impl<T: LowerExp> Display for PrettyDec<T> {
         f.write_str(&v)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use egui::Pos2;
+    use egui_plot::PlotPoint;
+
+    use crate::logplot::{Axis, BasicPlot, LogScale, PlotSeries};
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 1e-6,
+            "expected {expected} but was {actual}"
+        );
+    }
+
+    fn series(name: &str, points: &[(f64, f64)]) -> PlotSeries {
+        let data = points.iter().map(|(x, y)| PlotPoint::new(*x, *y)).collect();
+        PlotSeries::new(name, Arc::new(data))
+    }
+
+    fn log_axis(scale: LogScale) -> Axis {
+        Axis {
+            scale_mode: scale.into(),
+            incr_sign: 1.0,
+            screen_origin: 0.0,
+            screen_range: 100.0,
+            screen_limit: 100.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    pub fn log10_decades() {
+        let points = [0.5, -1.0, 0.0, 3.0, 250.0, f64::NAN]
+            .into_iter()
+            .map(|y| PlotPoint::new(0.0, y))
+            .collect::<Vec<_>>();
+        let mut axis = log_axis(LogScale::Log10);
+        axis.update_range(&points, |p| p.y);
+
+        assert!(axis.draw_log_clip_warning);
+        assert_close(-1.0, axis.min_val);
+        assert_close(3.0, axis.max_val);
+        let labels = axis
+            .detents
+            .iter()
+            .map(|(_, l)| l.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["10^-1", "10^0", "10^1", "10^2", "10^3"], labels);
+        assert_eq!(4 * 8, axis.minor_detents.len());
+
+        assert_eq!(None, axis.scale_value(0.0));
+        assert_eq!(None, axis.scale_value(-2.0));
+        assert_eq!(None, axis.scale_value(f64::NAN));
+        assert_close(0.0, f64::from(axis.scale_value(0.1).unwrap_or_default()));
+        assert_close(50.0, f64::from(axis.scale_value(10.0).unwrap_or_default()));
+        assert_close(
+            100.0,
+            f64::from(axis.scale_value(1000.0).unwrap_or_default()),
+        );
+        assert_close(10.0, axis.unscale_value(50.0));
+    }
+
+    #[test]
+    pub fn ln_decades() {
+        let points = [2.0, 40.0]
+            .into_iter()
+            .map(|y| PlotPoint::new(0.0, y))
+            .collect::<Vec<_>>();
+        let mut axis = log_axis(LogScale::Ln);
+        axis.update_range(&points, |p| p.y);
+
+        assert!(!axis.draw_log_clip_warning);
+        assert_close(0.0, axis.min_val);
+        assert_close(100f64.ln(), axis.max_val);
+        let labels = axis
+            .detents
+            .iter()
+            .map(|(_, l)| l.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["10^0", "10^1", "10^2"], labels);
+        assert_eq!(2 * 8, axis.minor_detents.len());
+        assert_close(50.0, f64::from(axis.scale_value(10.0).unwrap_or_default()));
+        assert_close(10.0, axis.unscale_value(50.0));
+    }
+
+    #[test]
+    pub fn log_without_data() {
+        let mut axis = log_axis(LogScale::Log10);
+        axis.update_range(&[PlotPoint::new(0.0, -5.0)], |p| p.y);
+        assert!(axis.draw_log_clip_warning);
+        assert_close(0.0, axis.min_val);
+        assert_close(1.0, axis.max_val);
+        assert_eq!(2, axis.detents.len());
+        assert_eq!(8, axis.minor_detents.len());
+    }
+
+    #[test]
+    pub fn nearest_sample() {
+        let series = series("", &[(0.0, 1.0), (1.0, 2.0), (3.0, 3.0), (10.0, 4.0)]);
+        let nearest = |x: f64| series.nearest_sample(x).map(|p| p.y);
+        assert_eq!(Some(1.0), nearest(-5.0));
+        assert_eq!(Some(1.0), nearest(0.4));
+        assert_eq!(Some(2.0), nearest(1.9));
+        assert_eq!(Some(3.0), nearest(2.1));
+        assert_eq!(Some(3.0), nearest(3.0));
+        assert_eq!(Some(4.0), nearest(100.0));
+        assert_eq!(None, PlotSeries::default().nearest_sample(1.0).map(|p| p.y));
+    }
+
+    #[test]
+    pub fn describe_sample() {
+        let point = PlotPoint::new(1_704_067_230.0, 12.5);
+        assert_eq!(
+            "x: 1.7041e9\ny: 1.2500e1",
+            PlotSeries::default().describe_sample(&point)
+        );
+        let series = series("temp", &[]).with_unit("degC").with_time_x();
+        assert_eq!(
+            "temp\nx: 2024-01-01T00:00:30Z\ny: 1.2500e1 degC",
+            series.describe_sample(&point)
+        );
+    }
+
+    #[test]
+    pub fn snaps_across_series() {
+        let mut plot = BasicPlot::new(Arc::new(vec![
+            PlotPoint::new(0.0, 0.0),
+            PlotPoint::new(10.0, 0.0),
+        ]))
+        .with_series(series("upper", &[(0.0, 10.0), (5.0, 10.0), (10.0, 10.0)]));
+        for axis in [&mut plot.x_axis, &mut plot.y_axis] {
+            axis.incr_sign = 1.0;
+            axis.screen_range = 100.0;
+            axis.screen_limit = 100.0;
+        }
+        plot.x_axis
+            .update_range(plot.series.iter().flat_map(|s| s.data.iter()), |p| p.x);
+        plot.y_axis
+            .update_range(plot.series.iter().flat_map(|s| s.data.iter()), |p| p.y);
+
+        let nearest = |pos: Pos2| {
+            plot.nearest_sample(pos)
+                .map(|(series, point, _)| (series.name.clone(), point.x, point.y))
+        };
+        assert_eq!(
+            Some((String::new(), 0.0, 0.0)),
+            nearest(Pos2::new(20.0, 10.0))
+        );
+        assert_eq!(
+            Some(("upper".to_string(), 5.0, 10.0)),
+            nearest(Pos2::new(40.0, 60.0))
+        );
+        assert_eq!(
+            Some(("upper".to_string(), 10.0, 10.0)),
+            nearest(Pos2::new(90.0, 90.0))
+        );
+    }
+}
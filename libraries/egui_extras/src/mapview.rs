@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! A basic pan & zoom "slippy map" widget, built on the [`SphericalMercatorProjection`]
+
+use egui::{pos2, vec2, Color32, Rect, Response, Sense, TextureHandle, Ui, Vec2};
+use irox_carto::coordinate::EllipticalCoordinate;
+use irox_carto::epsg3857::SphericalMercatorProjection;
+
+/// The standard edge size of a web mercator tile, in pixels.
+pub const DEFAULT_TILE_SIZE: f32 = 256.0;
+/// The default lowest zoom level
+pub const DEFAULT_MIN_ZOOM: f64 = 0.0;
+/// The default highest zoom level
+pub const DEFAULT_MAX_ZOOM: f64 = 19.0;
+
+/// How many zoom levels a single "point" of scroll delta moves
+const SCROLL_ZOOM_RATE: f64 = 1.0 / 200.0;
+
+///
+/// Provides a (possibly not-yet-loaded) texture for a specific `(zoom, x, y)` tile index.  Return
+/// [`None`] if the tile isn't available yet - it will be requested again next frame.
+pub type TileSourceFn = dyn FnMut(u8, u64, u64) -> Option<TextureHandle>;
+
+///
+/// A single tile that intersects the viewport.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VisibleTile {
+    /// Integer zoom level of the tile
+    pub zoom: u8,
+    /// Tile column, wrapped into the range `[0, 2^zoom)`
+    pub x: u64,
+    /// Tile row, in the range `[0, 2^zoom)`
+    pub y: u64,
+    /// Where the tile is drawn, relative to the top-left corner of the viewport
+    pub rect: Rect,
+}
+
+///
+/// Computes the set of tiles required to cover a viewport of the specified size, centered on the
+/// `center` coordinate, at the provided fractional `zoom` level.  Tiles are requested at the
+/// integer zoom level below `zoom`, and scaled up to fill the gap.
+#[must_use]
+pub fn visible_tiles(
+    center: &EllipticalCoordinate,
+    zoom: f64,
+    viewport: Vec2,
+    tile_size: f32,
+) -> Vec<VisibleTile> {
+    let zoom = zoom.max(0.0);
+    let tile_zoom = zoom.floor() as u8;
+    let proj = SphericalMercatorProjection::new(tile_zoom);
+    let num_tiles = proj.max_tile_index() + 1;
+    let scaled_tile_size = f64::from(tile_size) * 2_f64.powf(zoom - f64::from(tile_zoom));
+    if scaled_tile_size <= 0.0 {
+        return Vec::new();
+    }
+
+    let center_x = proj.tile_x_index(center);
+    let center_y = proj.tile_y_index(center);
+    let half_width = f64::from(viewport.x) / 2.0 / scaled_tile_size;
+    let half_height = f64::from(viewport.y) / 2.0 / scaled_tile_size;
+
+    let min_x = (center_x - half_width).floor() as i64;
+    let max_x = (center_x + half_width).ceil() as i64;
+    let min_y = ((center_y - half_height).floor() as i64).max(0);
+    let max_y = ((center_y + half_height).ceil() as i64).min(num_tiles as i64);
+
+    let mut out = Vec::new();
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let left = (x as f64 - center_x) * scaled_tile_size + f64::from(viewport.x) / 2.0;
+            let top = (y as f64 - center_y) * scaled_tile_size + f64::from(viewport.y) / 2.0;
+            let rect = Rect::from_min_size(
+                pos2(left as f32, top as f32),
+                vec2(scaled_tile_size as f32, scaled_tile_size as f32),
+            );
+            out.push(VisibleTile {
+                zoom: tile_zoom,
+                x: x.rem_euclid(num_tiles as i64) as u64,
+                y: y as u64,
+                rect,
+            });
+        }
+    }
+    out
+}
+
+///
+/// A slippy-map viewer.  Drag to pan, scroll to zoom.
+pub struct MapView {
+    center: EllipticalCoordinate,
+    zoom: f64,
+    min_zoom: f64,
+    max_zoom: f64,
+    tile_size: f32,
+    tile_source: Box<TileSourceFn>,
+}
+
+impl MapView {
+    ///
+    /// Creates a new map view centered on the provided coordinate, at the provided zoom level.
+    #[must_use]
+    pub fn new(center: EllipticalCoordinate, zoom: f64, tile_source: Box<TileSourceFn>) -> MapView {
+        MapView {
+            center,
+            zoom: zoom.clamp(DEFAULT_MIN_ZOOM, DEFAULT_MAX_ZOOM),
+            min_zoom: DEFAULT_MIN_ZOOM,
+            max_zoom: DEFAULT_MAX_ZOOM,
+            tile_size: DEFAULT_TILE_SIZE,
+            tile_source,
+        }
+    }
+
+    ///
+    /// Sets the allowable zoom range, clamping the current zoom level into it.
+    #[must_use]
+    pub fn with_zoom_range(mut self, min_zoom: f64, max_zoom: f64) -> MapView {
+        self.min_zoom = min_zoom.max(0.0);
+        self.max_zoom = max_zoom.max(self.min_zoom);
+        self.zoom = self.zoom.clamp(self.min_zoom, self.max_zoom);
+        self
+    }
+
+    ///
+    /// Sets the edge size of the tiles provided by the tile source, in pixels.
+    #[must_use]
+    pub fn with_tile_size(mut self, tile_size: f32) -> MapView {
+        self.tile_size = tile_size;
+        self
+    }
+
+    /// Returns the coordinate at the center of the view
+    #[must_use]
+    pub fn center(&self) -> &EllipticalCoordinate {
+        &self.center
+    }
+
+    /// Moves the center of the view to the provided coordinate
+    pub fn set_center(&mut self, center: EllipticalCoordinate) {
+        self.center = center;
+    }
+
+    /// Returns the current fractional zoom level
+    #[must_use]
+    pub fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    /// Sets the current zoom level, clamped to the allowable range
+    pub fn set_zoom(&mut self, zoom: f64) {
+        self.zoom = zoom.clamp(self.min_zoom, self.max_zoom);
+    }
+
+    /// Returns the size of the whole world, in pixels, at the current zoom level
+    fn world_size(&self) -> f64 {
+        f64::from(self.tile_size) * 2_f64.powf(self.zoom)
+    }
+
+    ///
+    /// Shifts the center of the map such that the normalized `[0,1]` mercator position `norm`
+    /// is drawn at `offset` pixels from the center of the view.
+    fn place_normalized_at(&mut self, norm: (f64, f64), offset: Vec2) {
+        let world = self.world_size();
+        let x = (norm.0 - f64::from(offset.x) / world).rem_euclid(1.0);
+        let y = (norm.1 - f64::from(offset.y) / world).clamp(0.0, 1.0);
+        let proj = SphericalMercatorProjection::new(0);
+        self.center = EllipticalCoordinate::new(
+            proj.latitude(y),
+            proj.longitude(x),
+            *self.center.get_reference_frame(),
+        );
+    }
+
+    /// Returns the normalized `[0,1]` mercator position `offset` pixels from the center.
+    fn normalized_at(&self, offset: Vec2) -> (f64, f64) {
+        let proj = SphericalMercatorProjection::new(0);
+        let world = self.world_size();
+        (
+            proj.tile_x_index(&self.center) + f64::from(offset.x) / world,
+            proj.tile_y_index(&self.center) + f64::from(offset.y) / world,
+        )
+    }
+
+    ///
+    /// Draws the map filling the available space, and processes any pan/zoom input.
+    pub fn show(&mut self, ui: &mut Ui) -> Response {
+        let size = ui.available_size();
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+
+        if response.dragged() {
+            let norm = self.normalized_at(Vec2::ZERO);
+            self.place_normalized_at(norm, response.drag_delta());
+        }
+        if let Some(hover) = response.hover_pos() {
+            let scroll = ui.input(|i| i.smooth_scroll_delta.y);
+            if scroll != 0.0 {
+                // keep the point under the mouse cursor fixed while zooming.
+                let offset = hover - rect.center();
+                let norm = self.normalized_at(offset);
+                self.set_zoom(self.zoom + f64::from(scroll) * SCROLL_ZOOM_RATE);
+                self.place_normalized_at(norm, offset);
+            }
+        }
+
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+        let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+        for tile in visible_tiles(&self.center, self.zoom, rect.size(), self.tile_size) {
+            let Some(texture) = (self.tile_source)(tile.zoom, tile.x, tile.y) else {
+                continue;
+            };
+            let tile_rect = tile.rect.translate(rect.min.to_vec2());
+            painter.image(texture.id(), tile_rect, uv, Color32::WHITE);
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mapview::visible_tiles;
+    use egui::vec2;
+    use irox_carto::coordinate::EllipticalCoordinate;
+
+    #[test]
+    pub fn visible_tile_set() {
+        let center = EllipticalCoordinate::new_degrees_wgs84(0.0, 0.0);
+
+        // zoom 1 is a 2x2 grid, all 4 tiles centered in a 512x512 viewport.
+        let tiles = visible_tiles(&center, 1.0, vec2(512.0, 512.0), 256.0);
+        let mut idx: Vec<(u64, u64)> = tiles.iter().map(|t| (t.x, t.y)).collect();
+        idx.sort_unstable();
+        assert_eq!(idx, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert!(tiles.iter().all(|t| t.zoom == 1));
+
+        // a 256x256 view at zoom 1 still touches all 4 tiles, each drawn at full size.
+        let tiles = visible_tiles(&center, 1.0, vec2(256.0, 256.0), 256.0);
+        assert_eq!(4, tiles.len());
+        assert!(tiles.iter().all(|t| (t.rect.width() - 256.0).abs() < 1e-3));
+
+        // fractional zoom requests the lower tile level and scales up
+        let tiles = visible_tiles(&center, 1.5, vec2(256.0, 256.0), 256.0);
+        assert!(tiles.iter().all(|t| t.zoom == 1));
+        let expected = 256.0 * 2_f32.sqrt();
+        assert!(tiles
+            .iter()
+            .all(|t| (t.rect.width() - expected).abs() < 1e-3));
+    }
+
+    #[test]
+    pub fn visible_tiles_wrap_longitude() {
+        let center = EllipticalCoordinate::new_degrees_wgs84(0.0, 179.9);
+        let tiles = visible_tiles(&center, 2.0, vec2(512.0, 256.0), 256.0);
+        assert!(tiles.iter().all(|t| t.x < 4 && t.y < 4));
+        // tiles past the antimeridian wrap back around to the first column
+        assert!(tiles.iter().any(|t| t.x == 0));
+        assert!(tiles.iter().any(|t| t.x == 3));
+    }
+}
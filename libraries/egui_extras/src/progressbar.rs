@@ -11,6 +11,11 @@ use egui::{
     Color32, NumExt, Rect, Response, Rgba, Sense, Stroke, TextStyle, Ui, Vec2, Widget, WidgetText,
 };
 
+/// Time for the highlight band of an indeterminate bar to sweep across the bar, in seconds
+const SWEEP_PERIOD: f64 = 1.5;
+/// Width of the highlight band of an indeterminate bar, as a fraction of the width of the bar
+const BAND_WIDTH: f32 = 0.25;
+
 ///
 /// A progress bar that supports both determinate (`[0->100]`) and indeterminate modes.
 #[derive(Debug, Default, Clone)]
@@ -25,7 +30,7 @@ pub struct ProgressBar {
     pub right_text: Option<String>,
     /// Request an immediate repaint of the bar
     pub animate: bool,
-    /// The indicated progress is ignored, and the bar bounces back and forth
+    /// The indicated progress is ignored, and a highlight band sweeps across the bar
     pub is_indeterminate: bool,
     /// The desired draw width of the bar
     pub desired_width: Option<f32>,
@@ -45,8 +50,9 @@ impl ProgressBar {
     }
 
     ///
-    /// Creates a new progress bar that is indeterminate.  The little bar will bounce back and
-    /// forth to indicate stuff is happening.
+    /// Creates a new progress bar that is indeterminate, for when the total amount of work isn't
+    /// known.  A highlight band repeatedly sweeps across the bar to indicate stuff is happening,
+    /// repainting continuously while the bar is visible.
     #[must_use]
     pub fn indeterminate() -> ProgressBar {
         ProgressBar {
@@ -126,7 +132,7 @@ impl Widget for ProgressBar {
             is_indeterminate,
         } = self;
 
-        let animate = animate && progress < 1.0;
+        let animate = is_indeterminate || (animate && progress < 1.0);
 
         let desired_width =
             desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x.at_least(96.0));
@@ -141,15 +147,17 @@ impl Widget for ProgressBar {
 
             let visuals = ui.style().visuals.clone();
             let rounding = outer_rect.height() / 2.0;
-            let time = ui.input(|i| i.time).cos().abs() as f32;
+            let time = ui.input(|i| i.time);
             ui.painter()
                 .rect(outer_rect, rounding, visuals.extreme_bg_color, Stroke::NONE);
             let inner_rect = if is_indeterminate {
-                let max_x = outer_rect.width() * 0.8;
-                let offset = lerp(0.0f32..=max_x, time);
+                // sweeps in from past the left edge, and out past the right edge.
+                let band_width = outer_rect.width() * BAND_WIDTH;
+                let phase = (time % SWEEP_PERIOD / SWEEP_PERIOD) as f32;
+                let offset = lerp(-band_width..=outer_rect.width(), phase);
                 Rect::from_min_size(
                     egui::pos2(outer_rect.min.x + offset, outer_rect.min.y),
-                    egui::vec2(outer_rect.width() * 0.2, outer_rect.height()),
+                    egui::vec2(band_width, outer_rect.height()),
                 )
             } else {
                 Rect::from_min_size(
@@ -162,9 +170,13 @@ impl Widget for ProgressBar {
             };
 
             let (dark, bright) = (0.7, 1.0);
-            let color_factor = lerp(dark..=bright, time);
+            let color_factor = if is_indeterminate {
+                bright
+            } else {
+                lerp(dark..=bright, time.cos().abs() as f32)
+            };
 
-            ui.painter().rect(
+            ui.painter().with_clip_rect(outer_rect).rect(
                 inner_rect,
                 rounding,
                 Color32::from(Rgba::from(visuals.selection.bg_fill) * color_factor),
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Frame-rate limiting and on-demand repainting, for apps that shouldn't redraw a static scene
+//! continuously.
+
+use std::time::Duration;
+
+use eframe::Frame;
+use egui::Context;
+
+use crate::frame_history::FrameHistory;
+
+///
+/// How often the app should be repainted, in addition to the repaints egui does on input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RepaintPolicy {
+    /// Repaint continuously, at up to the specified number of frames per second.  Zero is
+    /// treated as [`RepaintPolicy::OnDemand`].
+    Fps(u32),
+    /// Only repaint on input, or after [`RepaintLimiter::mark_changed`] is called.
+    OnDemand,
+}
+
+///
+/// Schedules the next repaint according to a [`RepaintPolicy`], and tracks the effective frame
+/// rate.  Call [`RepaintLimiter::on_new_frame`] at the start of each `update`.
+pub struct RepaintLimiter {
+    policy: RepaintPolicy,
+    changed: bool,
+    next_frame: Option<f64>,
+    frame_history: FrameHistory,
+}
+
+impl RepaintLimiter {
+    pub fn new(policy: RepaintPolicy) -> Self {
+        Self {
+            policy,
+            changed: false,
+            next_frame: None,
+            frame_history: FrameHistory::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn policy(&self) -> RepaintPolicy {
+        self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: RepaintPolicy) {
+        self.policy = policy;
+        self.next_frame = None;
+    }
+
+    ///
+    /// Flags that the displayed data has changed, so another frame is drawn as soon as possible.
+    /// Data changing outside of `update` should call [`Context::request_repaint`] instead.
+    pub fn mark_changed(&mut self) {
+        self.changed = true;
+    }
+
+    ///
+    /// Computes the delay from `now` (in seconds, like [`egui::InputState::time`]) until the next
+    /// frame should be drawn, or [`None`] if no repaint is needed.  Frames are scheduled on a
+    /// fixed cadence, so a frame drawn late (or early, on input) doesn't shift the following
+    /// frames.
+    pub fn next_repaint_delay(&mut self, now: f64) -> Option<Duration> {
+        if std::mem::take(&mut self.changed) {
+            return Some(Duration::ZERO);
+        }
+        let RepaintPolicy::Fps(fps) = self.policy else {
+            return None;
+        };
+        if fps == 0 {
+            return None;
+        }
+        let interval = 1.0 / f64::from(fps);
+        let next = match self.next_frame {
+            // well before the scheduled frame, this one was likely due to input.
+            Some(next) if next - now > interval / 2.0 => next,
+            Some(next) if next + interval > now => next + interval,
+            _ => now + interval,
+        };
+        self.next_frame = Some(next);
+        Some(Duration::from_secs_f64(next - now))
+    }
+
+    ///
+    /// Records the frame in the [`FrameHistory`], and requests the next repaint per the policy.
+    pub fn on_new_frame(&mut self, ctx: &Context, frame: &Frame) {
+        let now = ctx.input(|i| i.time);
+        self.frame_history.on_new_frame(now, frame.info().cpu_usage);
+        if let Some(delay) = self.next_repaint_delay(now) {
+            ctx.request_repaint_after(delay);
+        }
+    }
+
+    #[must_use]
+    pub fn frame_history(&self) -> &FrameHistory {
+        &self.frame_history
+    }
+
+    pub fn frame_history_mut(&mut self) -> &mut FrameHistory {
+        &mut self.frame_history
+    }
+
+    ///
+    /// Returns the effective rate that frames are being drawn at
+    #[must_use]
+    pub fn fps(&self) -> f32 {
+        self.frame_history.fps()
+    }
+}
+
+impl Default for RepaintLimiter {
+    fn default() -> Self {
+        Self::new(RepaintPolicy::OnDemand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::repaint::{RepaintLimiter, RepaintPolicy};
+
+    fn assert_delay(expected_us: u128, actual: Option<Duration>) {
+        let actual = actual.map(|d| d.as_micros());
+        assert!(
+            actual.is_some_and(|a| a.abs_diff(expected_us) <= 1),
+            "expected {expected_us}us but was {actual:?}"
+        );
+    }
+
+    #[test]
+    pub fn fps_cadence() {
+        let mut limiter = RepaintLimiter::new(RepaintPolicy::Fps(20));
+        assert_delay(50_000, limiter.next_repaint_delay(0.0));
+        assert_delay(50_000, limiter.next_repaint_delay(0.05));
+        // late frame keeps the cadence
+        assert_delay(40_000, limiter.next_repaint_delay(0.11));
+        // early frame due to input doesn't reschedule
+        assert_delay(30_000, limiter.next_repaint_delay(0.12));
+        assert_delay(50_000, limiter.next_repaint_delay(0.15));
+        // fell well behind, restart the cadence
+        assert_delay(50_000, limiter.next_repaint_delay(1.0));
+
+        limiter.set_policy(RepaintPolicy::Fps(60));
+        for frame in 0..600 {
+            let now = 10.0 + f64::from(frame) / 60.0;
+            assert_delay(16_667, limiter.next_repaint_delay(now));
+        }
+    }
+
+    #[test]
+    pub fn on_demand() {
+        let mut limiter = RepaintLimiter::default();
+        assert_eq!(RepaintPolicy::OnDemand, limiter.policy());
+        assert_eq!(None, limiter.next_repaint_delay(0.0));
+        limiter.mark_changed();
+        assert_eq!(Some(Duration::ZERO), limiter.next_repaint_delay(0.5));
+        assert_eq!(None, limiter.next_repaint_delay(0.6));
+
+        limiter.set_policy(RepaintPolicy::Fps(0));
+        assert_eq!(None, limiter.next_repaint_delay(0.7));
+    }
+}
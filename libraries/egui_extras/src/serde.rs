@@ -4,6 +4,7 @@
 
 //!
 //! Adds an implementation of [`serde::Serializer`] that draws a [`egui`] debug window containing the contents of the object.
+//! Also persists the layout of a [`crate::composite::CompositeApp`].
 
 use egui::{CollapsingHeader, Ui};
 use std::fmt::{Display, Formatter};
@@ -16,6 +17,24 @@ use serde::{Serialize, Serializer};
 
 use irox_tools::hex::HexDump;
 
+use crate::composite::CompositeState;
+
+///
+/// Serializes the layout of a [`crate::composite::CompositeApp`] to a JSON string.
+pub fn save_layout(state: &CompositeState) -> Result<String, serde_json::Error> {
+    serde_json::to_string(state)
+}
+
+///
+/// Parses a layout previously returned by [`save_layout`].  Restore it with
+/// [`crate::composite::CompositeApp::restore_state`], which drops any tab ids that no longer
+/// match a sub-app.
+pub fn load_layout(layout: &str) -> Result<CompositeState, Error> {
+    serde_json::from_str(layout).map_err(|e| Error {
+        message: format!("Invalid layout: {e}"),
+    })
+}
+
 ///
 /// [`serde::Serializer`] that will draw a series of nested [`egui::CollapsingHeader`]s with the contents of the object
 /// for debug purposes.
@@ -537,3 +556,35 @@ impl<'a> SerializeMap for Mapped<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::composite::{CompositeState, TabState};
+    use crate::serde::{load_layout, save_layout};
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn layout_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let state = CompositeState {
+            tabs: vec![
+                TabState {
+                    id: "plots".to_string(),
+                    open: true,
+                },
+                TabState {
+                    id: "log".to_string(),
+                    open: false,
+                },
+            ],
+            active: Some("plots".to_string()),
+        };
+        let layout = save_layout(&state)?;
+        assert_eq!(
+            r#"{"tabs":[{"id":"plots","open":true},{"id":"log","open":false}],"active":"plots"}"#,
+            layout
+        );
+        assert_eq!(state, load_layout(&layout)?);
+        assert!(load_layout("{\"tabs\":").is_err());
+        Ok(())
+    }
+}
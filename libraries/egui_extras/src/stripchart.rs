@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! An oscilloscope-style live scrolling chart of a single signal
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use egui::{
+    emath, pos2, Align2, Color32, Rect, Response, Sense, Shape, Stroke, TextStyle, Ui, Vec2,
+};
+#[cfg(feature = "time")]
+use irox_time::datetime::UTCDateTime;
+#[cfg(feature = "time")]
+use irox_time::epoch::UnixTimestamp;
+
+///
+/// A live chart that holds a fixed-duration window of samples.  The newest sample is drawn at the
+/// right edge, and the chart scrolls left as newer samples arrive.  Samples older than the window
+/// (relative to the newest sample) are discarded.
+///
+/// Sample times are seconds on any consistent timeline, use [`StripChart::push_datetime`] for
+/// absolute times.  The Y axis is scaled to the samples in the window, unless a fixed range is
+/// set.
+#[derive(Debug, Clone)]
+pub struct StripChart {
+    window: f64,
+    samples: VecDeque<(f64, f64)>,
+    fixed_range: Option<(f64, f64)>,
+    /// The color of the signal line
+    pub color: Option<Color32>,
+    /// The desired draw height of the chart
+    pub desired_height: Option<f32>,
+}
+
+impl StripChart {
+    ///
+    /// Creates a new chart that displays the provided duration of samples
+    #[must_use]
+    pub fn new(window: Duration) -> StripChart {
+        StripChart {
+            window: window.as_secs_f64(),
+            samples: VecDeque::new(),
+            fixed_range: None,
+            color: None,
+            desired_height: None,
+        }
+    }
+
+    ///
+    /// Fixes the Y axis to the provided range, rather than scaling to the samples.
+    #[must_use]
+    pub fn with_fixed_range(self, min: f64, max: f64) -> StripChart {
+        StripChart {
+            fixed_range: Some((min, max)),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the color of the signal line
+    #[must_use]
+    pub fn with_color(self, color: Color32) -> StripChart {
+        StripChart {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the desired draw height of the chart
+    #[must_use]
+    pub fn with_desired_height(self, height: f32) -> StripChart {
+        StripChart {
+            desired_height: Some(height),
+            ..self
+        }
+    }
+
+    ///
+    /// Adds a sample at the provided time, in seconds.  Samples are expected to arrive in time
+    /// order, but any out of order samples are inserted in place.
+    pub fn push(&mut self, time: f64, value: f64) {
+        let idx = self.samples.partition_point(|(t, _)| *t <= time);
+        self.samples.insert(idx, (time, value));
+        self.evict();
+    }
+
+    ///
+    /// Adds a sample at the provided absolute time.  Requires the `time` feature.
+    #[cfg(feature = "time")]
+    pub fn push_datetime(&mut self, time: UTCDateTime, value: f64) {
+        let time: UnixTimestamp = time.into();
+        self.push(time.get_offset().as_seconds_f64(), value);
+    }
+
+    /// Removes all the samples from the chart
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Returns the number of samples currently in the window
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns true if there are no samples in the window
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns the `(time, value)` samples currently in the window, oldest first
+    pub fn samples(&self) -> impl Iterator<Item = &(f64, f64)> {
+        self.samples.iter()
+    }
+
+    ///
+    /// Returns the `(min, max)` Y axis range that will be drawn - the fixed range if set,
+    /// otherwise the range of the values in the window.
+    #[must_use]
+    pub fn y_range(&self) -> Option<(f64, f64)> {
+        if let Some(range) = self.fixed_range {
+            return Some(range);
+        }
+        self.samples
+            .iter()
+            .map(|(_, v)| *v)
+            .filter(|v| v.is_finite())
+            .fold(None, |acc, v| match acc {
+                None => Some((v, v)),
+                Some((min, max)) => Some((v.min(min), v.max(max))),
+            })
+    }
+
+    /// Drops any samples older than the window, relative to the newest sample
+    fn evict(&mut self) {
+        let Some((newest, _)) = self.samples.back() else {
+            return;
+        };
+        let oldest = newest - self.window;
+        while self.samples.front().is_some_and(|(t, _)| *t < oldest) {
+            self.samples.pop_front();
+        }
+    }
+
+    ///
+    /// Draws the chart
+    pub fn ui(&self, ui: &mut Ui) -> Response {
+        let height = self
+            .desired_height
+            .unwrap_or_else(|| ui.spacing().slider_width);
+        let size = Vec2::new(ui.available_size_before_wrap().x, height);
+        let (rect, response) = ui.allocate_at_least(size, Sense::hover());
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+        let style = ui.style().noninteractive();
+        let mut shapes = Vec::with_capacity(4);
+        shapes.push(Shape::rect_filled(
+            rect,
+            style.rounding,
+            ui.visuals().extreme_bg_color,
+        ));
+        shapes.push(Shape::rect_stroke(rect, style.rounding, style.bg_stroke));
+
+        let rect = rect.shrink(4.0);
+        let text_color = ui.visuals().text_color();
+        if let (Some((newest, _)), Some((mut min, mut max))) = (self.samples.back(), self.y_range())
+        {
+            if (max - min).abs() < f64::EPSILON {
+                // flat signal, center it.
+                min -= 1.0;
+                max += 1.0;
+            }
+            let graph_rect =
+                Rect::from_x_y_ranges(-self.window as f32..=0.0, max as f32..=min as f32);
+            let to_screen = emath::RectTransform::from_to(graph_rect, rect);
+            let points = self
+                .samples
+                .iter()
+                .map(|(t, v)| to_screen.transform_pos_clamped(pos2((t - newest) as f32, *v as f32)))
+                .collect();
+            let stroke = Stroke::new(1.5, self.color.unwrap_or(text_color));
+            shapes.push(Shape::line(points, stroke));
+
+            let font = TextStyle::Small.resolve(ui.style());
+            ui.fonts(|f| {
+                shapes.push(Shape::text(
+                    f,
+                    rect.left_top(),
+                    Align2::LEFT_TOP,
+                    format!("{max:.2}"),
+                    font.clone(),
+                    text_color,
+                ));
+                shapes.push(Shape::text(
+                    f,
+                    rect.left_bottom(),
+                    Align2::LEFT_BOTTOM,
+                    format!("{min:.2}"),
+                    font,
+                    text_color,
+                ));
+            });
+        }
+        ui.painter().extend(shapes);
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stripchart::StripChart;
+    use std::time::Duration;
+
+    #[test]
+    pub fn evicts_old_samples() {
+        let mut chart = StripChart::new(Duration::from_secs(10));
+        for t in 0..=10 {
+            chart.push(f64::from(t), f64::from(t * 2));
+        }
+        assert_eq!(11, chart.len());
+        assert_eq!(Some((0.0, 20.0)), chart.y_range());
+
+        chart.push(15.5, -4.0);
+        // anything before 5.5 is outside the window
+        let times: Vec<f64> = chart.samples().map(|(t, _)| *t).collect();
+        assert_eq!(vec![6.0, 7.0, 8.0, 9.0, 10.0, 15.5], times);
+        assert_eq!(Some((-4.0, 20.0)), chart.y_range());
+
+        chart.push(100.0, 1.0);
+        assert_eq!(1, chart.len());
+
+        let chart = chart.with_fixed_range(0.0, 50.0);
+        assert_eq!(Some((0.0, 50.0)), chart.y_range());
+    }
+
+    #[cfg(feature = "time")]
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn datetime_samples() -> Result<(), irox_units::bounds::GreaterThanEqualToValueError<u8>> {
+        use irox_time::datetime::UTCDateTime;
+        let mut chart = StripChart::new(Duration::from_secs(60));
+        chart.push_datetime(UTCDateTime::try_from_values(2024, 1, 1, 0, 0, 0)?, 1.0);
+        chart.push_datetime(UTCDateTime::try_from_values(2024, 1, 1, 0, 0, 30)?, 2.0);
+        assert_eq!(2, chart.len());
+        chart.push_datetime(UTCDateTime::try_from_values(2024, 1, 1, 0, 1, 1)?, 3.0);
+        assert_eq!(2, chart.len());
+        assert_eq!(Some((2.0, 3.0)), chart.y_range());
+        Ok(())
+    }
+}
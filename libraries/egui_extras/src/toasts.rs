@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Transient notification messages ("toasts"), stacked in a corner of the screen over the app.
+
+use std::time::Duration;
+
+use egui::{Align, Align2, Area, Color32, Context, Frame, Id, Layout, Order, RichText, Stroke};
+
+use crate::WithAlpha;
+
+/// Default time a toast is shown for
+pub const DEFAULT_TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Toasts fade out over this final part of their duration
+pub const FADE_DURATION: Duration = Duration::from_millis(300);
+
+/// Distance between the toasts and the edge of the screen
+const MARGIN: f32 = 10.0;
+
+///
+/// How important the message is, determines the color it's drawn with.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Severity {
+    Info,
+    Warn,
+    Error,
+}
+
+///
+/// A single message, shown for a duration from the first frame it's displayed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    text: String,
+    severity: Severity,
+    duration: Duration,
+    shown_at: Option<f64>,
+}
+
+impl Toast {
+    pub fn new<T: Into<String>>(severity: Severity, text: T, duration: Duration) -> Toast {
+        Toast {
+            text: text.into(),
+            severity,
+            duration,
+            shown_at: None,
+        }
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Seconds remaining at `now` before this toast expires.
+    fn remaining(&self, now: f64) -> f64 {
+        let shown_at = self.shown_at.unwrap_or(now);
+        shown_at + self.duration.as_secs_f64() - now
+    }
+
+    ///
+    /// Returns the opacity of the toast at `now` (in seconds, like [`egui::InputState::time`]),
+    /// `1.0` until the final [`FADE_DURATION`], then fading linearly to `0.0`.
+    #[must_use]
+    pub fn alpha(&self, now: f64) -> f32 {
+        let remaining = self.remaining(now);
+        (remaining / FADE_DURATION.as_secs_f64()).clamp(0.0, 1.0) as f32
+    }
+}
+
+///
+/// A queue of [`Toast`]s drawn stacked in a corner of the screen, newest nearest the corner.
+/// Keep one in the app state, push messages with [`Toasts::info`], [`Toasts::warn`] and
+/// [`Toasts::error`], and call [`Toasts::show`] every frame.
+pub struct Toasts {
+    id: Id,
+    anchor: Align2,
+    duration: Duration,
+    toasts: Vec<Toast>,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Toasts {
+            id: Id::new("irox_toasts"),
+            anchor: Align2::RIGHT_BOTTOM,
+            duration: DEFAULT_TOAST_DURATION,
+            toasts: Vec::new(),
+        }
+    }
+}
+
+impl Toasts {
+    pub fn new() -> Toasts {
+        Self::default()
+    }
+
+    ///
+    /// Sets the corner of the screen the toasts are drawn in, defaults to the bottom right.
+    #[must_use]
+    pub fn with_anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    ///
+    /// Sets how long the messages are shown for, defaults to [`DEFAULT_TOAST_DURATION`]
+    #[must_use]
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    ///
+    /// Sets the id of the overlay, required to show more than one [`Toasts`] at once.
+    #[must_use]
+    pub fn with_id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn add(&mut self, toast: Toast) -> &mut Self {
+        self.toasts.push(toast);
+        self
+    }
+
+    pub fn info<T: Into<String>>(&mut self, text: T) -> &mut Self {
+        self.add(Toast::new(Severity::Info, text, self.duration))
+    }
+
+    pub fn warn<T: Into<String>>(&mut self, text: T) -> &mut Self {
+        self.add(Toast::new(Severity::Warn, text, self.duration))
+    }
+
+    pub fn error<T: Into<String>>(&mut self, text: T) -> &mut Self {
+        self.add(Toast::new(Severity::Error, text, self.duration))
+    }
+
+    #[must_use]
+    pub fn toasts(&self) -> &[Toast] {
+        &self.toasts
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    ///
+    /// Starts the timers of newly added toasts, and removes the expired ones.
+    pub fn update(&mut self, now: f64) {
+        for toast in &mut self.toasts {
+            toast.shown_at.get_or_insert(now);
+        }
+        self.toasts.retain(|toast| toast.remaining(now) > 0.0);
+    }
+
+    ///
+    /// Draws the current toasts over the app, call once per frame.
+    pub fn show(&mut self, ctx: &Context) {
+        let now = ctx.input(|i| i.time);
+        self.update(now);
+        if self.toasts.is_empty() {
+            return;
+        }
+
+        let offset = egui::vec2(
+            edge_offset(self.anchor.x()) * MARGIN,
+            edge_offset(self.anchor.y()) * MARGIN,
+        );
+        let layout = if self.anchor.y() == Align::Max {
+            Layout::bottom_up(self.anchor.x())
+        } else {
+            Layout::top_down(self.anchor.x())
+        };
+        Area::new(self.id)
+            .anchor(self.anchor, offset)
+            .order(Order::Foreground)
+            .interactable(false)
+            .show(ctx, |ui| {
+                ui.with_layout(layout, |ui| {
+                    // newest nearest the corner
+                    for toast in self.toasts.iter().rev() {
+                        let alpha = toast.alpha(now);
+                        let visuals = ui.visuals();
+                        let color = match toast.severity {
+                            Severity::Info => visuals.text_color(),
+                            Severity::Warn => visuals.warn_fg_color,
+                            Severity::Error => visuals.error_fg_color,
+                        };
+                        let mut frame = Frame::popup(ui.style());
+                        frame.fill = fade(frame.fill, alpha);
+                        frame.shadow.color = fade(frame.shadow.color, alpha);
+                        frame.stroke = Stroke::new(1.0, fade(color, alpha));
+                        frame.show(ui, |ui| {
+                            ui.label(RichText::new(&toast.text).color(fade(color, alpha)));
+                        });
+                    }
+                });
+            });
+
+        // wake up for the next fade frame, or when the next fade starts.
+        let next = self
+            .toasts
+            .iter()
+            .map(|toast| toast.remaining(now) - FADE_DURATION.as_secs_f64())
+            .reduce(f64::min)
+            .unwrap_or_default();
+        ctx.request_repaint_after(Duration::from_secs_f64(next.max(0.0)));
+    }
+}
+
+/// Direction to offset the area from the screen edge for the alignment
+fn edge_offset(align: Align) -> f32 {
+    match align {
+        Align::Min => 1.0,
+        Align::Center => 0.0,
+        Align::Max => -1.0,
+    }
+}
+
+/// Scales the existing alpha of the color
+fn fade(color: Color32, alpha: f32) -> Color32 {
+    color.with_alpha_f32(f32::from(color.a()) / 255.0 * alpha)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::toasts::{Severity, Toast, Toasts};
+
+    fn assert_close(expected: f32, actual: f32) {
+        assert!(
+            (expected - actual).abs() < 1e-4,
+            "expected {expected} but was {actual}"
+        );
+    }
+
+    #[test]
+    pub fn fade_and_expire() {
+        let mut toasts = Toasts::new().with_duration(Duration::from_secs(3));
+        toasts.info("saved").warn("disk nearly full");
+        toasts.update(10.0);
+        toasts.error("write failed");
+        toasts.update(11.0);
+
+        let severities = toasts
+            .toasts()
+            .iter()
+            .map(Toast::severity)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![Severity::Info, Severity::Warn, Severity::Error],
+            severities
+        );
+        let alphas = |toasts: &Toasts, now: f64| {
+            toasts
+                .toasts()
+                .iter()
+                .map(|t| t.alpha(now))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![1.0, 1.0, 1.0], alphas(&toasts, 12.7));
+        let faded = alphas(&toasts, 12.8);
+        assert_close(2.0 / 3.0, faded.first().copied().unwrap_or_default());
+        assert_close(1.0, faded.get(2).copied().unwrap_or_default());
+
+        toasts.update(13.0);
+        assert_eq!(1, toasts.toasts().len());
+        assert_eq!(
+            Some("write failed"),
+            toasts.toasts().first().map(Toast::text)
+        );
+        assert_close(
+            0.5,
+            alphas(&toasts, 13.85).first().copied().unwrap_or_default(),
+        );
+
+        toasts.update(14.0);
+        assert!(toasts.is_empty());
+    }
+}
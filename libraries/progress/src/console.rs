@@ -145,7 +145,10 @@ impl ConsoleProgressPrinter {
                 };
 
                 let delay = next_run - UnixTimestamp::now();
-                std::thread::sleep(delay.into());
+                // a negative delay means we're already late for the next run.
+                if let Ok(delay) = delay.try_into() {
+                    std::thread::sleep(delay);
+                }
             }
             let _r = stdout().write_all(&[0x1B, b'[', b'2', b'K']);
             for task in &tasks {
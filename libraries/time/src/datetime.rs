@@ -6,13 +6,17 @@
 //!
 
 use crate::epoch::{UnixTimestamp, UNIX_EPOCH};
-use crate::format::iso8601::BASIC_DATE_TIME_OF_DAY;
-use crate::format::Format;
+use crate::format::iso8601::{BASIC_DATE_TIME_OF_DAY, ISO8601_DATE_TIME};
+use crate::format::rfc2822::RFC2822_DATE_TIME;
+use crate::format::rfc3339::RFC3339_DATE_TIME;
+use crate::format::{Format, FormatError, FormatErrorType, FormatParser};
 use crate::gregorian::Date;
 use crate::julian::JulianDate;
 use crate::Time;
-use core::fmt::{Display, Formatter};
-use core::ops::{Add, AddAssign, Sub};
+use core::fmt::{Display, Formatter, Write};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::str::FromStr;
+use irox_tools::format;
 use irox_units::bounds::GreaterThanEqualToValueError;
 use irox_units::units::duration::Duration;
 extern crate alloc;
@@ -20,7 +24,7 @@ pub use alloc::string::String;
 
 ///
 /// Represents a Gregorian Date and Time in UTC
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct UTCDateTime {
     pub(crate) date: Date,
     pub(crate) time: Time,
@@ -84,6 +88,44 @@ impl UTCDateTime {
         self.time
     }
 
+    ///
+    /// Returns the exact number of nanoseconds since the Unix epoch, negative before it.  Unlike
+    /// the conversion to [`UnixTimestamp`], no floating point is involved.
+    #[must_use]
+    pub fn as_unix_nanos(&self) -> i128 {
+        let days = days_before_year(i64::from(self.date.year)) - UNIX_EPOCH_DAYS
+            + i64::from(self.date.day_of_year);
+        let seconds =
+            i128::from(days) * i128::from(SECONDS_PER_DAY) + i128::from(self.time.get_seconds());
+        seconds * NANOS_PER_SECOND + i128::from(self.time.get_nanoseconds())
+    }
+
+    ///
+    /// Creates a UTCDateTime from the exact number of nanoseconds since the Unix epoch, the
+    /// inverse of [`UTCDateTime::as_unix_nanos`].  Returns [`None`] if the year doesn't fit in an
+    /// `i32`.
+    #[must_use]
+    pub fn from_unix_nanos(nanos: i128) -> Option<UTCDateTime> {
+        let seconds = nanos.div_euclid(NANOS_PER_SECOND);
+        let nanoseconds = nanos.rem_euclid(NANOS_PER_SECOND) as u32;
+        let second_of_day = seconds.rem_euclid(i128::from(SECONDS_PER_DAY)) as u32;
+        let days = i64::try_from(seconds.div_euclid(i128::from(SECONDS_PER_DAY))).ok()?;
+        let days = days.checked_add(UNIX_EPOCH_DAYS)?;
+
+        // estimate from the average year length, then correct.
+        let mut year = (i128::from(days) * 400).div_euclid(DAYS_PER_400_YEARS) as i64 + 1;
+        while days_before_year(year) > days {
+            year -= 1;
+        }
+        while days_before_year(year + 1) <= days {
+            year += 1;
+        }
+        let day_of_year = u16::try_from(days - days_before_year(year)).ok()?;
+        let date = Date::new(i32::try_from(year).ok()?, day_of_year).ok()?;
+        let time = Time::new(second_of_day, nanoseconds).ok()?;
+        Some(UTCDateTime { date, time })
+    }
+
     ///
     /// Returns the current instant in time as reported by the local system
     /// clock.
@@ -97,8 +139,96 @@ impl UTCDateTime {
     pub fn format<T: Format<UTCDateTime>>(&self, format: &T) -> String {
         format.format(self)
     }
+
+    ///
+    /// Appends this date time, formatted with the provided format, to the provided buffer.
+    pub fn format_into<T: Format<UTCDateTime>>(&self, format: &T, out: &mut String) {
+        format.format_into(self, out);
+    }
+
+    ///
+    /// Parses an ISO8601 date and time, in either the basic (`20231105T134500Z`) or extended
+    /// (`2023-11-05T13:45:00Z`) format, with optional fractional seconds.  A time offset like
+    /// `+01:00` is applied to convert the value to UTC, without one the time is assumed to be UTC.
+    /// See [`ISO8601_DATE_TIME`] for the accepted forms.
+    pub fn parse_iso8601(data: &str) -> Result<UTCDateTime, FormatError> {
+        ISO8601_DATE_TIME.try_from(data)
+    }
+
+    ///
+    /// Parses the string, auto-detecting the format from the following, in order of precedence:
+    ///
+    /// 1. A bare integer (optionally negative) is always a Unix epoch timestamp, and never an
+    ///    ISO8601 basic date like `20240102`.  Values with a magnitude of at least
+    ///    [`EPOCH_MILLIS_THRESHOLD`] are milliseconds, smaller values are seconds - so millisecond
+    ///    timestamps within about 3 years of 1970 will be misread as seconds.
+    /// 2. [`RFC3339`](crate::format::rfc3339::RFC3339_DATE_TIME) - tried before ISO8601 as it
+    ///    requires a time offset, which is applied to convert to UTC.
+    /// 3. [`ISO8601`](crate::format::iso8601::ISO8601_DATE_TIME) - basic or extended, any time
+    ///    offset is applied to convert to UTC.
+    /// 4. [`RFC2822`](crate::format::rfc2822::RFC2822_DATE_TIME) - like email and HTTP headers.
+    ///
+    /// The first format that parses successfully is returned.  If none do, the returned error
+    /// lists each format that was tried, and why it failed.
+    pub fn parse_auto(data: &str) -> Result<UTCDateTime, FormatError> {
+        let data = data.trim();
+        let digits = data.strip_prefix('-').unwrap_or(data);
+        if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            let value = i64::from_str(data).map_err(|e| {
+                FormatError::new(
+                    FormatErrorType::NumberFormatError,
+                    format!("Unable to parse '{data}' as a Unix epoch: {e}"),
+                )
+            })?;
+            let timestamp = if value.unsigned_abs() >= EPOCH_MILLIS_THRESHOLD {
+                UnixTimestamp::from_millis(value)
+            } else {
+                UnixTimestamp::from_seconds_f64(value as f64)
+            };
+            return Ok(timestamp.into());
+        }
+
+        let parsers: [(&str, &dyn FormatParser<UTCDateTime>); 3] = [
+            ("RFC3339", &RFC3339_DATE_TIME),
+            ("ISO8601", &ISO8601_DATE_TIME),
+            ("RFC2822", &RFC2822_DATE_TIME),
+        ];
+        let mut tried = String::new();
+        for (name, parser) in parsers {
+            match parser.try_from(data) {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    let _ = write!(tried, "; {name}: {e}");
+                }
+            }
+        }
+        FormatError::err(format!(
+            "Unable to parse '{data}' as any known format, tried{tried}"
+        ))
+    }
+}
+
+const SECONDS_PER_DAY: u32 = 86_400;
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const DAYS_PER_400_YEARS: i128 = 146_097;
+const MICROS_PER_DAY: f64 = 86_400_000_000.;
+/// Julian day number starting at midnight, 1970-01-01
+const UNIX_EPOCH_JULIAN_DAY: i128 = 2_440_588;
+/// Days from 0001-01-01 to 1970-01-01
+const UNIX_EPOCH_DAYS: i64 = days_before_year(1970);
+
+/// Returns the number of days from 0001-01-01 to January 1st of the proleptic gregorian year
+const fn days_before_year(year: i64) -> i64 {
+    let prev = year - 1;
+    365 * prev + prev.div_euclid(4) - prev.div_euclid(100) + prev.div_euclid(400)
 }
 
+///
+/// Bare integers with a magnitude at or above this are treated as milliseconds since the Unix
+/// epoch by [`UTCDateTime::parse_auto`], below as seconds.  `1e11` seconds is in the year 5138,
+/// `1e11` milliseconds is 1973-03-03.
+pub const EPOCH_MILLIS_THRESHOLD: u64 = 100_000_000_000;
+
 impl From<&UnixTimestamp> for UTCDateTime {
     fn from(value: &UnixTimestamp) -> Self {
         let date = value.as_date();
@@ -155,6 +285,30 @@ impl From<&UTCDateTime> for JulianDate {
     }
 }
 
+impl From<JulianDate> for UTCDateTime {
+    fn from(value: JulianDate) -> Self {
+        From::<&JulianDate>::from(&value)
+    }
+}
+
+impl From<&JulianDate> for UTCDateTime {
+    ///
+    /// Julian days start at noon, so `.0` is 12:00 and `.5` is midnight.  The time of day is
+    /// rounded to the nearest microsecond - a [`f64`] Julian date only resolves about 40us for
+    /// current dates.  Returns the default date time if the year doesn't fit in an `i32`.
+    #[allow(unused_imports)]
+    fn from(value: &JulianDate) -> Self {
+        use irox_tools::f64::FloatExt;
+        // shift to start the day at midnight, then split into whole days and the time of day.
+        let shifted = value.get_day_number() + 0.5;
+        let days = shifted.floor();
+        let micros_of_day = ((shifted - days) * MICROS_PER_DAY).round() as i128;
+        let days = days as i128 - UNIX_EPOCH_JULIAN_DAY;
+        let nanos = days * i128::from(SECONDS_PER_DAY) * NANOS_PER_SECOND + micros_of_day * 1_000;
+        UTCDateTime::from_unix_nanos(nanos).unwrap_or_default()
+    }
+}
+
 impl Sub<Self> for UTCDateTime {
     type Output = Duration;
 
@@ -209,3 +363,375 @@ impl AddAssign<&Duration> for UTCDateTime {
         self.date += excess;
     }
 }
+
+impl Sub<Duration> for UTCDateTime {
+    type Output = UTCDateTime;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        let (time, borrowed) = self.time.wrapping_sub(rhs);
+        let date = self.date - borrowed;
+        UTCDateTime { date, time }
+    }
+}
+impl Sub<&Duration> for UTCDateTime {
+    type Output = UTCDateTime;
+
+    fn sub(self, rhs: &Duration) -> Self::Output {
+        let (time, borrowed) = self.time.wrapping_sub(*rhs);
+        let date = self.date - borrowed;
+        UTCDateTime { date, time }
+    }
+}
+
+impl SubAssign<Duration> for UTCDateTime {
+    fn sub_assign(&mut self, rhs: Duration) {
+        let (time, borrowed) = self.time.wrapping_sub(rhs);
+        self.time = time;
+        self.date -= borrowed;
+    }
+}
+impl SubAssign<&Duration> for UTCDateTime {
+    fn sub_assign(&mut self, rhs: &Duration) {
+        let (time, borrowed) = self.time.wrapping_sub(*rhs);
+        self.time = time;
+        self.date -= borrowed;
+    }
+}
+
+///
+/// Wraps a [`UTCDateTime`] to change its serialized form from a RFC3339 string (like
+/// `2024-03-01T12:30:15.5Z`) to the compact integer number of nanoseconds since the Unix epoch.
+/// The integer is an `i64`, so only datetimes between the years 1677 and 2262 can be serialized.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UnixNanos(pub UTCDateTime);
+
+impl From<UTCDateTime> for UnixNanos {
+    fn from(value: UTCDateTime) -> Self {
+        UnixNanos(value)
+    }
+}
+
+impl From<UnixNanos> for UTCDateTime {
+    fn from(value: UnixNanos) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use crate::datetime::{UTCDateTime, UnixNanos};
+    use crate::format::iso8601::EXTENDED_DATE_TIME_FORMAT;
+    use crate::format::rfc3339::RFC3339_DATE_TIME;
+    use crate::format::FormatParser;
+    use alloc::string::String;
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl Serialize for UTCDateTime {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.format(&EXTENDED_DATE_TIME_FORMAT))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for UTCDateTime {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let value = String::deserialize(deserializer)?;
+            RFC3339_DATE_TIME
+                .try_from(&value)
+                .map_err(|e| D::Error::custom(format_args!("Invalid RFC3339 '{value}': {e}")))
+        }
+    }
+
+    impl Serialize for UnixNanos {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let nanos = i64::try_from(self.0.as_unix_nanos()).map_err(|_| {
+                S::Error::custom(format_args!(
+                    "{} is out of range for i64 nanoseconds",
+                    self.0
+                ))
+            })?;
+            serializer.serialize_i64(nanos)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for UnixNanos {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let nanos = i64::deserialize(deserializer)?;
+            UTCDateTime::from_unix_nanos(i128::from(nanos))
+                .map(UnixNanos)
+                .ok_or_else(|| D::Error::custom("Nanoseconds out of range"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datetime::UTCDateTime;
+    use crate::epoch::UnixTimestamp;
+    use crate::format::iso8601::{BASIC_DATE_TIME_OF_DAY, ISO8601_DATE_TIME};
+    use crate::format::rfc3339::RFC3339_DATE_TIME;
+    use crate::format::FormatError;
+    use crate::gregorian::Date;
+    use crate::julian::{JulianDate, JULIAN_EPOCH};
+    use crate::Time;
+    use irox_units::bounds::GreaterThanEqualToValueError;
+    use irox_units::units::duration::Duration;
+    use std::collections::HashMap;
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn unix_nanos() -> Result<(), FormatError> {
+        assert_eq!(
+            Some(0),
+            UTCDateTime::from_unix_nanos(0).map(|v| v.as_unix_nanos())
+        );
+        let dt = UTCDateTime::new(
+            Date::try_from_values(2024, 2, 29)?,
+            Time::new(45_296, 123_456_789)?,
+        );
+        assert_eq!(1_709_210_096_123_456_789, dt.as_unix_nanos());
+        for nanos in [
+            0,
+            -1,
+            1,
+            1_709_210_096_123_456_789,
+            -86_400_000_000_001,
+            // 0001-01-01 and 9999-12-31T23:59:59.999999999
+            -62_135_596_800_000_000_000,
+            253_402_300_799_999_999_999,
+        ] {
+            let Some(dt) = UTCDateTime::from_unix_nanos(nanos) else {
+                return FormatError::err(format!("{nanos} out of range"));
+            };
+            assert_eq!(nanos, dt.as_unix_nanos());
+            let approx = UnixTimestamp::from(dt).as_nanos();
+            assert!((approx - nanos).abs() < 1_000_000, "{dt} {approx} {nanos}");
+        }
+        assert_eq!(None, UTCDateTime::from_unix_nanos(i128::MAX));
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn equal_datetimes_hash_equally() -> Result<(), FormatError> {
+        let mut buckets: HashMap<UTCDateTime, u32> = HashMap::new();
+        let first = UTCDateTime::parse_auto("2024-03-01T12:30:15Z")?;
+        let second = UTCDateTime::parse_auto("2024-03-01T13:30:15+01:00")?;
+        assert_eq!(first, second);
+        *buckets.entry(first).or_default() += 1;
+        *buckets.entry(second).or_default() += 1;
+        assert_eq!(Some(&2), buckets.get(&first));
+        assert_eq!(1, buckets.len());
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn serde_round_trip() -> Result<(), serde_json::Error> {
+        use crate::datetime::UnixNanos;
+        let Some(dt) = UTCDateTime::from_unix_nanos(1_709_210_096_123_456_789) else {
+            return Err(serde::de::Error::custom("out of range"));
+        };
+        let human = serde_json::to_string(&dt)?;
+        assert_eq!("\"2024-02-29T12:34:56.123456789Z\"", human);
+        assert_eq!(dt, serde_json::from_str::<UTCDateTime>(&human)?);
+
+        let compact = serde_json::to_string(&UnixNanos(dt))?;
+        assert_eq!("1709210096123456789", compact);
+        assert_eq!(UnixNanos(dt), serde_json::from_str(&compact)?);
+
+        assert!(serde_json::from_str::<UTCDateTime>("\"not a date\"").is_err());
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn parse_auto() -> Result<(), FormatError> {
+        let expected = UTCDateTime::try_from_values(2024, 3, 1, 12, 30, 15)?;
+        for input in [
+            // ISO8601 extended & basic
+            "2024-03-01T12:30:15",
+            "20240301T123015Z",
+            // RFC3339
+            "2024-03-01T14:30:15+02:00",
+            // RFC2822
+            "Fri, 01 Mar 2024 07:30:15 -0500",
+            // epoch seconds and millis
+            "1709296215",
+            " 1709296215000 ",
+        ] {
+            assert_eq!(expected, UTCDateTime::parse_auto(input)?, "{input}");
+        }
+        // negative and small values are seconds
+        assert_eq!(
+            UTCDateTime::try_from_values(1969, 12, 31, 23, 59, 0)?,
+            UTCDateTime::parse_auto("-60")?
+        );
+
+        let err = UTCDateTime::parse_auto("yesterday at noon")
+            .err()
+            .map(|e| e.to_string())
+            .unwrap_or_default();
+        for tried in ["RFC3339", "ISO8601", "RFC2822"] {
+            assert!(err.contains(tried), "{err}");
+        }
+        assert!(UTCDateTime::parse_auto("").is_err());
+        assert!(UTCDateTime::parse_auto("99999999999999999999").is_err());
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn parse_iso8601() -> Result<(), FormatError> {
+        // round trips through both the basic and extended formats
+        for (date, nanos) in [
+            (Date::try_from_values(2023, 11, 5)?, 0),
+            (Date::try_from_values(1970, 1, 1)?, 500_000_000),
+            (Date::try_from_values(2024, 2, 29)?, 123_456_789),
+            (Date::try_from_values(1582, 10, 15)?, 1),
+        ] {
+            for second_of_day in [0, 49_500, 86_399] {
+                let dt = UTCDateTime::new(date, Time::new(second_of_day, nanos)?);
+                for format in [
+                    dt.format(&ISO8601_DATE_TIME),
+                    dt.format(&BASIC_DATE_TIME_OF_DAY),
+                ] {
+                    assert_eq!(dt, UTCDateTime::parse_iso8601(&format)?, "{format}");
+                }
+            }
+        }
+
+        // offsets are converted to UTC
+        let expected = UTCDateTime::try_from_values(2023, 11, 5, 13, 45, 0)?;
+        for input in [
+            "20231105T134500Z",
+            "2023-11-05T13:45:00Z",
+            "2023-11-05t13:45:00",
+            "2023-11-05T14:45:00+01:00",
+            "20231105T144500+0100",
+            "2023-11-05T08:45:00-05",
+            "2023-11-06T01:15:00+11:30",
+            "2023-309T13:45:00.000Z",
+        ] {
+            assert_eq!(expected, UTCDateTime::parse_iso8601(input)?, "{input}");
+        }
+        let frac = UTCDateTime::parse_iso8601("20231105T134500,25-00:30")?;
+        assert_eq!((14, 15, 0), frac.get_time().as_hms());
+        assert_eq!(250_000_000, frac.get_time().get_nanoseconds());
+        let leap = UTCDateTime::parse_iso8601("2016-12-31T23:59:60Z")?;
+        assert!(leap.get_time().is_leap_second());
+
+        // the failing component is named
+        for (input, component) in [
+            ("2023-13-05T13:45:00Z", "month"),
+            ("2023-00-05T13:45:00Z", "month"),
+            ("2023-11-31T13:45:00Z", "day"),
+            ("20230229T134500Z", "day"),
+            ("2023-11-05T24:45:00Z", "hour"),
+            ("2023-11-05T13:60:00Z", "minute"),
+            ("20231105T134560Z", "second"),
+            ("2023-11-05T13:45:00+24:00", "offset hour"),
+            ("2023-11-05T13:45:00+0160", "offset minute"),
+        ] {
+            let err = UTCDateTime::parse_iso8601(input)
+                .err()
+                .map(|e| e.to_string())
+                .unwrap_or_default();
+            assert!(err.contains(&format!("The {component} ")), "{input}: {err}");
+        }
+        for invalid in [
+            "2023-11-05",
+            "2023-11-05T13:45",
+            "2023-11-05T13:45:00+1",
+            "2023/11/05T13:45:00",
+        ] {
+            assert!(UTCDateTime::parse_iso8601(invalid).is_err(), "{invalid}");
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn from_julian_date() -> Result<(), FormatError> {
+        for (jd, expected) in [
+            (2_451_545.0, "2000-01-01T12:00:00Z"),
+            (2_451_544.5, "2000-01-01T00:00:00Z"),
+            (2_451_545.25, "2000-01-01T18:00:00Z"),
+            (2_451_544.75, "2000-01-01T06:00:00Z"),
+            (2_451_544.25, "1999-12-31T18:00:00Z"),
+            (2_440_587.5, "1970-01-01T00:00:00Z"),
+            (2_299_160.5, "1582-10-15T00:00:00Z"),
+            (0.0, "-4713-11-24T12:00:00Z"),
+        ] {
+            let dt: UTCDateTime = JulianDate::new(JULIAN_EPOCH, jd).into();
+            assert_eq!(expected, dt.format(&RFC3339_DATE_TIME), "{jd}");
+        }
+
+        // round trips, limited by the resolution of the julian date
+        for input in [
+            "2023-11-05T13:45:10.123456Z",
+            "1969-07-20T20:17:40Z",
+            "2038-01-19T03:14:07.5Z",
+        ] {
+            let dt = UTCDateTime::parse_iso8601(input)?;
+            let jd: JulianDate = dt.into();
+            let from_jd: UTCDateTime = jd.into();
+            let diff = from_jd.as_unix_nanos() - dt.as_unix_nanos();
+            assert!(diff.abs() < 50_000, "{input}: {diff}");
+
+            let from_unix: UTCDateTime = UnixTimestamp::from(jd).into();
+            let diff = from_unix.as_unix_nanos() - dt.as_unix_nanos();
+            assert!(diff.abs() < 50_000, "{input}: {diff}");
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn sub_across_year_boundary() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        let midnight = UTCDateTime::try_from_values(2024, 1, 1, 0, 0, 0)?;
+        let expected = UTCDateTime::try_from_values(2023, 12, 30, 23, 0, 0)?;
+        let result = midnight - Duration::from_hours(25);
+        assert_eq!(expected, result);
+        assert!(result < midnight);
+
+        let mut value = midnight;
+        value -= Duration::from_hours(25);
+        assert_eq!(expected, value);
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn sub_across_day_boundary() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        let start = UTCDateTime::try_from_values(2023, 6, 15, 0, 30, 0)?;
+        let expected = UTCDateTime::try_from_values(2023, 6, 14, 23, 45, 0)?;
+        assert_eq!(expected, start - Duration::from_minutes(45));
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn sub_across_month_boundary() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        // 2024 is a leap year
+        let start = UTCDateTime::try_from_values(2024, 3, 1, 12, 0, 0)?;
+        let expected = UTCDateTime::try_from_values(2024, 2, 29, 12, 0, 0)?;
+        assert_eq!(expected, start - Duration::from_days(1));
+
+        let expected = UTCDateTime::try_from_values(2024, 2, 28, 11, 59, 59)?;
+        assert_eq!(expected, start - Duration::from_seconds(86400 * 2 + 1));
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn sub_is_inverse_of_add() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        let start = UTCDateTime::try_from_values(2023, 11, 5, 7, 8, 9)?;
+        let dur = Duration::from_seconds(86400 * 400 + 3723);
+        assert_eq!(start, (start + dur) - dur);
+        assert_eq!(start, (start - dur) + dur);
+        Ok(())
+    }
+}
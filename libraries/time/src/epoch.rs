@@ -11,7 +11,7 @@
 use core::marker::PhantomData;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 
-use irox_units::units::duration::Duration;
+use irox_units::units::duration::{Duration, DurationUnit};
 
 use crate::gregorian::Date;
 
@@ -23,30 +23,68 @@ pub struct Epoch(pub Date);
 impl Epoch {
     ///
     /// The Gregorian Date of this particular Epoch.
+    #[doc(alias = "reference_date")]
     pub fn get_gregorian_date(&self) -> Date {
         self.0
     }
+
+    ///
+    /// Returns the offset of this Epoch from the other Epoch (`self - other`), positive if this
+    /// Epoch is later than the other, negative if it's earlier.  Adding the offset to a timestamp
+    /// relative to this Epoch gives the timestamp relative to the other Epoch.
+    #[must_use]
+    pub fn duration_since(&self, other: &Epoch) -> Duration {
+        self.0 - other.0
+    }
 }
 
 ///
 /// Represents a [`Duration`] offset from a particular [`Epoch`]
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone)]
 pub struct Timestamp<T> {
     epoch: Epoch,
     offset: Duration,
+    /// Whole nanoseconds added to the offset, held exactly rather than in the [`f64`] offset.
+    nanos: i64,
 
     _phantom: PhantomData<T>,
 }
 
 impl<T> Timestamp<T> {
-    pub(crate) fn new(epoch: Epoch, duration: Duration) -> Self {
+    ///
+    /// Shifts the timestamp by the duration, rounded to the nearest nanosecond.  The whole seconds
+    /// are added to the offset, and the nanoseconds kept exactly.
+    #[allow(unused_imports)]
+    fn shifted(&self, duration: &Duration, negate: bool) -> Self {
+        use irox_tools::f64::FloatExt;
+        let seconds = duration.as_seconds_f64();
+        let mut whole = seconds.trunc();
+        let mut nanos = ((seconds - whole) * 1e9).round() as i64;
+        if negate {
+            whole = -whole;
+            nanos = -nanos;
+        }
+        let nanos = self.nanos + nanos;
+        let carry = nanos.div_euclid(NANOS_PER_SECOND as i64);
         Self {
-            epoch,
-            offset: duration,
+            offset: self.offset + Duration::new_seconds(whole + carry as f64),
+            nanos: nanos.rem_euclid(NANOS_PER_SECOND as i64),
+            epoch: self.epoch,
             _phantom: PhantomData,
         }
     }
 
+    ///
+    /// Returns the offset from the epoch in nanoseconds, rounded to the nearest nanosecond.
+    #[allow(unused_imports)]
+    fn exact_nanos(&self) -> i128 {
+        use irox_tools::f64::FloatExt;
+        let seconds = self.offset.as_seconds_f64();
+        let whole = seconds.trunc();
+        let frac = ((seconds - whole) * 1e9).round();
+        whole as i128 * NANOS_PER_SECOND + frac as i128 + i128::from(self.nanos)
+    }
+
     ///
     /// Returns the base epoch for this timestamp
     #[must_use]
@@ -58,7 +96,27 @@ impl<T> Timestamp<T> {
     /// Returns the relative offset of this timestamp from the specified epoch.
     #[must_use]
     pub fn get_offset(&self) -> Duration {
-        self.offset
+        self.offset + Duration::new(self.nanos as f64, DurationUnit::Nanosecond)
+    }
+}
+
+impl<T> PartialEq for Timestamp<T> {
+    /// Timestamps are equal if they're from the same epoch, and equal to the nearest nanosecond.
+    fn eq(&self, other: &Self) -> bool {
+        self.epoch == other.epoch && self.exact_nanos() == other.exact_nanos()
+    }
+}
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Divides, rounding to the nearest integer with halves away from zero.
+fn div_round(value: i128, divisor: i128) -> i128 {
+    let rem = value % divisor;
+    let quot = value / divisor;
+    if rem.abs() * 2 >= divisor {
+        quot + value.signum()
+    } else {
+        quot
     }
 }
 
@@ -90,6 +148,7 @@ macro_rules! derive_timestamp_impl {
                 $name {
                     epoch: $epoch,
                     offset,
+                    nanos: 0,
                     _phantom: PhantomData {},
                 }
             }
@@ -111,6 +170,7 @@ macro_rules! derive_timestamp_impl {
                 $name {
                     epoch: $epoch,
                     offset: Duration::default(),
+                    nanos: 0,
 
                     _phantom: Default::default(),
                 }
@@ -126,7 +186,10 @@ macro_rules! derive_timestamp_impl {
             fn from_timestamp(other: &Timestamp<T>) -> Self {
                 let epoch_offset = $epoch.0 - other.epoch.0;
                 let new_duration = other.offset - epoch_offset;
-                $name::from_offset(new_duration)
+                $name {
+                    nanos: other.nanos,
+                    ..$name::from_offset(new_duration)
+                }
             }
         }
     };
@@ -136,14 +199,13 @@ impl<T> Add<Duration> for Timestamp<T> {
     type Output = Timestamp<T>;
 
     fn add(self, rhs: Duration) -> Self::Output {
-        let offset = self.offset + rhs;
-        Self::new(self.epoch, offset)
+        self.shifted(&rhs, false)
     }
 }
 
 impl<T> AddAssign<Duration> for Timestamp<T> {
     fn add_assign(&mut self, rhs: Duration) {
-        self.offset += rhs;
+        *self = self.shifted(&rhs, false);
     }
 }
 
@@ -151,14 +213,13 @@ impl<T> Sub<Duration> for Timestamp<T> {
     type Output = Timestamp<T>;
 
     fn sub(self, rhs: Duration) -> Self::Output {
-        let offset = self.offset - rhs;
-        Self::new(self.epoch, offset)
+        self.shifted(&rhs, true)
     }
 }
 
 impl<T> SubAssign<Duration> for Timestamp<T> {
     fn sub_assign(&mut self, rhs: Duration) {
-        self.offset -= rhs;
+        *self = self.shifted(&rhs, true);
     }
 }
 
@@ -166,14 +227,13 @@ impl<T> Add<&Duration> for Timestamp<T> {
     type Output = Timestamp<T>;
 
     fn add(self, rhs: &Duration) -> Self::Output {
-        let offset = self.offset + *rhs;
-        Self::new(self.epoch, offset)
+        self.shifted(rhs, false)
     }
 }
 
 impl<T> AddAssign<&Duration> for Timestamp<T> {
     fn add_assign(&mut self, rhs: &Duration) {
-        self.offset += *rhs;
+        *self = self.shifted(rhs, false);
     }
 }
 
@@ -181,14 +241,13 @@ impl<T> Sub<&Duration> for Timestamp<T> {
     type Output = Timestamp<T>;
 
     fn sub(self, rhs: &Duration) -> Self::Output {
-        let offset = self.offset - *rhs;
-        Self::new(self.epoch, offset)
+        self.shifted(rhs, true)
     }
 }
 
 impl<T> SubAssign<&Duration> for Timestamp<T> {
     fn sub_assign(&mut self, rhs: &Duration) {
-        self.offset -= *rhs;
+        *self = self.shifted(rhs, true);
     }
 }
 
@@ -196,7 +255,7 @@ impl<T> Sub<Timestamp<T>> for Timestamp<T> {
     type Output = Duration;
 
     fn sub(self, rhs: Timestamp<T>) -> Self::Output {
-        self.offset - rhs.offset
+        self - &rhs
     }
 }
 
@@ -204,7 +263,8 @@ impl<T> Sub<&Timestamp<T>> for Timestamp<T> {
     type Output = Duration;
 
     fn sub(self, rhs: &Timestamp<T>) -> Self::Output {
-        self.offset - rhs.offset
+        let nanos = self.nanos - rhs.nanos;
+        self.offset - rhs.offset + Duration::new(nanos as f64, DurationUnit::Nanosecond)
     }
 }
 
@@ -228,7 +288,7 @@ impl UnixTimestamp {
     #[must_use]
     #[cfg(feature = "std")]
     pub fn elapsed(&self) -> Duration {
-        Self::now().offset - self.offset
+        Self::now() - self
     }
 
     ///
@@ -237,6 +297,60 @@ impl UnixTimestamp {
     pub fn as_date(&self) -> Date {
         self.into()
     }
+
+    ///
+    /// Creates a new timestamp given the specified number of milliseconds since the epoch.
+    /// Negative values are before the epoch.
+    #[must_use]
+    pub const fn from_millis(millis: i64) -> UnixTimestamp {
+        UnixTimestamp::from_offset(Duration::new(millis as f64, DurationUnit::Millisecond))
+    }
+
+    ///
+    /// Creates a new timestamp given the specified number of microseconds since the epoch.
+    /// Negative values are before the epoch.
+    #[must_use]
+    pub const fn from_micros(micros: i64) -> UnixTimestamp {
+        UnixTimestamp::from_offset(Duration::new(micros as f64, DurationUnit::Microsecond))
+    }
+
+    ///
+    /// Creates a new timestamp given the specified number of nanoseconds since the epoch.
+    /// Negative values are before the epoch.  The nanoseconds are held exactly, and returned
+    /// unchanged by [`UnixTimestamp::as_nanos`].
+    #[must_use]
+    pub const fn from_nanos(nanos: i128) -> UnixTimestamp {
+        let seconds = nanos.div_euclid(NANOS_PER_SECOND);
+        UnixTimestamp {
+            nanos: nanos.rem_euclid(NANOS_PER_SECOND) as i64,
+            ..UnixTimestamp::from_offset(Duration::new(seconds as f64, DurationUnit::Second))
+        }
+    }
+
+    ///
+    /// Returns the number of milliseconds since the epoch, rounded to the nearest millisecond.
+    /// Negative values are before the epoch.
+    #[must_use]
+    pub fn as_millis(&self) -> i64 {
+        div_round(self.exact_nanos(), 1_000_000) as i64
+    }
+
+    ///
+    /// Returns the number of microseconds since the epoch, rounded to the nearest microsecond.
+    /// Negative values are before the epoch.
+    #[must_use]
+    pub fn as_micros(&self) -> i64 {
+        div_round(self.exact_nanos(), 1_000) as i64
+    }
+
+    ///
+    /// Returns the number of nanoseconds since the epoch, rounded to the nearest nanosecond.
+    /// Negative values are before the epoch.  Exact for timestamps created with
+    /// [`UnixTimestamp::from_nanos`].
+    #[must_use]
+    pub fn as_nanos(&self) -> i128 {
+        self.exact_nanos()
+    }
 }
 derive_timestamp_impl!(UNIX_EPOCH, UnixTimestamp);
 
@@ -322,3 +436,98 @@ derive_timestamp_impl!(PRIME_EPOCH, PrimeTimestamp);
 ///
 /// The NTP epoch is the same as the [`PRIME_EPOCH`]
 pub const NTP_EPOCH: Epoch = PRIME_EPOCH;
+
+#[cfg(test)]
+mod tests {
+    use crate::epoch::{UnixTimestamp, GPS_EPOCH, NTP_EPOCH, UNIX_EPOCH, WINDOWS_NT_EPOCH};
+    use crate::gregorian::Date;
+    use irox_units::bounds::GreaterThanEqualToValueError;
+    use irox_units::units::duration::Duration;
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn millis_round_trip() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        // 2023-11-14T22:13:20.123Z
+        let millis = 1_700_000_000_123;
+        let ts = UnixTimestamp::from_millis(millis);
+        assert_eq!(millis, ts.as_millis());
+        assert_eq!(millis * 1000, ts.as_micros());
+        assert_eq!(1_700_000_000, ts.get_offset().as_seconds());
+        assert_eq!(Date::try_from_values(2023, 11, 14)?, ts.as_date());
+
+        let ts = UnixTimestamp::from_micros(1_700_000_000_123_456);
+        assert_eq!(1_700_000_000_123_456, ts.as_micros());
+        assert_eq!(1_700_000_000_123, ts.as_millis());
+
+        let ts = UnixTimestamp::from_nanos(1_234_567_891);
+        assert_eq!(1_234_567_891, ts.as_nanos());
+        assert_eq!(1_235, ts.as_millis());
+
+        // present-day nanoseconds are beyond the f64 resolution, but kept exactly.
+        let nanos = 1_700_000_000_123_456_789;
+        let ts = UnixTimestamp::from_nanos(nanos);
+        assert_eq!(nanos, ts.as_nanos());
+        assert_eq!(1_700_000_000_123_457, ts.as_micros());
+        assert_eq!(nanos + 1, (ts + Duration::from_nanos(1)).as_nanos());
+        assert_eq!(1, (UnixTimestamp::from_nanos(nanos + 1) - ts).as_nanos());
+        assert_eq!(
+            UnixTimestamp::from_nanos(1_500_000_000),
+            UnixTimestamp::from_seconds_f64(1.5)
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn millis_pre_epoch() {
+        // 1969-07-20T20:17:40Z
+        let millis = -14_182_940_000;
+        let ts = UnixTimestamp::from_millis(millis);
+        assert_eq!(millis, ts.as_millis());
+        assert_eq!(millis * 1000, ts.as_micros());
+        assert_eq!(-14_182_940, ts.get_offset().as_seconds_f64() as i64);
+
+        let ts = UnixTimestamp::from_nanos(-1_500);
+        assert_eq!(-1_500, ts.as_nanos());
+        assert_eq!(-2, ts.as_micros());
+        assert_eq!(0, ts.as_millis());
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn epoch_offsets() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        assert_eq!(
+            Date::try_from_values(1980, 1, 6)?,
+            GPS_EPOCH.get_gregorian_date()
+        );
+
+        // the GPS epoch is 3657 days after the unix epoch.
+        assert_eq!(
+            -315_964_800,
+            UNIX_EPOCH.duration_since(&GPS_EPOCH).as_seconds_f64() as i64
+        );
+        assert_eq!(
+            315_964_800,
+            GPS_EPOCH.duration_since(&UNIX_EPOCH).as_seconds_f64() as i64
+        );
+        assert_eq!(
+            11_644_473_600,
+            UNIX_EPOCH
+                .duration_since(&WINDOWS_NT_EPOCH)
+                .as_seconds_f64() as i64
+        );
+        assert_eq!(
+            2_208_988_800,
+            UNIX_EPOCH.duration_since(&NTP_EPOCH).as_seconds_f64() as i64
+        );
+        assert_eq!(0, GPS_EPOCH.duration_since(&GPS_EPOCH).as_nanos());
+
+        // offsets compose
+        let gps_to_nt =
+            GPS_EPOCH.duration_since(&UNIX_EPOCH) + UNIX_EPOCH.duration_since(&WINDOWS_NT_EPOCH);
+        assert_eq!(
+            GPS_EPOCH.duration_since(&WINDOWS_NT_EPOCH).as_seconds(),
+            gps_to_nt.as_seconds()
+        );
+        Ok(())
+    }
+}
@@ -7,17 +7,21 @@
 
 extern crate alloc;
 use alloc::string::String;
+use alloc::vec::Vec;
 
+use core::fmt::Write;
 use core::str::FromStr;
 
 use irox_tools::fmt::DecimalFormatF64;
 use irox_tools::format;
 use irox_tools::iterators::Itertools;
-use irox_units::units::duration::{Duration, SEC_TO_NANOS};
+use irox_units::units::duration::Duration;
 
 use crate::datetime::UTCDateTime;
-use crate::format::{Format, FormatError, FormatParser};
-use crate::gregorian::Date;
+use crate::format::{
+    format_to_string, local_to_utc, Format, FormatError, FormatErrorType, FormatParser,
+};
+use crate::gregorian::{Date, DayOfWeek, Month};
 use crate::Time;
 
 ///
@@ -34,11 +38,12 @@ pub const BASIC_DATE_TIME_OF_DAY: BasicDateTimeOfDay = BasicDateTimeOfDay {};
 
 impl Format<UTCDateTime> for BasicDateTimeOfDay {
     fn format(&self, date: &UTCDateTime) -> String {
-        format!(
-            "{}{}",
-            BasicCalendarDate::format(&date.get_date()),
-            BasicTimeOfDay::format(&date.get_time())
-        )
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        BasicCalendarDate.format_to(&date.get_date(), out)?;
+        BasicTimeOfDay.format_to(&date.get_time(), out)
     }
 }
 
@@ -78,7 +83,12 @@ impl BasicCalendarDate {
 
 impl Format<Date> for BasicCalendarDate {
     fn format(&self, date: &Date) -> String {
-        format!(
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &Date, out: &mut dyn Write) -> core::fmt::Result {
+        write!(
+            out,
             "{:04}{:02}{:02}",
             date.year(),
             date.month_of_year() as u8,
@@ -153,6 +163,10 @@ impl Format<UTCDateTime> for BasicCalendarDate {
     fn format(&self, date: &UTCDateTime) -> String {
         BasicCalendarDate.format(&date.date)
     }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        BasicCalendarDate.format_to(&date.date, out)
+    }
 }
 
 ///
@@ -170,12 +184,16 @@ pub const BASIC_TIME_OF_DAY: BasicTimeOfDay = BasicTimeOfDay {};
 
 impl Format<Time> for BasicTimeOfDay {
     fn format(&self, date: &Time) -> String {
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &Time, out: &mut dyn Write) -> core::fmt::Result {
         let (h, m, s) = date.as_hms();
         if date.nanoseconds == 0 {
-            format!("T{h:02}{m:02}{s:02}Z")
+            write!(out, "T{h:02}{m:02}{s:02}Z")
         } else {
             let s = s as f64 + date.get_secondsfrac();
-            format!("T{h:02}{m:02}{}", DecimalFormatF64(2, 9, s))
+            write!(out, "T{h:02}{m:02}{}", DecimalFormatF64(2, 9, s))
         }
     }
 }
@@ -184,6 +202,10 @@ impl Format<UTCDateTime> for BasicTimeOfDay {
     fn format(&self, date: &UTCDateTime) -> String {
         BasicTimeOfDay::format(&date.time)
     }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        BasicTimeOfDay.format_to(&date.time, out)
+    }
 }
 
 impl FormatParser<Time> for BasicTimeOfDay {
@@ -224,10 +246,9 @@ impl FormatParser<Time> for BasicTimeOfDay {
 
         let hours = u32::from_str(String::from_iter(hour_string).as_str())?;
         let minutes = u32::from_str(String::from_iter(minute_string).as_str())?;
-        let seconds = f64::from_str(String::from_iter(second_string).as_str())?;
+        let (seconds, nanoseconds) = parse_seconds(String::from_iter(second_string).as_str())?;
 
-        let second_of_day = hours * 3600 + minutes * 60 + seconds as u32;
-        let nanoseconds = (seconds.fract() * SEC_TO_NANOS) as u32;
+        let second_of_day = hours * 3600 + minutes * 60 + u32::from(seconds);
 
         Ok(Time {
             second_of_day,
@@ -242,23 +263,41 @@ impl BasicTimeOfDay {
     }
 }
 
+///
+/// Parses `ss[.sss]` seconds, with the fraction as exact integer nanoseconds - parsing as a
+/// [`f64`] can't represent all of them.  Digits after the ninth are truncated.
+fn parse_seconds(data: &str) -> Result<(u8, u32), FormatError> {
+    let (seconds, fraction) = data.split_once(['.', ',']).unwrap_or((data, ""));
+    let seconds = u8::from_str(seconds)?;
+    if fraction.is_empty() {
+        return Ok((seconds, 0));
+    }
+    let digits = fraction.get(..9).unwrap_or(fraction);
+    let nanoseconds = u32::from_str(digits)? * 10_u32.pow(9 - digits.len() as u32);
+    Ok((seconds, nanoseconds))
+}
+
 pub struct ISO8601Duration;
 
 pub const DURATION: ISO8601Duration = ISO8601Duration;
 
 impl Format<Duration> for ISO8601Duration {
     fn format(&self, date: &Duration) -> String {
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &Duration, out: &mut dyn Write) -> core::fmt::Result {
         let (days, hours, minutes, seconds) = date.as_dhms();
         if days > 0 {
-            return format!("P{days}DT{hours:02}H{minutes:02}M{seconds:02}S");
+            return write!(out, "P{days}DT{hours:02}H{minutes:02}M{seconds:02}S");
         }
         if hours > 0 {
-            return format!("PT{hours}H{minutes:02}M{seconds:02}S");
+            return write!(out, "PT{hours}H{minutes:02}M{seconds:02}S");
         }
         if minutes > 0 {
-            return format!("PT{minutes}M{seconds:02}S");
+            return write!(out, "PT{minutes}M{seconds:02}S");
         }
-        format!("PT{seconds}S")
+        write!(out, "PT{seconds}S")
     }
 }
 
@@ -268,7 +307,12 @@ pub const EXTENDED_DATE_FORMAT: ExtendedDateFormat = ExtendedDateFormat;
 
 impl Format<Date> for ExtendedDateFormat {
     fn format(&self, date: &Date) -> String {
-        format!(
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &Date, out: &mut dyn Write) -> core::fmt::Result {
+        write!(
+            out,
             "{:04}-{:02}-{:02}",
             date.year(),
             date.month_of_year() as u8,
@@ -303,6 +347,10 @@ impl Format<UTCDateTime> for ExtendedDateFormat {
     fn format(&self, date: &UTCDateTime) -> String {
         ExtendedDateFormat.format(&date.date)
     }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        ExtendedDateFormat.format_to(&date.date, out)
+    }
 }
 
 pub struct ExtendedTimeFormat;
@@ -311,12 +359,16 @@ pub const EXTENDED_TIME_FORMAT: ExtendedTimeFormat = ExtendedTimeFormat;
 
 impl Format<Time> for ExtendedTimeFormat {
     fn format(&self, date: &Time) -> String {
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &Time, out: &mut dyn Write) -> core::fmt::Result {
         let (h, m, s) = date.as_hms();
         if date.nanoseconds == 0 {
-            format!("T{h:02}:{m:02}:{s:02}Z")
+            write!(out, "T{h:02}:{m:02}:{s:02}Z")
         } else {
             let s = s as f64 + date.get_secondsfrac();
-            format!("T{h:02}:{m:02}:{}Z", DecimalFormatF64(2, 9, s))
+            write!(out, "T{h:02}:{m:02}:{}Z", DecimalFormatF64(2, 9, s))
         }
     }
 }
@@ -325,6 +377,10 @@ impl Format<UTCDateTime> for ExtendedTimeFormat {
     fn format(&self, date: &UTCDateTime) -> String {
         ExtendedTimeFormat.format(&date.time)
     }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        ExtendedTimeFormat.format_to(&date.time, out)
+    }
 }
 
 impl FormatParser<Time> for ExtendedTimeFormat {
@@ -354,9 +410,9 @@ impl FormatParser<Time> for ExtendedTimeFormat {
         };
         let hours = u8::from_str(hour)?;
         let minutes = u8::from_str(minute)?;
-        let seconds = f64::from_str(second)?;
-        let time = Time::from_hms_f64(hours, minutes, seconds)?;
-        Ok(time)
+        let (seconds, nanoseconds) = parse_seconds(second)?;
+        let time = Time::from_hms(hours, minutes, seconds)?;
+        Ok(Time::new(time.get_seconds(), nanoseconds)?)
     }
 }
 
@@ -365,11 +421,12 @@ pub const EXTENDED_DATE_TIME_FORMAT: ExtendedDateTimeFormat = ExtendedDateTimeFo
 
 impl Format<UTCDateTime> for ExtendedDateTimeFormat {
     fn format(&self, date: &UTCDateTime) -> String {
-        format!(
-            "{}{}",
-            ExtendedDateFormat.format(&date.get_date()),
-            ExtendedTimeFormat.format(&date.get_time())
-        )
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        ExtendedDateFormat.format_to(&date.get_date(), out)?;
+        ExtendedTimeFormat.format_to(&date.get_time(), out)
     }
 }
 
@@ -388,23 +445,135 @@ impl FormatParser<UTCDateTime> for ExtendedDateTimeFormat {
     }
 }
 
+///
+/// IS0 8601-1:2019 Date and Time of Day, in either the basic (`YYYYMMddTHHmmss[.sss]`) or the
+/// extended (`YYYY-MM-ddTHH:mm:ss[.sss]`) format.  Calendar, ordinal and week dates are accepted.
+/// The time may be followed by a time offset of `Z`, `+HH`, `+HHmm` or `+HH:mm` (or `-`), which
+/// is applied to convert the parsed value to UTC.  Formats as the extended format.
 pub struct ISO8601DateTime;
 
 pub const ISO8601_DATE_TIME: ISO8601DateTime = ISO8601DateTime;
 
 impl FormatParser<UTCDateTime> for ISO8601DateTime {
     fn try_from(&self, data: &str) -> Result<UTCDateTime, FormatError> {
-        if data.contains(':') {
-            ExtendedDateTimeFormat.try_from(data)
-        } else {
-            BasicDateTimeOfDay.try_from(data)
-        }
+        let Some((date, time)) = data.split_once(['T', 't', '_', ' ']) else {
+            return FormatError::err(format!(
+                "Expecting a date and time separated by 'T', but was {data}"
+            ));
+        };
+        let (time, offset_minutes) = split_time_offset(time)?;
+        let date = parse_date(date)?;
+        let time = parse_time(time)?;
+        Ok(local_to_utc(UTCDateTime::new(date, time), offset_minutes))
     }
 }
+
+/// Returns an [`FormatErrorType::OutOfRangeError`] naming the component if the value isn't
+/// within the range.
+fn check_component(
+    component: &str,
+    value: u8,
+    range: core::ops::RangeInclusive<u8>,
+) -> Result<u8, FormatError> {
+    if range.contains(&value) {
+        return Ok(value);
+    }
+    Err(FormatError::new(
+        FormatErrorType::OutOfRangeError,
+        format!(
+            "The {component} {value} is out of range, expecting {} to {}",
+            range.start(),
+            range.end()
+        ),
+    ))
+}
+
+/// Parses a basic or extended calendar, ordinal, or week date.
+fn parse_date(data: &str) -> Result<Date, FormatError> {
+    if data.contains('W') {
+        return ISO8601WeekDate.try_from(data);
+    }
+    let (year, month, day) = match data.split('-').collect::<Vec<_>>().as_slice() {
+        [year, month, day] => (*year, *month, *day),
+        [_, _] => return ISO8601OrdinalDate.try_from(data),
+        _ if data.len() == 7 => return ISO8601OrdinalDate.try_from(data),
+        _ => match (data.get(..4), data.get(4..6), data.get(6..)) {
+            (Some(year), Some(month), Some(day)) if data.len() == 8 => (year, month, day),
+            _ => {
+                return FormatError::err(format!(
+                    "Expecting a date like YYYY-MM-dd or YYYYMMdd, but was {data}"
+                ))
+            }
+        },
+    };
+    let year = i32::from_str(year)?;
+    let month = check_component("month", u8::from_str(month)?, 1..=12)?;
+    let month = Month::try_from(month)?;
+    let day = check_component("day", u8::from_str(day)?, 1..=month.days_in_month(year))?;
+    Ok(Date::try_from(year, month, day)?)
+}
+
+/// Parses a basic or extended time of day, without any time offset.
+fn parse_time(data: &str) -> Result<Time, FormatError> {
+    let (hours, minutes, seconds) = if data.contains(':') {
+        let mut split = data.split(':');
+        (split.next(), split.next(), split.next())
+    } else {
+        (data.get(..2), data.get(2..4), data.get(4..))
+    };
+    let (Some(hours), Some(minutes), Some(seconds)) = (hours, minutes, seconds) else {
+        return FormatError::err(format!(
+            "Expecting a time like HH:mm:ss or HHmmss, but was {data}"
+        ));
+    };
+    let hours = check_component("hour", u8::from_str(hours)?, 0..=23)?;
+    let minutes = check_component("minute", u8::from_str(minutes)?, 0..=59)?;
+    let (seconds, nanoseconds) = parse_seconds(seconds)?;
+    // a leap second can only be inserted at the end of the day.
+    let max_seconds = if hours == 23 && minutes == 59 { 60 } else { 59 };
+    let seconds = check_component("second", seconds, 0..=max_seconds)?;
+    let time = Time::from_hms(hours, minutes, seconds)?;
+    Ok(Time::new(time.get_seconds(), nanoseconds)?)
+}
+
+///
+/// Splits the `Z`, `+HH`, `+HHmm` or `+HH:mm` time offset from the end of the time, returning
+/// the offset in minutes east of UTC.  A time without an offset is assumed to be UTC.
+fn split_time_offset(time: &str) -> Result<(&str, i32), FormatError> {
+    if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        return Ok((time, 0));
+    }
+    let Some(idx) = time.rfind(['+', '-']) else {
+        return Ok((time, 0));
+    };
+    let (time, offset) = time.split_at(idx);
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let digits = offset.get(1..).unwrap_or_default();
+    let (hours, minutes) = match (digits.len(), digits.get(2..3)) {
+        (2, _) => (digits, "0"),
+        (4, _) => digits.split_at(2),
+        (5, Some(":")) => (
+            digits.get(..2).unwrap_or_default(),
+            digits.get(3..).unwrap_or_default(),
+        ),
+        _ => {
+            return FormatError::err(format!(
+                "Expecting a time offset like +HH, +HHmm or +HH:mm, but was {offset}"
+            ))
+        }
+    };
+    let hours = check_component("offset hour", u8::from_str(hours)?, 0..=23)?;
+    let minutes = check_component("offset minute", u8::from_str(minutes)?, 0..=59)?;
+    Ok((time, sign * (i32::from(hours) * 60 + i32::from(minutes))))
+}
 impl Format<UTCDateTime> for ISO8601DateTime {
     fn format(&self, date: &UTCDateTime) -> String {
         ExtendedDateTimeFormat.format(date)
     }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        ExtendedDateTimeFormat.format_to(date, out)
+    }
 }
 
 pub struct ISO8601Date;
@@ -413,7 +582,9 @@ pub const ISO8601_DATE: ISO8601Date = ISO8601Date;
 
 impl FormatParser<Date> for ISO8601Date {
     fn try_from(&self, data: &str) -> Result<Date, FormatError> {
-        if data.contains('-') {
+        if data.contains('W') {
+            ISO8601WeekDate.try_from(data)
+        } else if data.contains('-') {
             ExtendedDateFormat.try_from(data)
         } else {
             BasicCalendarDate.try_from(data)
@@ -425,6 +596,10 @@ impl Format<Date> for ISO8601Date {
     fn format(&self, date: &Date) -> String {
         ExtendedDateFormat.format(date)
     }
+
+    fn format_to(&self, date: &Date, out: &mut dyn Write) -> core::fmt::Result {
+        ExtendedDateFormat.format_to(date, out)
+    }
 }
 
 pub struct ISO8601Time;
@@ -443,13 +618,124 @@ pub struct ISO8601WeekNumber;
 pub const ISO8601_WEEK_NUMBER: ISO8601WeekNumber = ISO8601WeekNumber;
 impl Format<Date> for ISO8601WeekNumber {
     fn format(&self, date: &Date) -> String {
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &Date, out: &mut dyn Write) -> core::fmt::Result {
         let (year, wkno) = date.week_number();
-        format!("{year}W{wkno:02}")
+        write!(out, "{year}W{wkno:02}")
+    }
+}
+
+///
+/// IS0 8601-1:2019 Ordinal Date Format, section 5.2.3
+///
+/// Formats as the extended `YYYY-DDD`, like `2023-309`, and parses both the extended and the basic
+/// `YYYYDDD` forms.
+pub struct ISO8601OrdinalDate;
+pub const ISO8601_ORDINAL_DATE: ISO8601OrdinalDate = ISO8601OrdinalDate;
+
+impl Format<Date> for ISO8601OrdinalDate {
+    fn format(&self, date: &Date) -> String {
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &Date, out: &mut dyn Write) -> core::fmt::Result {
+        write!(out, "{:04}-{:03}", date.year(), date.day_of_year())
+    }
+}
+
+impl FormatParser<Date> for ISO8601OrdinalDate {
+    fn try_from(&self, data: &str) -> Result<Date, FormatError> {
+        let (year, day) = match data.split_once('-') {
+            Some(parts) => parts,
+            None if data.len() == 7 && data.is_char_boundary(4) => data.split_at(4),
+            None => {
+                return FormatError::err(format!(
+                    "Expecting an ordinal date like YYYY-DDD or YYYYDDD, but was {data}"
+                ))
+            }
+        };
+        let year = i32::from_str(year)?;
+        let day = u16::from_str(day)?;
+        let Some(day) = day.checked_sub(1) else {
+            return FormatError::err_str("Ordinal day of year starts at 001");
+        };
+        Ok(Date::new(year, day)?)
+    }
+}
+
+impl Format<UTCDateTime> for ISO8601OrdinalDate {
+    fn format(&self, date: &UTCDateTime) -> String {
+        ISO8601OrdinalDate.format(&date.date)
+    }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        ISO8601OrdinalDate.format_to(&date.date, out)
+    }
+}
+
+///
+/// IS0 8601-1:2019 Week Date Format, section 5.2.4
+///
+/// Formats as the extended `YYYY-Www-D`, like `2023-W45-2`, and parses both the extended and the
+/// basic `YYYYWwwD` forms.  The year is the week-numbering year, which differs from the calendar
+/// year for the days at the start or end of the year that are in a week of the adjacent year.
+/// The reduced `YYYY-Www` form parses as the Monday of the week.
+pub struct ISO8601WeekDate;
+pub const ISO8601_WEEK_DATE: ISO8601WeekDate = ISO8601WeekDate;
+
+impl Format<Date> for ISO8601WeekDate {
+    fn format(&self, date: &Date) -> String {
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &Date, out: &mut dyn Write) -> core::fmt::Result {
+        let (year, week, day) = date.week_date();
+        write!(out, "{year:04}-W{week:02}-{}", day as u8 + 1)
+    }
+}
+
+impl FormatParser<Date> for ISO8601WeekDate {
+    fn try_from(&self, data: &str) -> Result<Date, FormatError> {
+        let Some((year, rest)) = data.split_once('W') else {
+            return FormatError::err(format!(
+                "Expecting a week date like YYYY-Www-D or YYYYWwwD, but was {data}"
+            ));
+        };
+        let year = i32::from_str(year.trim_end_matches('-'))?;
+        let rest: String = rest.chars().filter(|c| *c != '-').collect();
+        let (week, day) = match rest.len() {
+            2 => (rest.as_str(), "1"),
+            3 if rest.is_char_boundary(2) => rest.split_at(2),
+            _ => {
+                return FormatError::err(format!(
+                    "Expecting a 2 digit week and 1 digit day, but was {data}"
+                ))
+            }
+        };
+        let week = u8::from_str(week)?;
+        let day = match u8::from_str(day)? {
+            0 => return FormatError::err_str("Day of the week starts at 1 (Monday)"),
+            day => DayOfWeek::try_from(day - 1)?,
+        };
+        Ok(Date::from_week_date(year, week, day)?)
+    }
+}
+
+impl Format<UTCDateTime> for ISO8601WeekDate {
+    fn format(&self, date: &UTCDateTime) -> String {
+        ISO8601WeekDate.format(&date.date)
+    }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        ISO8601WeekDate.format_to(&date.date, out)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::String;
     use alloc::vec;
     use irox_tools::ansi_colors::{FORMAT_COLOR_FG_GREEN, FORMAT_COLOR_FG_RED, FORMAT_RESET};
     use irox_tools::format;
@@ -462,11 +748,12 @@ mod tests {
     };
     use crate::format::iso8601::{
         ExtendedDateFormat, ExtendedDateTimeFormat, ExtendedTimeFormat, ISO8601Date,
-        ISO8601DateTime, ISO8601Time, BASIC_CALENDAR_DATE, BASIC_TIME_OF_DAY,
-        EXTENDED_DATE_TIME_FORMAT, ISO8601_DATE_TIME, ISO8601_WEEK_NUMBER,
+        ISO8601DateTime, ISO8601Time, BASIC_CALENDAR_DATE, BASIC_DATE_TIME_OF_DAY,
+        BASIC_TIME_OF_DAY, EXTENDED_DATE_TIME_FORMAT, ISO8601_DATE_TIME, ISO8601_ORDINAL_DATE,
+        ISO8601_WEEK_DATE, ISO8601_WEEK_NUMBER,
     };
     use crate::format::{Format, FormatError, FormatParser};
-    use crate::gregorian::Date;
+    use crate::gregorian::{Date, DayOfWeek};
     use crate::Time;
 
     #[test]
@@ -971,10 +1258,142 @@ mod tests {
             (Date::try_from_values(2021, 04, 05)?, "2021W14"),
             (Date::try_from_values(2023, 04, 28)?, "2023W17"),
             (Date::try_from_values(2023, 10, 31)?, "2023W44"),
+            (Date::try_from_values(2004, 1, 5)?, "2004W02"),
+            (Date::try_from_values(2015, 1, 1)?, "2015W01"),
+            (Date::try_from_values(2015, 1, 5)?, "2015W02"),
+            (Date::try_from_values(2020, 12, 31)?, "2020W53"),
+            (Date::try_from_values(2027, 1, 3)?, "2026W53"),
         ];
         for (d, e) in test_cases {
             assert_eq!(e, d.format(&ISO8601_WEEK_NUMBER));
         }
         Ok(())
     }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn format_into_reused_buffer() -> Result<(), FormatError> {
+        let times = [
+            UTCDateTime::try_from_values(2023, 4, 1, 1, 1, 1)?,
+            UTCDateTime::try_from_values_f64(2023, 4, 1, 1, 1, 1.01)?,
+            UTCDateTime::try_from_values(1999, 12, 31, 23, 59, 59)?,
+        ];
+        let mut buf = String::new();
+        for time in times {
+            buf.clear();
+            time.format_into(&EXTENDED_DATE_TIME_FORMAT, &mut buf);
+            assert_eq!(time.format(&EXTENDED_DATE_TIME_FORMAT), buf);
+
+            buf.clear();
+            BASIC_DATE_TIME_OF_DAY.format_into(&time, &mut buf);
+            assert_eq!(BASIC_DATE_TIME_OF_DAY.format(&time), buf);
+
+            buf.clear();
+            ISO8601_WEEK_NUMBER.format_into(&time.get_date(), &mut buf);
+            assert_eq!(time.get_date().format(&ISO8601_WEEK_NUMBER), buf);
+        }
+
+        // format_into appends rather than replaces
+        let time = UTCDateTime::try_from_values(2023, 4, 1, 1, 1, 1)?;
+        let mut buf = String::from("time=");
+        time.format_into(&ISO8601_DATE_TIME, &mut buf);
+        assert_eq!("time=2023-04-01T01:01:01Z", buf);
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn ordinal_and_week_dates() -> Result<(), FormatError> {
+        let test_cases = [
+            (
+                Date::try_from_values(2023, 11, 7)?,
+                "2023-311",
+                "2023-W45-2",
+            ),
+            (
+                Date::try_from_values(2023, 11, 5)?,
+                "2023-309",
+                "2023-W44-7",
+            ),
+            // week-year after the calendar year
+            (
+                Date::try_from_values(2024, 12, 30)?,
+                "2024-365",
+                "2025-W01-1",
+            ),
+            (
+                Date::try_from_values(2024, 12, 31)?,
+                "2024-366",
+                "2025-W01-2",
+            ),
+            // week-year before the calendar year
+            (Date::try_from_values(2021, 1, 1)?, "2021-001", "2020-W53-5"),
+            (Date::try_from_values(2027, 1, 3)?, "2027-003", "2026-W53-7"),
+        ];
+        for (date, ordinal, week) in test_cases {
+            assert_eq!(ordinal, date.format(&ISO8601_ORDINAL_DATE));
+            assert_eq!(week, date.format(&ISO8601_WEEK_DATE));
+            assert_eq!(date, Date::parse_from(&ISO8601_ORDINAL_DATE, ordinal)?);
+            assert_eq!(date, Date::parse_from(&ISO8601_WEEK_DATE, week)?);
+            assert_eq!(date, ISO8601Date.try_from(week)?);
+
+            // and the basic forms
+            let basic_ordinal = ordinal.replace('-', "");
+            let basic_week = week.replace('-', "");
+            assert_eq!(
+                date,
+                Date::parse_from(&ISO8601_ORDINAL_DATE, &basic_ordinal)?
+            );
+            assert_eq!(date, Date::parse_from(&ISO8601_WEEK_DATE, &basic_week)?);
+        }
+        // reduced precision is the monday of the week.
+        assert_eq!(
+            Date::try_from_values(2024, 12, 30)?,
+            Date::parse_from(&ISO8601_WEEK_DATE, "2025-W01")?
+        );
+
+        for invalid in [
+            "2023-000",
+            "2023-366",
+            "2023-W00-1",
+            "2023-W53-1",
+            "2023-W45-8",
+            "2023",
+        ] {
+            assert!(
+                ISO8601_ORDINAL_DATE.try_from(invalid).is_err()
+                    || ISO8601_WEEK_DATE.try_from(invalid).is_err(),
+                "{invalid} should be invalid"
+            );
+        }
+        assert!(ISO8601_ORDINAL_DATE.try_from("2023-000").is_err());
+        assert!(ISO8601_WEEK_DATE.try_from("2023-W53-1").is_err());
+        assert!(ISO8601_WEEK_DATE.try_from("2020-W53-1").is_ok());
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn week_dates_are_consecutive() -> Result<(), FormatError> {
+        let mut date = Date::try_from_values(1999, 1, 1)?;
+        let mut prev = date.week_date();
+        while date.year() < 2031 {
+            date = date.add_days(1);
+            let current = date.week_date();
+            assert_eq!(date, Date::from_week_date(current.0, current.1, current.2)?);
+            let (year, week, day) = prev;
+            let expected = if day == DayOfWeek::Sunday {
+                if week == crate::gregorian::weeks_in_year(year) {
+                    (year + 1, 1)
+                } else {
+                    (year, week + 1)
+                }
+            } else {
+                (year, week)
+            };
+            assert_eq!(expected, (current.0, current.1), "{date}");
+            prev = current;
+        }
+        Ok(())
+    }
 }
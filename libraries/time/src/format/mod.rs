@@ -24,8 +24,12 @@ use core::num::{ParseFloatError, ParseIntError};
 
 pub use alloc::string::{String, ToString};
 use irox_units::bounds::GreaterThanEqualToValueError;
+use irox_units::units::duration::Duration;
+
+use crate::datetime::UTCDateTime;
 
 pub mod iso8601;
+pub mod rfc2822;
 pub mod rfc3339;
 
 ///
@@ -34,6 +38,41 @@ pub trait Format<T> {
     ///
     /// Implementation-specific format of a date or time
     fn format(&self, date: &T) -> alloc::string::String;
+
+    ///
+    /// Writes the formatted date or time into the provided writer.  The default implementation
+    /// allocates using [`Format::format`], implementations should override this to write directly.
+    fn format_to(&self, date: &T, out: &mut dyn core::fmt::Write) -> core::fmt::Result {
+        out.write_str(&self.format(date))
+    }
+
+    ///
+    /// Appends the formatted date or time to the provided buffer, allowing the buffer to be
+    /// reused between calls.
+    fn format_into(&self, date: &T, out: &mut alloc::string::String) {
+        // writing into a string is infallible.
+        let _ = self.format_to(date, out);
+    }
+}
+
+///
+/// Formats into a new string using [`Format::format_to`], for implementations that write directly.
+pub(crate) fn format_to_string<T, F: Format<T> + ?Sized>(format: &F, date: &T) -> String {
+    let mut out = String::new();
+    format.format_into(date, &mut out);
+    out
+}
+
+///
+/// Converts a local date and time with the offset from UTC (in minutes, positive east of
+/// Greenwich) to UTC.
+pub(crate) fn local_to_utc(local: UTCDateTime, offset_minutes: i32) -> UTCDateTime {
+    let offset = Duration::from_minutes(u64::from(offset_minutes.unsigned_abs()));
+    if offset_minutes < 0 {
+        local + offset
+    } else {
+        local - offset
+    }
 }
 
 ///
@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+
+//!
+//! Implementations of [`Format`] and [`FormatParser`] based on RFC2822, "Internet Message Format", as used by
+//! email and the HTTP `Date` header.
+//!
+
+use core::fmt::Write;
+use core::str::FromStr;
+
+use irox_tools::format;
+
+use irox_enums::EnumIterItem;
+
+use crate::datetime::UTCDateTime;
+use crate::format::{format_to_string, local_to_utc, Format, FormatError, FormatParser, String};
+use crate::gregorian::{Date, Month};
+use crate::Time;
+
+///
+/// RFC2822 Date Time, section 3.3
+///
+/// Equivalent to `[EEE, ]dd MMM YYYY HH:mm[:ss] (+|-)HHmm`, like `Tue, 01 Jul 2003 10:52:37 +0200`.
+/// The obsolete zone names (`UT`, `GMT`, `EST`, `PDT`, etc) and two-digit years of section 4.3 are
+/// also accepted.  Parsed values are converted to UTC.  Formats in UTC with the `+0000` zone, like
+/// `Sun, 06 Nov 1994 08:49:37 +0000`.
+pub struct RFC2822DateTime;
+
+///
+/// RFC2822 Date Time, section 3.3
+///
+/// Equivalent to `[EEE, ]dd MMM YYYY HH:mm[:ss] (+|-)HHmm`
+pub const RFC2822_DATE_TIME: RFC2822DateTime = RFC2822DateTime;
+
+impl Format<UTCDateTime> for RFC2822DateTime {
+    fn format(&self, date: &UTCDateTime) -> String {
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        let day = date.get_date();
+        let (h, m, s) = date.get_time().as_hms();
+        write!(
+            out,
+            "{}, {:02} {} {:04} {h:02}:{m:02}:{s:02} +0000",
+            abbreviation(day.day_of_week().name()),
+            day.day_of_month() + 1,
+            abbreviation(day.month_of_year().name()),
+            day.year(),
+        )
+    }
+}
+
+impl FormatParser<UTCDateTime> for RFC2822DateTime {
+    fn try_from(&self, data: &str) -> Result<UTCDateTime, FormatError> {
+        let (day_of_week, data) = match data.split_once(',') {
+            Some((dow, rest)) => (Some(dow.trim()), rest),
+            None => (None, data),
+        };
+        let mut parts = data
+            .split_ascii_whitespace()
+            .take_while(|p| !p.starts_with('('));
+        let (Some(day), Some(month), Some(year), Some(time), Some(zone), None) = (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) else {
+            return FormatError::err_str("Expecting 'dd MMM YYYY HH:mm:ss zone'");
+        };
+
+        let day = u8::from_str(day)?;
+        let Some(month) =
+            Month::iter_items().find(|m| abbreviation(m.name()).eq_ignore_ascii_case(month))
+        else {
+            return FormatError::err(format!("Unknown month name: {month}"));
+        };
+        let year = match (year.len(), i32::from_str(year)?) {
+            // obsolete years, section 4.3
+            (2, y) if y < 50 => y + 2000,
+            (2 | 3, y) => y + 1900,
+            (_, y) => y,
+        };
+        let date = Date::try_from_values(year, month as u8, day)?;
+        if let Some(dow) = day_of_week {
+            let expected = abbreviation(date.day_of_week().name());
+            if !expected.eq_ignore_ascii_case(dow) {
+                return FormatError::err(format!(
+                    "Day of week '{dow}' doesn't match the date, expecting '{expected}'"
+                ));
+            }
+        }
+
+        let mut hms = time.split(':');
+        let (Some(hour), Some(minute), second, None) =
+            (hms.next(), hms.next(), hms.next(), hms.next())
+        else {
+            return FormatError::err(format!("Expecting a time of HH:mm[:ss]: {time}"));
+        };
+        let second = match second {
+            Some(s) => u8::from_str(s)?,
+            None => 0,
+        };
+        let time = Time::from_hms(u8::from_str(hour)?, u8::from_str(minute)?, second)?;
+
+        Ok(local_to_utc(
+            UTCDateTime::new(date, time),
+            parse_zone(zone)?,
+        ))
+    }
+}
+
+/// The first three characters of the name
+fn abbreviation(name: &str) -> &str {
+    name.get(..3).unwrap_or(name)
+}
+
+/// Parses the zone into signed minutes
+fn parse_zone(zone: &str) -> Result<i32, FormatError> {
+    let hours = match zone.to_ascii_uppercase().as_str() {
+        "UT" | "GMT" | "Z" => 0,
+        "EDT" => -4,
+        "EST" | "CDT" => -5,
+        "CST" | "MDT" => -6,
+        "MST" | "PDT" => -7,
+        "PST" => -8,
+        _ => {
+            let sign = match zone.get(..1) {
+                Some("+") => 1,
+                Some("-") => -1,
+                _ => return FormatError::err(format!("Unknown zone: {zone}")),
+            };
+            let (Some(hours), Some(minutes), 5) = (zone.get(1..3), zone.get(3..5), zone.len())
+            else {
+                return FormatError::err(format!("Expecting a zone of +HHmm: {zone}"));
+            };
+            let hours = i32::from_str(hours)?;
+            let minutes = i32::from_str(minutes)?;
+            if minutes > 59 {
+                return FormatError::err(format!("Invalid zone: {zone}"));
+            }
+            return Ok(sign * (hours * 60 + minutes));
+        }
+    };
+    Ok(hours * 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datetime::UTCDateTime;
+    use crate::format::rfc2822::RFC2822_DATE_TIME;
+    use crate::format::{FormatError, FormatParser};
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn test_parse() -> Result<(), FormatError> {
+        // examples from RFC2822 appendix A
+        let expected = UTCDateTime::try_from_values(1997, 11, 21, 15, 55, 6)?;
+        assert_eq!(
+            expected,
+            RFC2822_DATE_TIME.try_from("Fri, 21 Nov 1997 09:55:06 -0600")?
+        );
+        // obsolete two-digit year and zone name
+        assert_eq!(
+            UTCDateTime::try_from_values(1997, 11, 21, 9, 55, 6)?,
+            RFC2822_DATE_TIME.try_from("21 Nov 97 09:55:06 GMT")?
+        );
+        assert_eq!(
+            UTCDateTime::try_from_values(2003, 7, 1, 8, 52, 37)?,
+            RFC2822_DATE_TIME.try_from("Tue, 1 Jul 2003 10:52:37 +0200")?
+        );
+        // HTTP Date header
+        assert_eq!(
+            UTCDateTime::try_from_values(1994, 11, 6, 8, 49, 37)?,
+            RFC2822_DATE_TIME.try_from("Sun, 06 Nov 1994 08:49:37 GMT")?
+        );
+        assert_eq!(
+            UTCDateTime::try_from_values(1969, 2, 14, 3, 2, 0)?,
+            RFC2822_DATE_TIME.try_from("Thu, 13 Feb 1969 23:32 -0330 (Newfoundland Time)")?
+        );
+
+        for invalid in [
+            "Sat, 21 Nov 1997 09:55:06 -0600",
+            "21 Foo 1997 09:55:06 -0600",
+            "21 Nov 1997 09:55:06",
+            "21 Nov 1997 09:55:06 +06:00",
+            "1997-11-21T09:55:06Z",
+        ] {
+            assert!(RFC2822_DATE_TIME.try_from(invalid).is_err(), "{invalid}");
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn test_format() -> Result<(), FormatError> {
+        for (expected, dt) in [
+            (
+                "Sun, 06 Nov 1994 08:49:37 +0000",
+                UTCDateTime::try_from_values(1994, 11, 6, 8, 49, 37)?,
+            ),
+            (
+                "Fri, 21 Nov 1997 15:55:06 +0000",
+                UTCDateTime::try_from_values(1997, 11, 21, 15, 55, 6)?,
+            ),
+            (
+                "Thu, 01 Jan 1970 00:00:00 +0000",
+                UTCDateTime::try_from_values(1970, 1, 1, 0, 0, 0)?,
+            ),
+            (
+                "Thu, 29 Feb 2024 23:59:59 +0000",
+                UTCDateTime::try_from_values(2024, 2, 29, 23, 59, 59)?,
+            ),
+        ] {
+            assert_eq!(expected, dt.format(&RFC2822_DATE_TIME));
+            assert_eq!(dt, RFC2822_DATE_TIME.try_from(expected)?);
+        }
+        Ok(())
+    }
+}
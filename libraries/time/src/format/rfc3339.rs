@@ -1,2 +1,183 @@
 // SPDX-License-Identifier: MIT
 // Copyright 2023 IROX Contributors
+
+//!
+//! Implementations of [`Format`] and [`FormatParser`] based on RFC3339, "Date and Time on the Internet:
+//! Timestamps", a strict profile of ISO8601.
+//!
+
+use core::fmt::Write;
+use core::str::FromStr;
+
+use irox_tools::format;
+
+use crate::datetime::UTCDateTime;
+use crate::format::iso8601::{ExtendedDateFormat, ExtendedTimeFormat};
+use crate::format::{format_to_string, local_to_utc, Format, FormatError, FormatParser, String};
+
+///
+/// RFC3339 Date Time, section 5.6
+///
+/// Equivalent to `YYYY-MM-ddTHH:mm:ss[.SSS](Z|+HH:mm|-HH:mm)`.  The time offset is required, and
+/// parsed values are converted to UTC.  Formats in UTC with the `Z` offset, and only as many
+/// fractional second digits as needed, like `1985-04-12T23:20:50.52Z`.
+pub struct RFC3339DateTime;
+
+///
+/// RFC3339 Date Time, section 5.6
+///
+/// Equivalent to `YYYY-MM-ddTHH:mm:ss[.SSS](Z|+HH:mm|-HH:mm)`
+pub const RFC3339_DATE_TIME: RFC3339DateTime = RFC3339DateTime;
+
+impl Format<UTCDateTime> for RFC3339DateTime {
+    fn format(&self, date: &UTCDateTime) -> String {
+        format_to_string(self, date)
+    }
+
+    fn format_to(&self, date: &UTCDateTime, out: &mut dyn Write) -> core::fmt::Result {
+        ExtendedDateFormat.format_to(&date.get_date(), out)?;
+        let time = date.get_time();
+        let (h, m, s) = time.as_hms();
+        write!(out, "T{h:02}:{m:02}:{s:02}")?;
+        let nanos = time.get_nanoseconds();
+        if nanos > 0 {
+            let fraction = format!("{nanos:09}");
+            write!(out, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        out.write_char('Z')
+    }
+}
+
+impl FormatParser<UTCDateTime> for RFC3339DateTime {
+    fn try_from(&self, data: &str) -> Result<UTCDateTime, FormatError> {
+        let Some((date, sep, time)) = data
+            .get(..10)
+            .zip(data.get(10..11))
+            .zip(data.get(11..))
+            .map(|((d, s), t)| (d, s, t))
+        else {
+            return FormatError::err_str("Expecting a full-date and a full-time.");
+        };
+        if !matches!(sep, "T" | "t" | " ") {
+            return FormatError::err(format!(
+                "Expecting 'T' between date and time, but was '{sep}'"
+            ));
+        }
+        let bytes = date.as_bytes();
+        if bytes.get(4) != Some(&b'-') || bytes.get(7) != Some(&b'-') {
+            return FormatError::err_str("Expecting a date of the form YYYY-MM-dd");
+        }
+        let date = ExtendedDateFormat.try_from(date)?;
+
+        let (time, offset_minutes) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+            (time, 0)
+        } else {
+            let idx = time.len().saturating_sub(6);
+            let (Some(time), Some(offset)) = (time.get(..idx), time.get(idx..)) else {
+                return FormatError::err_str("Expecting a time offset of Z or +HH:mm");
+            };
+            (time, parse_offset(offset)?)
+        };
+        let time = ExtendedTimeFormat.try_from(time)?;
+        Ok(local_to_utc(UTCDateTime::new(date, time), offset_minutes))
+    }
+}
+
+/// Parses `+HH:mm` or `-HH:mm` into signed minutes
+fn parse_offset(offset: &str) -> Result<i32, FormatError> {
+    let sign = match offset.get(..1) {
+        Some("+") => 1,
+        Some("-") => -1,
+        _ => return FormatError::err_str("Expecting the time offset to start with + or -"),
+    };
+    let (Some(hours), Some(":"), Some(minutes)) =
+        (offset.get(1..3), offset.get(3..4), offset.get(4..))
+    else {
+        return FormatError::err_str("Expecting a time offset of the form +HH:mm");
+    };
+    let hours = i32::from_str(hours)?;
+    let minutes = i32::from_str(minutes)?;
+    if hours > 23 || minutes > 59 {
+        return FormatError::err(format!("Invalid time offset: {offset}"));
+    }
+    Ok(sign * (hours * 60 + minutes))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datetime::UTCDateTime;
+    use crate::format::rfc3339::RFC3339_DATE_TIME;
+    use crate::format::{Format, FormatError, FormatParser, String};
+    use crate::gregorian::Date;
+    use crate::Time;
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn test_parse() -> Result<(), FormatError> {
+        let expected = UTCDateTime::try_from_values(1985, 4, 12, 23, 20, 50)?;
+        assert_eq!(
+            expected,
+            RFC3339_DATE_TIME.try_from("1985-04-12T23:20:50Z")?
+        );
+        assert_eq!(
+            expected,
+            RFC3339_DATE_TIME.try_from("1985-04-12t23:20:50z")?
+        );
+        // offsets are converted to UTC, across the day boundary.
+        assert_eq!(
+            expected,
+            RFC3339_DATE_TIME.try_from("1985-04-13T01:20:50+02:00")?
+        );
+        assert_eq!(
+            expected,
+            RFC3339_DATE_TIME.try_from("1985-04-12T15:20:50-08:00")?
+        );
+        let frac = RFC3339_DATE_TIME.try_from("1985-04-12T23:20:50.52Z")?;
+        assert_eq!(expected.get_time().as_hms(), frac.get_time().as_hms());
+
+        for invalid in [
+            "1985-04-12T23:20:50",
+            "1985-04-12T23:20:50+0200",
+            "1985-04-12T23:20:50+25:00",
+            "19850412T232050Z",
+            "1985-04-12",
+        ] {
+            assert!(RFC3339_DATE_TIME.try_from(invalid).is_err(), "{invalid}");
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn test_format() -> Result<(), FormatError> {
+        let dt = UTCDateTime::try_from_values(1985, 4, 12, 23, 20, 50)?;
+        assert_eq!("1985-04-12T23:20:50Z", dt.format(&RFC3339_DATE_TIME));
+        let date = Date::try_from_values(1985, 4, 12)?;
+        for (nanos, expected) in [
+            (520_000_000, "1985-04-12T23:20:50.52Z"),
+            (1, "1985-04-12T23:20:50.000000001Z"),
+            (123_456_789, "1985-04-12T23:20:50.123456789Z"),
+        ] {
+            let dt = UTCDateTime::new(date, Time::new(84_050, nanos)?);
+            assert_eq!(expected, dt.format(&RFC3339_DATE_TIME));
+            assert_eq!(dt, RFC3339_DATE_TIME.try_from(expected)?);
+        }
+        assert_eq!(
+            "0001-01-01T00:00:00Z",
+            UTCDateTime::try_from_values(1, 1, 1, 0, 0, 0)?.format(&RFC3339_DATE_TIME)
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn test_format_dyn() -> Result<(), FormatError> {
+        let dt = UTCDateTime::try_from_values(1985, 4, 12, 23, 20, 50)?;
+        let format: &dyn Format<UTCDateTime> = &RFC3339_DATE_TIME;
+        assert_eq!("1985-04-12T23:20:50Z", format.format(&dt));
+        let mut out = String::from("at ");
+        format.format_into(&dt, &mut out);
+        assert_eq!("at 1985-04-12T23:20:50Z", out);
+        Ok(())
+    }
+}
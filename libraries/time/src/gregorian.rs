@@ -4,11 +4,20 @@
 //!
 //! Contains [`Date`] and associated elements to represent a Proleptic Gregorian Date.
 //!
+//! All dates are in the proleptic Gregorian calendar by default - the Gregorian leap year rules
+//! are extended backwards before the calendar was introduced on 15-OCT-1582
+//! ([`crate::epoch::GREGORIAN_EPOCH`]).  Historical records before the reform are usually in the
+//! Julian calendar, and differ from the proleptic Gregorian date by a number of days (10 days at
+//! the reform).  Use [`Date::from_julian_calendar`] or [`Date::to_gregorian_across_cutover`] to
+//! convert these dates, and [`Date::to_julian_calendar`] to convert back.
+//!
 
 use core::fmt::{Display, Formatter};
-use core::ops::{Add, AddAssign, Sub};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 
 use irox_enums::{EnumIterItem, EnumName, EnumTryFromStr};
+#[allow(unused_imports)]
+use irox_tools::f64::FloatExt;
 use irox_units::bounds::{GreaterThanEqualToValueError, LessThanValue, Range};
 use irox_units::units::duration::{Duration, DurationUnit};
 
@@ -214,7 +223,7 @@ impl TryFrom<u8> for Month {
 
 ///
 /// Gregorian Date - a specific date on a calendar.
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Date {
     ///
     /// Year is the Proleptic Gregorian Year
@@ -272,6 +281,79 @@ impl Date {
         Ok(Date { year, day_of_year })
     }
 
+    ///
+    /// Constructs a new date from the provided values in the (proleptic) Julian calendar, where
+    /// every fourth year is a leap year.  For example, the Julian date 4-OCT-1582 (the last day
+    /// before the Gregorian reform) is the proleptic Gregorian date 14-OCT-1582.  If month or day is
+    /// out of range for the Julian calendar, will return `Err(OutsideRangeError)`.
+    ///
+    /// Note: The 'day' parameter here is in the range of `1..=31` as would be shown on a calendar
+    pub fn from_julian_calendar(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date, GreaterThanEqualToValueError<u8>> {
+        let month: Month = month.try_into()?;
+        let days_in_month = match month {
+            Month::February if year.rem_euclid(4) == 0 => 29,
+            _ => month.days_in_month(year),
+        };
+        LessThanValue::new(days_in_month + 1).check_value_is_valid(&day)?;
+
+        let first = Self::try_from(year, month, 1)?;
+        let month = month as u8;
+        // the Julian date is offset from the gregorian date with the same values by this many days
+        let shift = julian_calendar_day_number(year, month, day)
+            - gregorian_calendar_day_number(year, month, day);
+        let offset = i64::from(day) - 1 + shift;
+        if offset >= 0 {
+            Ok(first.add_days(offset as u32))
+        } else {
+            Ok(first.sub_days(offset.unsigned_abs() as u16))
+        }
+    }
+
+    ///
+    /// Constructs a new date from the provided values as they'd be written historically - in the
+    /// Julian calendar before the Gregorian reform on 15-OCT-1582, and in the Gregorian calendar
+    /// from then on.  The Julian 4-OCT-1582 was immediately followed by the Gregorian
+    /// 15-OCT-1582, the skipped dates (5-OCT to 14-OCT-1582) are treated as Julian dates.
+    ///
+    /// Note: The 'day' parameter here is in the range of `1..=31` as would be shown on a calendar
+    pub fn to_gregorian_across_cutover(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date, GreaterThanEqualToValueError<u8>> {
+        if (year, month, day) < (1582, 10, 15) {
+            Self::from_julian_calendar(year, month, day)
+        } else {
+            Self::try_from_values(year, month, day)
+        }
+    }
+
+    ///
+    /// Returns the `(year, month, day)` of this date in the (proleptic) Julian calendar.  Month
+    /// is in the range `1..=12`, and day is in the range `1..=31`.
+    #[must_use]
+    #[allow(clippy::integer_division)]
+    pub fn to_julian_calendar(&self) -> (i32, u8, u8) {
+        let jdn = gregorian_calendar_day_number(
+            self.year,
+            self.month_of_year() as u8,
+            self.day_of_month() + 1,
+        );
+        // Richards' algorithm, from "Mapping Time: The Calendar and its History"
+        let c = jdn + 32082;
+        let d = (4 * c + 3).div_euclid(1461);
+        let e = c - (1461 * d).div_euclid(4);
+        let m = (5 * e + 2).div_euclid(153);
+        let day = e - (153 * m + 2).div_euclid(5) + 1;
+        let month = m + 3 - 12 * (m / 10);
+        let year = d - 4800 + m / 10;
+        (year as i32, month as u8, day as u8)
+    }
+
     ///
     /// Returns the gregorian year this date represents
     #[must_use]
@@ -349,10 +431,11 @@ impl Date {
 
         loop {
             if days_remaining > days {
+                // roll back to the first day of this year, then the (one-past) last day of the
+                // previous year.
                 years -= 1;
-                let days_in_year = days_in_year(years);
                 days_remaining -= days;
-                days += days_in_year;
+                days = days_in_year(years);
                 continue;
             }
             days -= days_remaining;
@@ -416,7 +499,8 @@ impl Date {
     /// Returns the day-of-the-week name of this date, using ISO8601 convention that the week starts on Monday.
     pub fn day_of_week(&self) -> DayOfWeek {
         let prime: PrimeDate = self.as_julian_day().into();
-        let dow = prime.get_day_number() as i32 % 7;
+        // the prime epoch (1900-01-01) was a Monday, and dates before it are negative.
+        let dow = (prime.get_day_number() as i32).rem_euclid(7);
         match dow {
             1 => DayOfWeek::Tuesday,
             2 => DayOfWeek::Wednesday,
@@ -429,50 +513,146 @@ impl Date {
     }
 
     ///
-    /// Returns a pair (year number, week of year)
+    /// Returns a pair (year number, week of year).  The ISO8601 week-numbering year differs from the
+    /// calendar year at the boundaries - the first week of the year is the week with the first
+    /// Thursday of the year in it.
     pub fn week_number(&self) -> (i32, u8) {
-        let jan01 = Date {
-            year: self.year,
-            day_of_year: 0,
-        };
         let dow = self.day_of_week() as i32;
-        let wkno = (9 + self.day_of_year as i32 - dow) / 7;
-
-        if wkno == 0 {
-            let dow = jan01.day_of_week() as i32;
-            if (0..=3).contains(&dow) {
-                return (self.year, 1);
-            }
+        let wkno = (10 + self.day_of_year as i32 - dow) / 7;
+        if wkno < 1 {
             let year = self.year - 1;
-            if dow == 4 {
-                // friday, always W53 of prev year
-                return (year, 53);
-            }
-            if dow == 5 {
-                // saturday, W53 in leaps, W52 otherwise.
-                let prev_yr_starts_on = Date {
-                    year,
-                    day_of_year: 0,
-                }
-                .day_of_week();
-                if is_leap_year(year) && prev_yr_starts_on == DayOfWeek::Thursday {
-                    return (year, 53);
-                }
-            }
-            // sunday, always W52
-            return (year, 52);
+            return (year, weeks_in_year(year));
         }
-        if wkno == 53 {
-            // only actually 53 if is a long year
-            if is_long_year(self.year) {
-                return (self.year, 53);
-            }
-            // otherwise is Year 01 of the following year.
+        if wkno > i32::from(weeks_in_year(self.year)) {
             return (self.year + 1, 1);
         }
-
         (self.year, wkno as u8)
     }
+
+    ///
+    /// Returns the ISO8601 week of this date, (week-numbering year, week of year).  Weeks start on
+    /// Monday, and week 1 is the week containing the first Thursday of the year - so the 29th -
+    /// 31st of December may be in week 1 of the next year, and the 1st - 3rd of January may be in
+    /// the last week (52 or 53) of the previous year.  Equivalent to [`Date::week_number`].
+    #[must_use]
+    pub fn iso_week(&self) -> (i32, u8) {
+        self.week_number()
+    }
+
+    ///
+    /// Returns the ISO8601 week date of this date, (week-numbering year, week of year, day of week)
+    pub fn week_date(&self) -> (i32, u8, DayOfWeek) {
+        let (year, week) = self.week_number();
+        (year, week, self.day_of_week())
+    }
+
+    ///
+    /// Creates a date from an ISO8601 week date.  The week-numbering `year` may differ from the
+    /// calendar year of the returned date, for weeks that span the start or end of the year.
+    ///
+    /// Returns `Err(GreaterThanEqualToValueError)` if the week is zero, or past the last week of
+    /// the year.
+    pub fn from_week_date(
+        year: i32,
+        week: u8,
+        day: DayOfWeek,
+    ) -> Result<Date, GreaterThanEqualToValueError<u8>> {
+        let max_week = weeks_in_year(year);
+        if week == 0 || week > max_week {
+            return GreaterThanEqualToValueError::err(week, LessThanValue::new(max_week + 1));
+        }
+        // the 4th of January is always in week 1.
+        let jan04 = Date {
+            year,
+            day_of_year: 3,
+        };
+        let week1_monday = jan04.sub_days(jan04.day_of_week() as u16);
+        Ok(week1_monday.add_days(u32::from(week - 1) * 7 + day as u32))
+    }
+
+    ///
+    /// Returns the calendar quarter of this date, 1 (January - March) through 4 (October -
+    /// December).
+    #[must_use]
+    pub fn quarter(&self) -> u8 {
+        (self.month_of_year() as u8 - 1) / 3 + 1
+    }
+
+    ///
+    /// Returns the quarter (1-4) of the fiscal year that starts on the first day of the
+    /// `fy_start_month`.  With a fiscal year starting in July, July - September is Q1 and
+    /// January - March is Q3.
+    #[must_use]
+    pub fn fiscal_quarter(&self, fy_start_month: Month) -> u8 {
+        let months_in = (self.month_of_year() as u8 + 12 - fy_start_month as u8) % 12;
+        months_in / 3 + 1
+    }
+
+    ///
+    /// Returns the fiscal year of this date, for a fiscal year that starts on the first day of
+    /// the `fy_start_month`.  Fiscal years are named by the calendar year they end in, so with a
+    /// fiscal year starting in October, 01-OCT-2023 is the first day of fiscal year 2024.
+    #[must_use]
+    pub fn fiscal_year(&self, fy_start_month: Month) -> i32 {
+        if fy_start_month != Month::January && self.month_of_year() >= fy_start_month {
+            self.year + 1
+        } else {
+            self.year
+        }
+    }
+
+    ///
+    /// Returns the meteorological season of this date in the hemisphere.  Meteorological seasons
+    /// are whole months: in the northern hemisphere, spring is March - May, summer is June -
+    /// August, autumn is September - November, and winter is December - February.  The southern
+    /// hemisphere is offset by 6 months.
+    #[must_use]
+    pub fn season(&self, hemisphere: Hemisphere) -> Season {
+        let northern = match self.month_of_year() {
+            Month::March | Month::April | Month::May => Season::Spring,
+            Month::June | Month::July | Month::August => Season::Summer,
+            Month::September | Month::October | Month::November => Season::Autumn,
+            Month::December | Month::January | Month::February => Season::Winter,
+        };
+        match hemisphere {
+            Hemisphere::Northern => northern,
+            Hemisphere::Southern => northern.opposite(),
+        }
+    }
+}
+
+///
+/// Hemisphere of the earth, north or south of the equator.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, EnumName, EnumIterItem, EnumTryFromStr)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+///
+/// Season of the year
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, EnumName, EnumIterItem, EnumTryFromStr,
+)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    ///
+    /// Returns the season at the same time of year in the opposite hemisphere.
+    #[must_use]
+    pub const fn opposite(&self) -> Season {
+        match self {
+            Season::Spring => Season::Autumn,
+            Season::Summer => Season::Winter,
+            Season::Autumn => Season::Spring,
+            Season::Winter => Season::Summer,
+        }
+    }
 }
 
 ///
@@ -507,6 +687,16 @@ impl TryFrom<u8> for DayOfWeek {
     }
 }
 
+///
+/// Returns the number of ISO8601 weeks in the year, 53 in a "Long Year", 52 otherwise.
+pub fn weeks_in_year(year: i32) -> u8 {
+    if is_long_year(year) {
+        53
+    } else {
+        52
+    }
+}
+
 ///
 /// Returns true if the indicated year is a ISO8601 "Long Year" with 53 Weeks in it.
 pub fn is_long_year(year: i32) -> bool {
@@ -562,18 +752,32 @@ pub const fn seconds_in_year(year: i32) -> u32 {
     days_in_year(year) as u32 * SECONDS_IN_DAY
 }
 
+/// Returns the Julian Day Number of the date in the Julian calendar
+fn julian_calendar_day_number(year: i32, month: u8, day: u8) -> i64 {
+    let (y, m) = shifted_year_month(year, month);
+    i64::from(day) + (153 * m + 2).div_euclid(5) + 365 * y + y.div_euclid(4) - 32083
+}
+
+/// Returns the Julian Day Number of the date in the Gregorian calendar
+fn gregorian_calendar_day_number(year: i32, month: u8, day: u8) -> i64 {
+    let (y, m) = shifted_year_month(year, month);
+    i64::from(day) + (153 * m + 2).div_euclid(5) + 365 * y + y.div_euclid(4) - y.div_euclid(100)
+        + y.div_euclid(400)
+        - 32045
+}
+
+/// Shifts the year to start on 1-MAR, 4800 BC, so the leap day is at the end of the year.
+fn shifted_year_month(year: i32, month: u8) -> (i64, i64) {
+    let a = i64::from(month <= 2);
+    let y = i64::from(year) + 4800 - a;
+    let m = i64::from(month) + 12 * a - 3;
+    (y, m)
+}
+
 impl From<&Date> for UnixTimestamp {
     fn from(value: &Date) -> Self {
-        let years_duration = value.year - UNIX_EPOCH.0.year;
-        if years_duration < 0 {
-            return UnixTimestamp::default();
-        }
-        let mut secs_duration: u64 = value.day_of_year as u64 * SECONDS_IN_DAY as u64;
-        for year in UNIX_EPOCH.0.year..value.year {
-            secs_duration += seconds_in_year(year) as u64;
-        }
-
-        UnixTimestamp::from_seconds_f64(secs_duration as f64)
+        // negative for dates before the epoch.
+        UnixTimestamp::from_offset(*value - UNIX_EPOCH.get_gregorian_date())
     }
 }
 
@@ -586,7 +790,8 @@ impl From<&UnixTimestamp> for Date {
         // Algorithm impl based on libmusl __secs_to_tm.c
         let sec_in_day = SECONDS_IN_DAY as i64;
         let leapoch = LEAPOCH.get_offset().as_seconds() as i64;
-        let offset = value.get_offset().as_seconds() as i64;
+        // floored, so that pre-epoch timestamps land on the previous day.
+        let offset = value.get_offset().as_seconds_f64().floor() as i64;
 
         // clever impl - the leapoch is a nice round 400 cycle leap year
         // so we compute the negative offset (for dates before the leapoch)
@@ -752,13 +957,47 @@ impl AddAssign<&Duration> for Date {
     }
 }
 
+impl Sub<Duration> for Date {
+    type Output = Date;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        let mut days = rhs.as_days();
+        let mut date = self;
+        while days > 0 {
+            let step = days.min(u16::MAX as u64);
+            date = date.sub_days(step as u16);
+            days -= step;
+        }
+        date
+    }
+}
+
+impl Sub<&Duration> for Date {
+    type Output = Date;
+
+    fn sub(self, rhs: &Duration) -> Self::Output {
+        self - *rhs
+    }
+}
+
+impl SubAssign<Duration> for Date {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&Duration> for Date {
+    fn sub_assign(&mut self, rhs: &Duration) {
+        *self = *self - rhs;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use irox_enums::EnumIterItem;
     use irox_units::bounds::GreaterThanEqualToValueError;
 
     use crate::epoch::{UnixTimestamp, GPS_EPOCH, PRIME_EPOCH, UNIX_EPOCH};
-    use crate::gregorian::{is_leap_year, Date, Month};
+    use crate::gregorian::{is_leap_year, Date, DayOfWeek, Hemisphere, Month, Season};
 
     #[test]
     pub fn leap_year_test() {
@@ -963,4 +1202,190 @@ mod tests {
         assert_eq!("2021-04-02", date.to_string());
         Ok(())
     }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn test_julian_calendar() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        // the last day of the julian calendar was followed by the first day of the gregorian.
+        let last_julian = Date::to_gregorian_across_cutover(1582, 10, 4)?;
+        let first_gregorian = Date::to_gregorian_across_cutover(1582, 10, 15)?;
+        assert_eq!(Date::try_from_values(1582, 10, 14)?, last_julian);
+        assert_eq!(first_gregorian, last_julian.add_days(1));
+        assert_eq!(Date::try_from_values(1582, 10, 15)?, first_gregorian);
+        assert_eq!(
+            Date::from_julian_calendar(1582, 10, 4)?,
+            Date::try_from_values(1582, 10, 14)?
+        );
+        assert_eq!((1582, 10, 4), last_julian.to_julian_calendar());
+        assert_eq!((1582, 10, 5), first_gregorian.to_julian_calendar());
+
+        // 1500 is a leap year in the julian calendar, but not the gregorian.
+        let leap = Date::from_julian_calendar(1500, 2, 29)?;
+        assert_eq!(Date::try_from_values(1500, 3, 10)?, leap);
+        assert_eq!((1500, 2, 29), leap.to_julian_calendar());
+        assert!(Date::try_from_values(1500, 2, 29).is_err());
+        assert!(Date::from_julian_calendar(1500, 2, 30).is_err());
+
+        // the calendars agree in the 3rd century, and the julian is behind before that.
+        assert_eq!(
+            Date::try_from_values(250, 6, 1)?,
+            Date::from_julian_calendar(250, 6, 1)?
+        );
+        assert_eq!(
+            Date::try_from_values(100, 2, 28)?,
+            Date::from_julian_calendar(100, 3, 1)?
+        );
+
+        assert_eq!(
+            (1999, 12, 19),
+            Date::try_from_values(2000, 1, 1)?.to_julian_calendar()
+        );
+        assert_eq!(
+            Date::try_from_values(2024, 3, 1)?,
+            Date::to_gregorian_across_cutover(2024, 3, 1)?
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn quarters() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        let date = Date::try_from_values(2024, 2, 15)?;
+        assert_eq!(1, date.quarter());
+        // fiscal year starting in July, Jan-Mar is the 3rd quarter of FY2024.
+        assert_eq!(3, date.fiscal_quarter(Month::July));
+        assert_eq!(2024, date.fiscal_year(Month::July));
+        assert_eq!(1, date.fiscal_quarter(Month::January));
+        assert_eq!(2024, date.fiscal_year(Month::January));
+
+        // across the calendar year boundary
+        let date = Date::try_from_values(2023, 10, 1)?;
+        assert_eq!(4, date.quarter());
+        assert_eq!(1, date.fiscal_quarter(Month::October));
+        assert_eq!(2024, date.fiscal_year(Month::October));
+        let date = Date::try_from_values(2023, 9, 30)?;
+        assert_eq!(3, date.quarter());
+        assert_eq!(4, date.fiscal_quarter(Month::October));
+        assert_eq!(2023, date.fiscal_year(Month::October));
+
+        // every month lands in each quarter exactly once, for every fiscal year start.
+        for start in Month::iter_items() {
+            let mut counts = [0; 4];
+            for month in Month::iter_items() {
+                let date = Date::try_from_values(2024, month as u8, 1)?;
+                let quarter = date.fiscal_quarter(start);
+                if let Some(count) = counts.get_mut(usize::from(quarter) - 1) {
+                    *count += 1;
+                }
+                if month == start {
+                    assert_eq!(1, quarter);
+                }
+            }
+            assert_eq!([3; 4], counts);
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn seasons() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        let date = Date::try_from_values(2024, 1, 15)?;
+        assert_eq!(Season::Winter, date.season(Hemisphere::Northern));
+        assert_eq!(Season::Summer, date.season(Hemisphere::Southern));
+
+        let date = Date::try_from_values(2024, 3, 1)?;
+        assert_eq!(Season::Spring, date.season(Hemisphere::Northern));
+        assert_eq!(Season::Autumn, date.season(Hemisphere::Southern));
+        let date = Date::try_from_values(2024, 2, 29)?;
+        assert_eq!(Season::Winter, date.season(Hemisphere::Northern));
+
+        let date = Date::try_from_values(2024, 8, 31)?;
+        assert_eq!(Season::Summer, date.season(Hemisphere::Northern));
+        assert_eq!(Season::Winter, date.season(Hemisphere::Southern));
+        let date = Date::try_from_values(2024, 11, 30)?;
+        assert_eq!(Season::Autumn, date.season(Hemisphere::Northern));
+        assert_eq!(Season::Spring, date.season(Hemisphere::Southern));
+        let date = Date::try_from_values(2024, 12, 1)?;
+        assert_eq!(Season::Winter, date.season(Hemisphere::Northern));
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn day_of_week() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        for (year, month, day, expected) in [
+            (1900, 1, 1, DayOfWeek::Monday),
+            (1899, 12, 31, DayOfWeek::Sunday),
+            (1899, 12, 30, DayOfWeek::Saturday),
+            (1776, 7, 4, DayOfWeek::Thursday),
+            (1600, 1, 1, DayOfWeek::Saturday),
+            (1970, 1, 1, DayOfWeek::Thursday),
+            (2000, 2, 29, DayOfWeek::Tuesday),
+            (2024, 12, 25, DayOfWeek::Wednesday),
+        ] {
+            let date = Date::try_from_values(year, month, day)?;
+            assert_eq!(expected, date.day_of_week(), "{date}");
+        }
+
+        // each following day is the next day of the week
+        let mut date = Date::try_from_values(1890, 1, 1)?;
+        let mut expected = date.day_of_week() as u8;
+        for _ in 0..10_000 {
+            date = date.add_days(1);
+            expected = (expected + 1) % 7;
+            assert_eq!(expected, date.day_of_week() as u8, "{date}");
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn iso_week_boundaries() -> Result<(), GreaterThanEqualToValueError<u8>> {
+        for (year, month, day, week_year, week) in [
+            (2005, 1, 1, 2004, 53),
+            (2005, 1, 2, 2004, 53),
+            (2005, 1, 3, 2005, 1),
+            (2005, 12, 31, 2005, 52),
+            (2006, 1, 1, 2005, 52),
+            (2006, 1, 2, 2006, 1),
+            (2007, 1, 1, 2007, 1),
+            (2007, 12, 30, 2007, 52),
+            (2007, 12, 31, 2008, 1),
+            (2008, 1, 1, 2008, 1),
+            (2008, 12, 28, 2008, 52),
+            (2008, 12, 29, 2009, 1),
+            (2008, 12, 31, 2009, 1),
+            (2009, 1, 1, 2009, 1),
+            (2009, 12, 31, 2009, 53),
+            (2010, 1, 1, 2009, 53),
+            (2010, 1, 3, 2009, 53),
+            (2010, 1, 4, 2010, 1),
+            (2020, 12, 31, 2020, 53),
+            (2021, 1, 3, 2020, 53),
+            (2024, 12, 30, 2025, 1),
+            (2026, 6, 15, 2026, 25),
+        ] {
+            let date = Date::try_from_values(year, month, day)?;
+            assert_eq!((week_year, week), date.iso_week(), "{date}");
+            let (y, w, d) = date.week_date();
+            assert_eq!(Ok(date), Date::from_week_date(y, w, d).map_err(|_| ()));
+        }
+
+        // week dates round trip, and weeks only change on Mondays
+        let mut date = Date::try_from_values(1999, 12, 1)?;
+        let mut previous = date.iso_week();
+        for _ in 0..3000 {
+            date = date.add_days(1);
+            let week = date.iso_week();
+            if date.day_of_week() == DayOfWeek::Monday {
+                assert_ne!(previous, week, "{date}");
+            } else {
+                assert_eq!(previous, week, "{date}");
+            }
+            previous = week;
+            let (y, w, d) = date.week_date();
+            assert_eq!(Ok(date), Date::from_week_date(y, w, d).map_err(|_| ()));
+        }
+        Ok(())
+    }
 }
@@ -150,7 +150,7 @@ pub type RataDieDate = JulianDayNumber<RataDieEpoch>;
 pub const RATA_DIE_JD_OFFSET: f64 = 1721424.5_f64;
 
 /// The offset from the [`JULIAN_EPOCH`] for the [`UnixTimestamp`]
-pub const UNIX_TS_JD_OFFSET: f64 = 2240587.5_f64;
+pub const UNIX_TS_JD_OFFSET: f64 = 2440587.5_f64;
 
 ///
 /// The Prime Date is the fixed number of days since 01-JAN-1900.
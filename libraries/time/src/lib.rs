@@ -10,8 +10,11 @@
 //!     `UnixTimestamp`, `GPSTimestamp`, etc.
 //!  * [`gregorian`] - Contains `Date` and `Month`, that describe a gregorian calendar date.
 //!  * [`julian`] - Contains `JulianDate` and it's associated epochs.
+//!  * [`stopwatch`] - Contains `Stopwatch`, a monotonic timer returning `Duration`s (`std` only).
 //!  * [`crate::format`] - Contains `Format` and `FormatParser` to tranlate dates to and from strings.
 //!    * [`crate::format::iso8601`] - ISO8601 Implementations of `DateFormat` and `DateFormatParser`
+//!    * [`crate::format::rfc3339`] - RFC3339 Implementations of `DateFormatParser`
+//!    * [`crate::format::rfc2822`] - RFC2822 (Email & HTTP) Implementations of `DateFormatParser`
 //!
 //! The top level module Contains the various representations of [`Time`]
 //!
@@ -31,12 +34,13 @@
 extern crate alloc;
 use alloc::string::String;
 use core::fmt::{Display, Formatter};
+use core::ops::Sub;
 
 pub use irox_units::bounds::{GreaterThanEqualToValueError, LessThanValue, Range};
 pub use irox_units::units::duration::{Duration, DurationUnit};
 use irox_units::units::duration::{NANOS_TO_SEC, SEC_TO_NANOS};
 
-use crate::epoch::Epoch;
+use crate::epoch::{Epoch, UnixTimestamp, NTP_EPOCH, UNIX_EPOCH};
 use crate::format::{Format, FormatError, FormatParser};
 
 pub mod datetime;
@@ -44,12 +48,14 @@ pub mod epoch;
 pub mod format;
 pub mod gregorian;
 pub mod julian;
+#[cfg(feature = "std")]
+pub mod stopwatch;
 
 ///
 /// Represents a time of the day, an offset into the day from midnight.
 ///
 /// Corresponds to a `UTC of day` in section 5.3.3 of ISO8601
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Time {
     second_of_day: u32,
     nanoseconds: u32,
@@ -59,13 +65,14 @@ impl Time {
     ///
     /// Creates a Time from the specified seconds and nanoseconds,
     ///
-    /// The valid range of 'second_of_day' is `0..86400`,
+    /// The valid range of 'second_of_day' is `0..=86400`, where `86400` is a leap second,
+    /// `23:59:60`.
     /// The valid range of 'nanoseconds' is `0..1_000_000_000`
     pub fn new(
         second_of_day: u32,
         nanoseconds: u32,
     ) -> Result<Time, GreaterThanEqualToValueError<u32>> {
-        LessThanValue::new(86400).check_value_is_valid(&second_of_day)?;
+        LessThanValue::new(SECONDS_IN_LEAP_DAY).check_value_is_valid(&second_of_day)?;
         LessThanValue::new(1_000_000_000).check_value_is_valid(&nanoseconds)?;
         Ok(Time {
             second_of_day,
@@ -74,9 +81,10 @@ impl Time {
     }
 
     ///
-    /// Creates a Time from the specified fractional seconds, valid range `0..86400`
+    /// Creates a Time from the specified fractional seconds, valid range `0..86401`, where
+    /// `86400..86401` is a leap second.
     pub fn from_seconds_f64(seconds: f64) -> Result<Time, GreaterThanEqualToValueError<f64>> {
-        LessThanValue::new(86400_f64).check_value_is_valid(&seconds)?;
+        LessThanValue::new(SECONDS_IN_LEAP_DAY as f64).check_value_is_valid(&seconds)?;
 
         let second_of_day = seconds as u32;
         let frac_nanos = seconds - second_of_day as f64;
@@ -87,6 +95,9 @@ impl Time {
         })
     }
 
+    ///
+    /// Creates a Time from the specified hours, minutes, and seconds.  Seconds may only be `60`
+    /// for a leap second, `23:59:60`.
     pub fn from_hms(
         hours: u8,
         minutes: u8,
@@ -94,7 +105,12 @@ impl Time {
     ) -> Result<Time, GreaterThanEqualToValueError<u8>> {
         LessThanValue::new(24u8).check_value_is_valid(&hours)?;
         LessThanValue::new(60u8).check_value_is_valid(&minutes)?;
-        LessThanValue::new(60u8).check_value_is_valid(&seconds)?;
+        let max_seconds = if hours == 23 && minutes == 59 {
+            61u8
+        } else {
+            60u8
+        };
+        LessThanValue::new(max_seconds).check_value_is_valid(&seconds)?;
 
         let second_of_day = hours as u32 * 3600 + minutes as u32 * 60 + seconds as u32;
         Ok(Time {
@@ -111,7 +127,8 @@ impl Time {
         LessThanValue::new(24u8).check_value_is_valid(&hours)?;
         LessThanValue::new(60u8).check_value_is_valid(&minutes)?;
         LessThanValue::new(60f64).check_value_is_valid(&seconds)?;
-        let nanoseconds = (seconds.fract() * SEC_TO_NANOS) as u32;
+        // rounded, as decimal fractions like .61 aren't exact in binary.
+        let nanoseconds = ((seconds.fract() * SEC_TO_NANOS).round() as u32).min(999_999_999);
         let second_of_day = hours as u32 * 3600 + minutes as u32 * 60 + seconds as u32;
         Ok(Time {
             second_of_day,
@@ -155,18 +172,26 @@ impl Time {
         Duration::new(time, DurationUnit::Second)
     }
 
+    ///
+    /// Returns true if this time is within the leap second, `23:59:60`
+    #[must_use]
+    pub fn is_leap_second(&self) -> bool {
+        self.second_of_day >= SECONDS_IN_DAY
+    }
+
     ///
     /// Returns the number of hours represented by this time.
     #[must_use]
     pub fn as_hours(&self) -> u32 {
-        (self.second_of_day as f64 / SECONDS_IN_HOUR as f64) as u32
+        // a leap second is still part of the last hour of the day.
+        self.second_of_day.min(SECONDS_IN_DAY - 1) / SECONDS_IN_HOUR
     }
 
     ///
     /// Returns the minute offset into the day represented by this time.
     #[must_use]
     pub fn as_minutes(&self) -> u32 {
-        (self.second_of_day as f64 / SECONDS_IN_MINUTE as f64) as u32
+        self.second_of_day.min(SECONDS_IN_DAY - 1) / SECONDS_IN_MINUTE
     }
 
     ///
@@ -216,7 +241,8 @@ impl Time {
     ///
     /// Adds the duration to this time, returning a new value of 'time'.  If the duration is longer
     /// than a single day, returns the number of days that got consumed in the second 'duration'
-    /// parameter
+    /// parameter.  If this time is a leap second, the current day is 86401 seconds long, and all
+    /// following days are 86400 seconds long.
     /// # Example:
     /// ```
     /// # use std::error::Error;
@@ -244,9 +270,16 @@ impl Time {
             new_seconds += 1;
         }
         let mut rollover = Duration::default();
-        if new_seconds >= SECONDS_IN_DAY as u64 {
-            let days = new_seconds / SECONDS_IN_DAY as u64;
-            new_seconds -= days * SECONDS_IN_DAY as u64;
+        // if this is a leap second, the current day has an extra second.
+        let day_length = if self.is_leap_second() {
+            SECONDS_IN_LEAP_DAY
+        } else {
+            SECONDS_IN_DAY
+        } as u64;
+        if new_seconds >= day_length {
+            new_seconds -= day_length;
+            let days = 1 + new_seconds / SECONDS_IN_DAY as u64;
+            new_seconds %= SECONDS_IN_DAY as u64;
             rollover += Duration::from_days(days);
         }
         (
@@ -257,6 +290,50 @@ impl Time {
             rollover,
         )
     }
+
+    ///
+    /// Subtracts the duration from this time, returning a new value of 'time'.  If the duration
+    /// reaches back past midnight, returns the number of days that were borrowed in the second
+    /// 'duration' parameter
+    /// # Example:
+    /// ```
+    /// # use irox_time::Time;
+    /// # use irox_units::bounds::GreaterThanEqualToValueError;
+    /// # use irox_units::units::duration::Duration;
+    /// # pub fn test() -> Result<(), GreaterThanEqualToValueError<u32>> {
+    ///     let time = Time::new(500, 0)?;
+    ///     let duration_to_sub = Duration::from_seconds(129600); // 1.5 days
+    ///     let (time, borrowed) = time.wrapping_sub(duration_to_sub);
+    ///
+    ///     assert_eq!(time, Time::new(43700, 0)?);
+    ///     assert_eq!(borrowed, Duration::from_days(2));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn wrapping_sub(&self, duration: Duration) -> (Time, Duration) {
+        let sub_seconds = duration.as_seconds();
+        let sub_nanos = (duration - Duration::from_seconds(sub_seconds)).as_nanos();
+        let mut days = sub_seconds / SECONDS_IN_DAY as u64;
+        let sub_seconds = sub_seconds - days * SECONDS_IN_DAY as u64;
+        let mut new_seconds = self.second_of_day as i64 - sub_seconds as i64;
+        let mut new_nanos = self.nanoseconds as i64 - sub_nanos as i64;
+        if new_nanos < 0 {
+            new_nanos += NANOS_IN_SECOND as i64;
+            new_seconds -= 1;
+        }
+        if new_seconds < 0 {
+            new_seconds += SECONDS_IN_DAY as i64;
+            days += 1;
+        }
+        (
+            Time {
+                second_of_day: new_seconds as u32,
+                nanoseconds: new_nanos as u32,
+            },
+            Duration::from_days(days),
+        )
+    }
 }
 
 impl Display for Time {
@@ -277,6 +354,31 @@ impl From<Time> for Duration {
     }
 }
 
+impl Sub<Time> for Time {
+    type Output = Duration;
+
+    ///
+    /// Returns the elapsed time from `rhs` to `self`, both within the same day - negative if
+    /// `rhs` is later.  For the elapsed time across midnight, use [`Time::wrapping_sub`] or add a
+    /// day to a negative result.
+    fn sub(self, rhs: Time) -> Self::Output {
+        let seconds = self.second_of_day as i64 - rhs.second_of_day as i64;
+        let nanos = self.nanoseconds as i64 - rhs.nanoseconds as i64;
+        Duration::new(
+            (seconds * NANOS_IN_SECOND as i64 + nanos) as f64,
+            DurationUnit::Nanosecond,
+        )
+    }
+}
+
+impl Sub<&Time> for Time {
+    type Output = Duration;
+
+    fn sub(self, rhs: &Time) -> Self::Output {
+        self - *rhs
+    }
+}
+
 /// 24 Hours in a Day
 pub const HOURS_IN_DAY: u32 = 24;
 
@@ -296,6 +398,10 @@ pub const SECONDS_IN_HOUR: u32 = 3600;
 /// Generally 86400, but occasionally 86401 for leap seconds.
 pub const SECONDS_IN_DAY: u32 = 86400;
 
+///
+/// Seconds in a day with a positive leap second, 86401.
+pub const SECONDS_IN_LEAP_DAY: u32 = SECONDS_IN_DAY + 1;
+
 ///
 /// Nanoseconds in a Microsecond
 pub const NANOS_IN_MICRO: u32 = 1000;
@@ -345,6 +451,18 @@ impl Time32 {
     pub fn as_u32(&self) -> u32 {
         ((self.seconds as u32) << 16) | (self.fractional_seconds as u32)
     }
+
+    ///
+    /// Creates a Time32 from a raw Q16.16 value, the upper 16 bits are the seconds and
+    /// the lower 16 bits are the fractional seconds.  The inverse of [`Time32::as_u32`].
+    #[must_use]
+    pub fn from_u32(epoch: Epoch, value: u32) -> Self {
+        Self {
+            epoch,
+            seconds: (value >> 16) as u16,
+            fractional_seconds: value as u16,
+        }
+    }
 }
 
 ///
@@ -388,12 +506,80 @@ impl Time64 {
         ((self.seconds as u64) << 32) | (self.fractional_seconds as u64)
     }
 
+    ///
+    /// Creates a Time64 from a raw Q32.32 value, the upper 32 bits are the seconds and
+    /// the lower 32 bits are the fractional seconds.  The inverse of [`Time64::as_u64`].
+    #[must_use]
+    pub fn from_u64(epoch: Epoch, value: u64) -> Self {
+        Self {
+            epoch,
+            seconds: (value >> 32) as u32,
+            fractional_seconds: value as u32,
+        }
+    }
+
     ///
     /// Returns the reference epoch of this Time64
     #[must_use]
     pub fn get_epoch(&self) -> Epoch {
         self.epoch
     }
+
+    ///
+    /// Returns the offset from the epoch, `seconds + fractional_seconds / 2^32`.  The duration
+    /// is stored as a [`f64`], so the fractional part keeps about 21 bits of precision (~0.5us).
+    #[must_use]
+    pub fn as_duration(&self) -> Duration {
+        Duration::new_seconds(
+            self.seconds as f64 + self.fractional_seconds as f64 / TIME64_FRACTIONS_PER_SECOND,
+        )
+    }
+
+    ///
+    /// Creates a Time64 from a (possibly negative) number of seconds since the epoch.  The
+    /// fractional seconds are rounded to the nearest 2^-32 second, carrying into the seconds if
+    /// they round up to a whole second.  Like NTP, the seconds wrap every 2^32 seconds (~136
+    /// years) into the next (or previous) era.
+    #[must_use]
+    #[allow(unused_imports)]
+    fn from_seconds_f64(epoch: Epoch, seconds: f64) -> Self {
+        use irox_tools::f64::FloatExt;
+        let mut whole = seconds.floor();
+        let mut fractional = ((seconds - whole) * TIME64_FRACTIONS_PER_SECOND).round();
+        if fractional >= TIME64_FRACTIONS_PER_SECOND {
+            whole += 1.;
+            fractional = 0.;
+        }
+        Self {
+            epoch,
+            seconds: (whole as i64).rem_euclid(1 << 32) as u32,
+            fractional_seconds: fractional as u32,
+        }
+    }
+}
+
+/// Number of [`Time64`] fractional seconds in a second, 2^32
+const TIME64_FRACTIONS_PER_SECOND: f64 = 4_294_967_296.;
+
+impl From<Time64> for UnixTimestamp {
+    ///
+    /// Converts the Time64 (in any epoch) into a unix timestamp.  An NTP time is assumed to be in
+    /// the first NTP era (1900-2036).
+    fn from(value: Time64) -> Self {
+        UnixTimestamp::from_offset(value.as_duration() + value.epoch.duration_since(&UNIX_EPOCH))
+    }
+}
+
+impl From<UnixTimestamp> for Time64 {
+    ///
+    /// Converts the unix timestamp into an NTP Time64, relative to the [`NTP_EPOCH`].  Times
+    /// after the 2036 rollover (`2036-02-07T06:28:16Z`) wrap around to the start of the next
+    /// NTP era.
+    fn from(value: UnixTimestamp) -> Self {
+        let seconds = value.get_offset().as_seconds_f64()
+            + UNIX_EPOCH.duration_since(&NTP_EPOCH).as_seconds_f64();
+        Time64::from_seconds_f64(NTP_EPOCH, seconds)
+    }
 }
 
 ///
@@ -441,6 +627,18 @@ impl Time128 {
         ((self.seconds as u128) << 64) | (self.fractional_seconds as u128)
     }
 
+    ///
+    /// Creates a Time128 from a raw Q64.64 value, the upper 64 bits are the seconds and
+    /// the lower 64 bits are the fractional seconds.  The inverse of [`Time128::as_u128`].
+    #[must_use]
+    pub fn from_u128(epoch: Epoch, value: u128) -> Self {
+        Self {
+            epoch,
+            seconds: (value >> 64) as u64,
+            fractional_seconds: value as u64,
+        }
+    }
+
     ///
     /// Returns the reference epoch of this Time128
     #[must_use]
@@ -448,3 +646,152 @@ impl Time128 {
         self.epoch
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::epoch::{UnixTimestamp, NTP_EPOCH, UNIX_EPOCH};
+    use crate::{Duration, GreaterThanEqualToValueError, Time, Time128, Time32, Time64};
+
+    #[test]
+    pub fn raw_round_trip() {
+        for value in [0, 1, 0x8000_0001, 0x1234_5678, u32::MAX] {
+            let time = Time32::from_u32(NTP_EPOCH, value);
+            assert_eq!(value, time.as_u32());
+        }
+        assert_eq!(
+            Time32::new(NTP_EPOCH, 0x1234, 0x5678),
+            Time32::from_u32(NTP_EPOCH, 0x1234_5678)
+        );
+
+        for value in [0, 1, 0xE7E0_6F1A_8000_0000, 0x0123_4567_89AB_CDEF, u64::MAX] {
+            let time = Time64::from_u64(NTP_EPOCH, value);
+            assert_eq!(value, time.as_u64());
+            assert_eq!(NTP_EPOCH, time.get_epoch());
+        }
+        assert_eq!(
+            Time64::new(UNIX_EPOCH, 0x0123_4567, 0x89AB_CDEF),
+            Time64::from_u64(UNIX_EPOCH, 0x0123_4567_89AB_CDEF)
+        );
+
+        for value in [0, 1, 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210, u128::MAX] {
+            let time = Time128::from_u128(NTP_EPOCH, value);
+            assert_eq!(value, time.as_u128());
+        }
+        assert_eq!(
+            Time128::new(NTP_EPOCH, 0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210),
+            Time128::from_u128(NTP_EPOCH, 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210)
+        );
+    }
+
+    #[test]
+    pub fn time64_unix_conversions() {
+        // the unix epoch is 2,208,988,800 seconds into the NTP epoch.
+        let ntp = Time64::from(UnixTimestamp::from_seconds(0));
+        assert_eq!(Time64::new(NTP_EPOCH, 2_208_988_800, 0), ntp);
+        assert_eq!(0, UnixTimestamp::from(ntp).as_nanos());
+
+        // 2023-11-14T22:13:20.5Z
+        let unix = UnixTimestamp::from_millis(1_700_000_000_500);
+        let ntp = Time64::from(unix);
+        assert_eq!(Time64::new(NTP_EPOCH, 3_908_988_800, 0x8000_0000), ntp);
+        assert_eq!(
+            3_908_988_800.5_f64.to_bits(),
+            ntp.as_duration().as_seconds_f64().to_bits()
+        );
+        assert_eq!(1_700_000_000_500, UnixTimestamp::from(ntp).as_millis());
+
+        // the last representable NTP time of era 0, and the rollover into era 1
+        let last = Time64::new(NTP_EPOCH, u32::MAX, u32::MAX);
+        let unix = UnixTimestamp::from(last);
+        assert_eq!(2_085_978_496_000, unix.as_millis());
+        let rollover = UnixTimestamp::from_seconds(2_085_978_496);
+        assert_eq!(Time64::new(NTP_EPOCH, 0, 0), Time64::from(rollover));
+        assert_eq!(
+            Time64::new(NTP_EPOCH, u32::MAX, 0),
+            Time64::from(UnixTimestamp::from_seconds(2_085_978_495))
+        );
+
+        // fractions round to the nearest 2^-32, and carry into the seconds.
+        assert_eq!(
+            Time64::new(UNIX_EPOCH, 1, 0),
+            Time64::from_seconds_f64(UNIX_EPOCH, 1.0 - 1e-11)
+        );
+        assert_eq!(
+            Time64::new(UNIX_EPOCH, 0, u32::MAX),
+            Time64::from_seconds_f64(UNIX_EPOCH, 1.0 - 2e-10)
+        );
+        assert_eq!(
+            Time64::new(UNIX_EPOCH, u32::MAX, 0x8000_0000),
+            Time64::from_seconds_f64(UNIX_EPOCH, -0.5)
+        );
+
+        // other epochs are converted through their offset from the unix epoch.
+        let unix = UnixTimestamp::from(Time64::new(UNIX_EPOCH, 1_700_000_000, 0x4000_0000));
+        assert_eq!(1_700_000_000_250, unix.as_millis());
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn time_sub() -> Result<(), GreaterThanEqualToValueError<u32>> {
+        let a = Time::new(3600, 250_000_000)?;
+        let b = Time::new(60, 750_000_000)?;
+        assert_eq!(3_539_500_000_000, (a - b).as_nanos());
+        assert_eq!((-3539.5f64).to_bits(), (b - a).as_seconds_f64().to_bits());
+        assert_eq!(0, (a - a).as_nanos());
+
+        // elapsed across midnight, 23:00 to 01:00
+        let start = Time::new(82800, 0)?;
+        let end = Time::new(3600, 0)?;
+        let elapsed = end - start + Duration::from_days(1);
+        assert_eq!(7200, elapsed.as_seconds());
+        let (time, borrowed) = end.wrapping_sub(elapsed);
+        assert_eq!(start, time);
+        assert_eq!(Duration::from_days(1), borrowed);
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn leap_second() -> Result<(), GreaterThanEqualToValueError<u32>> {
+        let leap = Time::new(86400, 500_000_000)?;
+        assert!(leap.is_leap_second());
+        assert!(Time::new(86401, 0).is_err());
+        assert!(Time::from_seconds_f64(86400.5).is_ok());
+        assert!(Time::from_seconds_f64(86401.).is_err());
+        assert_eq!((23, 59, 60), leap.as_hms());
+        assert_eq!(Time::new(86400, 0).ok(), Time::from_hms(23, 59, 60).ok());
+        assert!(Time::from_hms(23, 58, 60).is_err());
+        assert!(Time::from_hms(23, 59, 61).is_err());
+
+        // the leap second is part of the day
+        let midnight = Time::new(0, 0)?;
+        assert_eq!(
+            86400.5f64.to_bits(),
+            (leap - midnight).as_seconds_f64().to_bits()
+        );
+        let last = Time::new(86399, 0)?;
+        assert_eq!(1.5f64.to_bits(), (leap - last).as_seconds_f64().to_bits());
+
+        // the day of the leap second is one second longer.
+        let (time, excess) = leap.wrapping_add(Duration::from_millis(500));
+        assert_eq!(midnight, time);
+        assert_eq!(Duration::from_days(1), excess);
+        let (time, excess) = leap.wrapping_add(Duration::from_seconds(86400));
+        assert_eq!(Time::new(86399, 500_000_000)?, time);
+        assert_eq!(Duration::from_days(1), excess);
+        let (time, excess) = leap.wrapping_add(Duration::from_millis(200));
+        assert_eq!(Time::new(86400, 700_000_000)?, time);
+        assert_eq!(Duration::default(), excess);
+        let (time, excess) = last.wrapping_add(Duration::from_seconds(1));
+        assert_eq!(midnight, time);
+        assert_eq!(Duration::from_days(1), excess);
+
+        let (time, borrowed) = leap.wrapping_sub(Duration::from_seconds(86401));
+        assert_eq!(Time::new(86399, 500_000_000)?, time);
+        assert_eq!(Duration::from_days(1), borrowed);
+        let (time, borrowed) = leap.wrapping_sub(Duration::from_seconds(1));
+        assert_eq!(Time::new(86399, 500_000_000)?, time);
+        assert_eq!(Duration::default(), borrowed);
+        Ok(())
+    }
+}
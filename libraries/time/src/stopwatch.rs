@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Contains [`Stopwatch`], a monotonic timer for measuring elapsed time in terms of [`Duration`]
+//!
+
+use alloc::vec::Vec;
+use std::time::Instant;
+
+use irox_units::units::duration::Duration;
+
+///
+/// Measures elapsed time with the monotonic system clock ([`Instant`]), returning the crate's
+/// [`Duration`].  Laps split the elapsed time into consecutive intervals.
+///
+/// # Example
+/// ```
+/// use irox_time::stopwatch::Stopwatch;
+///
+/// let mut stopwatch = Stopwatch::start();
+/// // ... first step
+/// let first = stopwatch.lap();
+/// // ... second step
+/// let second = stopwatch.lap();
+///
+/// assert_eq!(&[first, second], stopwatch.laps());
+/// assert!(stopwatch.elapsed() >= first + second);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stopwatch {
+    started: Instant,
+    last_lap: Instant,
+    laps: Vec<Duration>,
+}
+
+impl Stopwatch {
+    ///
+    /// Creates a new stopwatch, running from now.
+    #[must_use]
+    pub fn start() -> Stopwatch {
+        let now = Instant::now();
+        Stopwatch {
+            started: now,
+            last_lap: now,
+            laps: Vec::new(),
+        }
+    }
+
+    ///
+    /// Returns the time elapsed since the stopwatch was started or last reset.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed().into()
+    }
+
+    ///
+    /// Records and returns the time elapsed since the previous lap, or since the stopwatch was
+    /// started if this is the first lap.
+    pub fn lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let lap: Duration = now.duration_since(self.last_lap).into();
+        self.last_lap = now;
+        self.laps.push(lap);
+        lap
+    }
+
+    ///
+    /// Returns all the recorded laps, in the order they were recorded.
+    #[must_use]
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+
+    ///
+    /// Restarts the stopwatch from now, discarding the recorded laps.
+    pub fn reset(&mut self) {
+        *self = Stopwatch::start();
+    }
+}
+
+impl Default for Stopwatch {
+    fn default() -> Self {
+        Stopwatch::start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stopwatch::Stopwatch;
+    use irox_units::units::duration::Duration;
+
+    #[test]
+    pub fn laps() {
+        let mut stopwatch = Stopwatch::start();
+        std::thread::sleep(core::time::Duration::from_millis(20));
+        let first = stopwatch.lap();
+        std::thread::sleep(core::time::Duration::from_millis(10));
+        let second = stopwatch.lap();
+        let elapsed = stopwatch.elapsed();
+
+        assert!(first >= Duration::from_millis(20), "{first}");
+        assert!(second >= Duration::from_millis(10), "{second}");
+        assert_eq!(&[first, second], stopwatch.laps());
+        assert!(elapsed >= first + second, "{elapsed}");
+
+        stopwatch.reset();
+        assert!(stopwatch.laps().is_empty());
+        assert!(stopwatch.elapsed() < elapsed);
+    }
+}
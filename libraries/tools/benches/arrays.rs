@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+use irox_tools::arrays::{minmax, sum};
+use irox_tools::random::{Pcg32, PRNG};
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rand = Pcg32::new(0xBEEF);
+    let arr: Vec<f64> = (0..1_000_003).map(|_| rand.range_f64(-1e3, 1e3)).collect();
+
+    let mut grp = c.benchmark_group("reductions");
+    grp.throughput(Throughput::Elements(arr.len() as u64));
+    grp.bench_function("minmax", |b| b.iter(|| minmax(&arr)));
+    grp.bench_function("scalar-minmax", |b| {
+        b.iter(|| {
+            let lo = arr.iter().copied().fold(f64::INFINITY, f64::min);
+            let hi = arr.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            (lo, hi)
+        })
+    });
+    grp.bench_function("sum", |b| b.iter(|| sum(&arr)));
+    grp.bench_function("scalar-sum", |b| b.iter(|| arr.iter().sum::<f64>()));
+    grp.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
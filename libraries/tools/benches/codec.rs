@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+use irox_tools::codec::{decode_signed_deltas, encode_signed_deltas};
+use irox_tools::random::{Random, PRNG};
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    // one hour of ~10Hz millisecond timestamps with some jitter
+    let mut rnd = Random::new_seed(0);
+    let mut last = 1_700_000_000_000i64;
+    let series: Vec<i64> = (0..36_000)
+        .map(|_| {
+            last += 95 + (rnd.next_u32() % 10) as i64;
+            last
+        })
+        .collect();
+    let encoded = encode_signed_deltas(&series);
+
+    let mut grp = c.benchmark_group("signed-deltas");
+    grp.throughput(Throughput::Elements(series.len() as u64));
+    grp.bench_function("encode", |b| b.iter(|| encode_signed_deltas(&series)));
+    grp.bench_function("decode", |b| b.iter(|| decode_signed_deltas(&encoded)));
+    grp.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
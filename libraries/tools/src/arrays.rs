@@ -6,6 +6,11 @@
 //! Functions and tools for manipulating arrays of items.
 //!
 
+#[allow(unused_imports)]
+use crate::f64::FloatExt;
+use core::ops::{Add, Div};
+use irox_bits::{Error, ErrorKind};
+
 ///
 /// Scans through the array, looking for the maximum value contained within it, using the
 /// [`PartialOrd`] operation.  Returns the first index of the maximum detected value.
@@ -75,9 +80,537 @@ pub fn longest_consecutive_values<T: PartialOrd>(arr: &[T], val: &T) -> Option<(
     None
 }
 
+/// Returns `Ok(val)`, or an error if the value is NaN.
+fn not_nan(val: f64) -> Result<f64, Error> {
+    if val.is_nan() {
+        return ErrorKind::InvalidData.err("NaN encountered in search");
+    }
+    Ok(val)
+}
+
+/// Returns an error if any value in the array is NaN.
+fn no_nans(arr: &[f64]) -> Result<(), Error> {
+    if arr.iter().any(|v| v.is_nan()) {
+        return ErrorKind::InvalidData.err("NaN in searched array");
+    }
+    Ok(())
+}
+
+///
+/// Returns the first index in the sorted array for which the predicate is false.  Errors if a NaN
+/// is visited by the search, NaNs elsewhere in the array aren't detected.
+fn partition_point_f64<F: Fn(f64) -> bool>(arr: &[f64], pred: F) -> Result<usize, Error> {
+    let mut lo = 0;
+    let mut hi = arr.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let Some(val) = arr.get(mid) else {
+            break;
+        };
+        if pred(not_nan(*val)?) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(lo)
+}
+
+///
+/// Binary searches the ascending sorted array for the first index whose value is not less than
+/// `target`, less the tolerance.  This is the index at which `target` would be inserted to keep
+/// the array sorted, before any existing equal values.  Returns the index, and whether the value
+/// at that index is within the tolerance of `target`.
+///
+/// A tolerance of [`None`] requires exact equality.  Returns an [`ErrorKind::InvalidData`] error
+/// if `target` or any value in the array is NaN - checking the array is `O(n)`, the search itself
+/// is `O(log n)`.
+///
+/// # Example
+/// ```
+/// use irox_tools::arrays::lower_bound;
+/// let arr = &[1.0, 2.0, 2.0, 3.0];
+/// assert_eq!((1, true), lower_bound(arr, 2.0, None).unwrap());
+/// assert_eq!((3, false), lower_bound(arr, 2.5, None).unwrap());
+/// assert_eq!((1, true), lower_bound(arr, 2.05, Some(0.1)).unwrap());
+/// ```
+pub fn lower_bound(
+    arr: &[f64],
+    target: f64,
+    tolerance: Option<f64>,
+) -> Result<(usize, bool), Error> {
+    let target = not_nan(target)?;
+    no_nans(arr)?;
+    let tol = tolerance.unwrap_or(0.0).abs();
+    let idx = partition_point_f64(arr, |v| v < target - tol)?;
+    let exact = arr.get(idx).is_some_and(|v| (v - target).abs() <= tol);
+    Ok((idx, exact))
+}
+
+///
+/// Binary searches the ascending sorted array for the first index whose value is greater than
+/// `target`, plus the tolerance.  This is the index at which `target` would be inserted to keep
+/// the array sorted, after any existing equal values.  Returns the index, and whether the value
+/// just before that index is within the tolerance of `target`.
+///
+/// A tolerance of [`None`] requires exact equality.  Returns an [`ErrorKind::InvalidData`] error
+/// if `target` or any value in the array is NaN - checking the array is `O(n)`, the search itself
+/// is `O(log n)`.
+///
+/// # Example
+/// ```
+/// use irox_tools::arrays::upper_bound;
+/// let arr = &[1.0, 2.0, 2.0, 3.0];
+/// assert_eq!((3, true), upper_bound(arr, 2.0, None).unwrap());
+/// assert_eq!((3, false), upper_bound(arr, 2.5, None).unwrap());
+/// assert_eq!((3, true), upper_bound(arr, 1.95, Some(0.1)).unwrap());
+/// ```
+pub fn upper_bound(
+    arr: &[f64],
+    target: f64,
+    tolerance: Option<f64>,
+) -> Result<(usize, bool), Error> {
+    let target = not_nan(target)?;
+    no_nans(arr)?;
+    let tol = tolerance.unwrap_or(0.0).abs();
+    let idx = partition_point_f64(arr, |v| v <= target + tol)?;
+    let exact = idx
+        .checked_sub(1)
+        .and_then(|i| arr.get(i))
+        .is_some_and(|v| (v - target).abs() <= tol);
+    Ok((idx, exact))
+}
+
+///
+/// Binary searches the ascending sorted array for the index of the value closest to `target`,
+/// preferring the lower index on a tie.  Returns the index and whether that value is within the
+/// tolerance of `target`, or [`None`] if the array is empty.
+///
+/// A tolerance of [`None`] requires exact equality.  Returns an [`ErrorKind::InvalidData`] error
+/// if `target` or any value in the array is NaN - checking the array is `O(n)`, the search itself
+/// is `O(log n)`.
+///
+/// # Example
+/// ```
+/// use irox_tools::arrays::nearest_index;
+/// let arr = &[1.0, 2.0, 4.0];
+/// assert_eq!(Some((1, false)), nearest_index(arr, 2.9, None).unwrap());
+/// assert_eq!(Some((2, true)), nearest_index(arr, 3.95, Some(0.1)).unwrap());
+/// assert_eq!(None, nearest_index(&[], 3.0, None).unwrap());
+/// ```
+pub fn nearest_index(
+    arr: &[f64],
+    target: f64,
+    tolerance: Option<f64>,
+) -> Result<Option<(usize, bool)>, Error> {
+    let target = not_nan(target)?;
+    no_nans(arr)?;
+    let tol = tolerance.unwrap_or(0.0).abs();
+    let idx = partition_point_f64(arr, |v| v < target)?;
+    let mut nearest: Option<(usize, f64)> = None;
+    for i in [idx.checked_sub(1), Some(idx)].into_iter().flatten() {
+        let Some(val) = arr.get(i) else {
+            continue;
+        };
+        let dist = (not_nan(*val)? - target).abs();
+        if nearest.map_or(true, |(_, best)| dist < best) {
+            nearest = Some((i, dist));
+        }
+    }
+    Ok(nearest.map(|(i, dist)| (i, dist <= tol)))
+}
+
+///
+/// Returns the index of the upper end of the interval of the ascending sorted `xs` containing
+/// `x`, or [`None`] if `x` is outside of `xs`, or is NaN.  An `x` equal to the last value returns
+/// `Some(len)`.  Only the values visited by the search are checked for NaN, so this stays
+/// `O(log n)` for repeated lookups.
+fn interval(xs: &[f64], x: f64) -> Option<usize> {
+    if x.is_nan() {
+        return None;
+    }
+    let idx = partition_point_f64(xs, |v| v <= x).ok()?;
+    let exact = idx
+        .checked_sub(1)
+        .and_then(|i| xs.get(i))
+        .is_some_and(|v| (v - x).abs() <= 0.0);
+    match idx {
+        0 => None,
+        idx if idx == xs.len() && !exact => None,
+        idx => Some(idx),
+    }
+}
+
+///
+/// Linearly interpolates the sampled function `ys = f(xs)` at `x`.  The `xs` must be sorted in
+/// ascending order, and be the same length as `ys`.  Where an `x` value is duplicated, `x`
+/// exactly on it returns the `y` of the last duplicate.
+///
+/// If `x` is outside of the range of `xs`, returns [`None`] unless `clamp` is true, in which case
+/// the `y` value at the nearest end is returned.  Also returns [`None`] if the arrays are empty or
+/// different lengths, or if `x` is NaN.
+///
+/// # Example
+/// ```
+/// use irox_tools::arrays::interp_linear;
+/// let xs = &[0.0, 1.0, 3.0];
+/// let ys = &[10.0, 20.0, 0.0];
+/// assert_eq!(Some(15.0), interp_linear(xs, ys, 0.5, false));
+/// assert_eq!(Some(10.0), interp_linear(xs, ys, 2.0, false));
+/// assert_eq!(None, interp_linear(xs, ys, 4.0, false));
+/// assert_eq!(Some(0.0), interp_linear(xs, ys, 4.0, true));
+/// ```
+#[must_use]
+pub fn interp_linear(xs: &[f64], ys: &[f64], x: f64, clamp: bool) -> Option<f64> {
+    if xs.len() != ys.len() || xs.is_empty() || x.is_nan() {
+        return None;
+    }
+    let Some(hi) = interval(xs, x) else {
+        if !clamp {
+            return None;
+        }
+        let first = xs.first()?;
+        return if x < *first { ys.first() } else { ys.last() }.copied();
+    };
+    let (Some(x0), Some(y0)) = (xs.get(hi - 1), ys.get(hi - 1)) else {
+        return None;
+    };
+    let (Some(x1), Some(y1)) = (xs.get(hi), ys.get(hi)) else {
+        // x is exactly the last value.
+        return Some(*y0);
+    };
+    Some(y0 + (y1 - y0) * (x - x0) / (x1 - x0))
+}
+
+crate::cfg_feature_alloc! {
+    ///
+    /// A natural cubic spline through a set of sampled points - the curve through every point with
+    /// continuous first and second derivatives, and a second derivative of zero at each end.
+    /// Building the spline takes `O(n)` time, each interpolation takes `O(log n)` time.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CubicSpline {
+        xs: alloc::vec::Vec<f64>,
+        ys: alloc::vec::Vec<f64>,
+        /// Second derivative of the spline at each point
+        y2: alloc::vec::Vec<f64>,
+    }
+}
+
+crate::cfg_feature_alloc! {
+    impl CubicSpline {
+        ///
+        /// Builds the spline through the sampled function `ys = f(xs)`.  The `xs` must be sorted
+        /// in ascending order, and be the same length as `ys`.  Where an `x` value is duplicated,
+        /// only the last of the duplicates is used.  A single point produces a constant, and two
+        /// points a straight line.  Returns [`None`] if the arrays are empty, different lengths,
+        /// not sorted, or contain a non-finite value.
+        pub fn new(xs: &[f64], ys: &[f64]) -> Option<CubicSpline> {
+            if xs.len() != ys.len() || xs.is_empty() {
+                return None;
+            }
+            let mut dx: alloc::vec::Vec<f64> = alloc::vec::Vec::with_capacity(xs.len());
+            let mut dy: alloc::vec::Vec<f64> = alloc::vec::Vec::with_capacity(ys.len());
+            for (x, y) in xs.iter().zip(ys) {
+                if !x.is_finite() || !y.is_finite() {
+                    return None;
+                }
+                match dx.last() {
+                    Some(last) if x < last => return None,
+                    // a duplicate, as smaller values were rejected above.
+                    Some(last) if x <= last => {
+                        if let Some(last) = dy.last_mut() {
+                            *last = *y;
+                        }
+                    }
+                    _ => {
+                        dx.push(*x);
+                        dy.push(*y);
+                    }
+                }
+            }
+            let (xs, ys) = (dx, dy);
+
+            // tridiagonal solve for the second derivatives, with the natural boundary condition of
+            // zero at both ends.
+            let len = xs.len();
+            let mut y2 = alloc::vec![0.0; len];
+            let mut u = alloc::vec![0.0; len];
+            for i in 1..len.saturating_sub(1) {
+                let (Some(xp), Some(x), Some(xn)) = (xs.get(i - 1), xs.get(i), xs.get(i + 1)) else {
+                    return None;
+                };
+                let (Some(yp), Some(y), Some(yn)) = (ys.get(i - 1), ys.get(i), ys.get(i + 1)) else {
+                    return None;
+                };
+                let prev_y2 = y2.get(i - 1).copied().unwrap_or_default();
+                let prev_u = u.get(i - 1).copied().unwrap_or_default();
+                let sig = (x - xp) / (xn - xp);
+                let p = sig * prev_y2 + 2.0;
+                let slope_diff = (yn - y) / (xn - x) - (y - yp) / (x - xp);
+                if let Some(v) = y2.get_mut(i) {
+                    *v = (sig - 1.0) / p;
+                }
+                if let Some(v) = u.get_mut(i) {
+                    *v = (6.0 * slope_diff / (xn - xp) - sig * prev_u) / p;
+                }
+            }
+            for i in (0..len.saturating_sub(1)).rev() {
+                let next = y2.get(i + 1).copied().unwrap_or_default();
+                let ui = u.get(i).copied().unwrap_or_default();
+                if let Some(v) = y2.get_mut(i) {
+                    *v = *v * next + ui;
+                }
+            }
+            Some(CubicSpline { xs, ys, y2 })
+        }
+
+        ///
+        /// Evaluates the spline at `x`.  If `x` is outside of the range of the points, returns
+        /// [`None`] unless `clamp` is true, in which case the `y` value at the nearest end is
+        /// returned.  Also returns [`None`] if `x` is NaN.
+        #[must_use]
+        pub fn interpolate(&self, x: f64, clamp: bool) -> Option<f64> {
+            if x.is_nan() {
+                return None;
+            }
+            let Some(hi) = interval(&self.xs, x) else {
+                if !clamp {
+                    return None;
+                }
+                let first = self.xs.first()?;
+                return if x < *first { self.ys.first() } else { self.ys.last() }.copied();
+            };
+            let lo = hi - 1;
+            let (Some(x0), Some(y0), Some(d0)) = (self.xs.get(lo), self.ys.get(lo), self.y2.get(lo))
+            else {
+                return None;
+            };
+            let (Some(x1), Some(y1), Some(d1)) = (self.xs.get(hi), self.ys.get(hi), self.y2.get(hi))
+            else {
+                // x is exactly the last value.
+                return Some(*y0);
+            };
+            let h = x1 - x0;
+            let a = (x1 - x) / h;
+            let b = (x - x0) / h;
+            Some(a * y0 + b * y1 + ((a * a * a - a) * d0 + (b * b * b - b) * d1) * (h * h) / 6.0)
+        }
+    }
+}
+
+crate::cfg_feature_alloc! {
+    ///
+    /// Interpolates the sampled function `ys = f(xs)` at `x` using a natural cubic spline.  See
+    /// [`CubicSpline`] for the requirements of the inputs, and [`CubicSpline::interpolate`] for
+    /// the handling of `x` and `clamp`.  When interpolating many values, build the
+    /// [`CubicSpline`] once instead.
+    ///
+    /// # Example
+    /// ```
+    /// use irox_tools::arrays::interp_cubic;
+    /// let xs = &[0.0, 1.0, 2.0];
+    /// let ys = &[0.0, 1.0, 0.0];
+    /// assert_eq!(Some(0.6875), interp_cubic(xs, ys, 0.5, false));
+    /// ```
+    #[must_use]
+    pub fn interp_cubic(xs: &[f64], ys: &[f64], x: f64, clamp: bool) -> Option<f64> {
+        CubicSpline::new(xs, ys)?.interpolate(x, clamp)
+    }
+}
+
+///
+/// The floating point types supported by the slice reductions ([`min`], [`max`], [`sum`], etc)
+pub trait SliceFloat: Copy + PartialOrd + Add<Output = Self> + Div<Output = Self> {
+    const ZERO: Self;
+    const NAN: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    fn is_nan(self) -> bool;
+    fn from_len(len: usize) -> Self;
+}
+
+macro_rules! impl_slice_float {
+    ($($ty:ty),+) => {
+        $(
+            impl SliceFloat for $ty {
+                const ZERO: Self = 0.0;
+                const NAN: Self = <$ty>::NAN;
+                const INFINITY: Self = <$ty>::INFINITY;
+                const NEG_INFINITY: Self = <$ty>::NEG_INFINITY;
+
+                fn is_nan(self) -> bool {
+                    <$ty>::is_nan(self)
+                }
+
+                fn from_len(len: usize) -> Self {
+                    len as $ty
+                }
+            }
+        )+
+    };
+}
+impl_slice_float!(f32, f64);
+
+/// Number of independent accumulators, wide enough for the compiler to keep them in vector
+/// registers.
+const LANES: usize = 8;
+
+/// The smallest and largest non-NaN values, and whether there were any NaNs.  If there were no
+/// non-NaN values, the min is `+inf` and the max is `-inf`.
+fn bounds<T: SliceFloat>(arr: &[T]) -> (T, T, bool) {
+    let mut mins = [T::INFINITY; LANES];
+    let mut maxs = [T::NEG_INFINITY; LANES];
+    let mut nans = [false; LANES];
+    let chunks = arr.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for (((min, max), nan), v) in mins
+            .iter_mut()
+            .zip(maxs.iter_mut())
+            .zip(nans.iter_mut())
+            .zip(chunk)
+        {
+            // comparisons with NaN are false, so NaNs never replace the accumulators.
+            *min = if *v < *min { *v } else { *min };
+            *max = if *v > *max { *v } else { *max };
+            *nan |= v.is_nan();
+        }
+    }
+    let mut min = T::INFINITY;
+    let mut max = T::NEG_INFINITY;
+    let mut nan = nans.iter().any(|n| *n);
+    for v in mins.iter().chain(remainder) {
+        if *v < min {
+            min = *v;
+        }
+    }
+    for v in maxs.iter().chain(remainder) {
+        if *v > max {
+            max = *v;
+        }
+    }
+    for v in remainder {
+        nan |= v.is_nan();
+    }
+    (min, max, nan)
+}
+
+///
+/// Returns the smallest value in the slice, or [`None`] if the slice is empty.  If any value is
+/// NaN, returns NaN.  See [`nanmin`] to skip NaNs instead.
+///
+/// # Example
+/// ```
+/// use irox_tools::arrays::min;
+/// assert_eq!(Some(-1.0), min(&[3.0, -1.0, 2.0]));
+/// assert!(min(&[3.0, f64::NAN]).is_some_and(f64::is_nan));
+/// assert_eq!(None, min::<f32>(&[]));
+/// ```
+#[must_use]
+pub fn min<T: SliceFloat>(arr: &[T]) -> Option<T> {
+    minmax(arr).map(|(min, _)| min)
+}
+
+///
+/// Returns the largest value in the slice, or [`None`] if the slice is empty.  If any value is
+/// NaN, returns NaN.  See [`nanmax`] to skip NaNs instead.
+#[must_use]
+pub fn max<T: SliceFloat>(arr: &[T]) -> Option<T> {
+    minmax(arr).map(|(_, max)| max)
+}
+
+///
+/// Returns the smallest and largest values in the slice in a single pass, or [`None`] if the
+/// slice is empty.  If any value is NaN, returns NaN for both.
+#[must_use]
+pub fn minmax<T: SliceFloat>(arr: &[T]) -> Option<(T, T)> {
+    if arr.is_empty() {
+        return None;
+    }
+    match bounds(arr) {
+        (_, _, true) => Some((T::NAN, T::NAN)),
+        (min, max, false) => Some((min, max)),
+    }
+}
+
+///
+/// Returns the smallest non-NaN value in the slice, or [`None`] if the slice is empty or all NaN.
+///
+/// # Example
+/// ```
+/// use irox_tools::arrays::nanmin;
+/// assert_eq!(Some(-1.0), nanmin(&[f64::NAN, 3.0, -1.0]));
+/// assert_eq!(None, nanmin(&[f64::NAN]));
+/// ```
+#[must_use]
+pub fn nanmin<T: SliceFloat>(arr: &[T]) -> Option<T> {
+    let (min, max, _) = bounds(arr);
+    // any non-NaN value leaves min <= max.
+    (min <= max).then_some(min)
+}
+
+///
+/// Returns the largest non-NaN value in the slice, or [`None`] if the slice is empty or all NaN.
+#[must_use]
+pub fn nanmax<T: SliceFloat>(arr: &[T]) -> Option<T> {
+    let (min, max, _) = bounds(arr);
+    (min <= max).then_some(max)
+}
+
+///
+/// Returns the sum of the values in the slice, zero if the slice is empty.  The values are
+/// accumulated in several interleaved partial sums, so the rounding (and result) may differ
+/// slightly from a sequential sum - usually for the better.  Any NaN results in NaN.
+#[must_use]
+pub fn sum<T: SliceFloat>(arr: &[T]) -> T {
+    let mut sums = [T::ZERO; LANES];
+    let chunks = arr.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for (sum, v) in sums.iter_mut().zip(chunk) {
+            *sum = *sum + *v;
+        }
+    }
+    // combine pairwise, then add the remainder.
+    let mut width = LANES;
+    while width > 1 {
+        width /= 2;
+        let (low, high) = sums.split_at_mut(width);
+        for (a, b) in low.iter_mut().zip(high.iter()) {
+            *a = *a + *b;
+        }
+    }
+    let total = sums.first().copied().unwrap_or(T::ZERO);
+    remainder.iter().fold(total, |acc, v| acc + *v)
+}
+
+///
+/// Returns the arithmetic mean of the values in the slice, or [`None`] if the slice is empty.  Any
+/// NaN results in NaN.
+///
+/// # Example
+/// ```
+/// use irox_tools::arrays::mean;
+/// assert_eq!(Some(2.5), mean(&[1.0, 2.0, 3.0, 4.0]));
+/// assert_eq!(None, mean::<f64>(&[]));
+/// ```
+#[must_use]
+pub fn mean<T: SliceFloat>(arr: &[T]) -> Option<T> {
+    if arr.is_empty() {
+        return None;
+    }
+    Some(sum(arr) / T::from_len(arr.len()))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::arrays::longest_consecutive_values;
+    #[cfg(feature = "alloc")]
+    use crate::arrays::{interp_cubic, CubicSpline};
+    use crate::arrays::{
+        interp_linear, longest_consecutive_values, lower_bound, max, mean, min, minmax, nanmax,
+        nanmin, nearest_index, sum, upper_bound,
+    };
+    use crate::random::{Pcg32, Random, PRNG};
 
     #[test]
     pub fn test1() {
@@ -96,4 +629,256 @@ mod tests {
         assert_eq!(position, 6);
         assert_eq!(length, 5);
     }
+
+    /// Sorted values with lots of duplicates, and a set of targets that hit, miss, and fall
+    /// outside of them.
+    fn random_sorted(rand: &mut Random) -> ([f64; 200], [f64; 50]) {
+        let mut arr = [0.0; 200];
+        for v in &mut arr {
+            *v = f64::from(rand.next_u32() % 500) / 10.0;
+        }
+        arr.sort_unstable_by(f64::total_cmp);
+        let mut targets = [0.0; 50];
+        for v in &mut targets {
+            *v = f64::from(rand.next_u32() % 600) / 10.0 - 5.0
+                + f64::from(rand.next_u8() % 3) * 0.01;
+        }
+        (arr, targets)
+    }
+
+    #[test]
+    pub fn bounds_match_linear_scan() {
+        let mut rand = Random::new_seed(0xC0FFEE);
+        for _ in 0..20 {
+            let (arr, targets) = random_sorted(&mut rand);
+            for target in targets {
+                for tol in [None, Some(0.015), Some(0.25)] {
+                    let t = tol.unwrap_or(0.0);
+                    let near = |v: &f64| (v - target).abs() <= t;
+
+                    let lower = arr
+                        .iter()
+                        .position(|v| *v >= target - t)
+                        .unwrap_or(arr.len());
+                    let exact = arr.get(lower).is_some_and(near);
+                    assert_eq!(
+                        Ok((lower, exact)),
+                        lower_bound(&arr, target, tol).map_err(|_| ())
+                    );
+
+                    let upper = arr
+                        .iter()
+                        .position(|v| *v > target + t)
+                        .unwrap_or(arr.len());
+                    let exact = upper > 0 && arr.get(upper - 1).is_some_and(near);
+                    assert_eq!(
+                        Ok((upper, exact)),
+                        upper_bound(&arr, target, tol).map_err(|_| ())
+                    );
+
+                    let mut best: Option<f64> = None;
+                    for v in arr {
+                        let dist = (v - target).abs();
+                        if best.map_or(true, |best| dist < best) {
+                            best = Some(dist);
+                        }
+                    }
+                    let found = nearest_index(&arr, target, tol).ok().flatten();
+                    // ties may resolve to either of the equal values, so compare distances
+                    let found_dist = found
+                        .and_then(|(idx, _)| arr.get(idx))
+                        .map(|v| (v - target).abs());
+                    assert_eq!(best.map(f64::to_bits), found_dist.map(f64::to_bits));
+                    assert_eq!(best.map(|d| d <= t), found.map(|(_, exact)| exact));
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn nan_errors() {
+        let arr = [1.0, 2.0, f64::NAN, 4.0, 5.0];
+        assert!(lower_bound(&arr, 3.0, None).is_err());
+        assert!(upper_bound(&arr, 3.0, None).is_err());
+        assert!(nearest_index(&arr, 3.0, None).is_err());
+        // a NaN that the binary search for 1.5 never visits.
+        let arr = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, f64::NAN];
+        assert!(lower_bound(&arr, 1.5, None).is_err());
+        assert!(upper_bound(&arr, 1.5, None).is_err());
+        assert!(nearest_index(&arr, 1.5, None).is_err());
+        assert!(lower_bound(&[1.0, 2.0], f64::NAN, None).is_err());
+        assert_eq!(Ok(None), nearest_index(&[], 1.0, None).map_err(|_| ()));
+    }
+
+    fn assert_near(expected: f64, actual: Option<f64>) {
+        let actual = actual.unwrap_or(f64::NAN);
+        assert!(
+            (expected - actual).abs() < 1e-9,
+            "expected {expected} but was {actual}"
+        );
+    }
+
+    #[test]
+    pub fn linear() {
+        let xs = [0.0, 1.0, 1.0, 3.0, 7.0];
+        let ys = [0.0, 10.0, 20.0, 0.0, 4.0];
+        assert_near(5.0, interp_linear(&xs, &ys, 0.5, false));
+        // duplicates use the last value
+        assert_near(20.0, interp_linear(&xs, &ys, 1.0, false));
+        assert_near(10.0, interp_linear(&xs, &ys, 2.0, false));
+        assert_near(0.0, interp_linear(&xs, &ys, 0.0, false));
+        assert_near(4.0, interp_linear(&xs, &ys, 7.0, false));
+        assert_near(1.0, interp_linear(&xs, &ys, 4.0, false));
+
+        assert_eq!(None, interp_linear(&xs, &ys, -0.1, false));
+        assert_eq!(None, interp_linear(&xs, &ys, 7.1, false));
+        assert_near(0.0, interp_linear(&xs, &ys, -0.1, true));
+        assert_near(4.0, interp_linear(&xs, &ys, 700.0, true));
+        assert_eq!(None, interp_linear(&xs, &ys, f64::NAN, true));
+
+        // single points and bad inputs
+        assert_near(3.0, interp_linear(&[2.0], &[3.0], 2.0, false));
+        assert_eq!(None, interp_linear(&[2.0], &[3.0], 2.5, false));
+        assert_near(3.0, interp_linear(&[2.0], &[3.0], -2.5, true));
+        assert_eq!(None, interp_linear(&[], &[], 0.0, true));
+        assert_eq!(None, interp_linear(&[1.0, 2.0], &[1.0], 1.0, true));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    pub fn straight_line_is_exact() {
+        let xs = [-3.0, -1.0, 0.0, 0.5, 2.0, 2.0, 10.0];
+        let ys = xs.map(|x| 2.5 * x - 1.0);
+        let spline = CubicSpline::new(&xs, &ys);
+        for step in 0..=260 {
+            let x = -3.0 + f64::from(step) * 0.05;
+            let expected = 2.5 * x - 1.0;
+            assert_near(expected, interp_linear(&xs, &ys, x, false));
+            assert_near(expected, interp_cubic(&xs, &ys, x, false));
+            assert_near(
+                expected,
+                spline.as_ref().and_then(|s| s.interpolate(x, false)),
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    pub fn cubic() {
+        let xs = [0.0, 1.0, 2.0];
+        let ys = [0.0, 1.0, 0.0];
+        // natural spline, y'' at the middle point is -3
+        assert_near(0.6875, interp_cubic(&xs, &ys, 0.5, false));
+        assert_near(0.6875, interp_cubic(&xs, &ys, 1.5, false));
+        assert_near(1.0, interp_cubic(&xs, &ys, 1.0, false));
+        assert_near(0.0, interp_cubic(&xs, &ys, 2.0, false));
+        assert_eq!(None, interp_cubic(&xs, &ys, 2.5, false));
+        assert_near(0.0, interp_cubic(&xs, &ys, 2.5, true));
+
+        // a smooth function is reproduced closely, and the spline passes through every point.
+        // sampled over a full period, so the natural end conditions match.
+        let step = core::f64::consts::TAU / 20.0;
+        let xs: alloc::vec::Vec<f64> = (0..=20).map(|v| f64::from(v) * step).collect();
+        let ys: alloc::vec::Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+        let spline = CubicSpline::new(&xs, &ys);
+        for (x, y) in xs.iter().zip(&ys) {
+            assert_near(*y, spline.as_ref().and_then(|s| s.interpolate(*x, false)));
+        }
+        for idx in 0..=600 {
+            let x = f64::from(idx) * core::f64::consts::TAU / 600.0;
+            let y = spline.as_ref().and_then(|s| s.interpolate(x, false));
+            let err = (y.unwrap_or(f64::NAN) - x.sin()).abs();
+            assert!(err < 1e-4, "error of {err} at {x}");
+        }
+
+        // degenerate inputs
+        assert_near(3.0, interp_cubic(&[2.0, 2.0], &[1.0, 3.0], 2.0, false));
+        assert_near(3.0, interp_cubic(&[2.0], &[3.0], 5.0, true));
+        assert_near(2.0, interp_cubic(&[0.0, 2.0], &[1.0, 3.0], 1.0, false));
+        assert_eq!(None, CubicSpline::new(&[1.0, 0.0], &[1.0, 1.0]));
+        assert_eq!(None, CubicSpline::new(&[0.0, f64::NAN], &[1.0, 1.0]));
+        assert_eq!(None, CubicSpline::new(&[], &[]));
+        assert_eq!(None, CubicSpline::new(&[0.0], &[]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    pub fn reductions_match_scalar() {
+        use crate::arrays::LANES;
+        let mut rand = Pcg32::new(0xBEEF);
+        // exercise the chunked loop and each of the remainder paths.
+        for len in [1, LANES - 1, LANES, LANES + 1, 4 * LANES + 3, 4_099] {
+            let arr = (0..len)
+                .map(|_| rand.range_f64(-1e3, 1e3))
+                .collect::<alloc::vec::Vec<f64>>();
+
+            let (lo, hi) = minmax(&arr).unwrap_or_default();
+            let total = sum(&arr);
+            let avg = mean(&arr).unwrap_or_default();
+            let ref_lo = arr.iter().copied().fold(f64::INFINITY, f64::min);
+            let ref_hi = arr.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let ref_total = arr.iter().sum::<f64>();
+
+            assert_eq!(ref_lo.to_bits(), lo.to_bits(), "{len}");
+            assert_eq!(ref_hi.to_bits(), hi.to_bits(), "{len}");
+            assert_eq!(Some(ref_lo), min(&arr), "{len}");
+            assert_eq!(Some(ref_hi), max(&arr), "{len}");
+            assert_eq!(Some(ref_lo), nanmin(&arr), "{len}");
+            assert_eq!(Some(ref_hi), nanmax(&arr), "{len}");
+            // both are within rounding of the exact sum.
+            let magnitude = arr.iter().map(|v| v.abs()).sum::<f64>();
+            assert!((ref_total - total).abs() <= magnitude * 1e-12, "{len}");
+            assert!(
+                (ref_total - avg * arr.len() as f64).abs() <= magnitude * 1e-12,
+                "{len}"
+            );
+
+            let arr32 = arr
+                .iter()
+                .map(|v| *v as f32)
+                .collect::<alloc::vec::Vec<f32>>();
+            let ref_lo32 = arr32.iter().copied().fold(f32::INFINITY, f32::min);
+            assert_eq!(Some(ref_lo32), min(&arr32), "{len}");
+            let ref_total32 = arr32.iter().map(|v| f64::from(*v)).sum::<f64>();
+            assert!(
+                (ref_total32 - f64::from(sum(&arr32))).abs() <= magnitude * 1e-5,
+                "{len}"
+            );
+        }
+
+        let empty: [f64; 0] = [];
+        assert_eq!(None, minmax(&empty));
+        assert_eq!(0.0f64.to_bits(), sum(&empty).to_bits());
+    }
+
+    #[test]
+    pub fn reductions_nan_and_empty() {
+        let arr = [2.0, f64::NAN, -3.0, 7.0, 1.0, 0.0, 4.0, 5.0, 6.0, f64::NAN];
+        assert!(min(&arr).is_some_and(f64::is_nan));
+        assert!(max(&arr).is_some_and(f64::is_nan));
+        assert!(minmax(&arr).is_some_and(|(lo, hi)| lo.is_nan() && hi.is_nan()));
+        assert!(sum(&arr).is_nan());
+        assert!(mean(&arr).is_some_and(f64::is_nan));
+        assert_eq!(Some(-3.0), nanmin(&arr));
+        assert_eq!(Some(7.0), nanmax(&arr));
+
+        let nans = [f32::NAN; 11];
+        assert_eq!(None, nanmin(&nans));
+        assert_eq!(None, nanmax(&nans));
+        assert!(min(&nans).is_some_and(f32::is_nan));
+
+        let infs = [f64::INFINITY, f64::INFINITY];
+        assert_eq!(Some(f64::INFINITY), nanmin(&infs));
+        assert_eq!(Some((f64::INFINITY, f64::INFINITY)), minmax(&infs));
+
+        let empty: [f64; 0] = [];
+        assert_eq!(None, min(&empty));
+        assert_eq!(None, max(&empty));
+        assert_eq!(None, minmax(&empty));
+        assert_eq!(None, nanmin(&empty));
+        assert_eq!(None, nanmax(&empty));
+        assert_eq!(None, mean(&empty));
+        assert_eq!(0, sum(&empty).to_bits());
+        assert_eq!(Some(3.0), mean(&[3.0f32]));
+    }
 }
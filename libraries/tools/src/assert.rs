@@ -5,6 +5,30 @@
 //! Additional assert macros for tests
 //!
 
+#[allow(unused_imports)]
+use crate::f64::FloatExt;
+
+///
+/// Approximate equality within a tolerance, for values that can't be compared exactly (like
+/// floating point values or physical quantities).  The tolerance is generally the same type as
+/// the values, so for quantities with units, a tolerance can be expressed in any units.
+pub trait ApproxEq<Tolerance = Self> {
+    /// Returns true if the difference between `self` and `other` is within the `tolerance`.
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool;
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &Self, tolerance: &Self) -> bool {
+        (*self - *other).abs() <= *tolerance
+    }
+}
+
+impl ApproxEq for f32 {
+    fn approx_eq(&self, other: &Self, tolerance: &Self) -> bool {
+        (*self - *other).abs() <= *tolerance
+    }
+}
+
 ///
 /// Assert Equal with floating point epsilon/delta values.  `|left-right| <= eps`
 #[macro_export]
@@ -24,6 +48,27 @@ macro_rules! assert_eq_eps {
     };
 }
 
+///
+/// Assert approximately equal for types that implement [`ApproxEq`], like the units quantities.
+/// The tolerance is the same type as the values, and unit conversions are handled by the
+/// [`ApproxEq`] implementation, so `left`, `right` and the tolerance can all be in different
+/// units.  On failure, the values are printed in their original units.
+#[macro_export]
+macro_rules! assert_quantity_eq {
+    ($left:expr, $right:expr, $tolerance:expr) => {
+        match (&$left, &$right, &$tolerance) {
+            (left_val, right_val, tolerance) => {
+                if !$crate::assert::ApproxEq::approx_eq(left_val, right_val, tolerance) {
+                    panic!(
+                        "Assertion failed, {:?} != {:?} within {:?}",
+                        &*left_val, &*right_val, &*tolerance
+                    )
+                }
+            }
+        }
+    };
+}
+
 ///
 /// Assert equal w/ hex value, for elements that implement [`core::fmt::UpperHex`]
 #[macro_export]
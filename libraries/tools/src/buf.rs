@@ -14,6 +14,10 @@ mod str;
 use crate::cfg_feature_alloc;
 pub use str::*;
 cfg_feature_alloc! {
+    pub use ring::*;
+    mod ring;
+    pub use smallstr::*;
+    mod smallstr;
     pub use unlimited::*;
     mod unlimited;
 }
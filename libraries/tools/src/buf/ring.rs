@@ -0,0 +1,248 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+extern crate alloc;
+use crate::buf::Buffer;
+use alloc::collections::VecDeque;
+
+///
+/// Heap-allocated circular buffer holding up to a fixed number of elements, allocating as it
+/// grows towards that capacity.  What happens when pushing into a full buffer depends on the
+/// constructor - [`RingBuf::bounded`] rejects the new element, [`RingBuf::overwriting`] drops the
+/// element at the other end to make room, retaining the most recent elements.
+///
+/// # Example
+/// ```
+/// use irox_tools::buf::{Buffer, RingBuf};
+///
+/// let mut history = RingBuf::overwriting(3);
+/// for v in 0..5 {
+///     history.push_back(v).unwrap();
+/// }
+/// assert_eq!(&[2, 3, 4], history.make_contiguous());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingBuf<T> {
+    buf: VecDeque<T>,
+    capacity: usize,
+    overwrite: bool,
+}
+
+impl<T> RingBuf<T> {
+    ///
+    /// Creates a buffer holding up to `capacity` elements (minimum of 1), where pushing into a
+    /// full buffer fails and returns the element.
+    #[must_use]
+    pub fn bounded(capacity: usize) -> RingBuf<T> {
+        RingBuf {
+            buf: VecDeque::new(),
+            capacity: capacity.max(1),
+            overwrite: false,
+        }
+    }
+
+    ///
+    /// Creates a buffer holding up to `capacity` elements (minimum of 1), where pushing into a
+    /// full buffer drops the element at the opposite end.
+    #[must_use]
+    pub fn overwriting(capacity: usize) -> RingBuf<T> {
+        RingBuf {
+            buf: VecDeque::new(),
+            capacity: capacity.max(1),
+            overwrite: true,
+        }
+    }
+
+    /// Returns true if pushing into a full buffer drops the oldest element
+    #[must_use]
+    pub fn is_overwriting(&self) -> bool {
+        self.overwrite
+    }
+
+    /// Returns true if the buffer holds `capacity` elements
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.buf.len() >= self.capacity
+    }
+
+    ///
+    /// Rotates the elements in place so they're stored front-to-back in a single slice, and
+    /// returns that slice.  Cheap if the buffer hasn't wrapped since the last call.
+    pub fn make_contiguous(&mut self) -> &[T] {
+        self.buf.make_contiguous()
+    }
+
+    ///
+    /// Returns the elements as two slices, front-to-back, without rearranging them.  The second
+    /// slice is empty if the elements are already contiguous.
+    #[must_use]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.buf.as_slices()
+    }
+
+    /// Iterates over the elements, front-to-back
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        self.buf.iter()
+    }
+}
+
+impl<T> Buffer<T> for RingBuf<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.buf.get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.buf.get_mut(index)
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn clear(&mut self) {
+        self.buf.clear();
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.buf.front()
+    }
+
+    fn front_mut(&mut self) -> Option<&mut T> {
+        self.buf.front_mut()
+    }
+
+    fn back(&self) -> Option<&T> {
+        self.buf.back()
+    }
+
+    fn back_mut(&mut self) -> Option<&mut T> {
+        self.buf.back_mut()
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.buf.pop_front()
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.buf.pop_back()
+    }
+
+    ///
+    /// Pushes the element onto the front of the buffer.  If the buffer is full, either drops the
+    /// back element, or returns the provided element if the buffer isn't overwriting.
+    fn push_front(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            if !self.overwrite {
+                return Err(value);
+            }
+            self.buf.pop_back();
+        }
+        self.buf.push_front(value);
+        Ok(())
+    }
+
+    ///
+    /// Pushes the element onto the back of the buffer.  If the buffer is full, either drops the
+    /// front element, or returns the provided element if the buffer isn't overwriting.
+    fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            if !self.overwrite {
+                return Err(value);
+            }
+            self.buf.pop_front();
+        }
+        self.buf.push_back(value);
+        Ok(())
+    }
+}
+
+impl<T> IntoIterator for RingBuf<T> {
+    type Item = T;
+    type IntoIter = alloc::collections::vec_deque::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.buf.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::buf::{Buffer, RingBuf};
+    use alloc::vec::Vec;
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn overwriting_wraps() -> Result<(), u32> {
+        let mut buf = RingBuf::<u32>::overwriting(4);
+        for v in 0..4 {
+            buf.push_back(v)?;
+        }
+        assert!(buf.is_full());
+        assert_eq!(&[0, 1, 2, 3], buf.make_contiguous());
+
+        // wrap around several times, checking the contents each step
+        for v in 4..23 {
+            buf.push_back(v)?;
+            assert_eq!(4, buf.len());
+            assert_eq!(4, buf.capacity());
+            let expected = (v - 3..=v).collect::<Vec<u32>>();
+            assert_eq!(expected, buf.iter().copied().collect::<Vec<u32>>());
+            assert_eq!(Some(&(v - 3)), buf.front());
+            assert_eq!(Some(&v), buf.back());
+            assert_eq!(Some(&(v - 1)), buf.get(2));
+            if v % 3 == 0 {
+                assert_eq!(expected.as_slice(), buf.make_contiguous());
+                assert!(buf.as_slices().1.is_empty());
+            }
+        }
+
+        assert_eq!(Some(19), buf.pop_front());
+        buf.push_back(23)?;
+        buf.push_back(24)?;
+        assert_eq!(&[21, 22, 23, 24], buf.make_contiguous());
+
+        // pushing onto the front drops the back
+        buf.push_front(20)?;
+        assert_eq!(&[20, 21, 22, 23], buf.make_contiguous());
+        assert_eq!(
+            alloc::vec![20, 21, 22, 23],
+            buf.into_iter().collect::<Vec<u32>>()
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn bounded_rejects() -> Result<(), u32> {
+        let mut buf = RingBuf::<u32>::bounded(3);
+        assert!(!buf.is_overwriting());
+        for v in 0..3 {
+            buf.push_back(v)?;
+        }
+        assert_eq!(Err(3), buf.push_back(3));
+        assert_eq!(Err(3), buf.push_front(3));
+        assert_eq!(&[0, 1, 2], buf.make_contiguous());
+
+        // wrap the storage around, then read it back out in order
+        for v in 3..10 {
+            assert_eq!(Some(v - 3), buf.pop_front());
+            buf.push_back(v)?;
+            assert_eq!(Err(100), buf.push_back(100));
+        }
+        assert_eq!(&[7, 8, 9], buf.make_contiguous());
+        assert_eq!(Some(9), buf.pop_back());
+        buf.push_front(6)?;
+        assert_eq!(&[6, 7, 8], buf.make_contiguous());
+
+        buf.clear();
+        assert!(buf.is_empty());
+        assert_eq!(None, buf.pop_front());
+        assert!(buf.make_contiguous().is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+///
+/// The maximum length in bytes of an owned string stored inline, without a heap allocation.
+pub const SMALL_STR_INLINE_CAPACITY: usize = 22;
+
+#[derive(Clone)]
+enum Repr<'a> {
+    Borrowed(&'a str),
+    /// Always valid UTF-8 in `buf[..len]`
+    Inline {
+        len: u8,
+        buf: [u8; SMALL_STR_INLINE_CAPACITY],
+    },
+    Heap(Arc<str>),
+}
+
+///
+/// A string that is either borrowed, or owned.  Owned strings up to
+/// [`SMALL_STR_INLINE_CAPACITY`] bytes are stored inline without allocating, longer ones are
+/// stored on the heap and shared - cloning a `SmallStr` never copies string data onto the heap.
+/// Dereferences to a [`str`], and compares, orders and hashes like one, regardless of how it's
+/// stored.
+#[derive(Clone)]
+pub struct SmallStr<'a> {
+    repr: Repr<'a>,
+}
+
+impl<'a> SmallStr<'a> {
+    ///
+    /// Wraps the borrowed string, without copying it.
+    #[must_use]
+    pub const fn from_borrowed(value: &'a str) -> SmallStr<'a> {
+        SmallStr {
+            repr: Repr::Borrowed(value),
+        }
+    }
+
+    ///
+    /// Takes ownership of the string.  Short strings are moved inline, and the string's allocation
+    /// is released.
+    #[must_use]
+    pub fn from_owned(value: String) -> SmallStr<'a> {
+        match Self::try_inline(&value) {
+            Some(repr) => SmallStr { repr },
+            None => SmallStr {
+                repr: Repr::Heap(Arc::from(value)),
+            },
+        }
+    }
+
+    ///
+    /// Copies the string into a new owned `SmallStr`, inline if it fits.
+    #[must_use]
+    pub fn copied(value: &str) -> SmallStr<'a> {
+        match Self::try_inline(value) {
+            Some(repr) => SmallStr { repr },
+            None => SmallStr {
+                repr: Repr::Heap(Arc::from(value)),
+            },
+        }
+    }
+
+    fn try_inline(value: &str) -> Option<Repr<'a>> {
+        let len = value.len();
+        if len > SMALL_STR_INLINE_CAPACITY {
+            return None;
+        }
+        let mut buf = [0u8; SMALL_STR_INLINE_CAPACITY];
+        buf.get_mut(..len)?.copy_from_slice(value.as_bytes());
+        Some(Repr::Inline {
+            len: len as u8,
+            buf,
+        })
+    }
+
+    /// Returns the string
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match &self.repr {
+            Repr::Borrowed(s) => s,
+            Repr::Inline { len, buf } => buf
+                .get(..usize::from(*len))
+                .and_then(|v| core::str::from_utf8(v).ok())
+                .unwrap_or_default(),
+            Repr::Heap(s) => s,
+        }
+    }
+
+    /// Returns true if the string is borrowed
+    #[must_use]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.repr, Repr::Borrowed(_))
+    }
+
+    /// Returns true if the string is owned and stored inline
+    #[must_use]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline { .. })
+    }
+
+    /// Returns true if the string is owned and stored on the heap
+    #[must_use]
+    pub fn is_heap(&self) -> bool {
+        matches!(self.repr, Repr::Heap(_))
+    }
+
+    ///
+    /// Converts this into an owned `SmallStr` that no longer borrows, copying the string only if
+    /// it's borrowed.
+    #[must_use]
+    pub fn into_owned(self) -> SmallStr<'static> {
+        let repr = match self.repr {
+            Repr::Borrowed(s) => return SmallStr::copied(s),
+            Repr::Inline { len, buf } => Repr::Inline { len, buf },
+            Repr::Heap(s) => Repr::Heap(s),
+        };
+        SmallStr { repr }
+    }
+}
+
+impl Default for SmallStr<'_> {
+    fn default() -> Self {
+        SmallStr::from_borrowed("")
+    }
+}
+
+impl Deref for SmallStr<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SmallStr<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for SmallStr<'_> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&'a str> for SmallStr<'a> {
+    fn from(value: &'a str) -> Self {
+        SmallStr::from_borrowed(value)
+    }
+}
+
+impl From<String> for SmallStr<'_> {
+    fn from(value: String) -> Self {
+        SmallStr::from_owned(value)
+    }
+}
+
+impl From<SmallStr<'_>> for String {
+    fn from(value: SmallStr<'_>) -> Self {
+        String::from(value.as_str())
+    }
+}
+
+impl PartialEq for SmallStr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SmallStr<'_> {}
+
+impl PartialEq<str> for SmallStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SmallStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for SmallStr<'_> {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<SmallStr<'_>> for str {
+    fn eq(&self, other: &SmallStr<'_>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<SmallStr<'_>> for &str {
+    fn eq(&self, other: &SmallStr<'_>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<SmallStr<'_>> for String {
+    fn eq(&self, other: &SmallStr<'_>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialOrd for SmallStr<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SmallStr<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for SmallStr<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl Display for SmallStr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Debug for SmallStr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::buf::{SmallStr, SMALL_STR_INLINE_CAPACITY};
+    use crate::hash::XxHash64;
+    use alloc::string::String;
+    use core::hash::{BuildHasher, BuildHasherDefault};
+
+    #[test]
+    pub fn inline_boundary() {
+        let max = "a".repeat(SMALL_STR_INLINE_CAPACITY);
+        let small = SmallStr::from_owned(max.clone());
+        assert!(small.is_inline());
+        assert_eq!(max, small);
+        assert_eq!(SMALL_STR_INLINE_CAPACITY, small.len());
+
+        let over = "a".repeat(SMALL_STR_INLINE_CAPACITY + 1);
+        let small = SmallStr::from_owned(over.clone());
+        assert!(small.is_heap());
+        assert_eq!(over, small);
+
+        // the boundary is in bytes, not chars.
+        let multibyte = "\u{e9}".repeat(SMALL_STR_INLINE_CAPACITY / 2);
+        assert!(SmallStr::copied(&multibyte).is_inline());
+        let multibyte = "\u{e9}".repeat(SMALL_STR_INLINE_CAPACITY / 2 + 1);
+        assert!(SmallStr::copied(&multibyte).is_heap());
+
+        let empty = SmallStr::from_owned(String::new());
+        assert!(empty.is_inline());
+        assert!(empty.is_empty());
+        assert_eq!(SmallStr::default(), empty);
+    }
+
+    #[test]
+    pub fn equal_across_representations() {
+        let hasher = BuildHasherDefault::<XxHash64>::default();
+        for value in ["", "$GPGGA", "a string that won't fit inline"] {
+            let borrowed = SmallStr::from_borrowed(value);
+            let owned = SmallStr::from_owned(String::from(value));
+            assert!(borrowed.is_borrowed());
+            assert!(!owned.is_borrowed());
+            assert_eq!(borrowed, owned);
+            assert_eq!(value, owned);
+            assert_eq!(hasher.hash_one(&borrowed), hasher.hash_one(&owned));
+            assert_eq!(hasher.hash_one(value), hasher.hash_one(&owned));
+
+            let cloned = owned.clone();
+            assert_eq!(owned, cloned);
+            let promoted = borrowed.into_owned();
+            assert!(!promoted.is_borrowed());
+            assert_eq!(owned, promoted);
+            assert_eq!(value, String::from(promoted));
+        }
+        assert_eq!(
+            core::cmp::Ordering::Less,
+            SmallStr::copied("abc").cmp(&SmallStr::from("abd"))
+        );
+        assert_eq!("\"abc\"", alloc::format!("{:?}", SmallStr::from("abc")));
+    }
+}
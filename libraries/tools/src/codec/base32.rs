@@ -0,0 +1,413 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! RFC-4648 Base32 binary-to-text encodings, which encode each group of 5 bytes as 8 characters.
+//! Both the standard alphabet (used by TOTP secrets) and the "Extended Hex" alphabet (which
+//! preserves the sort order of the encoded data) are supported.
+//!
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use irox_bits::{Bits, Error, ErrorKind, MutBits};
+
+use crate::base64::{BASE32HEX_ALPHABET, BASE32_ALPHABET};
+use crate::codec::Codec;
+
+const PAD: u8 = b'=';
+
+///
+/// The base32 variant to encode or decode
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum Base32Alphabet {
+    /// `A-Z,2-7`, see [`BASE32_ALPHABET`]
+    #[default]
+    Standard,
+    /// `0-9,A-V`, see [`BASE32HEX_ALPHABET`]
+    Hex,
+}
+
+impl Base32Alphabet {
+    #[must_use]
+    pub fn symbols(self) -> &'static [u8; 32] {
+        match self {
+            Base32Alphabet::Standard => &BASE32_ALPHABET,
+            Base32Alphabet::Hex => &BASE32HEX_ALPHABET,
+        }
+    }
+
+    /// Returns the value of the character, ignoring case.
+    fn decode_symbol(self, ch: u8) -> Option<u8> {
+        let ch = ch.to_ascii_uppercase();
+        self.symbols()
+            .iter()
+            .position(|v| *v == ch)
+            .map(|v| v as u8)
+    }
+}
+
+///
+/// Error returned when decoding an invalid base32 input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Base32Error {
+    /// The byte at the offset isn't part of the alphabet
+    InvalidCharacter { offset: usize },
+    /// The padding starting at the offset is followed by a non-padding character at the offset,
+    /// or doesn't pad the input to a multiple of 8 characters
+    InvalidPadding { offset: usize },
+    /// The final group has 1, 3 or 6 characters, which can't encode whole bytes
+    TruncatedGroup,
+}
+
+impl Base32Error {
+    ///
+    /// Returns the offset into the input of the invalid character or padding, if any.
+    #[must_use]
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Base32Error::InvalidCharacter { offset } | Base32Error::InvalidPadding { offset } => {
+                Some(*offset)
+            }
+            Base32Error::TruncatedGroup => None,
+        }
+    }
+}
+
+impl Display for Base32Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Base32Error::InvalidCharacter { offset } => {
+                write!(f, "Invalid base32 character at offset {offset}")
+            }
+            Base32Error::InvalidPadding { offset } => {
+                write!(f, "Invalid base32 padding at offset {offset}")
+            }
+            Base32Error::TruncatedGroup => {
+                f.write_str("Base32 final group has an invalid number of characters")
+            }
+        }
+    }
+}
+
+crate::cfg_feature_std! {
+    impl std::error::Error for Base32Error {}
+}
+
+///
+/// Converts to an [`ErrorKind::InvalidData`] error.  The offset of the invalid input is lost, use
+/// [`Base32Error::offset`] first if it's needed.
+impl From<Base32Error> for Error {
+    fn from(_value: Base32Error) -> Self {
+        ErrorKind::InvalidData.into()
+    }
+}
+
+///
+/// Encodes the (up to 5) bytes of a group, returning the 8 symbols of the group (ending
+/// with the `=` padding) and the number of those symbols that carry data.
+fn encode_group(chunk: &[u8], alphabet: Base32Alphabet) -> ([u8; 8], usize) {
+    let mut group = [0_u8; 8];
+    if let Some(dest) = group.get_mut(3..3 + chunk.len()) {
+        dest.copy_from_slice(chunk);
+    }
+    let value = u64::from_be_bytes(group);
+    let symbols = alphabet.symbols();
+    let used = (chunk.len() * 8).div_ceil(5);
+    let mut out = [PAD; 8];
+    for (idx, symbol) in out.iter_mut().enumerate().take(used) {
+        let digit = (value >> (35 - 5 * idx)) & 0x1F;
+        *symbol = symbols.get(digit as usize).copied().unwrap_or_default();
+    }
+    (out, used)
+}
+
+///
+/// Incremental decoder, fed one character at a time.
+struct Decoder {
+    alphabet: Base32Alphabet,
+    offset: usize,
+    buf: u32,
+    bits: u32,
+    group_len: usize,
+    pad_start: Option<usize>,
+}
+
+impl Decoder {
+    fn new(alphabet: Base32Alphabet) -> Decoder {
+        Decoder {
+            alphabet,
+            offset: 0,
+            buf: 0,
+            bits: 0,
+            group_len: 0,
+            pad_start: None,
+        }
+    }
+
+    /// Decodes the next character, returning a byte if one has been completed.
+    fn push(&mut self, ch: u8) -> Result<Option<u8>, Base32Error> {
+        let offset = self.offset;
+        self.offset += 1;
+        if ch == PAD {
+            self.pad_start.get_or_insert(offset);
+            return Ok(None);
+        }
+        if self.pad_start.is_some() {
+            return Err(Base32Error::InvalidPadding { offset });
+        }
+        let value = self
+            .alphabet
+            .decode_symbol(ch)
+            .ok_or(Base32Error::InvalidCharacter { offset })?;
+        self.group_len = (self.group_len + 1) % 8;
+        self.buf = (self.buf << 5) | u32::from(value);
+        self.bits += 5;
+        if self.bits < 8 {
+            return Ok(None);
+        }
+        self.bits -= 8;
+        let out = (self.buf >> self.bits) as u8;
+        self.buf &= (1 << self.bits) - 1;
+        Ok(Some(out))
+    }
+
+    /// Checks the final group is complete, any leftover bits are discarded.
+    fn finish(&self) -> Result<(), Base32Error> {
+        if !matches!(self.group_len, 0 | 2 | 4 | 5 | 7) {
+            return Err(Base32Error::TruncatedGroup);
+        }
+        if let Some(offset) = self.pad_start {
+            if self.group_len == 0 || self.offset % 8 != 0 {
+                return Err(Base32Error::InvalidPadding { offset });
+            }
+        }
+        Ok(())
+    }
+}
+
+///
+/// Streaming base32 [`Codec`].  Decoding ignores case, and accepts input with or without the end
+/// padding, but fails on any character outside the alphabet.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Base32Codec {
+    alphabet: Base32Alphabet,
+    pad_output: bool,
+}
+
+impl Base32Codec {
+    /// Creates a new codec, using the provided alphabet.
+    #[must_use]
+    pub fn new(alphabet: Base32Alphabet) -> Self {
+        Base32Codec {
+            alphabet,
+            pad_output: true,
+        }
+    }
+    /// if set, encoding will not write the end padding characters.  Decoding accepts input with
+    /// or without padding either way.
+    pub fn set_no_padding(&mut self) -> &mut Self {
+        self.pad_output = false;
+        self
+    }
+}
+
+impl Codec for Base32Codec {
+    fn encode<I: Bits, O: MutBits>(&self, mut input: I, output: &mut O) -> Result<usize, Error> {
+        let mut written = 0;
+        let mut chunk = [0_u8; 5];
+        loop {
+            let mut len = 0;
+            for byte in &mut chunk {
+                let Some(v) = input.next_u8()? else {
+                    break;
+                };
+                *byte = v;
+                len += 1;
+            }
+            let Some(chunk) = chunk.get(..len).filter(|c| !c.is_empty()) else {
+                return Ok(written);
+            };
+            let (symbols, used) = encode_group(chunk, self.alphabet);
+            let used = if self.pad_output { 8 } else { used };
+            let Some(symbols) = symbols.get(..used) else {
+                return Ok(written);
+            };
+            output.write_all_bytes(symbols)?;
+            written += used;
+            if len < 5 {
+                return Ok(written);
+            }
+        }
+    }
+
+    fn decode<I: Bits, O: MutBits>(&self, mut input: I, output: &mut O) -> Result<usize, Error> {
+        let mut decoder = Decoder::new(self.alphabet);
+        let mut written = 0;
+        while let Some(ch) = input.next_u8()? {
+            if let Some(byte) = decoder.push(ch)? {
+                output.write_u8(byte)?;
+                written += 1;
+            }
+        }
+        decoder.finish()?;
+        Ok(written)
+    }
+}
+
+///
+/// Encodes the input as base32 text, optionally with the `=` end padding.
+#[must_use]
+pub fn base32_encode(input: &[u8], alphabet: Base32Alphabet, padded: bool) -> String {
+    let mut out = String::with_capacity(input.len().div_ceil(5) * 8);
+    for chunk in input.chunks(5) {
+        let (symbols, used) = encode_group(chunk, alphabet);
+        let used = if padded { 8 } else { used };
+        for symbol in symbols.iter().take(used) {
+            out.push(*symbol as char);
+        }
+    }
+    out
+}
+
+///
+/// Decodes the base32 input, ignoring case.  The `=` end padding is optional, but must be
+/// correct if present.
+pub fn base32_decode(input: &[u8], alphabet: Base32Alphabet) -> Result<Vec<u8>, Base32Error> {
+    let mut decoder = Decoder::new(alphabet);
+    let mut out = Vec::with_capacity(input.len() * 5 / 8);
+    for ch in input {
+        if let Some(byte) = decoder.push(*ch)? {
+            out.push(byte);
+        }
+    }
+    decoder.finish()?;
+    Ok(out)
+}
+
+///
+/// Encodes the input as padded base32 text, using the standard [`BASE32_ALPHABET`]
+#[must_use]
+pub fn encode_base32(input: &[u8]) -> String {
+    base32_encode(input, Base32Alphabet::Standard, true)
+}
+
+///
+/// Decodes the base32 input using the standard [`BASE32_ALPHABET`], see [`base32_decode`]
+pub fn decode_base32(input: &str) -> Result<Vec<u8>, Base32Error> {
+    base32_decode(input.as_bytes(), Base32Alphabet::Standard)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codec::{
+        base32_decode, base32_encode, decode_base32, encode_base32, Base32Alphabet, Base32Codec,
+        Base32Error, Codec,
+    };
+    use alloc::vec::Vec;
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn rfc4648_vectors() -> Result<(), Base32Error> {
+        for (raw, standard, hex) in [
+            ("", "", ""),
+            ("f", "MY======", "CO======"),
+            ("fo", "MZXQ====", "CPNG===="),
+            ("foo", "MZXW6===", "CPNMU==="),
+            ("foob", "MZXW6YQ=", "CPNMUOG="),
+            ("fooba", "MZXW6YTB", "CPNMUOJ1"),
+            ("foobar", "MZXW6YTBOI======", "CPNMUOJ1E8======"),
+        ] {
+            assert_eq!(standard, encode_base32(raw.as_bytes()));
+            assert_eq!(raw.as_bytes(), decode_base32(standard)?);
+            assert_eq!(
+                hex,
+                base32_encode(raw.as_bytes(), Base32Alphabet::Hex, true)
+            );
+            assert_eq!(
+                raw.as_bytes(),
+                base32_decode(hex.as_bytes(), Base32Alphabet::Hex)?
+            );
+
+            // unpadded and lower case
+            let unpadded = standard.trim_end_matches('=');
+            assert_eq!(
+                unpadded,
+                base32_encode(raw.as_bytes(), Base32Alphabet::Standard, false)
+            );
+            assert_eq!(raw.as_bytes(), decode_base32(unpadded)?);
+            assert_eq!(raw.as_bytes(), decode_base32(&standard.to_lowercase())?);
+            assert_eq!(
+                raw.as_bytes(),
+                base32_decode(hex.to_lowercase().as_bytes(), Base32Alphabet::Hex)?
+            );
+        }
+        // a TOTP secret
+        assert_eq!(
+            b"Hello!\xDE\xAD\xBE\xEF".as_slice(),
+            decode_base32("jbswy3dpehpk3pxp")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn invalid() {
+        assert_eq!(
+            Some(Base32Error::InvalidCharacter { offset: 3 }),
+            decode_base32("MZX1").err()
+        );
+        assert_eq!(
+            Some(Base32Error::InvalidCharacter { offset: 0 }),
+            base32_decode(b"W", Base32Alphabet::Hex).err()
+        );
+        assert_eq!(
+            Some(Base32Error::InvalidCharacter { offset: 4 }),
+            decode_base32("MZXW 6YQ").err()
+        );
+        assert_eq!(
+            Some(Base32Error::InvalidPadding { offset: 6 }),
+            decode_base32("MZXQ==M=").err()
+        );
+        assert_eq!(
+            Some(Base32Error::InvalidPadding { offset: 4 }),
+            decode_base32("MZXQ===").err()
+        );
+        assert_eq!(
+            Some(Base32Error::InvalidPadding { offset: 0 }),
+            decode_base32("========").err()
+        );
+        assert_eq!(Some(Base32Error::TruncatedGroup), decode_base32("M").err());
+        assert_eq!(
+            Some(Some(6)),
+            decode_base32("MZXQ==M=").err().map(|e| e.offset())
+        );
+        assert_eq!(Some(None), decode_base32("M").err().map(|e| e.offset()));
+        assert_eq!(
+            Some(Base32Error::TruncatedGroup),
+            decode_base32("MZXW6Y==").err()
+        );
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn streaming() -> Result<(), irox_bits::Error> {
+        let data = (0..=255_u8).collect::<Vec<u8>>();
+        let codec = Base32Codec::new(Base32Alphabet::Standard);
+        let encoded = codec.encode_to_str(data.as_slice())?;
+        assert_eq!(encode_base32(&data), encoded);
+        assert_eq!(data, codec.decode_to_vec(encoded.as_bytes())?);
+
+        let mut nopad = Base32Codec::new(Base32Alphabet::Hex);
+        nopad.set_no_padding();
+        assert_eq!("CPNMUOJ1E8", nopad.encode_to_str(b"foobar".as_slice())?);
+        assert_eq!(
+            b"foobar".to_vec(),
+            nopad.decode_to_vec(b"cpnmuoj1e8".as_slice())?
+        );
+        assert!(codec.decode_to_vec(b"MZ!".as_slice()).is_err());
+        Ok(())
+    }
+}
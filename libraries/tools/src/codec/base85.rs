@@ -0,0 +1,332 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Base85 binary-to-text encodings, which encode each group of 4 bytes as 5 characters.  Both
+//! the Adobe ASCII85 variant (used in PostScript and PDF) and the RFC-1924 alphabet (used by
+//! git binary patches) are supported.
+//!
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use irox_bits::{Error, ErrorKind};
+
+static RFC1924_ALPHABET: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+const ASCII85_START: &[u8] = b"<~";
+const ASCII85_END: &[u8] = b"~>";
+
+///
+/// The base85 variant to encode or decode
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Base85Alphabet {
+    /// Adobe ASCII85: characters `!` through `u`, wrapped in `<~` and `~>` delimiters, with a
+    /// group of four zero bytes shortened to `z`.  Whitespace is ignored when decoding.
+    Ascii85,
+    /// The RFC-1924 alphabet: `0-9`, `A-Z`, `a-z` and 23 punctuation characters, without any
+    /// delimiters or shorthand.
+    Rfc1924,
+}
+
+impl Base85Alphabet {
+    fn encode_digit(self, digit: u32) -> u8 {
+        match self {
+            Base85Alphabet::Ascii85 => b'!' + digit as u8,
+            Base85Alphabet::Rfc1924 => RFC1924_ALPHABET
+                .get(digit as usize)
+                .copied()
+                .unwrap_or_default(),
+        }
+    }
+
+    fn decode_digit(self, ch: u8) -> Option<u32> {
+        match self {
+            Base85Alphabet::Ascii85 => match ch {
+                b'!'..=b'u' => Some(u32::from(ch - b'!')),
+                _ => None,
+            },
+            Base85Alphabet::Rfc1924 => RFC1924_ALPHABET
+                .iter()
+                .position(|v| *v == ch)
+                .map(|v| v as u32),
+        }
+    }
+}
+
+///
+/// Error returned when decoding an invalid base85 input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Base85Error {
+    /// The character at the offset isn't part of the alphabet, or is a `z` in the middle of a
+    /// group
+    InvalidCharacter { offset: usize },
+    /// The group ending at the offset decodes to a value larger than 32 bits
+    Overflow { offset: usize },
+    /// The final group is only a single character, which can't encode any bytes
+    TruncatedGroup,
+    /// The input started with the `<~` delimiter, but didn't end with `~>`
+    MissingEndDelimiter,
+}
+
+impl Display for Base85Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Base85Error::InvalidCharacter { offset } => {
+                write!(f, "Invalid base85 character at offset {offset}")
+            }
+            Base85Error::Overflow { offset } => {
+                write!(
+                    f,
+                    "Base85 group ending at offset {offset} overflows 32 bits"
+                )
+            }
+            Base85Error::TruncatedGroup => f.write_str("Base85 final group is a single character"),
+            Base85Error::MissingEndDelimiter => f.write_str("Missing the '~>' end delimiter"),
+        }
+    }
+}
+
+crate::cfg_feature_std! {
+    impl std::error::Error for Base85Error {}
+}
+
+impl From<Base85Error> for Error {
+    fn from(_value: Base85Error) -> Self {
+        ErrorKind::InvalidData.into()
+    }
+}
+
+///
+/// Encodes the input as base85 text.  A partial final group of `n` bytes is padded with zeros
+/// and written as its first `n + 1` characters.
+#[must_use]
+pub fn base85_encode(input: &[u8], alphabet: Base85Alphabet) -> String {
+    let mut out = String::with_capacity(input.len().div_ceil(4) * 5 + 4);
+    if alphabet == Base85Alphabet::Ascii85 {
+        out.push_str("<~");
+    }
+    for chunk in input.chunks(4) {
+        let mut group = [0_u8; 4];
+        if let Some(dest) = group.get_mut(..chunk.len()) {
+            dest.copy_from_slice(chunk);
+        }
+        let value = u32::from_be_bytes(group);
+        if value == 0 && chunk.len() == 4 && alphabet == Base85Alphabet::Ascii85 {
+            out.push('z');
+            continue;
+        }
+        let mut digits = [0_u8; 5];
+        let mut rem = value;
+        for digit in digits.iter_mut().rev() {
+            *digit = alphabet.encode_digit(rem % 85);
+            rem /= 85;
+        }
+        for digit in digits.iter().take(chunk.len() + 1) {
+            out.push(*digit as char);
+        }
+    }
+    if alphabet == Base85Alphabet::Ascii85 {
+        out.push_str("~>");
+    }
+    out
+}
+
+/// Decodes the (up to 5) digits of a group, padding any missing digits with the highest digit.
+fn decode_group(digits: &[u32], offset: usize, out: &mut Vec<u8>) -> Result<(), Base85Error> {
+    let mut value: u64 = 0;
+    for idx in 0..5 {
+        value = value * 85 + u64::from(digits.get(idx).copied().unwrap_or(84));
+    }
+    let Ok(value) = u32::try_from(value) else {
+        return Err(Base85Error::Overflow { offset });
+    };
+    let bytes = value.to_be_bytes();
+    out.extend_from_slice(bytes.get(..digits.len() - 1).unwrap_or_default());
+    Ok(())
+}
+
+///
+/// Decodes the base85 input.  For [`Base85Alphabet::Ascii85`], the `<~` and `~>` delimiters are
+/// optional, though if the input starts with `<~` it must end with `~>`, and whitespace is
+/// skipped.  A partial final group of `n` characters decodes to `n - 1` bytes.  Returns an error
+/// on characters outside of the alphabet, groups that overflow 32 bits, or a final group of a
+/// single character.
+pub fn base85_decode(input: &[u8], alphabet: Base85Alphabet) -> Result<Vec<u8>, Base85Error> {
+    let mut body = input;
+    let mut base = 0;
+    if alphabet == Base85Alphabet::Ascii85 {
+        if let Some(stripped) = body.strip_prefix(ASCII85_START) {
+            body = stripped
+                .strip_suffix(ASCII85_END)
+                .ok_or(Base85Error::MissingEndDelimiter)?;
+            base = ASCII85_START.len();
+        } else if let Some(stripped) = body.strip_suffix(ASCII85_END) {
+            body = stripped;
+        }
+    }
+    let mut out: Vec<u8> = Vec::with_capacity(body.len() / 5 * 4 + 4);
+    let mut digits: [u32; 5] = [0; 5];
+    let mut count = 0;
+    for (idx, ch) in body.iter().enumerate() {
+        let offset = base + idx;
+        if alphabet == Base85Alphabet::Ascii85 {
+            if ch.is_ascii_whitespace() {
+                continue;
+            }
+            if *ch == b'z' {
+                if count != 0 {
+                    return Err(Base85Error::InvalidCharacter { offset });
+                }
+                out.extend_from_slice(&[0; 4]);
+                continue;
+            }
+        }
+        let Some(digit) = alphabet.decode_digit(*ch) else {
+            return Err(Base85Error::InvalidCharacter { offset });
+        };
+        if let Some(slot) = digits.get_mut(count) {
+            *slot = digit;
+        }
+        count += 1;
+        if count == 5 {
+            decode_group(&digits, offset, &mut out)?;
+            count = 0;
+        }
+    }
+    match count {
+        0 => {}
+        1 => return Err(Base85Error::TruncatedGroup),
+        _ => decode_group(
+            digits.get(..count).unwrap_or_default(),
+            base + body.len(),
+            &mut out,
+        )?,
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codec::{base85_decode, base85_encode, Base85Alphabet, Base85Error};
+    use alloc::vec::Vec;
+
+    static LEVIATHAN: &str = "Man is distinguished, not only by his reason, but by this \
+        singular passion from other animals, which is a lust of the mind, that by a perseverance \
+        of delight in the continued and indefatigable generation of knowledge, exceeds the short \
+        vehemence of any carnal pleasure.";
+    static LEVIATHAN_ASCII85: &str = "<~9jqo^BlbD-BleB1DJ+*+F(f,q/0JhKF<GL>Cj@.4Gp$d7F!,L7@<6@)/\
+        0JDEF<G%<+EV:2F!,O<DJ+*.@<*K0@<6L(Df-\\0Ec5e;DffZ(EZee.Bl.9pF\"AGXBPCsi+DGm>@3BB/F*&OCAfu\
+        2/AKYi(DIb:@FD,*)+C]U=@3BN#EcYf8ATD3s@q?d$AftVqCh[NqF<G:8+EV:.+Cf>-FD5W8ARlolDIal(DId<j@\
+        <?3r@:F%a+D58'ATD4$Bl@l3De:,-DJs`8ARoFb/0JMK@qB4^F!,R<AKZ&-DfTqBG%G>uD.RTpAKYo'+CT/5+Cei\
+        #DII?(E,9)oF*2M7/c~>";
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn ascii85_vectors() -> Result<(), Base85Error> {
+        let encoded = base85_encode(LEVIATHAN.as_bytes(), Base85Alphabet::Ascii85);
+        assert_eq!(LEVIATHAN_ASCII85, encoded);
+        let decoded = base85_decode(encoded.as_bytes(), Base85Alphabet::Ascii85)?;
+        assert_eq!(LEVIATHAN.as_bytes(), decoded);
+
+        for (raw, encoded) in [
+            (b"".as_slice(), "<~~>"),
+            (b"h", "<~BE~>"),
+            (b"he", "<~BOq~>"),
+            (b"hel", "<~BOtu~>"),
+            (b"hell", "<~BOu!r~>"),
+            (b"hello", "<~BOu!rDZ~>"),
+            (b"sure.", "<~F*2M7/c~>"),
+            (b"\0\0\0\0", "<~z~>"),
+            (b"\0\0\0", "<~!!!!~>"),
+            (b"\xff\xff\xff\xff", "<~s8W-!~>"),
+        ] {
+            assert_eq!(encoded, base85_encode(raw, Base85Alphabet::Ascii85));
+            assert_eq!(
+                raw,
+                base85_decode(encoded.as_bytes(), Base85Alphabet::Ascii85)?
+            );
+        }
+
+        // delimiters are optional, whitespace is skipped, z expands between groups.
+        assert_eq!(
+            b"hell\0\0\0\0hello".as_slice(),
+            base85_decode(b" BOu!r\n z\r\nBOu!r DZ", Base85Alphabet::Ascii85)?
+        );
+        assert_eq!(
+            b"sure.".as_slice(),
+            base85_decode(b"F*2M7/c~>", Base85Alphabet::Ascii85)?
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn rfc1924_vectors() -> Result<(), Base85Error> {
+        for (raw, encoded) in [
+            (b"".as_slice(), ""),
+            (b"h", "Xa"),
+            (b"he", "Xk`"),
+            (b"hel", "Xk}~"),
+            (b"hell", "Xk~0{"),
+            (b"hello", "Xk~0{Zv"),
+            (b"sure.", "b9HiME&"),
+            (b"\0\0\0\0", "00000"),
+            (b"\xff\xff\xff\xff", "|NsC0"),
+        ] {
+            assert_eq!(encoded, base85_encode(raw, Base85Alphabet::Rfc1924));
+            assert_eq!(
+                raw,
+                base85_decode(encoded.as_bytes(), Base85Alphabet::Rfc1924)?
+            );
+        }
+        let all: Vec<u8> = (0..=255).collect();
+        let encoded = base85_encode(&all, Base85Alphabet::Rfc1924);
+        assert_eq!(
+            all,
+            base85_decode(encoded.as_bytes(), Base85Alphabet::Rfc1924)?
+        );
+        let encoded = base85_encode(&all, Base85Alphabet::Ascii85);
+        assert_eq!(
+            all,
+            base85_decode(encoded.as_bytes(), Base85Alphabet::Ascii85)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn invalid() {
+        assert_eq!(
+            Some(Base85Error::InvalidCharacter { offset: 3 }),
+            base85_decode(b"<~Bv~>", Base85Alphabet::Ascii85).err()
+        );
+        assert_eq!(
+            Some(Base85Error::MissingEndDelimiter),
+            base85_decode(b"<~BOu!r", Base85Alphabet::Ascii85).err()
+        );
+        assert_eq!(
+            Some(Base85Error::InvalidCharacter { offset: 2 }),
+            base85_decode(b"BOz", Base85Alphabet::Ascii85).err()
+        );
+        assert_eq!(
+            Some(Base85Error::Overflow { offset: 4 }),
+            base85_decode(b"uuuuu", Base85Alphabet::Ascii85).err()
+        );
+        assert_eq!(
+            Some(Base85Error::TruncatedGroup),
+            base85_decode(b"BOu!rD", Base85Alphabet::Ascii85).err()
+        );
+        assert_eq!(
+            Some(Base85Error::InvalidCharacter { offset: 1 }),
+            base85_decode(b"X\"", Base85Alphabet::Rfc1924).err()
+        );
+        // no shorthand or whitespace in RFC-1924
+        assert_eq!(
+            Some(Base85Error::InvalidCharacter { offset: 0 }),
+            base85_decode(b" z", Base85Alphabet::Rfc1924).err()
+        );
+    }
+}
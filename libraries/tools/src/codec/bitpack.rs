@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Bit-packing of small-range integer arrays, where each value is stored in exactly
+//! `bits_per_value` bits, for compact columnar storage.
+//!
+
+extern crate alloc;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use irox_bits::{Error, ErrorKind};
+
+///
+/// Error returned when the values can't be packed with the requested width.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BitPackError {
+    /// The requested width is more than 32 bits
+    InvalidWidth { bits_per_value: u8 },
+    /// The value at the index needs more than the requested number of bits
+    ValueTooLarge { index: usize, bits_per_value: u8 },
+    /// The packed data is shorter than the number of values requires
+    Truncated { expected: usize, actual: usize },
+}
+
+impl Display for BitPackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BitPackError::InvalidWidth { bits_per_value } => {
+                write!(f, "Invalid width of {bits_per_value} bits, max is 32")
+            }
+            BitPackError::ValueTooLarge {
+                index,
+                bits_per_value,
+            } => write!(
+                f,
+                "Value at index {index} doesn't fit in {bits_per_value} bits"
+            ),
+            BitPackError::Truncated { expected, actual } => write!(
+                f,
+                "Expected {expected} bytes of packed data, but was {actual}"
+            ),
+        }
+    }
+}
+
+crate::cfg_feature_std! {
+    impl std::error::Error for BitPackError {}
+}
+
+impl From<BitPackError> for Error {
+    fn from(_value: BitPackError) -> Self {
+        ErrorKind::InvalidData.into()
+    }
+}
+
+///
+/// An array of values packed into `bits_per_value` bits each, least significant bit first.  Each
+/// value is stored as an offset from the `reference` value, which is zero unless created with
+/// [`bitpack_for`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BitPacked {
+    bits_per_value: u8,
+    len: usize,
+    reference: u32,
+    data: Vec<u8>,
+}
+
+impl BitPacked {
+    ///
+    /// Reassembles a packed array from its stored parts, checking that the data is long enough to
+    /// hold `len` values.
+    pub fn from_parts(
+        bits_per_value: u8,
+        len: usize,
+        reference: u32,
+        data: Vec<u8>,
+    ) -> Result<BitPacked, BitPackError> {
+        if bits_per_value > 32 {
+            return Err(BitPackError::InvalidWidth { bits_per_value });
+        }
+        let expected = packed_len(bits_per_value, len);
+        if data.len() < expected {
+            return Err(BitPackError::Truncated {
+                expected,
+                actual: data.len(),
+            });
+        }
+        Ok(BitPacked {
+            bits_per_value,
+            len,
+            reference,
+            data,
+        })
+    }
+
+    /// Returns the number of bits used to store each value
+    #[must_use]
+    pub fn bits_per_value(&self) -> u8 {
+        self.bits_per_value
+    }
+
+    /// Returns the number of packed values
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no packed values
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the value that each packed value is an offset from
+    #[must_use]
+    pub fn reference(&self) -> u32 {
+        self.reference
+    }
+
+    /// Returns the packed data
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Returns the number of bytes needed to pack `len` values of `bits_per_value` bits
+fn packed_len(bits_per_value: u8, len: usize) -> usize {
+    (usize::from(bits_per_value) * len).div_ceil(8)
+}
+
+/// Returns the number of bits needed to store the value
+fn bits_needed(value: u32) -> u8 {
+    (u32::BITS - value.leading_zeros()) as u8
+}
+
+fn pack(
+    values: &[u32],
+    bits_per_value: Option<u8>,
+    reference: u32,
+) -> Result<BitPacked, BitPackError> {
+    let max = values
+        .iter()
+        .map(|v| v.wrapping_sub(reference))
+        .max()
+        .unwrap_or_default();
+    let bits_per_value = bits_per_value.unwrap_or_else(|| bits_needed(max));
+    if bits_per_value > 32 {
+        return Err(BitPackError::InvalidWidth { bits_per_value });
+    }
+    let mut data = Vec::with_capacity(packed_len(bits_per_value, values.len()));
+    let mut acc: u64 = 0;
+    let mut acc_bits: u32 = 0;
+    for (index, value) in values.iter().enumerate() {
+        let value = value.wrapping_sub(reference);
+        if bits_needed(value) > bits_per_value {
+            return Err(BitPackError::ValueTooLarge {
+                index,
+                bits_per_value,
+            });
+        }
+        acc |= u64::from(value) << acc_bits;
+        acc_bits += u32::from(bits_per_value);
+        while acc_bits >= 8 {
+            data.push(acc as u8);
+            acc >>= 8;
+            acc_bits -= 8;
+        }
+    }
+    if acc_bits > 0 {
+        data.push(acc as u8);
+    }
+    Ok(BitPacked {
+        bits_per_value,
+        len: values.len(),
+        reference,
+        data,
+    })
+}
+
+///
+/// Packs the values into `bits_per_value` bits each.  If `bits_per_value` isn't provided, the
+/// minimum width to store the largest value is used - zero if all the values are zero.  Returns
+/// an error if the width is more than 32 bits, or a value doesn't fit.
+pub fn bitpack(values: &[u32], bits_per_value: Option<u8>) -> Result<BitPacked, BitPackError> {
+    pack(values, bits_per_value, 0)
+}
+
+///
+/// Frame-of-reference packing: subtracts the minimum value from each value before packing, so
+/// values clustered away from zero (like status codes `1000..1007`) pack as tightly as values
+/// near zero.  An array of all-equal values packs to zero bits.  See [`bitpack`].
+pub fn bitpack_for(values: &[u32], bits_per_value: Option<u8>) -> Result<BitPacked, BitPackError> {
+    let reference = values.iter().min().copied().unwrap_or_default();
+    pack(values, bits_per_value, reference)
+}
+
+///
+/// Unpacks the values, reversing [`bitpack`] or [`bitpack_for`]
+#[must_use]
+pub fn unpack(packed: &BitPacked) -> Vec<u32> {
+    let bits = u32::from(packed.bits_per_value);
+    let mask = (1u64 << bits) - 1;
+    let mut out = Vec::with_capacity(packed.len);
+    let mut bytes = packed.data.iter();
+    let mut acc: u64 = 0;
+    let mut acc_bits: u32 = 0;
+    for _ in 0..packed.len {
+        while acc_bits < bits {
+            // from_parts checks the data is long enough, so this never runs out.
+            let byte = bytes.next().copied().unwrap_or_default();
+            acc |= u64::from(byte) << acc_bits;
+            acc_bits += 8;
+        }
+        out.push(((acc & mask) as u32).wrapping_add(packed.reference));
+        acc >>= bits;
+        acc_bits -= bits;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codec::{bitpack, bitpack_for, unpack, BitPackError, BitPacked};
+    use crate::random::{Random, PRNG};
+    use alloc::vec::Vec;
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn round_trip_all_widths() -> Result<(), BitPackError> {
+        let mut rand = Random::new_seed(0xB17_BAC);
+        for bits in 0..=32_u8 {
+            let mask = if bits == 32 {
+                u32::MAX
+            } else {
+                (1u32 << bits) - 1
+            };
+            let mut values: Vec<u32> = (0..97).map(|_| rand.next_u32() & mask).collect();
+            // make sure the full width is used.
+            values.push(mask);
+            let packed = bitpack(&values, None)?;
+            assert_eq!(bits, packed.bits_per_value());
+            assert_eq!(values.len(), packed.len());
+            assert_eq!(
+                (values.len() * bits as usize).div_ceil(8),
+                packed.as_bytes().len()
+            );
+            assert_eq!(values, unpack(&packed));
+
+            let packed = bitpack_for(&values, None)?;
+            assert_eq!(values, unpack(&packed));
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn smaller_than_raw() -> Result<(), BitPackError> {
+        let values: Vec<u32> = (0..1000).map(|v| v % 8).collect();
+        let packed = bitpack(&values, None)?;
+        assert_eq!(3, packed.bits_per_value());
+        // 3 bits instead of 32.
+        assert_eq!(375, packed.as_bytes().len());
+        assert!(packed.as_bytes().len() * 10 < values.len() * core::mem::size_of::<u32>());
+        assert_eq!(values, unpack(&packed));
+
+        // offset from zero, the frame of reference packs to the same size.
+        let offset: Vec<u32> = values.iter().map(|v| v + 1_000_000).collect();
+        assert_eq!(20, bitpack(&offset, None)?.bits_per_value());
+        let packed = bitpack_for(&offset, None)?;
+        assert_eq!(3, packed.bits_per_value());
+        assert_eq!(1_000_000, packed.reference());
+        assert_eq!(375, packed.as_bytes().len());
+        assert_eq!(offset, unpack(&packed));
+
+        // and stored & restored
+        let restored = BitPacked::from_parts(
+            packed.bits_per_value(),
+            packed.len(),
+            packed.reference(),
+            packed.as_bytes().to_vec(),
+        )?;
+        assert_eq!(offset, unpack(&restored));
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn zero_width() -> Result<(), BitPackError> {
+        let values = [42_u32; 100];
+        let packed = bitpack_for(&values, None)?;
+        assert_eq!(0, packed.bits_per_value());
+        assert!(packed.as_bytes().is_empty());
+        assert_eq!(values.to_vec(), unpack(&packed));
+
+        let packed = bitpack(&[0; 10], None)?;
+        assert_eq!(0, packed.bits_per_value());
+        assert_eq!(alloc::vec![0; 10], unpack(&packed));
+
+        let packed = bitpack(&[], None)?;
+        assert!(packed.is_empty());
+        assert!(unpack(&packed).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    pub fn invalid() {
+        assert_eq!(
+            Some(BitPackError::ValueTooLarge {
+                index: 2,
+                bits_per_value: 3
+            }),
+            bitpack(&[1, 7, 8], Some(3)).err()
+        );
+        assert_eq!(
+            Some(BitPackError::InvalidWidth { bits_per_value: 33 }),
+            bitpack(&[1], Some(33)).err()
+        );
+        assert_eq!(
+            Some(BitPackError::Truncated {
+                expected: 4,
+                actual: 3
+            }),
+            BitPacked::from_parts(3, 10, 0, alloc::vec![0; 3]).err()
+        );
+    }
+}
@@ -7,7 +7,26 @@
 //! encoding formats
 //!
 
+pub use varint::*;
+
+pub mod varint;
 pub mod vbyte;
+crate::cfg_feature_alloc! {
+    pub use base32::*;
+    pub mod base32;
+}
+crate::cfg_feature_alloc! {
+    pub use base85::*;
+    pub mod base85;
+}
+crate::cfg_feature_alloc! {
+    pub use bitpack::*;
+    pub mod bitpack;
+}
+crate::cfg_feature_alloc! {
+    pub use quoted_printable::*;
+    pub mod quoted_printable;
+}
 
 crate::cfg_feature_alloc! {
     use alloc::string::{String, ToString};
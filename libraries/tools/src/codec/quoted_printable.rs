@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! RFC-2045 "Quoted-Printable" Content-Transfer-Encoding
+//!
+
+extern crate alloc;
+use crate::hex::hex_char_to_nibble;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use irox_bits::{Error, ErrorKind};
+
+/// Maximum length of an encoded line, not including the line break.
+const MAX_LINE_LEN: usize = 76;
+
+static HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+///
+/// Error returned when decoding an invalid quoted-printable input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct QuotedPrintableError {
+    offset: usize,
+}
+
+impl QuotedPrintableError {
+    /// Returns the offset in the input of the `=` that started the invalid escape sequence
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for QuotedPrintableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Invalid quoted-printable escape at offset {}",
+            self.offset
+        )
+    }
+}
+
+impl From<QuotedPrintableError> for Error {
+    fn from(_value: QuotedPrintableError) -> Self {
+        ErrorKind::InvalidData.into()
+    }
+}
+
+/// Appends the encoded line to the output, wrapping it with soft line breaks as needed.
+fn encode_line(line: &[u8], out: &mut String) {
+    let mut line_len = 0;
+    let mut iter = line.iter().peekable();
+    while let Some(v) = iter.next() {
+        let is_last = iter.peek().is_none();
+        let literal = match *v {
+            b'=' => false,
+            // trailing whitespace would be stripped in transport, so it must be escaped.
+            b' ' | b'\t' => !is_last,
+            33..=126 => true,
+            _ => false,
+        };
+        let token_len = if literal { 1 } else { 3 };
+        // leave room for the trailing '=' of the soft break, unless this is the end of the line.
+        let limit = if is_last {
+            MAX_LINE_LEN
+        } else {
+            MAX_LINE_LEN - 1
+        };
+        if line_len + token_len > limit {
+            out.push_str("=\r\n");
+            line_len = 0;
+        }
+        if literal {
+            out.push(*v as char);
+        } else {
+            out.push('=');
+            for nibble in [v >> 4, v & 0xF] {
+                let ch = HEX_UPPER.get(nibble as usize).copied().unwrap_or_default();
+                out.push(ch as char);
+            }
+        }
+        line_len += token_len;
+    }
+}
+
+///
+/// Encodes the input as quoted-printable text.  Line breaks (`\n` or `\r\n`) in the input are
+/// preserved as `\r\n` hard line breaks, and lines longer than 76 characters are wrapped with
+/// `=\r\n` soft line breaks.  Bytes outside of the printable ASCII range, `=`, and whitespace at
+/// the end of a line are encoded as `=XX` escapes.
+#[must_use]
+pub fn quoted_printable_encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut lines = input.split(|v| *v == b'\n').peekable();
+    while let Some(line) = lines.next() {
+        let has_break = lines.peek().is_some();
+        let line = match line.strip_suffix(b"\r") {
+            Some(stripped) if has_break => stripped,
+            _ => line,
+        };
+        encode_line(line, &mut out);
+        if has_break {
+            out.push_str("\r\n");
+        }
+    }
+    out
+}
+
+///
+/// Decodes the quoted-printable input.  Soft line breaks (`=\r\n` or `=\n`) are removed, `=XX`
+/// escapes are replaced by the byte they represent (either case hex digits are accepted), and
+/// whitespace at the end of a line is dropped.  Returns an error with the offset of the `=` if
+/// it's not followed by a line break or two hex digits.
+pub fn quoted_printable_decode(input: &[u8]) -> Result<Vec<u8>, QuotedPrintableError> {
+    let mut out: Vec<u8> = Vec::with_capacity(input.len());
+    // length of the output before any trailing whitespace on the current line.
+    let mut content_len = 0;
+    let mut idx = 0;
+    while let Some(v) = input.get(idx) {
+        match *v {
+            b'=' => {
+                // whitespace before an escape or soft line break isn't trailing.
+                content_len = out.len();
+                let rest = input.get(idx + 1..).unwrap_or_default();
+                // soft line break, possibly with transport padding before it.
+                let pad = rest
+                    .iter()
+                    .take_while(|v| **v == b' ' || **v == b'\t')
+                    .count();
+                let after_pad = rest.get(pad..).unwrap_or_default();
+                if after_pad.starts_with(b"\r\n") {
+                    idx += 1 + pad + 2;
+                    continue;
+                } else if after_pad.starts_with(b"\n") {
+                    idx += 1 + pad + 1;
+                    continue;
+                }
+                let (Some(hi), Some(lo)) = (rest.first(), rest.get(1)) else {
+                    return Err(QuotedPrintableError { offset: idx });
+                };
+                let (Ok(hi), Ok(lo)) = (
+                    hex_char_to_nibble(*hi as char),
+                    hex_char_to_nibble(*lo as char),
+                ) else {
+                    return Err(QuotedPrintableError { offset: idx });
+                };
+                out.push(hi << 4 | lo);
+                content_len = out.len();
+                idx += 3;
+            }
+            b'\r' if input.get(idx + 1) == Some(&b'\n') => {
+                out.truncate(content_len);
+                out.extend_from_slice(b"\r\n");
+                content_len = out.len();
+                idx += 2;
+            }
+            b'\n' => {
+                out.truncate(content_len);
+                out.push(b'\n');
+                content_len = out.len();
+                idx += 1;
+            }
+            b' ' | b'\t' => {
+                out.push(*v);
+                idx += 1;
+            }
+            _ => {
+                out.push(*v);
+                content_len = out.len();
+                idx += 1;
+            }
+        }
+    }
+    out.truncate(content_len);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codec::{quoted_printable_decode, quoted_printable_encode, QuotedPrintableError};
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn rfc2045_soft_breaks() -> Result<(), QuotedPrintableError> {
+        // RFC-2045, Section 6.7, Rule #5
+        let encoded = b"Now's the time =\r\nfor all folk to come=\r\n to the aid of their country.";
+        assert_eq!(
+            b"Now's the time for all folk to come to the aid of their country.".as_slice(),
+            quoted_printable_decode(encoded)?
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn escapes() -> Result<(), QuotedPrintableError> {
+        let decoded =
+            "J'interdis aux marchands de vanter trop leurs marchandises. Car ils se font \
+            vite p\u{e9}dagogues et t'enseignent comme but ce qui n'est par essence qu'un moyen, \
+            et en vous trompant ainsi sur la route \u{e0} suivre les voil\u{e0} bient\u{f4}t qui vous d\u{e9}gradent, \
+            car si leur musique est vulgaire ils vous fabriquent pour vous la vendre une \u{e2}me \
+            vulgaire.";
+        // as published, with 77 character lines.
+        let published =
+            "J'interdis aux marchands de vanter trop leurs marchandises. Car ils se font =\r\n\
+            vite p=C3=A9dagogues et t'enseignent comme but ce qui n'est par essence qu'u=\r\n\
+            n moyen, et en vous trompant ainsi sur la route =C3=A0 suivre les voil=C3=\r\n\
+            =A0 bient=C3=B4t qui vous d=C3=A9gradent, car si leur musique est vulgaire i=\r\n\
+            ls vous fabriquent pour vous la vendre une =C3=A2me vulgaire.";
+        assert_eq!(
+            decoded.as_bytes(),
+            quoted_printable_decode(published.as_bytes())?
+        );
+
+        let encoded =
+            "J'interdis aux marchands de vanter trop leurs marchandises. Car ils se font=\r\n \
+            vite p=C3=A9dagogues et t'enseignent comme but ce qui n'est par essence qu=\r\n\
+            'un moyen, et en vous trompant ainsi sur la route =C3=A0 suivre les voil=C3=\r\n\
+            =A0 bient=C3=B4t qui vous d=C3=A9gradent, car si leur musique est vulgaire =\r\n\
+            ils vous fabriquent pour vous la vendre une =C3=A2me vulgaire.";
+        assert_eq!(encoded, quoted_printable_encode(decoded.as_bytes()));
+        assert!(encoded.split("\r\n").all(|line| line.len() <= 76));
+        assert_eq!(
+            decoded.as_bytes(),
+            quoted_printable_decode(encoded.as_bytes())?
+        );
+
+        assert_eq!("1+1=3D2", quoted_printable_encode(b"1+1=2"));
+        assert_eq!(b"1+1=2".as_slice(), quoted_printable_decode(b"1+1=3d2")?);
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn line_breaks_and_whitespace() -> Result<(), QuotedPrintableError> {
+        assert_eq!(
+            "trailing=20\r\n\ttab=09\r\nend",
+            quoted_printable_encode(b"trailing \n\ttab\t\r\nend")
+        );
+        assert_eq!(
+            b"trailing \r\n\ttab\t\r\nend".as_slice(),
+            quoted_printable_decode(b"trailing=20\r\n\ttab=09  \r\nend")?
+        );
+        // transport padding is dropped
+        assert_eq!(
+            b"padded\r\nline".as_slice(),
+            quoted_printable_decode(b"padded \t\r\nline")?
+        );
+        assert_eq!(b"soft".as_slice(), quoted_printable_decode(b"so=  \r\nft")?);
+        Ok(())
+    }
+
+    #[test]
+    pub fn invalid_escapes() {
+        assert_eq!(
+            Err(QuotedPrintableError { offset: 4 }),
+            quoted_printable_decode(b"bad =ZZ escape")
+        );
+        assert_eq!(
+            Err(QuotedPrintableError { offset: 5 }),
+            quoted_printable_decode(b"short=A")
+        );
+        assert_eq!(
+            Err(QuotedPrintableError { offset: 3 }),
+            quoted_printable_decode(b"end=")
+        );
+    }
+}
@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! ZigZag and LEB128-style variable-length integer encodings, and a delta encoder for signed
+//! integer series built on top of them.
+//!
+
+use irox_bits::{Bits, Error, ErrorKind, MutBits};
+
+/// Maximum number of bytes a varint-encoded `u64` can occupy.
+pub const MAX_VARINT_U64_LEN: usize = 10;
+
+///
+/// Maps a signed integer onto an unsigned integer so that values with a small magnitude (positive
+/// or negative) have a small encoding: `0 -> 0, -1 -> 1, 1 -> 2, -2 -> 3, ...`
+pub const fn zigzag_encode_i64(val: i64) -> u64 {
+    ((val << 1) ^ (val >> 63)) as u64
+}
+
+///
+/// Reverses [`zigzag_encode_i64`]
+pub const fn zigzag_decode_i64(val: u64) -> i64 {
+    ((val >> 1) as i64) ^ -((val & 0x1) as i64)
+}
+
+///
+/// Writes the value out in the little-endian base-128 format, 7 bits per byte, with the high bit
+/// of each byte set if more bytes follow.  Returns the number of bytes written.
+pub fn encode_varint_u64<O: MutBits>(mut val: u64, output: &mut O) -> Result<usize, Error> {
+    let mut written = 0;
+    loop {
+        let byte = (val & 0x7F) as u8;
+        val >>= 7;
+        written += 1;
+        if val == 0 {
+            output.write_u8(byte)?;
+            return Ok(written);
+        }
+        output.write_u8(byte | 0x80)?;
+    }
+}
+
+///
+/// Reads a single value encoded by [`encode_varint_u64`].  Returns `Ok(None)` if the input was
+/// exhausted before the first byte, an [`ErrorKind::UnexpectedEof`] error if the input ended
+/// partway through a value, and an [`ErrorKind::InvalidData`] error if the value doesn't fit in
+/// 64 bits.
+pub fn decode_varint_u64<I: Bits>(input: &mut I) -> Result<Option<u64>, Error> {
+    let Some(first) = input.next_u8()? else {
+        return Ok(None);
+    };
+    let mut val = (first & 0x7F) as u64;
+    let mut byte = first;
+    let mut shift = 7;
+    while byte & 0x80 != 0 {
+        let Some(next) = input.next_u8()? else {
+            return ErrorKind::UnexpectedEof.err("EOF in the middle of a varint");
+        };
+        byte = next;
+        // the tenth byte only has room for the top bit, and can't be continued.
+        if shift == 63 && byte > 1 {
+            return ErrorKind::InvalidData.err("Varint exceeds 64 bits");
+        }
+        val |= ((byte & 0x7F) as u64) << shift;
+        shift += 7;
+    }
+    Ok(Some(val))
+}
+
+///
+/// ZigZag encodes the value, then writes it out with [`encode_varint_u64`]
+pub fn encode_varint_i64<O: MutBits>(val: i64, output: &mut O) -> Result<usize, Error> {
+    encode_varint_u64(zigzag_encode_i64(val), output)
+}
+
+///
+/// Reads a single value encoded by [`encode_varint_i64`]
+pub fn decode_varint_i64<I: Bits>(input: &mut I) -> Result<Option<i64>, Error> {
+    Ok(decode_varint_u64(input)?.map(zigzag_decode_i64))
+}
+
+crate::cfg_feature_alloc! {
+    ///
+    /// Delta-encodes the series (the first value is relative to zero), then writes each delta as a
+    /// zigzag varint.  Series that change slowly, like timestamps, encode to 1 or 2 bytes a value.
+    pub fn encode_signed_deltas(values: &[i64]) -> alloc::vec::Vec<u8> {
+        let mut out = alloc::vec::Vec::with_capacity(values.len());
+        let mut last = 0i64;
+        for val in values {
+            // writing into a vec is infallible.
+            let _ = encode_varint_i64(val.wrapping_sub(last), &mut out);
+            last = *val;
+        }
+        out
+    }
+}
+
+crate::cfg_feature_alloc! {
+    ///
+    /// Reverses [`encode_signed_deltas`]
+    pub fn decode_signed_deltas(mut data: &[u8]) -> Result<alloc::vec::Vec<i64>, Error> {
+        let mut out = alloc::vec::Vec::new();
+        let mut last = 0i64;
+        while let Some(delta) = decode_varint_i64(&mut data)? {
+            last = last.wrapping_add(delta);
+            out.push(last);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use crate::codec::{
+        decode_signed_deltas, decode_varint_u64, encode_signed_deltas, encode_varint_u64,
+        zigzag_decode_i64, zigzag_encode_i64,
+    };
+    use alloc::vec::Vec;
+    use irox_bits::{Error, ErrorKind};
+
+    #[test]
+    pub fn zigzag_reference() {
+        // reference values from the protobuf sint64 encoding documentation
+        let tests: [(i64, u64); 8] = [
+            (0, 0),
+            (-1, 1),
+            (1, 2),
+            (-2, 3),
+            (2147483647, 4294967294),
+            (-2147483648, 4294967295),
+            (i64::MAX, u64::MAX - 1),
+            (i64::MIN, u64::MAX),
+        ];
+        for (i, o) in tests {
+            assert_eq!(o, zigzag_encode_i64(i));
+            assert_eq!(i, zigzag_decode_i64(o));
+        }
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn varint_reference() -> Result<(), Error> {
+        let tests: [(u64, &[u8]); 5] = [
+            (0, &[0x00]),
+            (1, &[0x01]),
+            (127, &[0x7F]),
+            (300, &[0xAC, 0x02]),
+            (
+                u64::MAX,
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            ),
+        ];
+        for (i, o) in tests {
+            let mut buf = Vec::new();
+            assert_eq!(o.len(), encode_varint_u64(i, &mut buf)?);
+            assert_eq!(o, buf.as_slice());
+            let mut rd = o;
+            assert_eq!(Some(i), decode_varint_u64(&mut rd)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    pub fn truncated_varint() {
+        let mut data: &[u8] = &[0xAC];
+        let res = decode_varint_u64(&mut data);
+        assert_eq!(Some(ErrorKind::UnexpectedEof), res.err().map(|e| e.kind()));
+    }
+
+    #[test]
+    pub fn overlong_varint() {
+        let tests: [&[u8]; 3] = [
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F],
+            &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02],
+            &[
+                0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x81, 0x00,
+            ],
+        ];
+        for mut data in tests {
+            let res = decode_varint_u64(&mut data);
+            assert_eq!(Some(ErrorKind::InvalidData), res.err().map(|e| e.kind()));
+        }
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn deltas_round_trip() -> Result<(), Error> {
+        let tests: [&[i64]; 6] = [
+            &[],
+            &[42],
+            &[-42],
+            &[
+                1_700_000_000_000,
+                1_700_000_000_100,
+                1_700_000_000_050,
+                1_700_000_000_200,
+            ],
+            &[5, 4, 3, -3, -4, -5, 0],
+            &[i64::MIN, i64::MAX, 0, i64::MAX, i64::MIN],
+        ];
+        for test in tests {
+            let enc = encode_signed_deltas(test);
+            assert_eq!(test, decode_signed_deltas(&enc)?.as_slice());
+        }
+        Ok(())
+    }
+
+    #[test]
+    pub fn deltas_compact() {
+        let series: Vec<i64> = (0..1000).map(|v| 1_700_000_000_000 + v * 10).collect();
+        let enc = encode_signed_deltas(&series);
+        // 6 bytes for the first value, one byte each after that.
+        assert_eq!(6 + 999, enc.len());
+    }
+}
@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! The [`flags!`](crate::flags) macro, for typed sets of bit flags.
+
+///
+/// Generates a struct wrapping an integer, with named `const` flag values and the set-style
+/// operations to manipulate them.  The generated struct is `Copy` and implements the bitwise
+/// operators (`|`, `&`, `^`, `!`, `-`) and their assignment variants, as well as a [`Debug`]
+/// impl that lists the names of the set flags.
+///
+/// Bits that don't correspond to a named flag are retained, and printed in hex by `Debug`.
+///
+/// # Example
+/// ```
+/// irox_tools::flags! {
+///     /// Which solution components are valid
+///     pub struct NavValid: u16 {
+///         const GPS_FIX = 0x01;
+///         const DGPS = 0x02;
+///         const ALTITUDE = 0x04;
+///     }
+/// }
+///
+/// let mut valid = NavValid::GPS_FIX | NavValid::ALTITUDE;
+/// assert!(valid.contains(NavValid::GPS_FIX));
+/// assert!(!valid.contains(NavValid::DGPS));
+///
+/// valid.insert(NavValid::DGPS);
+/// valid.remove(NavValid::GPS_FIX);
+/// assert_eq!(0x06, valid.bits());
+/// assert_eq!("NavValid(DGPS | ALTITUDE)", format!("{valid:?}"));
+/// ```
+#[macro_export]
+macro_rules! flags {
+    (
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident: $ty:ty {
+            $(
+                $(#[$inner:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$outer])*
+        #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name($ty);
+
+        #[allow(dead_code)]
+        impl $name {
+            $(
+                $(#[$inner])*
+                pub const $flag: $name = $name($value);
+            )*
+
+            /// All of the named flags, and their names
+            pub const FLAGS: &'static [(&'static str, $name)] = &[
+                $((stringify!($flag), $name::$flag),)*
+            ];
+
+            /// Returns a set with no flags set
+            #[must_use]
+            pub const fn empty() -> $name {
+                $name(0)
+            }
+
+            /// Returns a set with all of the named flags set
+            #[must_use]
+            pub const fn all() -> $name {
+                $name(0 $(| $value)*)
+            }
+
+            /// Creates a set from the raw value, retaining any unnamed bits
+            #[must_use]
+            pub const fn from_bits_retain(bits: $ty) -> $name {
+                $name(bits)
+            }
+
+            /// Creates a set from the raw value, dropping any unnamed bits
+            #[must_use]
+            pub const fn from_bits_truncate(bits: $ty) -> $name {
+                $name(bits & $name::all().0)
+            }
+
+            /// Returns the raw value of this set
+            #[must_use]
+            pub const fn bits(&self) -> $ty {
+                self.0
+            }
+
+            /// Returns true if no flags are set
+            #[must_use]
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// Returns true if all of the flags in `other` are set in this set
+            #[must_use]
+            pub const fn contains(&self, other: $name) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Returns true if any of the flags in `other` are set in this set
+            #[must_use]
+            pub const fn intersects(&self, other: $name) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            /// Sets all of the flags in `other`
+            pub fn insert(&mut self, other: $name) {
+                self.0 |= other.0;
+            }
+
+            /// Clears all of the flags in `other`
+            pub fn remove(&mut self, other: $name) {
+                self.0 &= !other.0;
+            }
+
+            /// Flips all of the flags in `other`
+            pub fn toggle(&mut self, other: $name) {
+                self.0 ^= other.0;
+            }
+
+            /// Sets or clears all of the flags in `other`
+            pub fn set(&mut self, other: $name, value: bool) {
+                if value {
+                    self.insert(other);
+                } else {
+                    self.remove(other);
+                }
+            }
+        }
+
+        impl ::core::ops::BitOr for $name {
+            type Output = $name;
+            fn bitor(self, rhs: $name) -> $name {
+                $name(self.0 | rhs.0)
+            }
+        }
+        impl ::core::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: $name) {
+                self.0 |= rhs.0;
+            }
+        }
+        impl ::core::ops::BitAnd for $name {
+            type Output = $name;
+            fn bitand(self, rhs: $name) -> $name {
+                $name(self.0 & rhs.0)
+            }
+        }
+        impl ::core::ops::BitAndAssign for $name {
+            fn bitand_assign(&mut self, rhs: $name) {
+                self.0 &= rhs.0;
+            }
+        }
+        impl ::core::ops::BitXor for $name {
+            type Output = $name;
+            fn bitxor(self, rhs: $name) -> $name {
+                $name(self.0 ^ rhs.0)
+            }
+        }
+        impl ::core::ops::BitXorAssign for $name {
+            fn bitxor_assign(&mut self, rhs: $name) {
+                self.0 ^= rhs.0;
+            }
+        }
+        impl ::core::ops::Sub for $name {
+            type Output = $name;
+            fn sub(self, rhs: $name) -> $name {
+                $name(self.0 & !rhs.0)
+            }
+        }
+        impl ::core::ops::SubAssign for $name {
+            fn sub_assign(&mut self, rhs: $name) {
+                self.0 &= !rhs.0;
+            }
+        }
+        impl ::core::ops::Not for $name {
+            type Output = $name;
+            /// Returns the complement of the named flags
+            fn not(self) -> $name {
+                $name(!self.0 & $name::all().0)
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(stringify!($name))?;
+                f.write_str("(")?;
+                if self.is_empty() {
+                    f.write_str("0x0")?;
+                }
+                let mut remaining = self.0;
+                let mut first = true;
+                for (name, flag) in $name::FLAGS {
+                    if flag.0 == 0 || !self.contains(*flag) || remaining & flag.0 == 0 {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    first = false;
+                    f.write_str(name)?;
+                    remaining &= !flag.0;
+                }
+                if remaining != 0 {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{remaining:#x}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use alloc::format;
+
+    crate::flags! {
+        /// Test flags
+        pub struct Power: u8 {
+            const TRICKLE = 0x01;
+            const PUSH_TO_FIX = 0x02;
+            /// Multiple bits
+            const LOW_POWER = 0x0C;
+        }
+    }
+
+    #[test]
+    pub fn set_operations() {
+        let mut flags = Power::empty();
+        assert!(flags.is_empty());
+        assert!(!flags.intersects(Power::TRICKLE));
+
+        flags.insert(Power::TRICKLE);
+        assert!(flags.contains(Power::TRICKLE));
+        assert!(!flags.contains(Power::TRICKLE | Power::PUSH_TO_FIX));
+        assert!(flags.intersects(Power::TRICKLE | Power::PUSH_TO_FIX));
+
+        flags.set(Power::LOW_POWER, true);
+        assert_eq!(0x0D, flags.bits());
+        flags.remove(Power::TRICKLE);
+        assert_eq!(Power::LOW_POWER, flags);
+        flags.toggle(Power::LOW_POWER | Power::PUSH_TO_FIX);
+        assert_eq!(Power::PUSH_TO_FIX, flags);
+        flags.set(Power::PUSH_TO_FIX, false);
+        assert!(flags.is_empty());
+
+        assert_eq!(0x0F, Power::all().bits());
+        assert_eq!(Power::all(), Power::from_bits_truncate(0xFF));
+        assert_eq!(0xFF, Power::from_bits_retain(0xFF).bits());
+    }
+
+    #[test]
+    pub fn operators() {
+        let a = Power::TRICKLE | Power::PUSH_TO_FIX;
+        let b = Power::PUSH_TO_FIX | Power::LOW_POWER;
+        assert_eq!(Power::PUSH_TO_FIX, a & b);
+        assert_eq!(Power::all(), a | b);
+        assert_eq!(Power::TRICKLE | Power::LOW_POWER, a ^ b);
+        assert_eq!(Power::TRICKLE, a - b);
+        assert_eq!(Power::LOW_POWER, !a);
+
+        let mut c = a;
+        c |= Power::LOW_POWER;
+        assert_eq!(Power::all(), c);
+        c &= b;
+        assert_eq!(b, c);
+        c ^= Power::PUSH_TO_FIX;
+        assert_eq!(Power::LOW_POWER, c);
+        c -= Power::LOW_POWER;
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    pub fn debug_lists_flags() {
+        assert_eq!("Power(0x0)", format!("{:?}", Power::empty()));
+        assert_eq!("Power(TRICKLE)", format!("{:?}", Power::TRICKLE));
+        assert_eq!(
+            "Power(TRICKLE | LOW_POWER)",
+            format!("{:?}", Power::TRICKLE | Power::LOW_POWER)
+        );
+        assert_eq!(
+            "Power(PUSH_TO_FIX | 0x80)",
+            format!("{:?}", Power::from_bits_retain(0x82))
+        );
+        // partially set multi-bit flags aren't named
+        assert_eq!("Power(0x4)", format!("{:?}", Power::from_bits_retain(0x04)));
+    }
+}
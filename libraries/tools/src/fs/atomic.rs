@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Crash-safe replacement of a file's contents.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use irox_bits::WriteBits;
+use std::fs::{File, OpenOptions};
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// Distinguishes temporary files created by this process at the same time.
+static TEMP_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+///
+/// Creates a new, uniquely named, hidden temporary file next to the target, so it's on the same
+/// filesystem and can be renamed over it.
+fn create_temp(path: &Path) -> Result<(File, PathBuf), Error> {
+    let Some(name) = path.file_name() else {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "Path doesn't have a file name",
+        ));
+    };
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    loop {
+        let mut temp_name = std::ffi::OsString::from(".");
+        temp_name.push(name);
+        temp_name.push(format!(
+            ".{}.{}.tmp",
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let temp = dir.join(temp_name);
+        match OpenOptions::new().write(true).create_new(true).open(&temp) {
+            Ok(file) => return Ok((file, temp)),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+///
+/// Clears the read-only attribute of the file, which otherwise prevents Windows from removing it.
+#[cfg(windows)]
+fn clear_readonly(path: &Path) {
+    if let Ok(meta) = std::fs::metadata(path) {
+        let mut permissions = meta.permissions();
+        if permissions.readonly() {
+            // only the windows attribute, not the unix permission bits the lint warns about.
+            #[allow(clippy::permissions_set_readonly_false)]
+            permissions.set_readonly(false);
+            let _ = std::fs::set_permissions(path, permissions);
+        }
+    }
+}
+
+///
+/// Renames the temporary file over the target.  On Windows, the rename fails if the target can't
+/// be replaced in place (for example, it's read-only), so the target's read-only attribute is
+/// cleared, the target is removed and the rename retried - leaving a brief window where the
+/// target doesn't exist, but never a partial file.  The new file has the original's permissions,
+/// so it's read-only again afterwards.
+fn replace(temp: &Path, path: &Path) -> Result<(), Error> {
+    match std::fs::rename(temp, path) {
+        Err(e) if cfg!(windows) && path.exists() => {
+            #[cfg(windows)]
+            clear_readonly(path);
+            std::fs::remove_file(path).map_err(|_| e)?;
+            std::fs::rename(temp, path)
+        }
+        res => res,
+    }
+}
+
+///
+/// Atomically replaces the contents of the file at the path with the provided bytes.  See
+/// [`write_atomic_with`].
+pub fn write_atomic<T: AsRef<Path>>(path: T, bytes: &[u8]) -> Result<(), Error> {
+    write_atomic_with(path, |out| {
+        use irox_bits::MutBits;
+        out.write_all_bytes(bytes)
+    })
+}
+
+///
+/// Atomically replaces the contents of the file at the path with the data written by `func`.
+///
+/// The data is written to a temporary file in the same directory, flushed to disk, and then
+/// renamed over the target - so a crash or error part-way through leaves either the original
+/// file or the complete new file, never a truncated one.  If `func` returns an error, the
+/// temporary file is removed and the target is untouched.  The permissions of an existing target
+/// are kept.
+///
+/// # Example
+/// ```no_run
+/// use irox_bits::MutBits;
+/// use irox_tools::fs::write_atomic_with;
+///
+/// write_atomic_with("state.txt", |out| {
+///     for line in ["first", "second"] {
+///         out.write_all_bytes(line.as_bytes())?;
+///         out.write_u8(b'\n')?;
+///     }
+///     Ok(())
+/// })?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write_atomic_with<T, F>(path: T, func: F) -> Result<(), Error>
+where
+    T: AsRef<Path>,
+    F: FnOnce(&mut WriteBits<&mut File>) -> Result<(), irox_bits::Error>,
+{
+    let path = path.as_ref();
+    let (mut file, temp) = create_temp(path)?;
+    let res = (|| -> Result<(), Error> {
+        {
+            let mut out = WriteBits::new(&mut file);
+            func(&mut out)?;
+            out.flush()?;
+        }
+        if let Ok(meta) = std::fs::metadata(path) {
+            file.set_permissions(meta.permissions())?;
+        }
+        file.sync_all()?;
+        drop(file);
+        replace(&temp, path)
+    })();
+    if let Err(e) = res {
+        let _ = std::fs::remove_file(&temp);
+        return Err(e);
+    }
+    // make the rename itself durable.
+    #[cfg(unix)]
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        if let Ok(dir) = File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fs::{write_atomic, write_atomic_with};
+    use irox_bits::{ErrorKind, MutBits};
+    use std::io::Error;
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn replaces_contents() -> Result<(), Error> {
+        let dir = std::env::temp_dir().join(format!("irox-atomic-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("state.txt");
+
+        write_atomic(&path, b"first version, quite long")?;
+        assert_eq!("first version, quite long", std::fs::read_to_string(&path)?);
+        write_atomic(&path, b"second")?;
+        assert_eq!("second", std::fs::read_to_string(&path)?);
+
+        write_atomic_with(&path, |out| {
+            for i in 0..1000 {
+                out.write_all_bytes(format!("{i}\n").as_bytes())?;
+            }
+            Ok(())
+        })?;
+        let contents = std::fs::read_to_string(&path)?;
+        assert_eq!(1000, contents.lines().count());
+        assert_eq!(Some("999"), contents.lines().last());
+
+        // a failure part-way through leaves the original file alone
+        let res = write_atomic_with(&path, |out| {
+            out.write_all_bytes(b"partial")?;
+            ErrorKind::Other.err("failed")
+        });
+        assert!(res.is_err());
+        assert_eq!(contents, std::fs::read_to_string(&path)?);
+
+        // and no temporary files are left behind
+        assert_eq!(1, std::fs::read_dir(&dir)?.count());
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Resolution of the standard per-user application directories, see [`app_dir`].
+
+use std::ffi::OsString;
+use std::io::{Error, ErrorKind};
+use std::path::PathBuf;
+
+///
+/// The kind of application directory to resolve
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AppDirKind {
+    /// User configuration files
+    Config,
+    /// Non-essential cached data, that can be safely deleted
+    Cache,
+    /// User-specific data files
+    Data,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Platform {
+    Xdg,
+    MacOS,
+    Windows,
+}
+
+impl Platform {
+    const fn current() -> Platform {
+        if cfg!(target_os = "windows") {
+            Platform::Windows
+        } else if cfg!(target_os = "macos") {
+            Platform::MacOS
+        } else {
+            Platform::Xdg
+        }
+    }
+}
+
+/// Returns the value of the environment variable, if it's set to an absolute path.
+fn env_path(env: &dyn Fn(&str) -> Option<OsString>, name: &str) -> Option<PathBuf> {
+    let path = PathBuf::from(env(name)?);
+    path.is_absolute().then_some(path)
+}
+
+fn base_dir(
+    kind: AppDirKind,
+    platform: Platform,
+    env: &dyn Fn(&str) -> Option<OsString>,
+) -> Result<PathBuf, Error> {
+    let (var, home_fallback) = match (platform, kind) {
+        (Platform::Xdg, AppDirKind::Config) => ("XDG_CONFIG_HOME", Some(".config")),
+        (Platform::Xdg, AppDirKind::Cache) => ("XDG_CACHE_HOME", Some(".cache")),
+        (Platform::Xdg, AppDirKind::Data) => ("XDG_DATA_HOME", Some(".local/share")),
+        (Platform::MacOS, AppDirKind::Cache) => ("HOME", Some("Library/Caches")),
+        (Platform::MacOS, _) => ("HOME", Some("Library/Application Support")),
+        (Platform::Windows, AppDirKind::Cache) => ("LOCALAPPDATA", None),
+        (Platform::Windows, _) => ("APPDATA", None),
+    };
+    if platform != Platform::MacOS {
+        if let Some(path) = env_path(env, var) {
+            return Ok(path);
+        }
+    }
+    if let Some(fallback) = home_fallback {
+        if let Some(home) = env_path(env, "HOME") {
+            return Ok(home.join(fallback));
+        }
+    }
+    Err(Error::new(
+        ErrorKind::NotFound,
+        format!("Unable to determine the {kind:?} directory, {var} is not set to an absolute path"),
+    ))
+}
+
+fn app_dir_from(
+    kind: AppDirKind,
+    app_name: &str,
+    create: bool,
+    platform: Platform,
+    env: &dyn Fn(&str) -> Option<OsString>,
+) -> Result<PathBuf, Error> {
+    if app_name.is_empty() || app_name == "." || app_name == ".." || app_name.contains(['/', '\\'])
+    {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "Application name must be a single path component",
+        ));
+    }
+    let path = base_dir(kind, platform, env)?.join(app_name);
+    if create {
+        std::fs::create_dir_all(&path)?;
+    }
+    Ok(path)
+}
+
+///
+/// Returns the standard directory of the provided kind for the application on the current
+/// platform, the `app_name` directory within:
+///
+/// | Kind   | Linux & other Unix                       | macOS                               | Windows          |
+/// |--------|------------------------------------------|-------------------------------------|------------------|
+/// | Config | `$XDG_CONFIG_HOME` or `$HOME/.config`    | `$HOME/Library/Application Support` | `%APPDATA%`      |
+/// | Cache  | `$XDG_CACHE_HOME` or `$HOME/.cache`      | `$HOME/Library/Caches`              | `%LOCALAPPDATA%` |
+/// | Data   | `$XDG_DATA_HOME` or `$HOME/.local/share` | `$HOME/Library/Application Support` | `%APPDATA%`      |
+///
+/// If `create` is true, the directory (and any missing parents) will be created.  Returns an
+/// error if the base directory can't be determined from the environment, the application name
+/// isn't a valid filename, or the directory can't be created.
+pub fn app_dir(kind: AppDirKind, app_name: &str, create: bool) -> Result<PathBuf, Error> {
+    app_dir_from(kind, app_name, create, Platform::current(), &|name| {
+        std::env::var_os(name)
+    })
+}
+
+///
+/// Returns the configuration directory for the application.  See [`app_dir`]
+pub fn config_dir(app_name: &str, create: bool) -> Result<PathBuf, Error> {
+    app_dir(AppDirKind::Config, app_name, create)
+}
+
+///
+/// Returns the cache directory for the application.  See [`app_dir`]
+pub fn cache_dir(app_name: &str, create: bool) -> Result<PathBuf, Error> {
+    app_dir(AppDirKind::Cache, app_name, create)
+}
+
+///
+/// Returns the data directory for the application.  See [`app_dir`]
+pub fn data_dir(app_name: &str, create: bool) -> Result<PathBuf, Error> {
+    app_dir(AppDirKind::Data, app_name, create)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{app_dir_from, AppDirKind, Platform};
+    use std::ffi::OsString;
+    use std::io::{Error, ErrorKind};
+    use std::path::PathBuf;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<OsString> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| OsString::from(v))
+        }
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    #[cfg(unix)]
+    pub fn xdg_dirs() -> Result<(), Error> {
+        let home = env(&[("HOME", "/home/user")]);
+        let resolve = |kind, env: &dyn Fn(&str) -> Option<OsString>| {
+            app_dir_from(kind, "irox", false, Platform::Xdg, env)
+        };
+        assert_eq!(
+            PathBuf::from("/home/user/.config/irox"),
+            resolve(AppDirKind::Config, &home)?
+        );
+        assert_eq!(
+            PathBuf::from("/home/user/.cache/irox"),
+            resolve(AppDirKind::Cache, &home)?
+        );
+        assert_eq!(
+            PathBuf::from("/home/user/.local/share/irox"),
+            resolve(AppDirKind::Data, &home)?
+        );
+
+        let xdg = env(&[
+            ("HOME", "/home/user"),
+            ("XDG_CONFIG_HOME", "/etc/xdg"),
+            ("XDG_CACHE_HOME", "relative/is/ignored"),
+            ("XDG_DATA_HOME", "/srv/data"),
+        ]);
+        assert_eq!(
+            PathBuf::from("/etc/xdg/irox"),
+            resolve(AppDirKind::Config, &xdg)?
+        );
+        assert_eq!(
+            PathBuf::from("/home/user/.cache/irox"),
+            resolve(AppDirKind::Cache, &xdg)?
+        );
+        assert_eq!(
+            PathBuf::from("/srv/data/irox"),
+            resolve(AppDirKind::Data, &xdg)?
+        );
+
+        let empty = env(&[]);
+        assert_eq!(
+            Some(ErrorKind::NotFound),
+            resolve(AppDirKind::Config, &empty).err().map(|e| e.kind())
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    #[cfg(unix)]
+    pub fn macos_dirs() -> Result<(), Error> {
+        let vars = env(&[("HOME", "/Users/user"), ("XDG_CONFIG_HOME", "/ignored")]);
+        assert_eq!(
+            PathBuf::from("/Users/user/Library/Application Support/irox"),
+            app_dir_from(AppDirKind::Config, "irox", false, Platform::MacOS, &vars)?
+        );
+        assert_eq!(
+            PathBuf::from("/Users/user/Library/Caches/irox"),
+            app_dir_from(AppDirKind::Cache, "irox", false, Platform::MacOS, &vars)?
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    #[cfg(unix)]
+    pub fn windows_dirs() -> Result<(), Error> {
+        // paths are only absolute with a drive letter on windows itself.
+        let vars = env(&[("APPDATA", "/roaming"), ("LOCALAPPDATA", "/local")]);
+        assert_eq!(
+            PathBuf::from("/roaming/irox"),
+            app_dir_from(AppDirKind::Config, "irox", false, Platform::Windows, &vars)?
+        );
+        assert_eq!(
+            PathBuf::from("/local/irox"),
+            app_dir_from(AppDirKind::Cache, "irox", false, Platform::Windows, &vars)?
+        );
+        // no fallback to HOME on windows
+        let vars = env(&[("HOME", "/home/user")]);
+        assert_eq!(
+            Some(ErrorKind::NotFound),
+            app_dir_from(AppDirKind::Data, "irox", false, Platform::Windows, &vars)
+                .err()
+                .map(|e| e.kind())
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn creates_dir() -> Result<(), Error> {
+        let root = std::env::temp_dir().join(format!("irox-dirs-test-{}", std::process::id()));
+        let root_str = root.to_string_lossy().to_string();
+        let vars = [
+            ("HOME", root_str.as_str()),
+            ("XDG_CACHE_HOME", root_str.as_str()),
+            ("LOCALAPPDATA", root_str.as_str()),
+        ];
+        let vars = env(&vars);
+        let path = app_dir_from(AppDirKind::Cache, "irox", false, Platform::current(), &vars)?;
+        assert!(!path.exists());
+        let path = app_dir_from(AppDirKind::Cache, "irox", true, Platform::current(), &vars)?;
+        assert!(path.is_dir());
+        std::fs::remove_dir_all(&root)?;
+
+        for invalid in ["", "..", "../escape", "a\\b"] {
+            assert_eq!(
+                Some(ErrorKind::InvalidInput),
+                app_dir_from(AppDirKind::Cache, invalid, true, Platform::current(), &vars)
+                    .err()
+                    .map(|e| e.kind())
+            );
+        }
+        Ok(())
+    }
+}
@@ -7,6 +7,15 @@
 
 use core::fmt::{Display, Formatter};
 
+crate::cfg_feature_std! {
+    mod atomic;
+    pub use atomic::*;
+    mod dirs;
+    pub use dirs::*;
+    mod tail;
+    pub use tail::*;
+}
+
 ///
 /// A list of characters that are usually prohibited by common filesystems like VFAT and NTFS.
 /// See [Wikipedia:Filename](https://en.wikipedia.org/wiki/Filename#Reserved_characters_and_words)
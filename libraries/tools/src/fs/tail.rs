@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Following a growing file, like `tail -F`.
+
+use alloc::collections::VecDeque;
+use core::time::Duration;
+use std::fs::{File, Metadata};
+use std::io::{Error, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Default time between checks for new data.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+///
+/// Identity of the file on disk, used to detect rotation.  Only available on unix, other
+/// platforms rely on the size of the file at the path shrinking.
+#[cfg(unix)]
+#[allow(clippy::unnecessary_wraps)]
+fn file_id(meta: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((meta.dev(), meta.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_id(_meta: &Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Removes the buffered line, without any trailing `\r`.
+fn take_line(partial: &mut Vec<u8>) -> String {
+    if partial.last() == Some(&b'\r') {
+        partial.pop();
+    }
+    let line = String::from_utf8_lossy(partial).to_string();
+    partial.clear();
+    line
+}
+
+///
+/// Reads the lines of a file to the end, then keeps polling the file for appended lines.
+///
+/// If the file is truncated, reading restarts from the beginning.  If the file is rotated (the
+/// path now refers to a different file), the remainder of the old file is read, then the new file
+/// is opened and read from the beginning.  A missing file, whether when opened or after being
+/// removed, is waited for and read from the beginning once it appears.
+///
+/// Changes are only detected by polling every [`TailReader::with_poll_interval`], there are no
+/// native file watchers (inotify, kqueue), so this works on any platform and filesystem.
+///
+/// Lines are yielded without their line ending, and any invalid UTF-8 is replaced.  A trailing
+/// line without a newline is held back until the newline is written, or returned as-is when the
+/// file is rotated, as nothing more will be appended to it.  The iterator only ends if an idle
+/// timeout is configured with [`TailReader::with_idle_timeout`].
+pub struct TailReader {
+    path: PathBuf,
+    file: Option<File>,
+    id: Option<(u64, u64)>,
+    position: u64,
+    partial: Vec<u8>,
+    lines: VecDeque<String>,
+    poll_interval: Duration,
+    idle_timeout: Option<Duration>,
+}
+
+impl TailReader {
+    ///
+    /// Opens the file at the path, starting from the beginning of the file.  If the file doesn't
+    /// exist yet, it's waited for.  Errors if the file exists but can't be opened.
+    pub fn open<T: AsRef<Path>>(path: T) -> Result<TailReader, Error> {
+        let mut reader = TailReader {
+            path: path.as_ref().to_path_buf(),
+            file: None,
+            id: None,
+            position: 0,
+            partial: Vec::new(),
+            lines: VecDeque::new(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            idle_timeout: None,
+        };
+        reader.reopen()?;
+        Ok(reader)
+    }
+
+    ///
+    /// Opens the file at the path, skipping any existing contents so only newly appended lines
+    /// are returned.  If the file doesn't exist yet, it's read from the beginning once it appears.
+    pub fn open_at_end<T: AsRef<Path>>(path: T) -> Result<TailReader, Error> {
+        let mut reader = Self::open(path)?;
+        if let Some(file) = &mut reader.file {
+            reader.position = file.seek(SeekFrom::End(0))?;
+        }
+        Ok(reader)
+    }
+
+    ///
+    /// Sets the time between checks for new data, defaults to [`DEFAULT_POLL_INTERVAL`]
+    #[must_use]
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    ///
+    /// Ends the iterator if no new line has been received within the timeout.
+    #[must_use]
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    ///
+    /// Checks the file once, returning the next complete line if one is available, without
+    /// waiting.
+    pub fn poll_line(&mut self) -> Result<Option<String>, Error> {
+        if self.lines.is_empty() {
+            self.read_available()?;
+        }
+        Ok(self.lines.pop_front())
+    }
+
+    fn read_available(&mut self) -> Result<(), Error> {
+        if self.file.is_none() {
+            self.reopen()?;
+        }
+        let Some(file) = &mut self.file else {
+            return Ok(());
+        };
+        if file.metadata()?.len() < self.position {
+            // truncated in place.
+            self.position = file.seek(SeekFrom::Start(0))?;
+            self.partial.clear();
+        }
+        if self.read_new()? > 0 {
+            return Ok(());
+        }
+        // nothing new in this file, has the path moved on to a different file?
+        let Ok(meta) = std::fs::metadata(&self.path) else {
+            return Ok(());
+        };
+        let rotated = match (self.id, file_id(&meta)) {
+            (Some(current), Some(new)) => current != new,
+            _ => meta.len() < self.position,
+        };
+        if rotated {
+            self.reopen()?;
+            self.read_new()?;
+        }
+        Ok(())
+    }
+
+    fn reopen(&mut self) -> Result<(), Error> {
+        if !self.partial.is_empty() {
+            // the old file won't be appended to anymore, so its last line is complete.
+            self.lines.push_back(take_line(&mut self.partial));
+        }
+        self.file = None;
+        self.id = None;
+        self.position = 0;
+        match File::open(&self.path) {
+            Ok(file) => {
+                self.id = file_id(&file.metadata()?);
+                self.file = Some(file);
+                Ok(())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn read_new(&mut self) -> Result<usize, Error> {
+        let Some(file) = &mut self.file else {
+            return Ok(0);
+        };
+        let mut buf = [0u8; 4096];
+        let mut total = 0;
+        loop {
+            let read = file.read(&mut buf)?;
+            if read == 0 {
+                return Ok(total);
+            }
+            total += read;
+            self.position += read as u64;
+            for byte in buf.iter().take(read) {
+                if *byte != b'\n' {
+                    self.partial.push(*byte);
+                    continue;
+                }
+                self.lines.push_back(take_line(&mut self.partial));
+            }
+        }
+    }
+}
+
+impl Iterator for TailReader {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = Instant::now();
+        loop {
+            match self.poll_line() {
+                Ok(Some(line)) => return Some(Ok(line)),
+                Ok(None) => {}
+                Err(e) => return Some(Err(e)),
+            }
+            if let Some(timeout) = self.idle_timeout {
+                if start.elapsed() >= timeout {
+                    return None;
+                }
+            }
+            std::thread::sleep(self.poll_interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use std::fs::{File, OpenOptions};
+    use std::io::{Error, Write};
+    use std::path::Path;
+
+    use crate::fs::TailReader;
+
+    fn append(path: &Path, data: &str) -> Result<(), Error> {
+        let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+        file.write_all(data.as_bytes())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn follows_appends() -> Result<(), Error> {
+        let path = std::env::temp_dir().join(format!("irox-tail-test-{}", std::process::id()));
+        File::create(&path)?.write_all(b"first\r\nsecond\n")?;
+
+        let mut reader = TailReader::open(&path)?
+            .with_poll_interval(Duration::from_millis(5))
+            .with_idle_timeout(Duration::from_secs(5));
+        assert_eq!(Some("first"), reader.next().transpose()?.as_deref());
+        assert_eq!(Some("second"), reader.next().transpose()?.as_deref());
+        assert_eq!(None, reader.poll_line()?);
+
+        let writer = {
+            let path = path.clone();
+            std::thread::spawn(move || -> Result<(), Error> {
+                for i in 0..10 {
+                    append(&path, &format!("line {i}\n"))?;
+                    std::thread::sleep(Duration::from_millis(2));
+                }
+                append(&path, "partial")?;
+                std::thread::sleep(Duration::from_millis(20));
+                append(&path, " line\n")
+            })
+        };
+        for i in 0..10 {
+            assert_eq!(Some(format!("line {i}")), reader.next().transpose()?);
+        }
+        assert_eq!(Some("partial line"), reader.next().transpose()?.as_deref());
+        writer
+            .join()
+            .map_err(|_| Error::other("writer panicked"))??;
+
+        // truncated in place
+        File::create(&path)?.write_all(b"new\n")?;
+        assert_eq!(Some("new"), reader.next().transpose()?.as_deref());
+
+        // rotated
+        let rotated = path.with_extension("1");
+        append(&path, "old\nunterminated")?;
+        std::fs::rename(&path, &rotated)?;
+        append(&path, "rotated\n")?;
+        assert_eq!(Some("old"), reader.next().transpose()?.as_deref());
+        assert_eq!(Some("unterminated"), reader.next().transpose()?.as_deref());
+        assert_eq!(Some("rotated"), reader.next().transpose()?.as_deref());
+
+        std::fs::remove_file(&path)?;
+        std::fs::remove_file(&rotated)?;
+
+        let mut reader = reader.with_idle_timeout(Duration::from_millis(20));
+        assert!(reader.next().is_none());
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn open_at_end() -> Result<(), Error> {
+        let path = std::env::temp_dir().join(format!("irox-tail-end-test-{}", std::process::id()));
+        File::create(&path)?.write_all(b"existing\n")?;
+        let mut reader = TailReader::open_at_end(&path)?;
+        assert_eq!(None, reader.poll_line()?);
+        append(&path, "appended\n")?;
+        assert_eq!(Some("appended"), reader.poll_line()?.as_deref());
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn waits_for_missing() -> Result<(), Error> {
+        let path =
+            std::env::temp_dir().join(format!("irox-tail-missing-test-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut reader = TailReader::open(&path)?;
+        assert_eq!(None, reader.poll_line()?);
+        append(&path, "created\n")?;
+        assert_eq!(Some("created"), reader.poll_line()?.as_deref());
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Implementation of the standard CRC-32 checksum (IEEE 802.3, as used by Ethernet, gzip, zip,
+//! and PNG) - reflected polynomial `0xEDB88320`, initial value and final xor of `0xFFFFFFFF`.
+//!
+
+/// The reflected form of the IEEE 802.3 polynomial `0x04C11DB7`
+pub const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+
+/// Byte-at-a-time lookup table for [`CRC32_POLYNOMIAL`]
+#[allow(clippy::indexing_slicing)]
+pub const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut idx = 0;
+    while idx < 256 {
+        let mut crc = idx as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                CRC32_POLYNOMIAL ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[idx] = crc;
+        idx += 1;
+    }
+    table
+};
+
+///
+/// Continues a CRC-32 checksum of earlier data (`0` for no earlier data) with more data.
+#[must_use]
+pub fn crc32_update(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for byte in data {
+        let idx = usize::from(crc as u8 ^ *byte);
+        crc = CRC32_TABLE.get(idx).copied().unwrap_or_default() ^ (crc >> 8);
+    }
+    !crc
+}
+
+///
+/// Returns the CRC-32 checksum of the data
+///
+/// # Example
+/// ```
+/// use irox_tools::hash::crc32::crc32;
+/// assert_eq!(0xCBF4_3926, crc32("123456789"));
+/// ```
+#[must_use]
+pub fn crc32<T: AsRef<[u8]>>(data: T) -> u32 {
+    crc32_update(0, data.as_ref())
+}
+
+///
+/// Incremental CRC-32.  Input can be provided in any number of [`Crc32::update`] calls, the
+/// result is identical to checksumming it all at once.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Crc32 {
+    crc: u32,
+}
+
+impl Crc32 {
+    #[must_use]
+    pub const fn new() -> Crc32 {
+        Crc32 { crc: 0 }
+    }
+
+    ///
+    /// Appends the data to the checksum
+    pub fn update(&mut self, data: &[u8]) {
+        self.crc = crc32_update(self.crc, data);
+    }
+
+    ///
+    /// Returns the checksum of all the data provided so far.
+    #[must_use]
+    pub const fn value(&self) -> u32 {
+        self.crc
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::hash::crc32::{crc32, Crc32};
+
+    #[test]
+    pub fn reference_vectors() {
+        assert_eq_hex!(0xCBF4_3926, crc32("123456789"));
+        assert_eq_hex!(0, crc32(""));
+        assert_eq_hex!(0xE8B7_BE43, crc32("a"));
+        assert_eq_hex!(
+            0x414F_A339,
+            crc32("The quick brown fox jumps over the lazy dog")
+        );
+    }
+
+    #[test]
+    pub fn incremental_matches_oneshot() {
+        let data: [u8; 100] = core::array::from_fn(|i| (i as u8).wrapping_mul(31));
+        for split in [1, 3, 7, 64] {
+            let mut crc = Crc32::new();
+            for chunk in data.chunks(split) {
+                crc.update(chunk);
+            }
+            assert_eq_hex!(crc32(data), crc.value());
+        }
+    }
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Implementation of the 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/index.html)
+//! non-cryptographic hash function.
+//!
+
+/// FNV-1a 64-bit offset basis, the initial state
+pub const FNV64_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+/// FNV-1a 64-bit prime, multiplied in after each byte
+pub const FNV64_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+///
+/// Returns the 64-bit FNV-1a hash of the data
+///
+/// # Example
+/// ```
+/// use irox_tools::hash::fnv::fnv1a_64;
+/// assert_eq!(0xAF63_DC4C_8601_EC8C, fnv1a_64("a"));
+/// ```
+#[must_use]
+pub fn fnv1a_64<T: AsRef<[u8]>>(data: T) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.update(data.as_ref());
+    hasher.value()
+}
+
+///
+/// Incremental 64-bit FNV-1a.  Input can be provided in any number of [`Fnv1a::update`] calls,
+/// the result is identical to hashing it all at once.  Also usable as a [`core::hash::Hasher`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Fnv1a {
+    hash: u64,
+}
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a::new()
+    }
+}
+
+impl Fnv1a {
+    #[must_use]
+    pub const fn new() -> Fnv1a {
+        Fnv1a {
+            hash: FNV64_OFFSET_BASIS,
+        }
+    }
+
+    ///
+    /// Appends the data to the hash
+    pub fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.hash ^= u64::from(*byte);
+            self.hash = self.hash.wrapping_mul(FNV64_PRIME);
+        }
+    }
+
+    ///
+    /// Returns the hash of all the data provided so far.
+    #[must_use]
+    pub const fn value(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl core::hash::Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::hash::fnv::{fnv1a_64, Fnv1a};
+
+    #[test]
+    pub fn reference_vectors() {
+        assert_eq_hex!(0xCBF2_9CE4_8422_2325, fnv1a_64(""));
+        assert_eq_hex!(0xAF63_DC4C_8601_EC8C, fnv1a_64("a"));
+        assert_eq_hex!(0x8594_4171_F739_67E8, fnv1a_64("foobar"));
+
+        let mut hasher = Fnv1a::new();
+        hasher.update(b"foo");
+        hasher.update(b"bar");
+        assert_eq_hex!(fnv1a_64("foobar"), hasher.value());
+    }
+}
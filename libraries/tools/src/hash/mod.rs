@@ -10,16 +10,22 @@
 #![deny(clippy::integer_division_remainder_used)]
 
 use core::ops::BitXorAssign;
+pub use crc32::{crc32, Crc32};
+pub use fnv::{fnv1a_64, Fnv1a};
 use irox_bits::MutBits;
 pub use md5::MD5;
 pub use murmur3::{murmur3_128, murmur3_128_seed};
 pub use sha1::SHA1;
 pub use sha2::{SHA224, SHA256, SHA384, SHA512};
+pub use xxhash::{xxhash64, xxhash64_seed, XxHash64};
 
+pub mod crc32;
+pub mod fnv;
 pub mod md5;
 pub mod murmur3;
 pub mod sha1;
 pub mod sha2;
+pub mod xxhash;
 
 /// Generic trait to describe a hash function
 pub trait HashDigest<const BLOCK_SIZE: usize, const OUTPUT_SIZE: usize>: Default {
@@ -28,6 +34,65 @@ pub trait HashDigest<const BLOCK_SIZE: usize, const OUTPUT_SIZE: usize>: Default
     fn finish(self) -> [u8; OUTPUT_SIZE];
 }
 
+///
+/// A checksum or hash that's computed incrementally as data streams through it, without
+/// buffering.  Feeding the data in any number of [`StreamingHasher::update`] calls produces the
+/// same result as feeding it all at once.
+///
+/// # Example
+/// ```
+/// use irox_tools::hash::{Crc32, Fnv1a, StreamingHasher};
+///
+/// fn checksum<H: StreamingHasher + Default>(packets: &[&[u8]]) -> u64 {
+///     let mut hasher = H::default();
+///     for packet in packets {
+///         hasher.update(packet);
+///     }
+///     hasher.finish()
+/// }
+/// assert_eq!(0xCBF4_3926, checksum::<Crc32>(&[b"1234", b"56789"]));
+/// ```
+pub trait StreamingHasher {
+    ///
+    /// Appends the data to the hash
+    fn update(&mut self, bytes: &[u8]);
+
+    ///
+    /// Returns the hash of all the data provided so far, widened to a [`u64`] for hashes with
+    /// smaller outputs.  The hasher can continue to be updated.
+    fn finish(&self) -> u64;
+}
+
+impl StreamingHasher for Crc32 {
+    fn update(&mut self, bytes: &[u8]) {
+        Crc32::update(self, bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.value())
+    }
+}
+
+impl StreamingHasher for Fnv1a {
+    fn update(&mut self, bytes: &[u8]) {
+        Fnv1a::update(self, bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.value()
+    }
+}
+
+impl StreamingHasher for XxHash64 {
+    fn update(&mut self, bytes: &[u8]) {
+        XxHash64::update(self, bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.finalize()
+    }
+}
+
 /// HMAC using the SHA1 algorithm
 pub type HMACSHA1 = HMAC<{ sha1::BLOCK_SIZE }, { sha1::OUTPUT_SIZE }, sha1::SHA1>;
 /// HMAC using the MD5 algorithm
@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Implementation of the [xxHash64](https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md)
+//! non-cryptographic hash function.
+//!
+
+const PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+const PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+const PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+const PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+/// Size of each stripe of input, 4 lanes of 8 bytes
+const STRIPE_SIZE: usize = 32;
+
+///
+/// Runs the xxHash64 hash function with a seed of zero
+pub fn xxhash64<T: AsRef<[u8]>>(data: T) -> u64 {
+    xxhash64_seed(data, 0)
+}
+
+///
+/// Runs the xxHash64 hash function with the specified seed
+pub fn xxhash64_seed<T: AsRef<[u8]>>(data: T, seed: u64) -> u64 {
+    let mut hash = XxHash64::with_seed(seed);
+    hash.update(data.as_ref());
+    hash.finalize()
+}
+
+#[inline]
+const fn round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+#[inline]
+const fn merge_round(acc: u64, val: u64) -> u64 {
+    (acc ^ round(0, val))
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(PRIME64_4)
+}
+
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(bytes);
+    u64::from_le_bytes(buf)
+}
+
+///
+/// Incremental xxHash64.  Input can be provided in any number of [`XxHash64::update`] calls, the
+/// result is identical to hashing it all at once.  Also usable as a [`core::hash::Hasher`], but
+/// note the result of hashing a value through [`core::hash::Hash`] depends on how the value writes
+/// itself, which is not the same as hashing its bytes.
+#[derive(Debug, Clone)]
+pub struct XxHash64 {
+    seed: u64,
+    /// The 4 lane accumulators, only used once a full stripe has been seen.
+    lanes: [u64; 4],
+    /// Partial stripe of input, waiting for more data
+    buffer: [u8; STRIPE_SIZE],
+    buffered: usize,
+    total_len: u64,
+}
+
+impl Default for XxHash64 {
+    fn default() -> Self {
+        XxHash64::with_seed(0)
+    }
+}
+
+impl XxHash64 {
+    ///
+    /// Creates a new hasher with the provided seed
+    #[must_use]
+    pub const fn with_seed(seed: u64) -> XxHash64 {
+        XxHash64 {
+            seed,
+            lanes: [
+                seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2),
+                seed.wrapping_add(PRIME64_2),
+                seed,
+                seed.wrapping_sub(PRIME64_1),
+            ],
+            buffer: [0; STRIPE_SIZE],
+            buffered: 0,
+            total_len: 0,
+        }
+    }
+
+    /// Mixes a full stripe into the lanes.  Each lane is independent, so this can be vectorized.
+    #[inline]
+    fn consume_stripe(lanes: &mut [u64; 4], stripe: &[u8]) {
+        for (lane, input) in lanes.iter_mut().zip(stripe.chunks_exact(8)) {
+            *lane = round(*lane, read_u64(input));
+        }
+    }
+
+    ///
+    /// Appends the data to the hash
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.total_len = self.total_len.wrapping_add(data.len() as u64);
+
+        if self.buffered > 0 {
+            let needed = STRIPE_SIZE - self.buffered;
+            let (head, rest) = data.split_at(needed.min(data.len()));
+            self.buffer[self.buffered..self.buffered + head.len()].copy_from_slice(head);
+            self.buffered += head.len();
+            data = rest;
+            if self.buffered < STRIPE_SIZE {
+                return;
+            }
+            let buffer = self.buffer;
+            Self::consume_stripe(&mut self.lanes, &buffer);
+            self.buffered = 0;
+        }
+
+        let mut stripes = data.chunks_exact(STRIPE_SIZE);
+        for stripe in &mut stripes {
+            Self::consume_stripe(&mut self.lanes, stripe);
+        }
+        let rest = stripes.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffered = rest.len();
+    }
+
+    ///
+    /// Returns the hash of all the data provided so far.  The hasher can continue to be updated.
+    #[must_use]
+    pub fn finalize(&self) -> u64 {
+        let mut hash = if self.total_len >= STRIPE_SIZE as u64 {
+            let [v1, v2, v3, v4] = self.lanes;
+            let mut hash = v1
+                .rotate_left(1)
+                .wrapping_add(v2.rotate_left(7))
+                .wrapping_add(v3.rotate_left(12))
+                .wrapping_add(v4.rotate_left(18));
+            for lane in self.lanes {
+                hash = merge_round(hash, lane);
+            }
+            hash
+        } else {
+            self.seed.wrapping_add(PRIME64_5)
+        };
+        hash = hash.wrapping_add(self.total_len);
+
+        let mut rest = &self.buffer[..self.buffered];
+        while rest.len() >= 8 {
+            let (word, tail) = rest.split_at(8);
+            hash ^= round(0, read_u64(word));
+            hash = hash
+                .rotate_left(27)
+                .wrapping_mul(PRIME64_1)
+                .wrapping_add(PRIME64_4);
+            rest = tail;
+        }
+        if rest.len() >= 4 {
+            let (word, tail) = rest.split_at(4);
+            let mut buf = [0u8; 4];
+            buf.copy_from_slice(word);
+            hash ^= u64::from(u32::from_le_bytes(buf)).wrapping_mul(PRIME64_1);
+            hash = hash
+                .rotate_left(23)
+                .wrapping_mul(PRIME64_2)
+                .wrapping_add(PRIME64_3);
+            rest = tail;
+        }
+        for byte in rest {
+            hash ^= u64::from(*byte).wrapping_mul(PRIME64_5);
+            hash = hash.rotate_left(11).wrapping_mul(PRIME64_1);
+        }
+
+        // avalanche
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(PRIME64_2);
+        hash ^= hash >> 29;
+        hash = hash.wrapping_mul(PRIME64_3);
+        hash ^= hash >> 32;
+        hash
+    }
+}
+
+impl core::hash::Hasher for XxHash64 {
+    fn finish(&self) -> u64 {
+        self.finalize()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::hash::xxhash::{xxhash64, xxhash64_seed, XxHash64};
+    use core::hash::Hasher;
+
+    #[test]
+    pub fn reference_vectors() {
+        let tests: [(&str, u64); 5] = [
+            ("", 0xEF46_DB37_51D8_E999),
+            ("a", 0xD24E_C4F1_A98C_6E5B),
+            ("abc", 0x44BC_2CF5_AD77_0999),
+            (
+                "Nobody inspects the spammish repetition",
+                0xFBCE_A83C_8A37_8BF1,
+            ),
+            (
+                "The quick brown fox jumps over the lazy dog",
+                0x0B24_2D36_1FDA_71BC,
+            ),
+        ];
+        for (input, expected) in tests {
+            assert_eq_hex!(expected, xxhash64(input));
+        }
+        assert_ne!(xxhash64("abc"), xxhash64_seed("abc", 1));
+    }
+
+    #[test]
+    pub fn incremental_matches_oneshot() {
+        let data: [u8; 300] = core::array::from_fn(|i| (i as u8).wrapping_mul(31));
+        for seed in [0, 1, 0xDEAD_BEEF] {
+            for len in [0, 1, 3, 4, 7, 8, 31, 32, 33, 63, 64, 65, 100, 300] {
+                let input = &data[..len];
+                let expected = xxhash64_seed(input, seed);
+                for split in [1, 5, 13, 32, 40] {
+                    let mut hasher = XxHash64::with_seed(seed);
+                    for chunk in input.chunks(split) {
+                        hasher.write(chunk);
+                    }
+                    assert_eq_hex!(expected, hasher.finish());
+                }
+            }
+        }
+    }
+}
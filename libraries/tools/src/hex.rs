@@ -10,7 +10,7 @@ crate::cfg_feature_alloc! {
 }
 use crate::buf::StrBuf;
 use core::fmt::Write;
-use irox_bits::{Error, ErrorKind, FormatBits, MutBits};
+use irox_bits::{Bits, Error, ErrorKind, FormatBits, MutBits};
 
 /// 0-9, A-F
 pub static HEX_UPPER_CHARS: [char; 16] = [
@@ -153,6 +153,62 @@ pub fn from_hex_into<T: MutBits>(hex: &str, out: &mut T) -> Result<usize, Error>
     Ok(wrote)
 }
 
+///
+/// Writes the bytes as hex characters into the provided [`MutBits`], without allocating.  Uses
+/// [`HEX_UPPER_CHARS`] if `uppercase` is set, [`HEX_LOWER_CHARS`] otherwise.
+pub fn hex_encode_to<W: MutBits>(bytes: &[u8], out: &mut W, uppercase: bool) -> Result<(), Error> {
+    let chars = if uppercase {
+        &HEX_UPPER_CHARS
+    } else {
+        &HEX_LOWER_CHARS
+    };
+    let mut buf = [0u8; 256];
+    for chunk in bytes.chunks(buf.len() / 2) {
+        for (v, pair) in chunk.iter().zip(buf.chunks_exact_mut(2)) {
+            if let [high, low] = pair {
+                *high = nibble_to_hex(chars, *v >> 4);
+                *low = nibble_to_hex(chars, *v);
+            }
+        }
+        if let Some(encoded) = buf.get(..chunk.len() * 2) {
+            out.write_all_bytes(encoded)?;
+        }
+    }
+    Ok(())
+}
+
+/// Looks up the character for the lower 4 bits of the value
+fn nibble_to_hex(chars: &[char; 16], val: u8) -> u8 {
+    chars.get((val & 0xF) as usize).copied().unwrap_or_default() as u8
+}
+
+///
+/// Reads hex characters [a-fA-F0-9] from the provided [`Bits`] until it's exhausted, and writes
+/// the associated bytes into the provided [`MutBits`], without allocating.  Whitespace is skipped.
+/// Returns the number of bytes written, or an [`ErrorKind::InvalidData`] if there's an invalid
+/// character or a dangling final nibble.
+pub fn hex_decode_from<R: Bits, W: MutBits>(input: &mut R, out: &mut W) -> Result<usize, Error> {
+    let mut high: Option<u8> = None;
+    let mut wrote = 0;
+    while let Some(ch) = input.next_u8()? {
+        if ch.is_ascii_whitespace() {
+            continue;
+        }
+        let nibble = hex_char_to_nibble(ch as char)?;
+        match high.take() {
+            None => high = Some(nibble),
+            Some(h) => {
+                out.write_u8(h << 4 | nibble)?;
+                wrote += 1;
+            }
+        }
+    }
+    if high.is_some() {
+        return ErrorKind::InvalidData.err("Hex string is an odd length");
+    }
+    Ok(wrote)
+}
+
 crate::cfg_feature_alloc! {
     ///
     /// Prints the value to a uppercase hex string
@@ -220,9 +276,13 @@ pub const fn hex_len(vals: &[&[u8]]) -> Option<usize> {
     let mut idx = 0;
     while idx < vals.len() {
         let val = vals[idx];
-        let len = val.len();
-
-        out += len;
+        let mut inneridx = 0;
+        while inneridx < val.len() {
+            if !val[inneridx].is_ascii_whitespace() {
+                out += 1;
+            }
+            inneridx += 1;
+        }
         idx += 1;
     }
     if out & 0x01 == 0x01 {
@@ -236,23 +296,31 @@ pub const fn hex_len(vals: &[&[u8]]) -> Option<usize> {
 pub const fn raw_hex<const L: usize>(vals: &[&[u8]]) -> Result<[u8; L], char> {
     let mut out = [0u8; L];
     let mut outidx = 0;
+    let mut high: Option<u8> = None;
     let mut idx = 0;
     while idx < vals.len() {
         let val = vals[idx];
         let mut inneridx = 0;
         while inneridx < val.len() {
-            let a = val[inneridx] as char;
-            let Ok(a) = hex_char_to_nibble(a) else {
-                return Err(a);
-            };
+            let ch = val[inneridx];
             inneridx += 1;
-            let b = val[inneridx] as char;
-            let Ok(b) = hex_char_to_nibble(b) else {
-                return Err(b);
+            if ch.is_ascii_whitespace() {
+                continue;
+            }
+            let Ok(nibble) = hex_char_to_nibble(ch as char) else {
+                return Err(ch as char);
             };
-            inneridx += 1;
-            out[outidx] = a << 4 | b;
-            outidx += 1;
+            match high {
+                None => high = Some(nibble),
+                Some(h) => {
+                    if outidx >= L {
+                        return Err(ch as char);
+                    }
+                    out[outidx] = h << 4 | nibble;
+                    outidx += 1;
+                    high = None;
+                }
+            }
         }
         idx += 1;
     }
@@ -262,10 +330,15 @@ pub const fn raw_hex<const L: usize>(vals: &[&[u8]]) -> Result<[u8; L], char> {
 #[allow(unused_macros)]
 #[macro_export]
 ///
-/// Const compile-time evaluation of the provided string literals
+/// Const compile-time evaluation of the provided string literals into a `[u8; N]`.  Whitespace
+/// between (or within) the literals is ignored.  An odd number of hex characters, or any
+/// character that isn't `[0-9a-fA-F]` or whitespace, fails to compile.
 /// ```
 /// let raw_hex = irox_tools::hex!("C0ffee" "BeEf");
-//  assert_eq_hex_slice!(&[0xc0, 0xff, 0xee, 0xbe, 0xef] as &[u8], &raw_hex);
+/// assert_eq!([0xc0, 0xff, 0xee, 0xbe, 0xef], raw_hex);
+///
+/// const HEADER: [u8; 4] = irox_tools::hex!("A0A2 0012");
+/// assert_eq!([0xA0, 0xA2, 0x00, 0x12], HEADER);
 /// ```
 macro_rules! hex {
     ($($input:literal)+) => {{
@@ -276,7 +349,7 @@ macro_rules! hex {
         };
         const RTN: [u8;LEN] = match $crate::hex::raw_hex::<LEN>(VALS) {
             Ok(v) => v,
-            Err(_) => panic!("Hex string contains invalid character")
+            Err(_) => panic!("Hex string contains invalid character, expected [0-9a-fA-F] or whitespace")
         };
         RTN
     }};
@@ -285,7 +358,7 @@ macro_rules! hex {
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod tests {
-    use crate::hex::HexDump;
+    use crate::hex::{hex_decode_from, hex_encode_to, to_hex_str_lower, to_hex_str_upper, HexDump};
     use alloc::vec::Vec;
 
     #[test]
@@ -309,4 +382,50 @@ mod tests {
         raw_hex.hexdump();
         Ok(())
     }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn stream_round_trip() -> Result<(), irox_bits::Error> {
+        let data = (0..1024 * 1024)
+            .map(|i: u32| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect::<Vec<u8>>();
+
+        let mut upper: Vec<u8> = Vec::new();
+        hex_encode_to(&data, &mut upper, true)?;
+        assert_eq!(to_hex_str_upper(&data).as_bytes(), upper.as_slice());
+
+        let mut lower: Vec<u8> = Vec::new();
+        hex_encode_to(&data, &mut lower, false)?;
+        assert_eq!(to_hex_str_lower(&data).as_bytes(), lower.as_slice());
+
+        let mut decoded: Vec<u8> = Vec::new();
+        assert_eq!(
+            data.len(),
+            hex_decode_from(&mut upper.as_slice(), &mut decoded)?
+        );
+        assert_eq!(data, decoded);
+        decoded.clear();
+        hex_decode_from(&mut lower.as_slice(), &mut decoded)?;
+        assert_eq!(data, decoded);
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn stream_decode_errors() -> Result<(), irox_bits::Error> {
+        let mut out: Vec<u8> = Vec::new();
+        hex_decode_from(&mut " c0 FF\nee ".as_bytes(), &mut out)?;
+        assert_eq!(vec![0xC0, 0xFF, 0xEE], out);
+        assert!(hex_decode_from(&mut "c0f".as_bytes(), &mut out).is_err());
+        assert!(hex_decode_from(&mut "c0fg".as_bytes(), &mut out).is_err());
+        Ok(())
+    }
+
+    #[test]
+    pub fn const_hex_whitespace() {
+        let raw_hex = hex!("A0A2 0012");
+        assert_eq!([0xA0, 0xA2, 0x00, 0x12], raw_hex);
+        let raw_hex = hex!(" a0 a2\n\t00 12 " "B 3");
+        assert_eq!([0xA0, 0xA2, 0x00, 0x12, 0xB3], raw_hex);
+    }
 }
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+///
+/// Collects the successful items into a vector, stopping at the first error.  The error is
+/// returned along with the index of the item that failed, for error messages like "row 3 is
+/// invalid".  No items are consumed after the first error.
+pub fn try_collect_indexed<T, E, I: IntoIterator<Item = Result<T, E>>>(
+    iter: I,
+) -> Result<Vec<T>, (usize, E)> {
+    let iter = iter.into_iter();
+    let mut out = Vec::with_capacity(iter.size_hint().0);
+    for (idx, item) in iter.enumerate() {
+        match item {
+            Ok(item) => out.push(item),
+            Err(e) => return Err((idx, e)),
+        }
+    }
+    Ok(out)
+}
+
+///
+/// Calls the function on each item, stopping at the first error.  The error is returned along with
+/// the index of the item that failed.  No items are consumed after the first error.
+pub fn try_for_each_indexed<T, E, I: IntoIterator<Item = T>, F: FnMut(T) -> Result<(), E>>(
+    iter: I,
+    mut func: F,
+) -> Result<(), (usize, E)> {
+    for (idx, item) in iter.into_iter().enumerate() {
+        func(item).map_err(|e| (idx, e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::iterators::{try_collect_indexed, try_for_each_indexed};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn parse(value: &str) -> Result<u32, core::num::ParseIntError> {
+        value.parse()
+    }
+
+    #[test]
+    pub fn collect_stops_at_failure() {
+        assert_eq!(
+            Ok(vec![1, 2, 3]),
+            try_collect_indexed(["1", "2", "3"].into_iter().map(parse))
+        );
+
+        let mut consumed = 0;
+        let result = try_collect_indexed(["1", "2", "x", "4", "y"].into_iter().map(|v| {
+            consumed += 1;
+            parse(v)
+        }));
+        assert!(matches!(result, Err((2, _))));
+        // nothing after the failure was consumed.
+        assert_eq!(3, consumed);
+
+        let empty: Vec<Result<u32, ()>> = Vec::new();
+        assert_eq!(Ok(vec![]), try_collect_indexed(empty));
+    }
+
+    #[test]
+    pub fn for_each_stops_at_failure() {
+        let mut seen = Vec::new();
+        let result = try_for_each_indexed(["1", "2", "x", "4"], |v| {
+            seen.push(parse(v)?);
+            Ok::<(), core::num::ParseIntError>(())
+        });
+        assert_eq!(Some(2), result.err().map(|(idx, _)| idx));
+        assert_eq!(vec![1, 2], seen);
+
+        let mut sum = 0;
+        let result = try_for_each_indexed([1, 2, 3], |v| {
+            sum += v;
+            Ok::<(), ()>(())
+        });
+        assert_eq!(Ok(()), result);
+        assert_eq!(6, sum);
+    }
+}
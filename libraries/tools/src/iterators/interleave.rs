@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+extern crate alloc;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+///
+/// Round-robin iterator across multiple iterators.  See [`interleave`]
+#[must_use]
+pub struct Interleave<I: Iterator> {
+    iters: VecDeque<I>,
+}
+
+impl<I: Iterator> Iterator for Interleave<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut iter) = self.iters.pop_front() {
+            if let Some(item) = iter.next() {
+                self.iters.push_back(iter);
+                return Some(item);
+            }
+            // exhausted, drop it.
+        }
+        None
+    }
+}
+
+///
+/// Returns an iterator that takes one item from each of the provided iterators in turn.  Once an
+/// iterator is exhausted, it's skipped, and the remaining iterators continue in the same order.
+/// For example, interleaving `[A, B, C]`, `[1]`, and `[x, y]` results in `[A, 1, x, B, y, C]`
+pub fn interleave<I: Iterator>(iters: Vec<I>) -> Interleave<I> {
+    Interleave {
+        iters: iters.into_iter().collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::iterators::interleave;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    pub fn interleave_uneven() {
+        let iters = vec![
+            vec!['a', 'b', 'c', 'd'].into_iter(),
+            vec!['1'].into_iter(),
+            vec![].into_iter(),
+            vec!['x', 'y'].into_iter(),
+        ];
+        let out: Vec<char> = interleave(iters).collect();
+        assert_eq!(vec!['a', '1', 'x', 'b', 'y', 'c', 'd'], out);
+
+        let empty: Vec<core::ops::Range<u8>> = Vec::new();
+        assert_eq!(None, interleave(empty).next());
+    }
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+extern crate alloc;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::vec::BinaryHeapMap;
+
+///
+/// Merges multiple sorted iterators into a single sorted iterator.  See [`kway_merge_by`]
+#[must_use]
+pub struct KWayMerge<I: Iterator, F> {
+    iters: Vec<I>,
+    /// The next item from each non-exhausted iterator, keyed by the index of the iterator it came
+    /// from.  The index is part of the priority, so ties come out in input order.
+    heap: BinaryHeapMap<usize, (I::Item, usize), F>,
+}
+
+impl<I, F> Iterator for KWayMerge<I, F>
+where
+    I: Iterator,
+    F: FnMut(&(I::Item, usize), &(I::Item, usize)) -> Ordering,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (source, (item, _)) = self.heap.pop_min()?;
+        if let Some(next) = self.iters.get_mut(source).and_then(Iterator::next) {
+            self.heap.push(source, (next, source));
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut lower = self.heap.len();
+        let mut upper = Some(self.heap.len());
+        for iter in &self.iters {
+            let (lo, hi) = iter.size_hint();
+            lower = lower.saturating_add(lo);
+            upper = upper.zip(hi).and_then(|(a, b)| a.checked_add(b));
+        }
+        (lower, upper)
+    }
+}
+
+///
+/// Returns an iterator that yields the items of all of the provided iterators in sorted order,
+/// according to the provided comparison function.  Each input iterator must already be sorted
+/// by the same comparison, only the next item of each iterator is held at any time, in a
+/// [`BinaryHeapMap`].  Equal items are yielded in the order of the iterators that provided them.
+pub fn kway_merge_by<I, F>(
+    iters: Vec<I>,
+    mut cmp: F,
+) -> KWayMerge<I, impl FnMut(&(I::Item, usize), &(I::Item, usize)) -> Ordering>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    let mut merge = KWayMerge {
+        iters,
+        heap: BinaryHeapMap::new_by(move |a: &(I::Item, usize), b: &(I::Item, usize)| {
+            cmp(&a.0, &b.0).then(a.1.cmp(&b.1))
+        }),
+    };
+    for source in 0..merge.iters.len() {
+        if let Some(item) = merge.iters.get_mut(source).and_then(Iterator::next) {
+            merge.heap.push(source, (item, source));
+        }
+    }
+    merge
+}
+
+#[cfg(test)]
+mod test {
+    use crate::iterators::kway_merge_by;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    pub fn merge_three_sorted() {
+        let iters = vec![
+            vec![1, 4, 7, 10].into_iter(),
+            vec![2, 3, 8].into_iter(),
+            vec![0, 5, 6, 9, 11, 12].into_iter(),
+        ];
+        let merge = kway_merge_by(iters, |a: &i32, b: &i32| a.cmp(b));
+        assert_eq!((13, Some(13)), merge.size_hint());
+        let out: Vec<i32> = merge.collect();
+        assert_eq!((0..=12).collect::<Vec<i32>>(), out);
+    }
+
+    #[test]
+    pub fn merge_is_stable() {
+        let iters = vec![
+            vec![(1, 'a'), (2, 'a')].into_iter(),
+            vec![(1, 'b'), (3, 'b')].into_iter(),
+            vec![(0, 'c'), (2, 'c')].into_iter(),
+        ];
+        let out: Vec<(i32, char)> = kway_merge_by(iters, |a, b| a.0.cmp(&b.0)).collect();
+        assert_eq!(
+            vec![(0, 'c'), (1, 'a'), (1, 'b'), (2, 'a'), (2, 'c'), (3, 'b')],
+            out
+        );
+    }
+}
@@ -15,8 +15,18 @@ extern crate alloc;
 use crate::iterators::join::MultiJoining;
 use alloc::vec::Vec;
 
+pub use fallible::*;
+pub use interleave::*;
+pub use windows::*;
+mod fallible;
+mod interleave;
 mod join;
 pub mod looping_forever;
+mod windows;
+crate::cfg_feature_alloc! {
+    pub use merge::*;
+    mod merge;
+}
 
 ///
 /// Itertools adds helpful additional methods to [`Iterator`]
@@ -127,6 +137,26 @@ pub trait Itertools: Iterator {
     {
         MultiJoining::new(self, delim)
     }
+
+    ///
+    /// Returns sliding windows of `size` items, where each window starts `step` items after the
+    /// start of the previous one.  If `step < size` the windows overlap, if `step == size` this
+    /// behaves like non-overlapping chunks, and if `step > size` the items between windows are
+    /// skipped.  A `size` or `step` of zero is treated as 1.  For example, windows of 3 with a
+    /// step of 2 over `[A, B, C, D, E, F]` are `[A, B, C]`, `[C, D, E]`, and, if the `trailing`
+    /// window is [`TrailingWindow::Emit`], `[E, F]`.
+    fn windows_overlapping(
+        self,
+        size: usize,
+        step: usize,
+        trailing: TrailingWindow,
+    ) -> WindowsOverlapping<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        WindowsOverlapping::new(self, size, step, trailing)
+    }
 }
 
 impl<T: ?Sized> Itertools for T where T: Iterator {}
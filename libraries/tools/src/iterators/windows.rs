@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+extern crate alloc;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+///
+/// What to do with the final window if the underlying iterator ends before it's filled.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TrailingWindow {
+    /// Return the shorter final window, as long as it contains at least one item that wasn't
+    /// part of a previous window.
+    Emit,
+    /// Discard the items that don't fill a complete window.
+    Drop,
+}
+
+///
+/// Sliding windows over an iterator.  See [`crate::iterators::Itertools::windows_overlapping`]
+#[must_use]
+pub struct WindowsOverlapping<I: Iterator> {
+    iter: I,
+    size: usize,
+    step: usize,
+    trailing: TrailingWindow,
+    window: VecDeque<I::Item>,
+    /// Number of items to discard before the start of the next window.
+    skip: usize,
+    done: bool,
+}
+
+impl<I: Iterator> WindowsOverlapping<I> {
+    pub fn new(iter: I, size: usize, step: usize, trailing: TrailingWindow) -> Self {
+        let size = size.max(1);
+        Self {
+            iter,
+            size,
+            step: step.max(1),
+            trailing,
+            window: VecDeque::with_capacity(size),
+            skip: 0,
+            done: false,
+        }
+    }
+
+    /// Number of windows produced from the current state if `remaining` more items are read
+    /// from the underlying iterator.
+    fn windows_for(&self, remaining: usize) -> usize {
+        let available = remaining
+            .saturating_add(self.window.len())
+            .saturating_sub(self.skip);
+        // items at the start of the next window that were in the previous window.
+        let seen = self.window.len().saturating_sub(self.skip);
+        let full = if available >= self.size {
+            1 + (available - self.size) / self.step
+        } else {
+            0
+        };
+        let covered = match full {
+            0 => seen,
+            _ => (full - 1)
+                .saturating_mul(self.step)
+                .saturating_add(self.size),
+        };
+        let leftover = available.saturating_sub(full.saturating_mul(self.step));
+        if self.trailing == TrailingWindow::Emit && leftover > 0 && available > covered {
+            full + 1
+        } else {
+            full
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for WindowsOverlapping<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        while self.skip > 0 {
+            if self.window.pop_front().is_none() && self.iter.next().is_none() {
+                self.done = true;
+                return None;
+            }
+            self.skip -= 1;
+        }
+        let mut fresh = false;
+        while self.window.len() < self.size {
+            let Some(item) = self.iter.next() else {
+                self.done = true;
+                break;
+            };
+            self.window.push_back(item);
+            fresh = true;
+        }
+        if self.window.len() < self.size
+            && (self.trailing == TrailingWindow::Drop || !fresh || self.window.is_empty())
+        {
+            return None;
+        }
+        self.skip = self.step;
+        Some(self.window.iter().cloned().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        (
+            self.windows_for(lower),
+            upper.map(|upper| self.windows_for(upper)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for WindowsOverlapping<I> where I::Item: Clone {}
+
+#[cfg(test)]
+mod test {
+    use crate::iterators::{Itertools, TrailingWindow};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// Checks the windows and that the size hint matched at every step.
+    fn windows(count: u32, size: usize, step: usize, trailing: TrailingWindow) -> Vec<Vec<u32>> {
+        let mut iter = (0..count).windows_overlapping(size, step, trailing);
+        let mut out = Vec::new();
+        loop {
+            let expected = iter.len();
+            let Some(window) = iter.next() else {
+                assert_eq!(0, expected);
+                return out;
+            };
+            assert_eq!(expected, iter.len() + 1);
+            out.push(window);
+        }
+    }
+
+    #[test]
+    pub fn overlapping() {
+        let expected: Vec<Vec<u32>> = vec![vec![0, 1, 2], vec![2, 3, 4]];
+        assert_eq!(expected, windows(5, 3, 2, TrailingWindow::Drop));
+        assert_eq!(expected, windows(5, 3, 2, TrailingWindow::Emit));
+
+        let expected: Vec<Vec<u32>> = vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5]];
+        assert_eq!(expected, windows(6, 3, 2, TrailingWindow::Emit));
+        assert_eq!(
+            expected.get(..2).unwrap_or_default(),
+            windows(6, 3, 2, TrailingWindow::Drop)
+        );
+
+        let expected: Vec<Vec<u32>> = vec![vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4]];
+        assert_eq!(expected, windows(5, 3, 1, TrailingWindow::Emit));
+    }
+
+    #[test]
+    pub fn chunks() {
+        let expected: Vec<Vec<u32>> = vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]];
+        assert_eq!(expected, windows(7, 3, 3, TrailingWindow::Emit));
+        assert_eq!(
+            expected.get(..2).unwrap_or_default(),
+            windows(7, 3, 3, TrailingWindow::Drop)
+        );
+        let expected: Vec<Vec<u32>> = vec![vec![0, 1], vec![4, 5], vec![8]];
+        assert_eq!(expected, windows(9, 2, 4, TrailingWindow::Emit));
+        assert_eq!(
+            vec![vec![0, 1], vec![4, 5]],
+            windows(7, 2, 4, TrailingWindow::Emit)
+        );
+    }
+
+    #[test]
+    pub fn short_input() {
+        assert_eq!(vec![vec![0, 1]], windows(2, 3, 1, TrailingWindow::Emit));
+        assert!(windows(2, 3, 1, TrailingWindow::Drop).is_empty());
+        assert!(windows(0, 3, 1, TrailingWindow::Emit).is_empty());
+    }
+
+    #[test]
+    pub fn unknown_length() {
+        let iter =
+            (0..10u32)
+                .filter(|v| v % 2 == 0)
+                .windows_overlapping(2, 1, TrailingWindow::Drop);
+        assert_eq!((0, Some(9)), iter.size_hint());
+        let out: Vec<Vec<u32>> = iter.collect();
+        assert_eq!(vec![vec![0, 2], vec![2, 4], vec![4, 6], vec![6, 8]], out);
+    }
+}
@@ -21,6 +21,8 @@ pub mod arrays;
 pub mod assert;
 pub mod codec;
 #[macro_use]
+pub mod flags;
+#[macro_use]
 pub mod fmt;
 pub mod hex;
 pub mod iterators;
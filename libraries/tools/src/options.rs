@@ -2,7 +2,7 @@
 // Copyright 2023 IROX Contributors
 
 //!
-//! `MaybeInto`, `MaybeFrom`, and `MaybeMap` - traits to improve chaining of optional functions and erasing `Result`s
+//! `MaybeInto`, `MaybeFrom`, `MaybeMap`, and `MaybeApply` - traits to improve chaining of optional functions and erasing `Result`s
 //!
 
 ///
@@ -69,3 +69,60 @@ impl<Base, Out> MaybeMap<Base, Out> for Option<Base> {
         map(self?)
     }
 }
+
+///
+/// Folds optional values into a value, usually a builder, without breaking the method chain with
+/// `if let Some` blocks.  Implemented for every type.
+///
+/// # Example
+/// ```
+/// use irox_tools::options::MaybeApply;
+///
+/// #[derive(Default)]
+/// struct ServerBuilder {
+///     host: String,
+///     port: u16,
+///     verbose: bool,
+/// }
+/// impl ServerBuilder {
+///     fn with_host(mut self, host: String) -> Self {
+///         self.host = host;
+///         self
+///     }
+///     fn with_port(mut self, port: u16) -> Self {
+///         self.port = port;
+///         self
+///     }
+///     fn with_verbose(mut self, verbose: bool) -> Self {
+///         self.verbose = verbose;
+///         self
+///     }
+/// }
+///
+/// let host: Option<String> = Some("example.com".to_string());
+/// let port: Option<u16> = None;
+/// let verbose: Option<bool> = Some(true);
+///
+/// let builder = ServerBuilder::default()
+///     .with_port(8080)
+///     .apply_if_some(host, ServerBuilder::with_host)
+///     .apply_if_some(port, ServerBuilder::with_port)
+///     .apply_if_some(verbose, ServerBuilder::with_verbose);
+///
+/// assert_eq!("example.com", builder.host);
+/// assert_eq!(8080, builder.port);
+/// assert!(builder.verbose);
+/// ```
+pub trait MaybeApply: Sized {
+    ///
+    /// Returns `func(self, value)` if the value is [`Some`], otherwise returns self unchanged.
+    #[must_use]
+    fn apply_if_some<T, F: FnOnce(Self, T) -> Self>(self, value: Option<T>, func: F) -> Self {
+        match value {
+            Some(value) => func(self, value),
+            None => self,
+        }
+    }
+}
+
+impl<S> MaybeApply for S {}
@@ -6,6 +6,7 @@
 
 use alloc::collections::VecDeque;
 use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
 use irox_bits::{Bits, Error, ErrorKind, MutBits};
 
 /// A packet is a series of bytes
@@ -137,3 +138,368 @@ impl<T: Bits> Packetization<T> for DelimitedPacketizer {
         }
     }
 }
+
+///
+/// Width and byte order of the length prefixed to each frame by [`FramedReader`] and
+/// [`FramedWriter`].  The length counts the payload bytes only, not the prefix itself.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum FrameLength {
+    /// 2-byte length, MSB first
+    BigEndianU16,
+    /// 2-byte length, LSB first
+    LittleEndianU16,
+    /// 4-byte length, MSB first
+    BigEndianU32,
+    /// 4-byte length, LSB first
+    LittleEndianU32,
+}
+
+impl FrameLength {
+    /// Returns the number of bytes in the length prefix
+    #[must_use]
+    pub const fn prefix_len(self) -> usize {
+        match self {
+            FrameLength::BigEndianU16 | FrameLength::LittleEndianU16 => 2,
+            FrameLength::BigEndianU32 | FrameLength::LittleEndianU32 => 4,
+        }
+    }
+
+    /// Returns the largest payload length the prefix can represent
+    #[must_use]
+    pub const fn max_len(self) -> usize {
+        match self {
+            FrameLength::BigEndianU16 | FrameLength::LittleEndianU16 => u16::MAX as usize,
+            FrameLength::BigEndianU32 | FrameLength::LittleEndianU32 => u32::MAX as usize,
+        }
+    }
+
+    fn decode(self, prefix: [u8; 4]) -> usize {
+        let [a, b, c, d] = prefix;
+        match self {
+            FrameLength::BigEndianU16 => u16::from_be_bytes([a, b]) as usize,
+            FrameLength::LittleEndianU16 => u16::from_le_bytes([a, b]) as usize,
+            FrameLength::BigEndianU32 => u32::from_be_bytes([a, b, c, d]) as usize,
+            FrameLength::LittleEndianU32 => u32::from_le_bytes([a, b, c, d]) as usize,
+        }
+    }
+
+    fn encode<T: MutBits>(self, len: usize, out: &mut T) -> Result<(), Error> {
+        match self {
+            FrameLength::BigEndianU16 => out.write_be_u16(len as u16),
+            FrameLength::LittleEndianU16 => out.write_le_u16(len as u16),
+            FrameLength::BigEndianU32 => out.write_be_u32(len as u32),
+            FrameLength::LittleEndianU32 => out.write_le_u32(len as u32),
+        }
+    }
+}
+
+///
+/// Errors reading or writing a length-prefixed frame.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FrameError {
+    /// The stream ended part-way through a frame (or its length prefix), after `actual` of the
+    /// `expected` bytes.
+    Incomplete { expected: usize, actual: usize },
+    /// The frame's length is more than the maximum allowed.
+    TooLarge { length: usize, max: usize },
+    /// The underlying stream failed.
+    Bits(Error),
+}
+
+impl Display for FrameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FrameError::Incomplete { expected, actual } => write!(
+                f,
+                "Incomplete frame, expected {expected} bytes but stream ended after {actual}"
+            ),
+            FrameError::TooLarge { length, max } => {
+                write!(f, "Frame length {length} exceeds the maximum of {max}")
+            }
+            FrameError::Bits(e) => write!(f, "{e:?}"),
+        }
+    }
+}
+
+crate::cfg_feature_std! {
+    impl std::error::Error for FrameError {}
+}
+
+impl From<Error> for FrameError {
+    fn from(value: Error) -> Self {
+        FrameError::Bits(value)
+    }
+}
+
+impl From<FrameError> for Error {
+    fn from(value: FrameError) -> Self {
+        match value {
+            FrameError::Incomplete { .. } => {
+                Error::new(ErrorKind::UnexpectedEof, "Incomplete frame")
+            }
+            FrameError::TooLarge { .. } => {
+                Error::new(ErrorKind::InvalidData, "Frame exceeded max length")
+            }
+            FrameError::Bits(e) => e,
+        }
+    }
+}
+
+///
+/// Reads length-prefixed frames from a stream, one packet per frame.
+///
+/// # Example
+/// ```
+/// use irox_tools::packetio::{FrameLength, FramedReader, FramedWriter};
+///
+/// let mut writer = FramedWriter::new(Vec::new(), FrameLength::BigEndianU16);
+/// writer.write_frame(b"hello").unwrap();
+/// writer.write_frame(b"world").unwrap();
+/// let stream = writer.into_inner();
+/// assert_eq!(14, stream.len());
+///
+/// let mut reader = FramedReader::new(stream.as_slice(), FrameLength::BigEndianU16);
+/// assert_eq!(Some(b"hello".to_vec()), reader.read_frame().unwrap());
+/// assert_eq!(Some(b"world".to_vec()), reader.read_frame().unwrap());
+/// assert_eq!(None, reader.read_frame().unwrap());
+/// ```
+pub struct FramedReader<R: Bits> {
+    reader: R,
+    length: FrameLength,
+    max_len: usize,
+}
+
+impl<R: Bits> FramedReader<R> {
+    /// Creates a reader accepting frames up to the largest length the prefix can represent
+    pub fn new(reader: R, length: FrameLength) -> Self {
+        FramedReader {
+            reader,
+            length,
+            max_len: length.max_len(),
+        }
+    }
+
+    ///
+    /// Limits the payload length of the frames read, longer frames are rejected with
+    /// [`FrameError::TooLarge`] before anything is allocated for them.
+    #[must_use]
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    ///
+    /// Reads the next frame's payload.  Returns [`None`] if the stream ends cleanly between
+    /// frames, or [`FrameError::Incomplete`] if it ends part-way through one.
+    pub fn read_frame(&mut self) -> Result<Option<PacketData>, FrameError> {
+        let prefix_len = self.length.prefix_len();
+        let mut prefix = [0u8; 4];
+        for (idx, byte) in prefix.iter_mut().take(prefix_len).enumerate() {
+            let Some(val) = self.reader.next_u8()? else {
+                if idx == 0 {
+                    return Ok(None);
+                }
+                return Err(FrameError::Incomplete {
+                    expected: prefix_len,
+                    actual: idx,
+                });
+            };
+            *byte = val;
+        }
+        let length = self.length.decode(prefix);
+        if length > self.max_len {
+            return Err(FrameError::TooLarge {
+                length,
+                max: self.max_len,
+            });
+        }
+        let mut out = Vec::with_capacity(length.min(u16::MAX as usize));
+        while out.len() < length {
+            let Some(val) = self.reader.next_u8()? else {
+                return Err(FrameError::Incomplete {
+                    expected: length,
+                    actual: out.len(),
+                });
+            };
+            out.push(val);
+        }
+        Ok(Some(out))
+    }
+
+    ///
+    /// Reads the next frame and builds a packet from its payload.  The builder only sees the
+    /// bytes of the one frame, any it doesn't consume are discarded.  Returns [`None`] if the
+    /// stream ends cleanly between frames.
+    pub fn read_packet<P, B>(&mut self, builder: &B) -> Result<Option<P>, B::Error>
+    where
+        B: PacketBuilder<P>,
+        B::Error: From<FrameError>,
+    {
+        let Some(frame) = self.read_frame()? else {
+            return Ok(None);
+        };
+        builder.build_from(&mut frame.as_slice()).map(Some)
+    }
+
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Bits> PacketTransport for FramedReader<R> {
+    type Error = FrameError;
+
+    /// Reads the next frame, failing with [`ErrorKind::UnexpectedEof`] at the end of the stream
+    fn poll_next_packet(&mut self) -> Result<PacketData, Self::Error> {
+        self.read_frame()?
+            .ok_or(FrameError::Bits(ErrorKind::UnexpectedEof.into()))
+    }
+
+    fn start(&mut self) -> Result<(), Self::Error> {
+        // noop.
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<(), Self::Error> {
+        // noop.
+        Ok(())
+    }
+}
+
+///
+/// Writes packets to a stream, each prefixed with its length.  See [`FramedReader`].
+pub struct FramedWriter<W: MutBits> {
+    writer: W,
+    length: FrameLength,
+}
+
+impl<W: MutBits> FramedWriter<W> {
+    pub fn new(writer: W, length: FrameLength) -> Self {
+        FramedWriter { writer, length }
+    }
+
+    ///
+    /// Writes the length prefix followed by the payload.  Fails with [`FrameError::TooLarge`],
+    /// without writing anything, if the prefix can't represent the payload's length.
+    pub fn write_frame(&mut self, payload: &[u8]) -> Result<(), FrameError> {
+        let max = self.length.max_len();
+        if payload.len() > max {
+            return Err(FrameError::TooLarge {
+                length: payload.len(),
+                max,
+            });
+        }
+        self.length.encode(payload.len(), &mut self.writer)?;
+        self.writer.write_all_bytes(payload)?;
+        Ok(())
+    }
+
+    /// Writes the packet's bytes as a single frame
+    pub fn write_packet<P: Packet>(&mut self, packet: &P) -> Result<(), FrameError> {
+        self.write_frame(&packet.get_bytes()?)
+    }
+
+    /// Returns the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::packetio::{
+        FrameError, FrameLength, FramedReader, FramedWriter, Packet, PacketBuilder,
+    };
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use irox_bits::{Bits, Error};
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Ping(u32);
+    impl Packet for Ping {
+        type PacketType = ();
+
+        fn get_bytes(&self) -> Result<Vec<u8>, Error> {
+            Ok(self.0.to_be_bytes().to_vec())
+        }
+
+        fn get_type(&self) -> Self::PacketType {}
+    }
+    struct PingBuilder;
+    impl PacketBuilder<Ping> for PingBuilder {
+        type Error = Error;
+
+        fn build_from<T: Bits>(&self, input: &mut T) -> Result<Ping, Self::Error> {
+            Ok(Ping(input.read_be_u32()?))
+        }
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn round_trip() -> Result<(), Error> {
+        for length in [
+            FrameLength::BigEndianU16,
+            FrameLength::LittleEndianU16,
+            FrameLength::BigEndianU32,
+            FrameLength::LittleEndianU32,
+        ] {
+            let mut writer = FramedWriter::new(Vec::new(), length);
+            writer.write_packet(&Ping(0xDEAD_BEEF))?;
+            writer.write_frame(&[])?;
+            writer.write_frame(&[1; 300])?;
+            writer.write_packet(&Ping(7))?;
+            let stream = writer.into_inner();
+            assert_eq!(4 * length.prefix_len() + 308, stream.len());
+
+            let mut reader = FramedReader::new(stream.as_slice(), length);
+            assert_eq!(Some(Ping(0xDEAD_BEEF)), reader.read_packet(&PingBuilder)?);
+            assert_eq!(Some(vec![]), reader.read_frame()?);
+            assert_eq!(Some(vec![1; 300]), reader.read_frame()?);
+            assert_eq!(Some(Ping(7)), reader.read_packet(&PingBuilder)?);
+            assert_eq!(None, reader.read_packet(&PingBuilder)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    pub fn short_reads() {
+        let mut reader = FramedReader::new([0x00u8].as_slice(), FrameLength::BigEndianU16);
+        assert_eq!(
+            Err(FrameError::Incomplete {
+                expected: 2,
+                actual: 1
+            }),
+            reader.read_frame()
+        );
+
+        let mut reader = FramedReader::new(
+            [5u8, 0, 0, 0, 1, 2].as_slice(),
+            FrameLength::LittleEndianU32,
+        );
+        assert_eq!(
+            Err(FrameError::Incomplete {
+                expected: 5,
+                actual: 2
+            }),
+            reader.read_frame()
+        );
+
+        let mut reader =
+            FramedReader::new([0u8, 9, 1].as_slice(), FrameLength::BigEndianU16).with_max_len(8);
+        assert_eq!(
+            Err(FrameError::TooLarge { length: 9, max: 8 }),
+            reader.read_frame()
+        );
+
+        let mut writer = FramedWriter::new(Vec::new(), FrameLength::BigEndianU16);
+        assert_eq!(
+            Err(FrameError::TooLarge {
+                length: 70_000,
+                max: 65535
+            }),
+            writer.write_frame(&[0; 70_000])
+        );
+        assert!(writer.into_inner().is_empty());
+    }
+}
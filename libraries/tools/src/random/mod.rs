@@ -8,6 +8,11 @@
 
 use core::ops::{BitXor, BitXorAssign};
 
+crate::cfg_feature_alloc! {
+    mod weighted;
+    pub use weighted::*;
+}
+
 /// Default starting state/seed if the system clock fails
 const DEFAULT_STATE: u64 = 0x4d595df4d0f33173u64;
 
@@ -141,6 +146,60 @@ impl PRNG for PcgXslRrRr {
     }
 }
 
+///
+/// The reference `pcg32` generator from the [PCG paper](https://www.pcg-random.org/) and its
+/// reference C implementation (`pcg32_random_r`), `PCG-XSH-RR` with 64 bits of state and 32-bit
+/// outputs.  The same seed (and stream) produces the same sequence on every platform.
+///
+/// Each step advances the state with the 64-bit LCG `state * 6364136223846793005 + inc`, where
+/// `inc` is `(stream << 1) | 1`, and outputs the old state xorshifted right by 18, shifted right
+/// by 27, then rotated right by the top 5 bits of the old state.  Seeding follows
+/// `pcg32_srandom_r`: zero the state, step, add the seed, step.
+///
+/// [`PRNG::next_u64`] is two consecutive outputs, the first as the upper 32 bits.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Pcg32 {
+    state: u64,
+    inc: u64,
+}
+
+impl Pcg32 {
+    ///
+    /// Creates a generator seeded with this number, on the default stream (increment of
+    /// `1442695040888963407`).
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self::new_stream(seed, INCREMENT >> 1)
+    }
+
+    ///
+    /// Creates a generator seeded with this number, on one of 2^63 independent streams.
+    #[must_use]
+    pub fn new_stream(seed: u64, stream: u64) -> Self {
+        let mut out = Self {
+            state: 0,
+            inc: stream << 1 | 1,
+        };
+        out.step();
+        out.state = out.state.wrapping_add(seed);
+        out.step();
+        out
+    }
+
+    fn step(&mut self) {
+        self.state = self.state.wrapping_mul(MULTIPLIER).wrapping_add(self.inc);
+    }
+}
+
+impl PRNG for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        xorshifted.rotate_right((old >> 59) as u32)
+    }
+}
+
 pub trait PRNG {
     ///
     /// Gets the next random [`u32`] for this random sequence
@@ -170,14 +229,38 @@ pub trait PRNG {
         a << 64 | b
     }
     ///
-    /// Gets the next random [`f32`] for this random sequence
+    /// Gets the next random [`f32`] for this random sequence, uniform in `[0, 1)` from the top
+    /// 24 bits of [`PRNG::next_u32`]
     fn next_f32(&mut self) -> f32 {
-        f32::from_bits(self.next_u32())
+        (self.next_u32() >> 8) as f32 / (1_u32 << 24) as f32
     }
     ///
-    /// Gets the next random [`f64`] for this random sequence
+    /// Gets the next random [`f64`] for this random sequence, uniform in `[0, 1)` from the top
+    /// 53 bits of [`PRNG::next_u64`]
     fn next_f64(&mut self) -> f64 {
-        f64::from_bits(self.next_u64())
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+    ///
+    /// Gets the next random [`u64`] uniform in `[min, max)`, without modulo bias (Lemire's
+    /// multiply-and-reject method).  Returns `min` if the range is empty.
+    fn range(&mut self, min: u64, max: u64) -> u64 {
+        if max <= min {
+            return min;
+        }
+        let span = max - min;
+        let mut wide = u128::from(self.next_u64()) * u128::from(span);
+        if (wide as u64) < span {
+            let threshold = span.wrapping_neg() % span;
+            while (wide as u64) < threshold {
+                wide = u128::from(self.next_u64()) * u128::from(span);
+            }
+        }
+        min + (wide >> 64) as u64
+    }
+    ///
+    /// Gets the next random [`f64`] uniform in `[min, max)`
+    fn range_f64(&mut self, min: f64, max: f64) -> f64 {
+        min + self.next_f64() * (max - min)
     }
 }
 
@@ -201,7 +284,51 @@ impl Default for Random {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     #![allow(clippy::all)]
-    use crate::random::{PcgRxsMXs64, PcgXslRrRr, PRNG};
+    use crate::random::{Pcg32, PcgRxsMXs64, PcgXslRrRr, PRNG};
+
+    #[allow(clippy::tests_outside_test_module)]
+    #[test]
+    pub fn pcg32_reference() {
+        // output of the reference pcg32-demo, seeded with 42 on stream 54
+        let mut rand = Pcg32::new_stream(42, 54);
+        let out = (0..6).map(|_| rand.next_u32()).collect::<Vec<_>>();
+        assert_eq!(
+            vec![0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e],
+            out
+        );
+
+        let mut a = Pcg32::new(1234);
+        let mut b = Pcg32::new(1234);
+        let mut c = Pcg32::new(1235);
+        let a = (0..100).map(|_| a.next_u64()).collect::<Vec<_>>();
+        assert_eq!(a, (0..100).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(a, (0..100).map(|_| c.next_u64()).collect::<Vec<_>>());
+    }
+
+    #[allow(clippy::tests_outside_test_module)]
+    #[test]
+    pub fn unit_and_range() {
+        let mut rand = Pcg32::new(0);
+        let mut counts = [0u32; 10];
+        for _ in 0..100_000 {
+            let v = rand.next_f64();
+            assert!((0.0..1.0).contains(&v));
+            let v = rand.next_f32();
+            assert!((0.0..1.0).contains(&v));
+            let v = rand.range_f64(-2.0, 3.0);
+            assert!((-2.0..3.0).contains(&v));
+            let v = rand.range(10, 20);
+            assert!((10..20).contains(&v));
+            if let Some(count) = counts.get_mut((v - 10) as usize) {
+                *count += 1;
+            }
+        }
+        for count in counts {
+            assert!((9_000..11_000).contains(&count), "{count}");
+        }
+        assert_eq!(5, rand.range(5, 5));
+        assert!(rand.range(0, u64::MAX) < u64::MAX);
+    }
 
     // #[test]
     // #[ignore]
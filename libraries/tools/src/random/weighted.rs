@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+extern crate alloc;
+use crate::random::PRNG;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+///
+/// Error returned when a [`WeightedSampler`] can't be built from the provided weights.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum WeightedSamplerError {
+    /// No weights were provided
+    NoWeights,
+    /// The weight at the index is negative, or not finite
+    InvalidWeight { index: usize },
+    /// All of the weights are zero
+    AllZero,
+}
+
+impl Display for WeightedSamplerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WeightedSamplerError::NoWeights => f.write_str("No weights provided"),
+            WeightedSamplerError::InvalidWeight { index } => {
+                write!(f, "Weight at index {index} is negative or not finite")
+            }
+            WeightedSamplerError::AllZero => f.write_str("All weights are zero"),
+        }
+    }
+}
+
+crate::cfg_feature_std! {
+    impl std::error::Error for WeightedSamplerError {}
+}
+
+///
+/// Samples indices with probabilities proportional to the provided weights, in `O(1)` time per
+/// sample.  Uses Vose's variant of Walker's alias method, building the tables takes `O(n)` time.
+/// Entries with a weight of zero are never selected.
+///
+/// # Example
+/// ```
+/// # use irox_tools::random::{Random, WeightedSampler};
+/// let sampler = WeightedSampler::new(&[1.0, 0.0, 3.0])?;
+/// let mut rand = Random::new_seed(1);
+/// let idx = sampler.sample(&mut rand);
+/// assert!(idx == 0 || idx == 2);
+/// # Ok::<(), irox_tools::random::WeightedSamplerError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeightedSampler {
+    /// Probability of selecting the column index itself, rather than its alias
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl WeightedSampler {
+    ///
+    /// Builds the alias tables for the provided weights.  The weights don't need to sum to 1, but
+    /// must all be finite and non-negative, with at least one non-zero weight.
+    pub fn new(weights: &[f64]) -> Result<WeightedSampler, WeightedSamplerError> {
+        if weights.is_empty() {
+            return Err(WeightedSamplerError::NoWeights);
+        }
+        if let Some(index) = weights.iter().position(|w| !w.is_finite() || *w < 0.0) {
+            return Err(WeightedSamplerError::InvalidWeight { index });
+        }
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return Err(WeightedSamplerError::AllZero);
+        }
+        let count = weights.len();
+        // index of the largest weight, for any zero-weight leftovers.
+        let mut fallback = 0;
+        let mut scaled: Vec<f64> = Vec::with_capacity(count);
+        let mut small: Vec<usize> = Vec::new();
+        let mut large: Vec<usize> = Vec::new();
+        for (idx, weight) in weights.iter().enumerate() {
+            if weights.get(fallback).is_some_and(|max| weight > max) {
+                fallback = idx;
+            }
+            let p = weight * count as f64 / total;
+            scaled.push(p);
+            if p < 1.0 {
+                small.push(idx);
+            } else {
+                large.push(idx);
+            }
+        }
+
+        let mut prob = vec![0.0; count];
+        let mut alias: Vec<usize> = (0..count).collect();
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            large.pop();
+            let less_p = scaled.get(less).copied().unwrap_or_default();
+            if let Some(p) = prob.get_mut(less) {
+                *p = less_p;
+            }
+            if let Some(a) = alias.get_mut(less) {
+                *a = more;
+            }
+            if let Some(more_p) = scaled.get_mut(more) {
+                *more_p = (*more_p + less_p) - 1.0;
+                if *more_p < 1.0 {
+                    small.push(more);
+                } else {
+                    large.push(more);
+                }
+            }
+        }
+        // anything left is (within rounding error) exactly 1, except for zero weights which
+        // must never be selected.
+        for idx in large.into_iter().chain(small) {
+            let is_zero = weights.get(idx).is_some_and(|w| *w == 0.0);
+            if let Some(p) = prob.get_mut(idx) {
+                *p = if is_zero { 0.0 } else { 1.0 };
+            }
+            if is_zero {
+                if let Some(a) = alias.get_mut(idx) {
+                    *a = fallback;
+                }
+            }
+        }
+        Ok(WeightedSampler { prob, alias })
+    }
+
+    /// Returns the number of weights (and possible indices)
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    /// Returns true if there are no weights.  Always false, as a sampler can't be built without
+    /// weights.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.prob.is_empty()
+    }
+
+    ///
+    /// Returns a random index, with the probability of each index proportional to its weight.
+    pub fn sample<T: PRNG>(&self, rng: &mut T) -> usize {
+        let count = self.prob.len() as u128;
+        let idx = ((rng.next_u64() as u128 * count) >> 64) as usize;
+        // uniform in [0, 1) from the top 53 bits.
+        let coin = (rng.next_u64() >> 11) as f64 / (1_u64 << 53) as f64;
+        match (self.prob.get(idx), self.alias.get(idx)) {
+            (Some(prob), _) if coin < *prob => idx,
+            (_, Some(alias)) => *alias,
+            _ => idx,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{Random, WeightedSampler, WeightedSamplerError};
+    use alloc::vec;
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn frequencies_match_weights() -> Result<(), WeightedSamplerError> {
+        let weights = [1.0, 0.0, 3.0, 6.0, 0.0, 10.0];
+        let total: f64 = weights.iter().sum();
+        let sampler = WeightedSampler::new(&weights)?;
+        assert_eq!(weights.len(), sampler.len());
+
+        let mut rand = Random::new_seed(0x1234_5678);
+        let draws = 500_000;
+        let mut counts = vec![0_u32; weights.len()];
+        for _ in 0..draws {
+            if let Some(count) = counts.get_mut(sampler.sample(&mut rand)) {
+                *count += 1;
+            }
+        }
+        for (weight, count) in weights.iter().zip(&counts) {
+            let expected = weight / total;
+            let actual = f64::from(*count) / f64::from(draws);
+            assert!(
+                actual > expected - 0.005 && actual < expected + 0.005,
+                "expected {expected} but was {actual}"
+            );
+            if *weight == 0.0 {
+                assert_eq!(0, *count);
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn single_weight() -> Result<(), WeightedSamplerError> {
+        let mut rand = Random::new_seed(5);
+        let sampler = WeightedSampler::new(&[0.0, 2.5, 0.0])?;
+        assert!((0..1000).all(|_| sampler.sample(&mut rand) == 1));
+        Ok(())
+    }
+
+    #[test]
+    pub fn invalid_weights() {
+        assert_eq!(
+            Some(WeightedSamplerError::NoWeights),
+            WeightedSampler::new(&[]).err()
+        );
+        assert_eq!(
+            Some(WeightedSamplerError::AllZero),
+            WeightedSampler::new(&[0.0, 0.0]).err()
+        );
+        assert_eq!(
+            Some(WeightedSamplerError::InvalidWeight { index: 1 }),
+            WeightedSampler::new(&[1.0, -1.0]).err()
+        );
+        assert_eq!(
+            Some(WeightedSamplerError::InvalidWeight { index: 2 }),
+            WeightedSampler::new(&[1.0, 2.0, f64::NAN]).err()
+        );
+    }
+}
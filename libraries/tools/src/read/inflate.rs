@@ -0,0 +1,842 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Streaming decompression of DEFLATE ([RFC1951](https://www.rfc-editor.org/rfc/rfc1951)) data,
+//! raw or wrapped in the gzip ([RFC1952](https://www.rfc-editor.org/rfc/rfc1952)) or zlib
+//! ([RFC1950](https://www.rfc-editor.org/rfc/rfc1950)) containers.
+
+extern crate alloc;
+use crate::hash::crc32::crc32_update;
+use alloc::vec::Vec;
+use std::io::{BufReader, Chain, Cursor, Error, ErrorKind, Read};
+
+/// Maximum distance of a back-reference
+const WINDOW_SIZE: usize = 32 * 1024;
+/// Number of bytes to decode ahead of the reader at a time
+const CHUNK_SIZE: usize = 16 * 1024;
+const MAX_CODE_BITS: usize = 15;
+
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+const GZIP_FLAG_HCRC: u8 = 0x02;
+const GZIP_FLAG_EXTRA: u8 = 0x04;
+const GZIP_FLAG_NAME: u8 = 0x08;
+const GZIP_FLAG_COMMENT: u8 = 0x10;
+const ZLIB_FLAG_DICT: u8 = 0x20;
+const DEFLATE_METHOD: u8 = 8;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+/// The order the code length code lengths are stored in a dynamic block header
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+fn adler32_update(adler: u32, data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let mut a = adler & 0xFFFF;
+    let mut b = adler >> 16;
+    // the largest chunk that can't overflow before the modulo
+    for chunk in data.chunks(5552) {
+        for byte in chunk {
+            a += u32::from(*byte);
+            b += a;
+        }
+        a %= MOD_ADLER;
+        b %= MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+fn invalid<T>(msg: &'static str) -> Result<T, Error> {
+    Err(Error::new(ErrorKind::InvalidData, msg))
+}
+
+///
+/// Reads the input least-significant bit first, as DEFLATE packs it.
+struct BitReader<R> {
+    inner: R,
+    bits: u64,
+    count: u32,
+}
+
+impl<R: Read> BitReader<R> {
+    fn next_byte(&mut self) -> Result<Option<u8>, Error> {
+        let mut byte = [0u8; 1];
+        loop {
+            match self.inner.read(&mut byte) {
+                Ok(0) => return Ok(None),
+                Ok(_) => {
+                    let [byte] = byte;
+                    return Ok(Some(byte));
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn bits(&mut self, count: u32) -> Result<u32, Error> {
+        while self.count < count {
+            let Some(byte) = self.next_byte()? else {
+                return Err(ErrorKind::UnexpectedEof.into());
+            };
+            self.bits |= u64::from(byte) << self.count;
+            self.count += 8;
+        }
+        let value = (self.bits & ((1 << count) - 1)) as u32;
+        self.bits >>= count;
+        self.count -= count;
+        Ok(value)
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        Ok(self.bits(8)? as u8)
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut out = [0u8; N];
+        for byte in &mut out {
+            *byte = self.byte()?;
+        }
+        Ok(out)
+    }
+
+    /// Skips to the next byte boundary
+    fn align(&mut self) {
+        let partial = self.count & 7;
+        self.bits >>= partial;
+        self.count -= partial;
+    }
+
+    /// Reads the next byte, returning [`None`] at the end of the input.  Must be byte-aligned.
+    fn try_byte(&mut self) -> Result<Option<u8>, Error> {
+        if self.count >= 8 {
+            return Ok(Some(self.byte()?));
+        }
+        self.next_byte()
+    }
+}
+
+///
+/// A canonical Huffman code, stored as the number of codes of each length and the symbols in
+/// code order.
+struct Huffman {
+    counts: [u16; MAX_CODE_BITS + 1],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Huffman, Error> {
+        let mut counts = [0u16; MAX_CODE_BITS + 1];
+        for len in lengths {
+            let Some(count) = counts.get_mut(usize::from(*len)) else {
+                return invalid("Invalid Huffman code length");
+            };
+            *count += 1;
+        }
+        let mut left: i32 = 1;
+        for count in counts.iter().skip(1) {
+            left = (left << 1) - i32::from(*count);
+            if left < 0 {
+                return invalid("Over-subscribed Huffman code");
+            }
+        }
+
+        let mut offsets = [0u16; MAX_CODE_BITS + 1];
+        let mut total = 0;
+        for (offset, count) in offsets.iter_mut().zip(counts).skip(1) {
+            *offset = total;
+            total += count;
+        }
+        let mut symbols = alloc::vec![0u16; usize::from(total)];
+        for (symbol, len) in lengths.iter().enumerate() {
+            if *len == 0 {
+                continue;
+            }
+            if let Some(offset) = offsets.get_mut(usize::from(*len)) {
+                if let Some(slot) = symbols.get_mut(usize::from(*offset)) {
+                    *slot = symbol as u16;
+                }
+                *offset += 1;
+            }
+        }
+        Ok(Huffman { counts, symbols })
+    }
+
+    fn decode<R: Read>(&self, input: &mut BitReader<R>) -> Result<u16, Error> {
+        let mut code: i32 = 0;
+        let mut first: i32 = 0;
+        let mut index: i32 = 0;
+        for count in self.counts.iter().skip(1) {
+            code |= input.bits(1)? as i32;
+            let count = i32::from(*count);
+            if code - count < first {
+                return match self.symbols.get((index + code - first) as usize) {
+                    Some(symbol) => Ok(*symbol),
+                    None => invalid("Invalid Huffman code"),
+                };
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        invalid("Invalid Huffman code")
+    }
+}
+
+fn fixed_tables() -> Result<(Huffman, Huffman), Error> {
+    let mut lengths = [8u8; 288];
+    for (symbol, len) in lengths.iter_mut().enumerate() {
+        match symbol {
+            144..=255 => *len = 9,
+            256..=279 => *len = 7,
+            _ => {}
+        }
+    }
+    Ok((Huffman::new(&lengths)?, Huffman::new(&[5; 30])?))
+}
+
+fn dynamic_tables<R: Read>(input: &mut BitReader<R>) -> Result<(Huffman, Huffman), Error> {
+    let num_lengths = input.bits(5)? as usize + 257;
+    let num_distances = input.bits(5)? as usize + 1;
+    let num_codes = input.bits(4)? as usize + 4;
+    if num_lengths > 286 || num_distances > 30 {
+        return invalid("Too many length or distance codes");
+    }
+
+    let mut code_lengths = [0u8; 19];
+    for idx in CODE_LENGTH_ORDER.iter().take(num_codes) {
+        if let Some(len) = code_lengths.get_mut(*idx) {
+            *len = input.bits(3)? as u8;
+        }
+    }
+    let code_lengths = Huffman::new(&code_lengths)?;
+
+    let total = num_lengths + num_distances;
+    let mut lengths: Vec<u8> = Vec::with_capacity(total);
+    while lengths.len() < total {
+        let symbol = code_lengths.decode(input)?;
+        let (len, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let Some(previous) = lengths.last() else {
+                    return invalid("Repeated code length with no previous length");
+                };
+                (*previous, 3 + input.bits(2)?)
+            }
+            17 => (0, 3 + input.bits(3)?),
+            _ => (0, 11 + input.bits(7)?),
+        };
+        let repeat = repeat as usize;
+        if lengths.len() + repeat > total {
+            return invalid("Too many code lengths");
+        }
+        lengths.extend(core::iter::repeat(len).take(repeat));
+    }
+
+    let (literals, distances) = lengths.split_at(num_lengths);
+    if literals.get(256) == Some(&0) {
+        return invalid("Missing end-of-block code");
+    }
+    Ok((Huffman::new(literals)?, Huffman::new(distances)?))
+}
+
+enum Block {
+    Header,
+    Stored(u16),
+    Codes {
+        literals: Huffman,
+        distances: Huffman,
+    },
+    End,
+}
+
+///
+/// The DEFLATE decoder state.  Decoded bytes are appended to the window, which holds at least the
+/// last [`WINDOW_SIZE`] bytes for back-references.
+struct Inflater {
+    block: Block,
+    last_block: bool,
+    window: Vec<u8>,
+}
+
+impl Inflater {
+    fn new() -> Inflater {
+        Inflater {
+            block: Block::Header,
+            last_block: false,
+            window: Vec::new(),
+        }
+    }
+
+    /// Drops the already-read bytes that are no longer needed for back-references
+    fn compact(&mut self, read_pos: &mut usize) {
+        if *read_pos < 2 * WINDOW_SIZE {
+            return;
+        }
+        let drop = *read_pos - WINDOW_SIZE;
+        self.window.drain(..drop);
+        *read_pos -= drop;
+    }
+
+    /// Decodes until the window holds at least `target` bytes, or the end of the stream.
+    fn decode<R: Read>(&mut self, input: &mut BitReader<R>, target: usize) -> Result<(), Error> {
+        while self.window.len() < target {
+            match &mut self.block {
+                Block::End => return Ok(()),
+                Block::Header => {
+                    if self.last_block {
+                        self.block = Block::End;
+                        continue;
+                    }
+                    self.last_block = input.bits(1)? == 1;
+                    self.block = match input.bits(2)? {
+                        0 => {
+                            input.align();
+                            let len = input.bits(16)? as u16;
+                            let inverse = input.bits(16)? as u16;
+                            if len != !inverse {
+                                return invalid("Stored block length doesn't match complement");
+                            }
+                            Block::Stored(len)
+                        }
+                        1 => {
+                            let (literals, distances) = fixed_tables()?;
+                            Block::Codes {
+                                literals,
+                                distances,
+                            }
+                        }
+                        2 => {
+                            let (literals, distances) = dynamic_tables(input)?;
+                            Block::Codes {
+                                literals,
+                                distances,
+                            }
+                        }
+                        _ => return invalid("Invalid block type"),
+                    };
+                }
+                Block::Stored(0) => self.block = Block::Header,
+                Block::Stored(remaining) => {
+                    *remaining -= 1;
+                    self.window.push(input.byte()?);
+                }
+                Block::Codes {
+                    literals,
+                    distances,
+                } => {
+                    let symbol = literals.decode(input)?;
+                    if symbol < 256 {
+                        self.window.push(symbol as u8);
+                        continue;
+                    }
+                    if symbol == 256 {
+                        self.block = Block::Header;
+                        continue;
+                    }
+                    let idx = usize::from(symbol - 257);
+                    let (Some(base), Some(extra)) = (LENGTH_BASE.get(idx), LENGTH_EXTRA.get(idx))
+                    else {
+                        return invalid("Invalid length code");
+                    };
+                    let length = usize::from(*base) + input.bits(u32::from(*extra))? as usize;
+
+                    let idx = usize::from(distances.decode(input)?);
+                    let (Some(base), Some(extra)) =
+                        (DISTANCE_BASE.get(idx), DISTANCE_EXTRA.get(idx))
+                    else {
+                        return invalid("Invalid distance code");
+                    };
+                    let distance = usize::from(*base) + input.bits(u32::from(*extra))? as usize;
+                    let Some(start) = self.window.len().checked_sub(distance) else {
+                        return invalid("Distance is before the start of the stream");
+                    };
+                    // may overlap the bytes being written, so copied one at a time.
+                    for idx in start..start + length {
+                        let byte = self.window.get(idx).copied().unwrap_or_default();
+                        self.window.push(byte);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Container {
+    Raw,
+    Gzip,
+    Zlib,
+}
+
+///
+/// Decompresses a DEFLATE stream as it's read.  The gzip container supports multiple
+/// concatenated members, which are decompressed as one stream, and both the gzip and zlib
+/// checksums are verified at the end of the stream.  Corrupt data returns an
+/// [`ErrorKind::InvalidData`] error.
+pub struct InflateReader<R: Read> {
+    input: BitReader<BufReader<R>>,
+    inflater: Inflater,
+    container: Container,
+    read_pos: usize,
+    started: bool,
+    finished: bool,
+    checksum: u32,
+    size: u32,
+}
+
+impl<R: Read> InflateReader<R> {
+    fn with_container(input: R, container: Container) -> InflateReader<R> {
+        InflateReader {
+            input: BitReader {
+                inner: BufReader::new(input),
+                bits: 0,
+                count: 0,
+            },
+            inflater: Inflater::new(),
+            container,
+            read_pos: 0,
+            started: false,
+            finished: false,
+            checksum: 0,
+            size: 0,
+        }
+    }
+
+    ///
+    /// Decompresses a raw DEFLATE stream, with no container.
+    pub fn new_raw(input: R) -> InflateReader<R> {
+        Self::with_container(input, Container::Raw)
+    }
+
+    ///
+    /// Decompresses a gzip file/stream
+    pub fn new_gzip(input: R) -> InflateReader<R> {
+        Self::with_container(input, Container::Gzip)
+    }
+
+    ///
+    /// Decompresses a zlib stream
+    pub fn new_zlib(input: R) -> InflateReader<R> {
+        Self::with_container(input, Container::Zlib)
+    }
+
+    fn read_header(&mut self) -> Result<(), Error> {
+        match self.container {
+            Container::Raw => {}
+            Container::Gzip => {
+                let first = self.input.byte()?;
+                self.read_gzip_header(first)?;
+            }
+            Container::Zlib => {
+                let [method, flags] = self.input.bytes()?;
+                if method & 0x0F != DEFLATE_METHOD
+                    || ((u16::from(method) << 8) | u16::from(flags)) % 31 != 0
+                {
+                    return invalid("Not a zlib stream");
+                }
+                if flags & ZLIB_FLAG_DICT != 0 {
+                    return invalid("Zlib preset dictionaries are not supported");
+                }
+                self.checksum = 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_gzip_header(&mut self, first: u8) -> Result<(), Error> {
+        let [second, method, flags, _mtime @ .., _extra_flags, _os] = self.input.bytes::<9>()?;
+        if [first, second] != GZIP_MAGIC {
+            return invalid("Not a gzip stream");
+        }
+        if method != DEFLATE_METHOD {
+            return invalid("Unsupported gzip compression method");
+        }
+        if flags & GZIP_FLAG_EXTRA != 0 {
+            let len = u16::from_le_bytes(self.input.bytes()?);
+            for _ in 0..len {
+                self.input.byte()?;
+            }
+        }
+        for flag in [GZIP_FLAG_NAME, GZIP_FLAG_COMMENT] {
+            if flags & flag != 0 {
+                // zero-terminated
+                while self.input.byte()? != 0 {}
+            }
+        }
+        if flags & GZIP_FLAG_HCRC != 0 {
+            self.input.bytes::<2>()?;
+        }
+        self.checksum = 0;
+        self.size = 0;
+        Ok(())
+    }
+
+    /// Verifies the container trailer at the end of the DEFLATE stream, and starts the next gzip
+    /// member if there is one.
+    fn read_trailer(&mut self) -> Result<(), Error> {
+        self.input.align();
+        match self.container {
+            Container::Raw => {}
+            Container::Gzip => {
+                let crc = u32::from_le_bytes(self.input.bytes()?);
+                let size = u32::from_le_bytes(self.input.bytes()?);
+                if crc != self.checksum || size != self.size {
+                    return invalid("Gzip checksum mismatch");
+                }
+                // anything other than another member is ignored, like gzip does.
+                if let Some(first @ 0x1F) = self.input.try_byte()? {
+                    self.read_gzip_header(first)?;
+                    self.inflater.block = Block::Header;
+                    self.inflater.last_block = false;
+                    return Ok(());
+                }
+            }
+            Container::Zlib => {
+                let adler = u32::from_be_bytes(self.input.bytes()?);
+                if adler != self.checksum {
+                    return invalid("Zlib checksum mismatch");
+                }
+            }
+        }
+        self.finished = true;
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for InflateReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if self.finished || buf.is_empty() {
+                return Ok(0);
+            }
+            if !self.started {
+                self.read_header()?;
+                self.started = true;
+            }
+            if self.read_pos == self.inflater.window.len() {
+                self.inflater.compact(&mut self.read_pos);
+                self.inflater
+                    .decode(&mut self.input, self.read_pos + CHUNK_SIZE)?;
+            }
+            let available = self
+                .inflater
+                .window
+                .get(self.read_pos..)
+                .unwrap_or_default();
+            if available.is_empty() {
+                self.read_trailer()?;
+                continue;
+            }
+            let len = available.len().min(buf.len());
+            let (Some(src), Some(dest)) = (available.get(..len), buf.get_mut(..len)) else {
+                return Ok(0);
+            };
+            dest.copy_from_slice(src);
+            match self.container {
+                Container::Raw => {}
+                Container::Gzip => {
+                    self.checksum = crc32_update(self.checksum, src);
+                    self.size = self.size.wrapping_add(len as u32);
+                }
+                Container::Zlib => self.checksum = adler32_update(self.checksum, src),
+            }
+            self.read_pos += len;
+            return Ok(len);
+        }
+    }
+}
+
+/// The input with the sniffed magic bytes put back in front
+type Sniffed<R> = Chain<Cursor<Vec<u8>>, R>;
+
+enum Decoder<R: Read> {
+    Unknown,
+    Plain(Sniffed<R>),
+    Compressed(Box<InflateReader<Sniffed<R>>>),
+}
+
+///
+/// A reader that transparently decompresses gzip or zlib input, and passes anything else through
+/// unchanged.  Created by [`maybe_gunzip`].
+pub struct MaybeGunzip<R: Read> {
+    input: Option<R>,
+    decoder: Decoder<R>,
+}
+
+impl<R: Read> MaybeGunzip<R> {
+    fn sniff(&mut self) -> Result<(), Error> {
+        let Some(mut input) = self.input.take() else {
+            return Ok(());
+        };
+        let mut magic = [0u8; 2];
+        let mut len = 0;
+        while len < magic.len() {
+            match input.read(magic.get_mut(len..).unwrap_or_default()) {
+                Ok(0) => break,
+                Ok(read) => len += read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let container = match magic {
+            _ if len < magic.len() => None,
+            GZIP_MAGIC => Some(Container::Gzip),
+            // the zlib headers for each compression level, with a 32K window.
+            [0x78, 0x01 | 0x5E | 0x9C | 0xDA] => Some(Container::Zlib),
+            _ => None,
+        };
+        let prefix = magic.get(..len).unwrap_or_default().to_vec();
+        let input = Cursor::new(prefix).chain(input);
+        self.decoder = match container {
+            Some(container) => {
+                Decoder::Compressed(Box::new(InflateReader::with_container(input, container)))
+            }
+            None => Decoder::Plain(input),
+        };
+        Ok(())
+    }
+
+    ///
+    /// Returns true if the input is compressed.  Reads the first bytes of the input to check, if
+    /// they haven't been read already.
+    pub fn is_compressed(&mut self) -> Result<bool, Error> {
+        self.sniff()?;
+        Ok(matches!(self.decoder, Decoder::Compressed(_)))
+    }
+}
+
+impl<R: Read> Read for MaybeGunzip<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.sniff()?;
+        match &mut self.decoder {
+            Decoder::Unknown => Ok(0),
+            Decoder::Plain(input) => input.read(buf),
+            Decoder::Compressed(input) => input.read(buf),
+        }
+    }
+}
+
+///
+/// Wraps the input so that if it's gzip (starting with the magic bytes `1F 8B`) or zlib
+/// (starting with `78 01`, `78 5E`, `78 9C` or `78 DA`) compressed, it's transparently
+/// decompressed as it's read.  Any other input is passed through unchanged, so parsers don't need
+/// to care whether their input was compressed.  Nothing is read until the first read.
+///
+/// ```
+/// # use std::io::Read;
+/// # use irox_tools::read::maybe_gunzip;
+/// let mut plain = String::new();
+/// maybe_gunzip("$GPGGA,...".as_bytes()).read_to_string(&mut plain)?;
+/// assert_eq!("$GPGGA,...", plain);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn maybe_gunzip<R: Read>(input: R) -> MaybeGunzip<R> {
+    MaybeGunzip {
+        input: Some(input),
+        decoder: Decoder::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::read::{maybe_gunzip, InflateReader};
+    use std::io::{BufRead, BufReader, ErrorKind, Read};
+
+    const NMEA: &str = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+        $GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n\
+        $GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n\
+        $GPGGA,123520,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+        $GPVTG,054.7,T,034.4,M,005.5,N,010.2,K*48\r\n";
+    /// `NMEA`, gzip'd with dynamic huffman codes
+    const NMEA_GZIP: [u8; 169] = [
+        0x1F, 0x8B, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x9D, 0x8E, 0x41, 0x0A, 0xC2,
+        0x40, 0x0C, 0x45, 0xF7, 0x42, 0x6F, 0xD1, 0x55, 0xF9, 0x84, 0x9F, 0x49, 0xA6, 0x9D, 0x59,
+        0x0E, 0x22, 0x5D, 0x48, 0x45, 0xB4, 0xE8, 0xFD, 0x6F, 0x62, 0x5A, 0x75, 0xE9, 0xC6, 0x2C,
+        0x12, 0x02, 0xC9, 0x7F, 0xAF, 0x9F, 0xAF, 0xF3, 0xDC, 0xA0, 0xC9, 0xB2, 0x56, 0x78, 0xE1,
+        0x24, 0xB4, 0x82, 0x0B, 0xA8, 0x6A, 0x2A, 0x24, 0x71, 0x82, 0x82, 0x05, 0x94, 0x8A, 0xEC,
+        0x59, 0x1C, 0x0B, 0x7C, 0x8C, 0x65, 0x01, 0x06, 0x9F, 0xBA, 0x43, 0x1F, 0x11, 0xF7, 0x86,
+        0x06, 0x03, 0x1D, 0xCC, 0x00, 0x6B, 0x04, 0x02, 0x48, 0x8E, 0xAD, 0x92, 0x64, 0xA8, 0x58,
+        0x4C, 0x1D, 0xAC, 0xEE, 0x0F, 0xB7, 0xE5, 0xF8, 0x65, 0xB6, 0x5F, 0x54, 0xA6, 0x14, 0x30,
+        0x16, 0x8F, 0x9E, 0x8C, 0x56, 0x63, 0xA1, 0x89, 0xE2, 0x39, 0x8C, 0xED, 0x8D, 0xFD, 0x98,
+        0x27, 0xFE, 0x6D, 0xFE, 0x58, 0xE7, 0x50, 0x76, 0x99, 0xB0, 0x82, 0xE6, 0xFB, 0x0D, 0x99,
+        0xC3, 0x78, 0x0B, 0xA2, 0x24, 0x9C, 0x07, 0x2F, 0xDD, 0xE1, 0x05, 0x55, 0xF4, 0x1C, 0x97,
+        0x28, 0x01, 0x00, 0x00,
+    ];
+    /// `NMEA`, gzip'd with no compression (stored blocks)
+    const NMEA_GZIP_STORED: [u8; 319] = [
+        0x1F, 0x8B, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x03, 0x01, 0x28, 0x01, 0xD7, 0xFE,
+        0x24, 0x47, 0x50, 0x47, 0x47, 0x41, 0x2C, 0x31, 0x32, 0x33, 0x35, 0x31, 0x39, 0x2C, 0x34,
+        0x38, 0x30, 0x37, 0x2E, 0x30, 0x33, 0x38, 0x2C, 0x4E, 0x2C, 0x30, 0x31, 0x31, 0x33, 0x31,
+        0x2E, 0x30, 0x30, 0x30, 0x2C, 0x45, 0x2C, 0x31, 0x2C, 0x30, 0x38, 0x2C, 0x30, 0x2E, 0x39,
+        0x2C, 0x35, 0x34, 0x35, 0x2E, 0x34, 0x2C, 0x4D, 0x2C, 0x34, 0x36, 0x2E, 0x39, 0x2C, 0x4D,
+        0x2C, 0x2C, 0x2A, 0x34, 0x37, 0x0D, 0x0A, 0x24, 0x47, 0x50, 0x47, 0x53, 0x41, 0x2C, 0x41,
+        0x2C, 0x33, 0x2C, 0x30, 0x34, 0x2C, 0x30, 0x35, 0x2C, 0x2C, 0x30, 0x39, 0x2C, 0x31, 0x32,
+        0x2C, 0x2C, 0x2C, 0x32, 0x34, 0x2C, 0x2C, 0x2C, 0x2C, 0x2C, 0x32, 0x2E, 0x35, 0x2C, 0x31,
+        0x2E, 0x33, 0x2C, 0x32, 0x2E, 0x31, 0x2A, 0x33, 0x39, 0x0D, 0x0A, 0x24, 0x47, 0x50, 0x52,
+        0x4D, 0x43, 0x2C, 0x31, 0x32, 0x33, 0x35, 0x31, 0x39, 0x2C, 0x41, 0x2C, 0x34, 0x38, 0x30,
+        0x37, 0x2E, 0x30, 0x33, 0x38, 0x2C, 0x4E, 0x2C, 0x30, 0x31, 0x31, 0x33, 0x31, 0x2E, 0x30,
+        0x30, 0x30, 0x2C, 0x45, 0x2C, 0x30, 0x32, 0x32, 0x2E, 0x34, 0x2C, 0x30, 0x38, 0x34, 0x2E,
+        0x34, 0x2C, 0x32, 0x33, 0x30, 0x33, 0x39, 0x34, 0x2C, 0x30, 0x30, 0x33, 0x2E, 0x31, 0x2C,
+        0x57, 0x2A, 0x36, 0x41, 0x0D, 0x0A, 0x24, 0x47, 0x50, 0x47, 0x47, 0x41, 0x2C, 0x31, 0x32,
+        0x33, 0x35, 0x32, 0x30, 0x2C, 0x34, 0x38, 0x30, 0x37, 0x2E, 0x30, 0x33, 0x38, 0x2C, 0x4E,
+        0x2C, 0x30, 0x31, 0x31, 0x33, 0x31, 0x2E, 0x30, 0x30, 0x30, 0x2C, 0x45, 0x2C, 0x31, 0x2C,
+        0x30, 0x38, 0x2C, 0x30, 0x2E, 0x39, 0x2C, 0x35, 0x34, 0x35, 0x2E, 0x34, 0x2C, 0x4D, 0x2C,
+        0x34, 0x36, 0x2E, 0x39, 0x2C, 0x4D, 0x2C, 0x2C, 0x2A, 0x34, 0x37, 0x0D, 0x0A, 0x24, 0x47,
+        0x50, 0x56, 0x54, 0x47, 0x2C, 0x30, 0x35, 0x34, 0x2E, 0x37, 0x2C, 0x54, 0x2C, 0x30, 0x33,
+        0x34, 0x2E, 0x34, 0x2C, 0x4D, 0x2C, 0x30, 0x30, 0x35, 0x2E, 0x35, 0x2C, 0x4E, 0x2C, 0x30,
+        0x31, 0x30, 0x2E, 0x32, 0x2C, 0x4B, 0x2A, 0x34, 0x38, 0x0D, 0x0A, 0x55, 0xF4, 0x1C, 0x97,
+        0x28, 0x01, 0x00, 0x00,
+    ];
+    /// `NMEA`, zlib compressed
+    const NMEA_ZLIB: [u8; 157] = [
+        0x78, 0x9C, 0x9D, 0x8E, 0x41, 0x0A, 0xC2, 0x40, 0x0C, 0x45, 0xF7, 0x42, 0x6F, 0xD1, 0x55,
+        0xF9, 0x84, 0x9F, 0x49, 0xA6, 0x9D, 0x59, 0x0E, 0x22, 0x5D, 0x48, 0x45, 0xB4, 0xE8, 0xFD,
+        0x6F, 0x62, 0x5A, 0x75, 0xE9, 0xC6, 0x2C, 0x12, 0x02, 0xC9, 0x7F, 0xAF, 0x9F, 0xAF, 0xF3,
+        0xDC, 0xA0, 0xC9, 0xB2, 0x56, 0x78, 0xE1, 0x24, 0xB4, 0x82, 0x0B, 0xA8, 0x6A, 0x2A, 0x24,
+        0x71, 0x82, 0x82, 0x05, 0x94, 0x8A, 0xEC, 0x59, 0x1C, 0x0B, 0x7C, 0x8C, 0x65, 0x01, 0x06,
+        0x9F, 0xBA, 0x43, 0x1F, 0x11, 0xF7, 0x86, 0x06, 0x03, 0x1D, 0xCC, 0x00, 0x6B, 0x04, 0x02,
+        0x48, 0x8E, 0xAD, 0x92, 0x64, 0xA8, 0x58, 0x4C, 0x1D, 0xAC, 0xEE, 0x0F, 0xB7, 0xE5, 0xF8,
+        0x65, 0xB6, 0x5F, 0x54, 0xA6, 0x14, 0x30, 0x16, 0x8F, 0x9E, 0x8C, 0x56, 0x63, 0xA1, 0x89,
+        0xE2, 0x39, 0x8C, 0xED, 0x8D, 0xFD, 0x98, 0x27, 0xFE, 0x6D, 0xFE, 0x58, 0xE7, 0x50, 0x76,
+        0x99, 0xB0, 0x82, 0xE6, 0xFB, 0x0D, 0x99, 0xC3, 0x78, 0x0B, 0xA2, 0x24, 0x9C, 0x07, 0x2F,
+        0xDD, 0xE1, 0x05, 0x3D, 0x23, 0x3A, 0xC8,
+    ];
+    /// The last line of `NMEA`, gzip'd with fixed huffman codes
+    const VTG_GZIP: [u8; 63] = [
+        0x1F, 0x8B, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x53, 0x71, 0x0F, 0x08, 0x0B,
+        0x71, 0xD7, 0x31, 0x30, 0x35, 0xD1, 0x33, 0xD7, 0x09, 0xD1, 0x31, 0x30, 0x36, 0xD1, 0x33,
+        0xD1, 0xF1, 0xD5, 0x31, 0x30, 0x30, 0xD5, 0x33, 0xD5, 0xF1, 0xD3, 0x31, 0x30, 0x34, 0xD0,
+        0x33, 0xD2, 0xF1, 0xD6, 0x32, 0xB1, 0xE0, 0xE5, 0x02, 0x00, 0x22, 0x52, 0xCC, 0x5D, 0x2B,
+        0x00, 0x00, 0x00,
+    ];
+    /// `far_data()`, zlib compressed
+    const FAR_ZLIB: [u8; 230] = [
+        0x78, 0xDA, 0xED, 0xDD, 0x61, 0x66, 0x82, 0x01, 0x00, 0x00, 0x50, 0xFD, 0xFE, 0x88, 0x88,
+        0x88, 0x11, 0xA3, 0xDF, 0x31, 0x22, 0x62, 0x44, 0x74, 0x81, 0x88, 0x88, 0x5A, 0x44, 0x74,
+        0x81, 0x88, 0x31, 0xC6, 0x88, 0xE8, 0x02, 0x11, 0x31, 0x62, 0x8C, 0xE8, 0x02, 0x11, 0x11,
+        0x11, 0x11, 0xBB, 0xC0, 0x18, 0x11, 0x31, 0x22, 0x5D, 0xE1, 0xE3, 0xFB, 0xFB, 0xDE, 0x45,
+        0x5E, 0x90, 0xAB, 0xF6, 0x27, 0xAB, 0xDF, 0xF8, 0x53, 0x6D, 0x30, 0x5D, 0xFF, 0x25, 0xF2,
+        0xF5, 0xD7, 0xD9, 0xE6, 0x94, 0x2C, 0x34, 0xDE, 0x3E, 0xB7, 0xE7, 0x54, 0xB1, 0xF9, 0x3E,
+        0xDF, 0x5D, 0xD2, 0xCF, 0xED, 0x8F, 0xAF, 0xFD, 0xFF, 0x43, 0xA9, 0x33, 0xFC, 0x3E, 0x5C,
+        0x33, 0xE5, 0xEE, 0x68, 0x71, 0xBC, 0x3D, 0x56, 0x7A, 0xE3, 0xE5, 0x4F, 0x2C, 0xDB, 0x04,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+        0x29, 0x88, 0xF8, 0x2A, 0xB7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x8A, 0xFA, 0x2A, 0xBF, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x84, 0x14, 0xF5, 0x55, 0xBE,
+        0x03, 0xE7, 0xE3, 0x9A, 0x36,
+    ];
+
+    /// A stand-in for a line-based parser
+    fn parse<R: Read>(input: R) -> Result<Vec<String>, std::io::Error> {
+        BufReader::new(maybe_gunzip(input)).lines().collect()
+    }
+
+    /// Back-references nearly the full window apart, and enough data to compact the window.
+    fn far_data() -> Vec<u8> {
+        let block: Vec<u8> = (0..64_u32).map(|i| ((i * 37 + 11) & 0xFF) as u8).collect();
+        let mut data = block.clone();
+        for filler in [b'a', b'b', b'c'] {
+            data.extend(core::iter::repeat(filler).take(31_000));
+            data.extend(&block);
+        }
+        data
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn plain_and_compressed_parse_the_same() -> Result<(), std::io::Error> {
+        let expected = parse(NMEA.as_bytes())?;
+        assert_eq!(5, expected.len());
+        for compressed in [
+            NMEA_GZIP.as_slice(),
+            NMEA_GZIP_STORED.as_slice(),
+            NMEA_ZLIB.as_slice(),
+        ] {
+            assert!(maybe_gunzip(compressed).is_compressed()?);
+            assert_eq!(expected, parse(compressed)?);
+        }
+        assert!(!maybe_gunzip(NMEA.as_bytes()).is_compressed()?);
+
+        // concatenated gzip members are one stream.
+        let multi = [NMEA_GZIP.as_slice(), VTG_GZIP.as_slice()].concat();
+        let lines = parse(multi.as_slice())?;
+        assert_eq!(expected.as_slice(), lines.get(..5).unwrap_or_default());
+        assert_eq!(expected.last(), lines.last());
+        assert_eq!(6, lines.len());
+
+        // too short to sniff
+        assert_eq!(vec!["$".to_string()], parse("$".as_bytes())?);
+        assert!(parse([].as_slice())?.is_empty());
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn long_distances_in_small_reads() -> Result<(), std::io::Error> {
+        let expected = far_data();
+        let mut all = Vec::new();
+        InflateReader::new_zlib(FAR_ZLIB.as_slice()).read_to_end(&mut all)?;
+        assert_eq!(expected, all);
+
+        let mut reader = maybe_gunzip(FAR_ZLIB.as_slice());
+        let mut chunked = Vec::new();
+        let mut buf = [0u8; 1000];
+        loop {
+            let read = reader.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            chunked.extend_from_slice(buf.get(..read).unwrap_or_default());
+        }
+        assert_eq!(expected, chunked);
+
+        // the raw DEFLATE stream, without the zlib header and trailer
+        let raw = FAR_ZLIB.get(2..FAR_ZLIB.len() - 4).unwrap_or_default();
+        let mut all = Vec::new();
+        InflateReader::new_raw(raw).read_to_end(&mut all)?;
+        assert_eq!(expected, all);
+        Ok(())
+    }
+
+    #[test]
+    pub fn corrupt_data() {
+        let mut bad_crc = NMEA_GZIP;
+        if let Some(byte) = bad_crc.get_mut(NMEA_GZIP.len() - 6) {
+            *byte ^= 0x01;
+        }
+        let err = parse(bad_crc.as_slice()).err().map(|e| e.kind());
+        assert_eq!(Some(ErrorKind::InvalidData), err);
+
+        let truncated = NMEA_ZLIB.get(..NMEA_ZLIB.len() - 10).unwrap_or_default();
+        assert!(parse(truncated).is_err());
+
+        let mut bad_block = NMEA_ZLIB;
+        if let Some(byte) = bad_block.get_mut(2) {
+            // block type 3 is reserved
+            *byte |= 0x06;
+        }
+        let err = parse(bad_block.as_slice()).err().map(|e| e.kind());
+        assert_eq!(Some(ErrorKind::InvalidData), err);
+    }
+}
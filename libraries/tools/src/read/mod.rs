@@ -10,18 +10,24 @@ use std::io::{Error, Read, Write};
 pub use buffer::*;
 pub use conv::*;
 pub use counting::*;
+pub use inflate::*;
 #[cfg(feature = "bits/std")]
 pub use pagefile::*;
+pub use peek::*;
 pub use readerator::*;
+pub use rewind::*;
 
 crate::cfg_feature_std! {
     mod buffer;
 }
 mod conv;
 mod counting;
+mod inflate;
 #[cfg(feature = "bits/std")]
 mod pagefile;
+mod peek;
 mod readerator;
+mod rewind;
 
 ///
 /// Reads the exact amount of bytes into an array and returns it
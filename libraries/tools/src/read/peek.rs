@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+use alloc::vec::Vec;
+use irox_bits::{Bits, Error, ErrorKind, MutBits};
+
+/// Default maximum number of bytes that can be peeked by a [`PeekReader`]
+pub const DEFAULT_PEEK_CAPACITY: usize = 256;
+
+///
+/// Wraps a [`Bits`] source, allowing upcoming bytes to be inspected without consuming them.  A
+/// parser can dispatch on a message-id byte with [`PeekReader::peek_u8`], and then hand the
+/// reader (still positioned at the message-id) to the appropriate
+/// [`crate::packetio::PacketBuilder`].
+///
+/// Peeked bytes are held in a small internal buffer of at most [`PeekReader::capacity`] bytes.
+/// [`PeekReader::fill_buf`] and [`PeekReader::consume`] work like their counterparts in
+/// [`std::io::BufRead`].
+///
+/// # Example
+/// ```
+/// # use irox_bits::Bits;
+/// # use irox_tools::read::PeekReader;
+/// let mut reader = PeekReader::new([0xA0, 0xA2, 0x00, 0x02].as_slice());
+/// assert_eq!(Some(0xA0), reader.peek_u8()?);
+/// assert_eq!(&[0xA0, 0xA2], reader.peek_bytes(2)?);
+///
+/// // nothing has been consumed yet.
+/// assert_eq!(0xA0A2, reader.read_be_u16()?);
+/// assert_eq!(0x0002, reader.read_be_u16()?);
+/// assert_eq!(None, reader.peek_u8()?);
+/// # Ok::<(), irox_bits::Error>(())
+/// ```
+pub struct PeekReader<R: Bits> {
+    reader: R,
+    /// Bytes read from the underlying reader, the unconsumed ones start at `position`
+    buffer: Vec<u8>,
+    position: usize,
+    capacity: usize,
+}
+
+impl<R: Bits> PeekReader<R> {
+    ///
+    /// Creates a new reader able to peek up to [`DEFAULT_PEEK_CAPACITY`] bytes
+    pub fn new(reader: R) -> Self {
+        Self::with_capacity(reader, DEFAULT_PEEK_CAPACITY)
+    }
+
+    ///
+    /// Creates a new reader able to peek up to `capacity` bytes (minimum of 1)
+    pub fn with_capacity(reader: R, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        PeekReader {
+            reader,
+            buffer: Vec::with_capacity(capacity),
+            position: 0,
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of bytes that can be peeked at once
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    ///
+    /// Returns the next byte without consuming it, or [`None`] if the stream has ended.
+    pub fn peek_u8(&mut self) -> Result<Option<u8>, Error> {
+        Ok(self.peek_bytes(1)?.first().copied())
+    }
+
+    ///
+    /// Returns up to the next `len` bytes without consuming them.  Less than `len` bytes are
+    /// returned only if the stream has ended.  Returns an error if `len` exceeds
+    /// [`PeekReader::capacity`].
+    pub fn peek_bytes(&mut self, len: usize) -> Result<&[u8], Error> {
+        if len > self.capacity {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Peek is larger than the buffer capacity",
+            ));
+        }
+        if self.buffer.len() - self.position < len {
+            // shift the unconsumed bytes to the front, so the buffer never grows past capacity.
+            self.buffer.drain(..self.position);
+            self.position = 0;
+            while self.buffer.len() < len {
+                let Some(val) = self.reader.next_u8()? else {
+                    break;
+                };
+                self.buffer.push(val);
+            }
+        }
+        let end = self.buffer.len().min(self.position + len);
+        Ok(self.buffer.get(self.position..end).unwrap_or_default())
+    }
+
+    ///
+    /// Returns the buffered, unconsumed bytes.  If there are none, reads a byte from the
+    /// underlying reader first - the returned slice is only empty if the stream has ended.
+    pub fn fill_buf(&mut self) -> Result<&[u8], Error> {
+        if self.position == self.buffer.len() {
+            return self.peek_bytes(1);
+        }
+        Ok(self.buffer.get(self.position..).unwrap_or_default())
+    }
+
+    ///
+    /// Marks `amt` bytes returned by [`PeekReader::fill_buf`] or [`PeekReader::peek_bytes`] as
+    /// consumed, so they won't be returned again.  Clamped to the number of buffered bytes.
+    pub fn consume(&mut self, amt: usize) {
+        self.position = self.buffer.len().min(self.position + amt);
+        if self.position == self.buffer.len() {
+            self.buffer.clear();
+            self.position = 0;
+        }
+    }
+
+    /// Returns the underlying reader, discarding any buffered bytes
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Bits> Bits for PeekReader<R> {
+    fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+        if let Some(val) = self.buffer.get(self.position).copied() {
+            self.consume(1);
+            return Ok(Some(val));
+        }
+        self.reader.next_u8()
+    }
+
+    fn read_some_into<T: MutBits>(&mut self, into: &mut T) -> Result<usize, Error> {
+        if self.position == self.buffer.len() {
+            return self.reader.read_some_into(into);
+        }
+        let wrote = into.write_some_bytes(self.buffer.get(self.position..).unwrap_or_default());
+        self.consume(wrote);
+        Ok(wrote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::read::PeekReader;
+    use irox_bits::{Bits, Error, ErrorKind};
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn peek_across_boundary() -> Result<(), Error> {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut reader = PeekReader::with_capacity(data.as_slice(), 8);
+
+        assert_eq!(Some(0), reader.peek_u8()?);
+        assert_eq!(&[0, 1, 2], reader.peek_bytes(3)?);
+        assert_eq!(0x0001_0203, reader.read_be_u32()?);
+
+        // only 3 bytes were buffered, the fourth came straight from the underlying reader.
+        assert_eq!(&[4, 5, 6, 7, 8, 9, 10, 11], reader.peek_bytes(8)?);
+        assert_eq!(&[4, 5, 6, 7, 8, 9, 10, 11], reader.fill_buf()?);
+        reader.consume(6);
+        assert_eq!(&[10, 11], reader.fill_buf()?);
+        // spans the 2 buffered bytes and 6 new ones.
+        assert_eq!(&[10, 11, 12, 13, 14, 15, 16, 17], reader.peek_bytes(8)?);
+        assert_eq!(
+            Err(ErrorKind::InvalidInput),
+            reader.peek_bytes(9).map_err(|e| e.kind())
+        );
+
+        // repeatedly consume part of a full buffer, then peek past the end of it.
+        let mut expected = 10u8;
+        while expected < 250 {
+            assert_eq!(Some(expected), reader.peek_u8()?);
+            let peeked = reader.peek_bytes(5)?.to_vec();
+            assert_eq!(
+                (expected..expected + 5).collect::<Vec<u8>>(),
+                peeked,
+                "peek at {expected}"
+            );
+            assert_eq!(Some(expected), reader.next_u8()?);
+            assert_eq!(Some(expected + 1), reader.next_u8()?);
+            expected += 2;
+        }
+
+        // at the end, peeks return what's left.
+        assert_eq!(&[250, 251, 252, 253, 254, 255], reader.peek_bytes(8)?);
+        let mut rest = Vec::new();
+        reader.read_all_into(&mut rest)?;
+        assert_eq!(vec![250, 251, 252, 253, 254, 255], rest);
+        assert_eq!(None, reader.peek_u8()?);
+        assert!(reader.fill_buf()?.is_empty());
+        assert!(reader.peek_bytes(4)?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+use alloc::vec::Vec;
+use std::io::{Error, ErrorKind, Read};
+
+/// Default maximum number of bytes retained after a call to [`RewindReader::mark`]
+pub const DEFAULT_MARK_LIMIT: usize = 8192;
+
+///
+/// Wraps a reader, allowing a bounded amount of lookahead.  [`RewindReader::peek`] returns
+/// upcoming data without consuming it, and [`RewindReader::mark`]/[`RewindReader::reset`] allow
+/// the stream to be re-read from a previous point.  This lets a classifier inspect the start of
+/// a stream, and then hand the whole stream to the appropriate parser.
+///
+/// The number of bytes retained (peeked, or read since the mark) is limited.  Reading past the
+/// limit after a mark invalidates it, and a following `reset()` will fail.
+///
+/// # Example
+/// ```
+/// # use std::io::Read;
+/// # use irox_tools::read::RewindReader;
+/// let mut reader = RewindReader::new(b"$GPGGA,...".as_slice());
+/// assert_eq!(b"$GP", reader.peek(3)?);
+///
+/// reader.mark();
+/// let mut buf = [0u8; 6];
+/// reader.read_exact(&mut buf)?;
+/// assert_eq!(b"$GPGGA", &buf);
+///
+/// reader.reset()?;
+/// reader.read_exact(&mut buf)?;
+/// assert_eq!(b"$GPGGA", &buf);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct RewindReader<T: Read> {
+    reader: T,
+    /// Data read from the underlying reader that is either unconsumed, or retained for a reset.
+    buffer: Vec<u8>,
+    /// Position of the next byte to be returned within the buffer.
+    position: usize,
+    marked: bool,
+    limit: usize,
+}
+
+impl<T: Read> RewindReader<T> {
+    ///
+    /// Creates a new reader with a lookahead limit of [`DEFAULT_MARK_LIMIT`]
+    pub fn new(reader: T) -> Self {
+        Self::with_limit(reader, DEFAULT_MARK_LIMIT)
+    }
+
+    ///
+    /// Creates a new reader that will retain at most `limit` bytes for peeks and resets.
+    pub fn with_limit(reader: T, limit: usize) -> Self {
+        RewindReader {
+            reader,
+            buffer: Vec::new(),
+            position: 0,
+            marked: false,
+            limit,
+        }
+    }
+
+    /// Returns the maximum number of bytes that will be retained for peeks and resets.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    ///
+    /// Marks the current position in the stream, a subsequent call to [`RewindReader::reset`]
+    /// will return to this point.  Any previous mark is discarded.
+    pub fn mark(&mut self) {
+        self.discard_consumed();
+        self.marked = true;
+    }
+
+    ///
+    /// Returns the stream to the position of the last [`RewindReader::mark`].  Returns an error
+    /// if there is no mark, or more than [`RewindReader::limit`] bytes have been read since the
+    /// mark.  The mark is retained, so the stream may be reset again.
+    pub fn reset(&mut self) -> Result<(), Error> {
+        if !self.marked {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Reset without a mark, or read past the mark limit",
+            ));
+        }
+        self.position = 0;
+        Ok(())
+    }
+
+    ///
+    /// Returns up to the next `len` bytes of the stream, without consuming them.  Less than `len`
+    /// bytes are returned only if the underlying reader reached EOF.  Returns an error if `len`
+    /// exceeds [`RewindReader::limit`].
+    pub fn peek(&mut self, len: usize) -> Result<&[u8], Error> {
+        if len > self.limit {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Peek is larger than the mark limit",
+            ));
+        }
+        if !self.marked {
+            self.discard_consumed();
+        }
+        let mut buf = [0u8; 4096];
+        while self.buffer.len() - self.position < len {
+            let want = (len - (self.buffer.len() - self.position)).min(buf.len());
+            let Some(buf) = buf.get_mut(..want) else {
+                break;
+            };
+            let read = self.reader.read(buf)?;
+            if read == 0 {
+                break;
+            }
+            self.buffer
+                .extend_from_slice(buf.get(..read).unwrap_or_default());
+        }
+        let end = self.buffer.len().min(self.position + len);
+        Ok(self.buffer.get(self.position..end).unwrap_or_default())
+    }
+
+    /// Drops any data before the current position
+    fn discard_consumed(&mut self) {
+        self.buffer.drain(..self.position);
+        self.position = 0;
+    }
+}
+
+impl<T: Read> Read for RewindReader<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if let Some(buffered) = self.buffer.get(self.position..) {
+            if !buffered.is_empty() {
+                let len = buffered.len().min(buf.len());
+                if let (Some(dst), Some(src)) = (buf.get_mut(..len), buffered.get(..len)) {
+                    dst.copy_from_slice(src);
+                }
+                self.position += len;
+                if !self.marked && self.position == self.buffer.len() {
+                    self.discard_consumed();
+                }
+                return Ok(len);
+            }
+        }
+
+        let read = self.reader.read(buf)?;
+        if self.marked {
+            if self.buffer.len() + read > self.limit {
+                // read too far past the mark, it can't be restored anymore.
+                self.marked = false;
+                self.buffer.clear();
+                self.position = 0;
+            } else {
+                self.buffer
+                    .extend_from_slice(buf.get(..read).unwrap_or_default());
+                self.position = self.buffer.len();
+            }
+        }
+        Ok(read)
+    }
+}
+
+impl<T: Read> irox_bits::Bits for RewindReader<T> {
+    fn next_u8(&mut self) -> Result<Option<u8>, irox_bits::Error> {
+        let mut byte: u8 = 0;
+        let read = self.read(core::slice::from_mut(&mut byte))?;
+        if read < 1 {
+            return Ok(None);
+        }
+        Ok(Some(byte))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::read::RewindReader;
+    use std::io::{ErrorKind, Read};
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn mark_read_reset() -> Result<(), std::io::Error> {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut reader = RewindReader::with_limit(data.as_slice(), 64);
+
+        let mut buf = [0u8; 10];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(data.get(..10), Some(buf.as_slice()));
+
+        reader.mark();
+        let mut first = [0u8; 40];
+        reader.read_exact(&mut first)?;
+        assert_eq!(data.get(10..50), Some(first.as_slice()));
+
+        reader.reset()?;
+        let mut second = [0u8; 50];
+        reader.read_exact(&mut second)?;
+        assert_eq!(data.get(10..60), Some(second.as_slice()));
+
+        // reset is repeatable
+        reader.reset()?;
+        reader.read_exact(&mut second)?;
+        assert_eq!(data.get(10..60), Some(second.as_slice()));
+
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest)?;
+        assert_eq!(data.get(60..), Some(rest.as_slice()));
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn peek_doesnt_consume() -> Result<(), std::io::Error> {
+        let data: Vec<u8> = (0..100).collect();
+        let mut reader = RewindReader::with_limit(data.as_slice(), 64);
+
+        assert_eq!(data.get(..4), Some(reader.peek(4)?));
+        assert_eq!(data.get(..20), Some(reader.peek(20)?));
+
+        let mut buf = [0u8; 8];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(data.get(..8), Some(buf.as_slice()));
+        assert_eq!(data.get(8..12), Some(reader.peek(4)?));
+
+        assert_eq!(
+            Some(ErrorKind::InvalidInput),
+            reader.peek(65).err().map(|e| e.kind())
+        );
+
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest)?;
+        assert_eq!(data.get(8..), Some(rest.as_slice()));
+        // peek at EOF returns whatever is left
+        assert!(reader.peek(4)?.is_empty());
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn reset_past_limit_fails() -> Result<(), std::io::Error> {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut reader = RewindReader::with_limit(data.as_slice(), 16);
+        assert!(reader.reset().is_err());
+
+        reader.mark();
+        let mut buf = [0u8; 32];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(data.get(..32), Some(buf.as_slice()));
+        assert_eq!(
+            Some(ErrorKind::InvalidInput),
+            reader.reset().err().map(|e| e.kind())
+        );
+
+        // reading continues normally after the mark is lost
+        reader.read_exact(&mut buf)?;
+        assert_eq!(data.get(32..64), Some(buf.as_slice()));
+        Ok(())
+    }
+}
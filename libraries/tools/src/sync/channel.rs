@@ -0,0 +1,374 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! A fixed-capacity, multi-producer, single-consumer channel.  Senders block when the channel is
+//! full, providing backpressure to the producers.
+//!
+//! # Example:
+//! ```
+//! use irox_tools::sync::bounded;
+//!
+//! let (tx, rx) = bounded::<u32>(2);
+//! let producer = std::thread::spawn(move || {
+//!     for i in 0..100 {
+//!         // blocks until the receiver has caught up.
+//!         tx.send(i).unwrap();
+//!     }
+//! });
+//! let total: u32 = rx.iter().sum();
+//! producer.join().unwrap();
+//! assert_eq!(4950, total);
+//! ```
+
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use core::fmt::{Debug, Display, Formatter};
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+///
+/// The receiver was dropped, the value couldn't be sent and is returned.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SendError<T>(pub T);
+
+///
+/// All the senders were dropped and the channel is empty.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RecvError;
+
+///
+/// Reasons a [`Sender::try_send`] failed, the value is returned.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TrySendError<T> {
+    /// The channel is at capacity
+    Full(T),
+    /// The receiver was dropped
+    Disconnected(T),
+}
+
+///
+/// Reasons a [`Receiver::try_recv`] failed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TryRecvError {
+    /// There are no values waiting in the channel
+    Empty,
+    /// All the senders were dropped and the channel is empty
+    Disconnected,
+}
+
+impl<T> Display for SendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "sending on a disconnected channel")
+    }
+}
+impl<T: Debug> std::error::Error for SendError<T> {}
+
+impl Display for RecvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "receiving on an empty and disconnected channel")
+    }
+}
+impl std::error::Error for RecvError {}
+
+impl<T> Display for TrySendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TrySendError::Full(_) => write!(f, "sending on a full channel"),
+            TrySendError::Disconnected(_) => write!(f, "sending on a disconnected channel"),
+        }
+    }
+}
+impl<T: Debug> std::error::Error for TrySendError<T> {}
+
+impl Display for TryRecvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "receiving on an empty channel"),
+            TryRecvError::Disconnected => {
+                write!(f, "receiving on an empty and disconnected channel")
+            }
+        }
+    }
+}
+impl std::error::Error for TryRecvError {}
+
+struct ChannelState<T> {
+    buffer: VecDeque<T>,
+    senders: usize,
+    receiver: bool,
+}
+
+struct Channel<T> {
+    capacity: usize,
+    state: Mutex<ChannelState<T>>,
+    /// Notified when a value is received, or the receiver is dropped
+    not_full: Condvar,
+    /// Notified when a value is sent, or the last sender is dropped
+    not_empty: Condvar,
+}
+
+impl<T> Channel<T> {
+    /// Locks the state, or returns [`None`] if another thread panicked while holding the lock,
+    /// which is treated as the channel being disconnected.
+    fn lock(&self) -> Option<MutexGuard<'_, ChannelState<T>>> {
+        self.state.lock().ok()
+    }
+}
+
+///
+/// Creates a channel that holds up to `capacity` values (minimum of 1).  The [`Sender`] can be
+/// cloned for multiple producers.
+#[must_use]
+pub fn bounded<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    let capacity = capacity.max(1);
+    let channel = Arc::new(Channel {
+        capacity,
+        state: Mutex::new(ChannelState {
+            buffer: VecDeque::with_capacity(capacity),
+            senders: 1,
+            receiver: true,
+        }),
+        not_full: Condvar::new(),
+        not_empty: Condvar::new(),
+    });
+    (
+        Sender {
+            channel: channel.clone(),
+        },
+        Receiver { channel },
+    )
+}
+
+///
+/// The sending half of a [`bounded`] channel.
+pub struct Sender<T> {
+    channel: Arc<Channel<T>>,
+}
+
+impl<T> Sender<T> {
+    ///
+    /// Sends the value, blocking while the channel is full.  Fails if the receiver has been
+    /// dropped.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let channel = &self.channel;
+        let Some(state) = channel.lock() else {
+            return Err(SendError(value));
+        };
+        let Ok(mut state) = channel
+            .not_full
+            .wait_while(state, |s| s.receiver && s.buffer.len() >= channel.capacity)
+        else {
+            return Err(SendError(value));
+        };
+        if !state.receiver {
+            return Err(SendError(value));
+        }
+        state.buffer.push_back(value);
+        drop(state);
+        channel.not_empty.notify_one();
+        Ok(())
+    }
+
+    ///
+    /// Sends the value if there's room in the channel, without blocking.
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let channel = &self.channel;
+        let Some(mut state) = channel.lock() else {
+            return Err(TrySendError::Disconnected(value));
+        };
+        if !state.receiver {
+            return Err(TrySendError::Disconnected(value));
+        }
+        if state.buffer.len() >= channel.capacity {
+            return Err(TrySendError::Full(value));
+        }
+        state.buffer.push_back(value);
+        drop(state);
+        channel.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// Returns the maximum number of values the channel holds
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.channel.capacity
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        if let Some(mut state) = self.channel.lock() {
+            state.senders += 1;
+        }
+        Sender {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        if let Some(mut state) = self.channel.lock() {
+            state.senders -= 1;
+        }
+        self.channel.not_empty.notify_all();
+    }
+}
+
+///
+/// The receiving half of a [`bounded`] channel.
+pub struct Receiver<T> {
+    channel: Arc<Channel<T>>,
+}
+
+impl<T> Receiver<T> {
+    ///
+    /// Receives the next value, blocking while the channel is empty.  Fails once all the senders
+    /// have been dropped and the remaining values have been received.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let channel = &self.channel;
+        let state = channel.lock().ok_or(RecvError)?;
+        let mut state = channel
+            .not_empty
+            .wait_while(state, |s| s.senders > 0 && s.buffer.is_empty())
+            .map_err(|_| RecvError)?;
+        let value = state.buffer.pop_front().ok_or(RecvError)?;
+        drop(state);
+        channel.not_full.notify_one();
+        Ok(value)
+    }
+
+    ///
+    /// Receives the next value if one is waiting, without blocking.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let channel = &self.channel;
+        let mut state = channel.lock().ok_or(TryRecvError::Disconnected)?;
+        let Some(value) = state.buffer.pop_front() else {
+            if state.senders == 0 {
+                return Err(TryRecvError::Disconnected);
+            }
+            return Err(TryRecvError::Empty);
+        };
+        drop(state);
+        channel.not_full.notify_one();
+        Ok(value)
+    }
+
+    ///
+    /// Returns a blocking iterator over the received values, ending when all the senders have
+    /// been dropped.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        core::iter::from_fn(|| self.recv().ok())
+    }
+
+    /// Returns the number of values waiting in the channel
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.channel
+            .lock()
+            .map(|s| s.buffer.len())
+            .unwrap_or_default()
+    }
+
+    /// Returns true if there are no values waiting in the channel
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of values the channel holds
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.channel.capacity
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        if let Some(mut state) = self.channel.lock() {
+            state.receiver = false;
+        }
+        self.channel.not_full.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::{bounded, RecvError, SendError, TryRecvError, TrySendError};
+    use alloc::vec::Vec;
+
+    #[test]
+    pub fn try_variants() {
+        let (tx, rx) = bounded::<u32>(2);
+        assert_eq!(Err(TryRecvError::Empty), rx.try_recv());
+        assert_eq!(Ok(()), tx.try_send(1));
+        assert_eq!(Ok(()), tx.try_send(2));
+        assert_eq!(Err(TrySendError::Full(3)), tx.try_send(3));
+        assert_eq!(2, rx.len());
+        assert_eq!(Ok(1), rx.try_recv());
+        assert_eq!(Ok(()), tx.try_send(3));
+
+        drop(tx);
+        assert_eq!(Ok(2), rx.try_recv());
+        assert_eq!(Ok(3), rx.recv());
+        assert_eq!(Err(TryRecvError::Disconnected), rx.try_recv());
+        assert_eq!(Err(RecvError), rx.recv());
+
+        let (tx, rx) = bounded::<u32>(2);
+        drop(rx);
+        assert_eq!(Err(TrySendError::Disconnected(1)), tx.try_send(1));
+        assert_eq!(Err(SendError(1)), tx.send(1));
+    }
+
+    #[test]
+    pub fn stress_test() {
+        const PRODUCERS: u64 = 4;
+        const PER_PRODUCER: u64 = 250_000;
+        let (tx, rx) = bounded::<(u64, u64)>(64);
+        let producers = (0..PRODUCERS)
+            .map(|producer| {
+                let tx = tx.clone();
+                std::thread::spawn(move || {
+                    for i in 0..PER_PRODUCER {
+                        if tx.send((producer, i)).is_err() {
+                            return;
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        drop(tx);
+
+        // each producer's values arrive in the order they were sent.
+        let mut next = [0u64; PRODUCERS as usize];
+        let mut received = 0;
+        for (producer, i) in rx.iter() {
+            assert!(rx.len() <= rx.capacity());
+            let expected = next.get_mut(producer as usize);
+            assert_eq!(Some(i), expected.as_deref().copied());
+            if let Some(expected) = expected {
+                *expected += 1;
+            }
+            received += 1;
+        }
+        for producer in producers {
+            assert!(producer.join().is_ok());
+        }
+        assert_eq!(PRODUCERS * PER_PRODUCER, received);
+        assert!(next.iter().all(|n| *n == PER_PRODUCER));
+    }
+
+    #[test]
+    pub fn send_blocks_when_full() {
+        let (tx, rx) = bounded::<u32>(1);
+        assert_eq!(Ok(()), tx.send(1));
+        let producer = std::thread::spawn(move || tx.send(2));
+        std::thread::sleep(core::time::Duration::from_millis(50));
+        assert!(!producer.is_finished());
+        assert_eq!(Ok(1), rx.recv());
+        assert!(matches!(producer.join(), Ok(Ok(()))));
+        assert_eq!(Ok(2), rx.recv());
+        assert_eq!(Err(RecvError), rx.recv());
+    }
+}
@@ -4,11 +4,15 @@
 
 //! More complex synchronization primitives than in the STD.
 
+pub use channel::*;
 pub use eventual::*;
 pub use flags::*;
 //pub use once::*;
 pub use optional::*;
+pub use pool::*;
+mod channel;
 mod eventual;
+mod flags;
 mod once;
 mod optional;
-mod flags;
+mod pool;
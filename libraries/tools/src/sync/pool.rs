@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! A small fixed-size thread pool.
+//!
+//! # Example:
+//! ```
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use std::sync::Arc;
+//! use irox_tools::sync::ThreadPool;
+//!
+//! let pool = ThreadPool::new(4);
+//! let total = Arc::new(AtomicU64::new(0));
+//! for i in 0..100 {
+//!     let total = total.clone();
+//!     pool.execute(move || {
+//!         total.fetch_add(i, Ordering::Relaxed);
+//!     });
+//! }
+//! pool.wait_idle();
+//! assert_eq!(4950, total.load(Ordering::Relaxed));
+//!
+//! // scoped tasks can borrow from the stack
+//! let data = vec![1, 2, 3, 4];
+//! let sum = AtomicU64::new(0);
+//! pool.scope(|s| {
+//!     for v in &data {
+//!         let sum = &sum;
+//!         s.execute(move || {
+//!             sum.fetch_add(*v, Ordering::Relaxed);
+//!         });
+//!     }
+//! });
+//! assert_eq!(10, sum.load(Ordering::Relaxed));
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::panic::AssertUnwindSafe;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::panic::{catch_unwind, resume_unwind};
+use std::sync::{Condvar, Mutex};
+use std::thread::JoinHandle;
+
+type Job<'a> = Box<dyn FnOnce() + Send + 'a>;
+
+/// A queue of jobs shared between a set of workers.
+struct JobQueue<'a> {
+    state: Mutex<QueueState<'a>>,
+    /// Notified when a job is added, or the queue is shut down
+    job_ready: Condvar,
+    /// Notified when a worker finishes a job
+    job_done: Condvar,
+    panicked: AtomicUsize,
+}
+
+struct QueueState<'a> {
+    jobs: VecDeque<Job<'a>>,
+    active: usize,
+    shutdown: bool,
+}
+
+impl<'a> JobQueue<'a> {
+    fn new() -> Self {
+        JobQueue {
+            state: Mutex::new(QueueState {
+                jobs: VecDeque::new(),
+                active: 0,
+                shutdown: false,
+            }),
+            job_ready: Condvar::new(),
+            job_done: Condvar::new(),
+            panicked: AtomicUsize::new(0),
+        }
+    }
+
+    fn push(&self, job: Job<'a>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.jobs.push_back(job);
+        }
+        self.job_ready.notify_one();
+    }
+
+    fn shutdown(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.shutdown = true;
+        }
+        self.job_ready.notify_all();
+    }
+
+    /// Blocks until a job is available, or returns [`None`] if the queue has been shut down and
+    /// there is no remaining work.
+    fn next_job(&self) -> Option<Job<'a>> {
+        let Ok(state) = self.state.lock() else {
+            return None;
+        };
+        let Ok(mut state) = self
+            .job_ready
+            .wait_while(state, |s| s.jobs.is_empty() && !s.shutdown)
+        else {
+            return None;
+        };
+        let job = state.jobs.pop_front()?;
+        state.active += 1;
+        Some(job)
+    }
+
+    /// Runs jobs until the queue is shut down.  Panicking jobs are caught and counted, so they
+    /// don't take the worker (or the queue's lock) down with them.
+    fn run_worker(&self) {
+        while let Some(job) = self.next_job() {
+            if catch_unwind(AssertUnwindSafe(job)).is_err() {
+                self.panicked.fetch_add(1, Ordering::Relaxed);
+            }
+            if let Ok(mut state) = self.state.lock() {
+                state.active -= 1;
+            }
+            self.job_done.notify_all();
+        }
+    }
+
+    fn wait_idle(&self) {
+        let Ok(state) = self.state.lock() else {
+            return;
+        };
+        let _guard = self
+            .job_done
+            .wait_while(state, |s| !s.jobs.is_empty() || s.active > 0);
+    }
+}
+
+///
+/// A fixed-size pool of worker threads.  Tasks are pulled from a shared queue in submission
+/// order.  When the pool is dropped, any queued tasks are completed and the workers are joined.
+pub struct ThreadPool {
+    queue: Arc<JobQueue<'static>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl ThreadPool {
+    ///
+    /// Creates a new pool with `num_threads` workers (minimum of 1)
+    #[must_use]
+    pub fn new(num_threads: usize) -> ThreadPool {
+        let num_threads = num_threads.max(1);
+        let queue = Arc::new(JobQueue::new());
+        let workers = (0..num_threads)
+            .map(|_| {
+                let queue = queue.clone();
+                std::thread::spawn(move || queue.run_worker())
+            })
+            .collect();
+        ThreadPool { queue, workers }
+    }
+
+    /// Returns the number of worker threads in this pool
+    #[must_use]
+    pub fn num_threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    ///
+    /// Queues the task to be run on the next available worker
+    pub fn execute<F: FnOnce() + Send + 'static>(&self, task: F) {
+        self.queue.push(Box::new(task));
+    }
+
+    ///
+    /// Blocks until all queued tasks have been completed.
+    pub fn wait_idle(&self) {
+        self.queue.wait_idle();
+    }
+
+    ///
+    /// Returns the total number of tasks that have panicked in this pool.
+    #[must_use]
+    pub fn panicked_count(&self) -> usize {
+        self.queue.panicked.load(Ordering::Relaxed)
+    }
+
+    ///
+    /// Creates a scope in which tasks may borrow data from the enclosing stack frame.  All tasks
+    /// spawned into the scope are complete when this function returns.
+    ///
+    /// Handing non-`'static` tasks to the long-lived workers isn't possible without unsafe code,
+    /// so the scope's tasks are run on a set of [`std::thread::scope`] threads of the same size as
+    /// this pool.
+    pub fn scope<'env, F, R>(&self, func: F) -> R
+    where
+        F: FnOnce(&PoolScope<'env>) -> R,
+    {
+        let scope = PoolScope {
+            queue: JobQueue::new(),
+        };
+        let out = std::thread::scope(|s| {
+            for _ in 0..self.num_threads() {
+                let queue = &scope.queue;
+                s.spawn(move || queue.run_worker());
+            }
+            // the workers must always be told to stop, or the scope will never exit.
+            let out = catch_unwind(AssertUnwindSafe(|| func(&scope)));
+            scope.queue.shutdown();
+            out
+        });
+        self.queue.panicked.fetch_add(
+            scope.queue.panicked.load(Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+        match out {
+            Ok(out) => out,
+            Err(e) => resume_unwind(e),
+        }
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        self.queue.shutdown();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+///
+/// A handle to submit borrowing tasks within a [`ThreadPool::scope`]
+pub struct PoolScope<'env> {
+    queue: JobQueue<'env>,
+}
+
+impl<'env> PoolScope<'env> {
+    ///
+    /// Queues the task to be run on the next available scoped worker
+    pub fn execute<F: FnOnce() + Send + 'env>(&self, task: F) {
+        self.queue.push(Box::new(task));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::ThreadPool;
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    #[test]
+    pub fn stress_test() {
+        let pool = ThreadPool::new(8);
+        let total = Arc::new(AtomicU64::new(0));
+        for i in 0..10_000 {
+            let total = total.clone();
+            pool.execute(move || {
+                total.fetch_add(i, Ordering::Relaxed);
+            });
+        }
+        pool.wait_idle();
+        assert_eq!(49_995_000, total.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    pub fn panics_dont_poison() {
+        let pool = ThreadPool::new(2);
+        let total = Arc::new(AtomicU64::new(0));
+        for i in 0..100 {
+            let total = total.clone();
+            pool.execute(move || {
+                assert_ne!(0, i % 10, "task {i} failed");
+                total.fetch_add(1, Ordering::Relaxed);
+            });
+        }
+        pool.wait_idle();
+        assert_eq!(90, total.load(Ordering::Relaxed));
+        assert_eq!(10, pool.panicked_count());
+    }
+
+    #[test]
+    pub fn scoped_borrow() {
+        let pool = ThreadPool::new(4);
+        let data: Vec<u64> = (0..10_000).collect();
+        let total = AtomicU64::new(0);
+        pool.scope(|s| {
+            for chunk in data.chunks(100) {
+                let total = &total;
+                s.execute(move || {
+                    total.fetch_add(chunk.iter().sum(), Ordering::Relaxed);
+                });
+            }
+        });
+        assert_eq!(49_995_000, total.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    pub fn drop_completes_queued() {
+        let total = Arc::new(AtomicU64::new(0));
+        {
+            let pool = ThreadPool::new(1);
+            for _ in 0..1000 {
+                let total = total.clone();
+                pool.execute(move || {
+                    total.fetch_add(1, Ordering::Relaxed);
+                });
+            }
+        }
+        assert_eq!(1000, total.load(Ordering::Relaxed));
+    }
+}
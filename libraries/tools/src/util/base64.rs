@@ -54,6 +54,7 @@ pub struct SixBitCodec {
     reverse: BTreeMap<u8, u8>,
     fail_on_invalid_decode: bool,
     pad: u8,
+    pad_output: bool,
 }
 impl SixBitCodec {
     /// Creates a new codec, using the provided alphabet.
@@ -68,6 +69,7 @@ impl SixBitCodec {
             reverse,
             fail_on_invalid_decode: false,
             pad: b'=',
+            pad_output: true,
         }
     }
     /// sets the end padding character (defaults to `'='`)
@@ -75,6 +77,12 @@ impl SixBitCodec {
         self.pad = pad;
         self
     }
+    /// if set, encoding will not write the end padding characters.  Decoding accepts input with
+    /// or without padding either way.
+    pub fn set_no_padding(&mut self) -> &mut Self {
+        self.pad_output = false;
+        self
+    }
     /// if set, decoding will return an error on invalid character - otherwise will just skip it.
     pub fn set_fail_on_invalid_character(&mut self) -> &mut Self {
         self.fail_on_invalid_decode = true;
@@ -120,20 +128,22 @@ impl Codec for SixBitCodec {
                 getalpha!(alpha, a),
                 getalpha!(alpha, b),
                 getalpha!(alpha, c),
-                self.pad,
             ])?;
-            written += 4;
+            written += 3;
+            if self.pad_output {
+                output.write_u8(self.pad)?;
+                written += 1;
+            }
         } else if ctr == 1 {
             buf <<= 4;
             let a = ((buf & 0xFC0) >> 6) as usize;
             let b = (buf & 0x030) as usize;
-            output.write_all_bytes(&[
-                getalpha!(alpha, a),
-                getalpha!(alpha, b),
-                self.pad,
-                self.pad,
-            ])?;
-            written += 4;
+            output.write_all_bytes(&[getalpha!(alpha, a), getalpha!(alpha, b)])?;
+            written += 2;
+            if self.pad_output {
+                output.write_all_bytes(&[self.pad, self.pad])?;
+                written += 2;
+            }
         }
         Ok(written)
     }
@@ -192,6 +202,14 @@ pub fn new_base64_codec() -> SixBitCodec {
 pub fn new_base64_safe_codec() -> SixBitCodec {
     SixBitCodec::new(&BASE64URL_ALPHABET)
 }
+/// Creates and returns a [`SixBitCodec`] compliant with the RFC4648 "Base64 URL" standard alphabet
+/// ([`BASE64URL_ALPHABET`]), that doesn't write the end padding characters when encoding, as
+/// commonly used in URLs and tokens.
+pub fn new_base64_safe_nopad_codec() -> SixBitCodec {
+    let mut codec = SixBitCodec::new(&BASE64URL_ALPHABET);
+    codec.set_no_padding();
+    codec
+}
 
 /// Encodes the provided the input, writing the encoding to output, using the standard RFC-4648
 /// [`BASE64_ALPHABET`], upon success, returns the number of bytes written out
@@ -241,11 +259,18 @@ crate::cfg_feature_alloc! {
         new_base64_safe_codec().decode_to_str_lossy(input)
     }
 }
+crate::cfg_feature_alloc! {
+    /// Encodes the provided input to a string, using the filesystem and URL-safe RFC-4648
+    /// [`BASE64URL_ALPHABET`], without the end padding characters.
+    pub fn base64_encode_safe_nopad_to_str<I: Bits>(input: I) -> Result<alloc::string::String, Error> {
+        new_base64_safe_nopad_codec().encode_to_str(input)
+    }
+}
 
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod tests {
-    use crate::base64::new_base64_codec;
+    use crate::base64::{new_base64_codec, new_base64_safe_codec, new_base64_safe_nopad_codec};
     use crate::codec::Codec;
 
     #[allow(clippy::panic_in_result_fn)]
@@ -268,4 +293,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn base64_url_tests() -> Result<(), std::io::Error> {
+        let padded = new_base64_safe_codec();
+        let unpadded = new_base64_safe_nopad_codec();
+        let tests: [(&[u8], &str, &str); 9] = [
+            (b"", "", ""),
+            (b"f", "Zg==", "Zg"),
+            (b"fo", "Zm8=", "Zm8"),
+            (b"foo", "Zm9v", "Zm9v"),
+            (b"foob", "Zm9vYg==", "Zm9vYg"),
+            (b"fooba", "Zm9vYmE=", "Zm9vYmE"),
+            (b"foobar", "Zm9vYmFy", "Zm9vYmFy"),
+            (&[0xFB, 0xFF], "-_8=", "-_8"),
+            (
+                &[0x14, 0xFB, 0x9C, 0x03, 0xD9, 0x7E],
+                "FPucA9l-",
+                "FPucA9l-",
+            ),
+        ];
+        for (i, p, u) in tests {
+            assert_eq!(p, padded.encode_to_str(i)?);
+            assert_eq!(u, unpadded.encode_to_str(i)?);
+            // either decodes either.
+            assert_eq!(i, padded.decode_to_vec(u.as_bytes())?);
+            assert_eq!(i, unpadded.decode_to_vec(p.as_bytes())?);
+        }
+        assert_eq!(
+            "+/8=",
+            new_base64_codec().encode_to_str([0xFBu8, 0xFF].as_slice())?
+        );
+
+        Ok(())
+    }
 }
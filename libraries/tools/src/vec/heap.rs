@@ -0,0 +1,292 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+struct Entry<K, P> {
+    key: K,
+    priority: P,
+    /// Insertion sequence number, used to break ties in FIFO order.
+    seq: u64,
+}
+
+///
+/// A binary min-heap of keys and their priorities, where the priority of an entry can be
+/// changed after it's been inserted.  Each key appears at most once in the heap, and the position
+/// of each key is tracked so the priority can be lowered in `O(log n)`.
+///
+/// Entries with equal priorities are popped in the order they were pushed.  Priorities are
+/// compared with [`Ord`], or a comparison function provided to [`BinaryHeapMap::new_by`].
+///
+/// # Example
+/// ```
+/// # use irox_tools::vec::BinaryHeapMap;
+/// let mut heap = BinaryHeapMap::new();
+/// heap.push("a", 5);
+/// heap.push("b", 3);
+/// heap.push("c", 7);
+/// heap.decrease_priority(&"c", 1);
+///
+/// assert_eq!(Some(("c", 1)), heap.pop_min());
+/// assert_eq!(Some(("b", 3)), heap.pop_min());
+/// assert_eq!(Some(("a", 5)), heap.pop_min());
+/// assert_eq!(None, heap.pop_min());
+/// ```
+pub struct BinaryHeapMap<K, P, F = fn(&P, &P) -> Ordering> {
+    heap: Vec<Entry<K, P>>,
+    /// Position of each key within the heap
+    positions: BTreeMap<K, usize>,
+    next_seq: u64,
+    cmp: F,
+}
+
+impl<K: Ord + Clone, P: Ord> Default for BinaryHeapMap<K, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Clone, P: Ord> BinaryHeapMap<K, P> {
+    ///
+    /// Creates a new, empty heap
+    #[must_use]
+    pub fn new() -> Self {
+        Self::new_by(P::cmp)
+    }
+}
+
+impl<K: Ord + Clone, P, F: FnMut(&P, &P) -> Ordering> BinaryHeapMap<K, P, F> {
+    ///
+    /// Creates a new, empty heap, ordering the priorities with the provided comparison function
+    /// rather than [`Ord`].
+    #[must_use]
+    pub fn new_by(cmp: F) -> Self {
+        BinaryHeapMap {
+            heap: Vec::new(),
+            positions: BTreeMap::new(),
+            next_seq: 0,
+            cmp,
+        }
+    }
+
+    /// Returns the number of entries in the heap
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns true if there are no entries in the heap
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns true if the key is present in the heap
+    #[must_use]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    /// Returns the current priority of the key, if it's present in the heap
+    #[must_use]
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        let idx = self.positions.get(key)?;
+        self.heap.get(*idx).map(|e| &e.priority)
+    }
+
+    /// Returns the entry with the lowest priority, without removing it.
+    #[must_use]
+    pub fn peek_min(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|e| (&e.key, &e.priority))
+    }
+
+    ///
+    /// Inserts the key with the provided priority.  If the key was already present, its priority
+    /// is replaced (and it's ordered as if newly pushed), and the previous priority is returned.
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        if let Some(idx) = self.positions.get(&key).copied() {
+            let entry = self.heap.get_mut(idx)?;
+            let old = core::mem::replace(&mut entry.priority, priority);
+            entry.seq = seq;
+            self.sift_up(idx);
+            self.sift_down(idx);
+            return Some(old);
+        }
+        let idx = self.heap.len();
+        self.positions.insert(key.clone(), idx);
+        self.heap.push(Entry { key, priority, seq });
+        self.sift_up(idx);
+        None
+    }
+
+    ///
+    /// Removes and returns the entry with the lowest priority.
+    pub fn pop_min(&mut self) -> Option<(K, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let entry = self.heap.swap_remove(0);
+        self.positions.remove(&entry.key);
+        if let Some(first) = self.heap.first() {
+            self.positions.insert(first.key.clone(), 0);
+            self.sift_down(0);
+        }
+        Some((entry.key, entry.priority))
+    }
+
+    ///
+    /// Lowers the priority of the key to the provided value.  Returns true if the priority was
+    /// changed, or false if the key isn't present or the new priority isn't lower than the
+    /// current priority.  The entry keeps its original place in the FIFO tie-break order.
+    pub fn decrease_priority(&mut self, key: &K, priority: P) -> bool {
+        let Some(idx) = self.positions.get(key).copied() else {
+            return false;
+        };
+        let Some(entry) = self.heap.get_mut(idx) else {
+            return false;
+        };
+        if (self.cmp)(&priority, &entry.priority) != Ordering::Less {
+            return false;
+        }
+        entry.priority = priority;
+        self.sift_up(idx);
+        true
+    }
+
+    /// Returns true if the entry at `a` should be popped before the entry at `b`
+    fn before(&mut self, a: usize, b: usize) -> bool {
+        match (self.heap.get(a), self.heap.get(b)) {
+            (Some(a), Some(b)) => {
+                (self.cmp)(&a.priority, &b.priority).then(a.seq.cmp(&b.seq)) == Ordering::Less
+            }
+            _ => false,
+        }
+    }
+
+    /// Swaps two entries, updating their tracked positions
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        for idx in [a, b] {
+            if let Some(entry) = self.heap.get(idx) {
+                if let Some(pos) = self.positions.get_mut(&entry.key) {
+                    *pos = idx;
+                }
+            }
+        }
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if !self.before(idx, parent) {
+                break;
+            }
+            self.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut idx: usize) {
+        loop {
+            let left = 2 * idx + 1;
+            let right = left + 1;
+            let mut smallest = idx;
+            if self.before(left, smallest) {
+                smallest = left;
+            }
+            if self.before(right, smallest) {
+                smallest = right;
+            }
+            if smallest == idx {
+                break;
+            }
+            self.swap(idx, smallest);
+            idx = smallest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vec::BinaryHeapMap;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    pub fn pop_order_matches_sorted() {
+        let priorities: Vec<u32> = vec![42, 7, 19, 7, 88, 3, 56, 19, 0, 23, 7, 64, 11, 3, 99, 31];
+        let mut heap = BinaryHeapMap::new();
+        for (key, priority) in priorities.iter().enumerate() {
+            assert_eq!(None, heap.push(key, *priority));
+        }
+        assert_eq!(priorities.len(), heap.len());
+
+        // a stable sort by priority gives the FIFO order for ties.
+        let mut expected: Vec<(usize, u32)> = priorities.iter().copied().enumerate().collect();
+        expected.sort_by_key(|(_, p)| *p);
+
+        let mut popped = Vec::new();
+        while let Some(entry) = heap.pop_min() {
+            popped.push(entry);
+        }
+        assert_eq!(expected, popped);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    pub fn decrease_key() {
+        let mut heap = BinaryHeapMap::new();
+        for (key, priority) in [('a', 10), ('b', 20), ('c', 30), ('d', 40), ('e', 50)] {
+            heap.push(key, priority);
+        }
+        assert_eq!(Some((&'a', &10)), heap.peek_min());
+
+        assert!(heap.decrease_priority(&'d', 5));
+        assert_eq!(Some(&5), heap.priority(&'d'));
+        assert_eq!(Some((&'d', &5)), heap.peek_min());
+
+        // not lower, or not present
+        assert!(!heap.decrease_priority(&'c', 30));
+        assert!(!heap.decrease_priority(&'c', 35));
+        assert!(!heap.decrease_priority(&'z', 1));
+
+        // ties with an earlier entry keep the original insertion order
+        assert!(heap.decrease_priority(&'e', 20));
+
+        // push of an existing key replaces the priority
+        assert_eq!(Some(30), heap.push('c', 1));
+        assert!(heap.contains_key(&'c'));
+
+        let mut popped = Vec::new();
+        while let Some(entry) = heap.pop_min() {
+            popped.push(entry);
+        }
+        assert_eq!(
+            vec![('c', 1), ('d', 5), ('a', 10), ('b', 20), ('e', 20)],
+            popped
+        );
+        assert!(!heap.contains_key(&'c'));
+    }
+
+    #[test]
+    pub fn custom_order() {
+        let mut heap = BinaryHeapMap::new_by(|a: &u32, b: &u32| b.cmp(a));
+        for (key, priority) in [('a', 10), ('b', 30), ('c', 20)] {
+            heap.push(key, priority);
+        }
+        assert!(heap.decrease_priority(&'a', 40));
+        assert!(!heap.decrease_priority(&'c', 5));
+
+        let mut popped = Vec::new();
+        while let Some(entry) = heap.pop_min() {
+            popped.push(entry);
+        }
+        assert_eq!(vec![('a', 40), ('b', 30), ('c', 20)], popped);
+    }
+}
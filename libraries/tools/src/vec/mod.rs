@@ -10,6 +10,9 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt::{Display, Formatter, UpperHex, Write};
 
+pub use heap::*;
+mod heap;
+
 ///
 /// This struct purely exists to implement [`Display`] and [`UpperHex`] for a borrowed Vec, whose elements implement [`Display`] or [`UpperHex`]
 pub struct PrettyVec<'a, T>(pub &'a Vec<T>);
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+#[test]
+pub fn hex_macro_compile_failures() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/hex_*.rs");
+}
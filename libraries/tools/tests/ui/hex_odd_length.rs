@@ -0,0 +1,3 @@
+fn main() {
+    let _val = irox_tools::hex!("A0A2 1");
+}
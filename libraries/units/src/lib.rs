@@ -15,12 +15,15 @@
 //!         `CompassDirection` orientation of the first axis
 //! * [`units`] - Physical Quantities
 //!   * [`units::angle`] -  Angle Types, `Angle`, `AngleUnits` for `Degrees` and `Radians`
+//!   * [`units::area`] - The SI `Area` quantity, representing `SquareMeters`, `Acres`, etc
 //!   * [`units::compass`] - Compass Types, `Compass`, and the absolute types: `Heading`, `Track`, `Bearing`, `Course`,
 //!       `Azimuth`, `CompassOffest`, and the relative type `RelativeBearing`
 //!   * [`units::datasize`] - Computer Data Sizes, `DataSize` representing `Bytes`, `Kilobytes`, etc
+//!   * [`units::derived`] - Multiplication & division between quantities, like `Length * Length = Area`
 //!   * [`units::length`] - The SI `Length` quantity, representing `Meters`, `Feet`, etc
 //!   * [`units::speed`] - The SI `Speed` quantity, representing `MetersPerSecond`, `Knots`, etc
 //!   * [`units::temperature`] - The SI `Temperature` quantity, representing `Celsius`, `Kelvin`, etc
+//!   * [`units::volume`] - The SI `Volume` quantity, representing `CubicMeters`, `Liters`, etc
 
 #![forbid(unsafe_code)]
 #![allow(clippy::cast_possible_truncation)]
@@ -29,6 +32,9 @@
 #![allow(clippy::module_name_repetitions)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod bounds;
 pub mod shapes;
 #[macro_use]
@@ -37,32 +37,37 @@ pub enum AngleUnits {
     /// Derived unit for Planar Angle - NATO Mil
     /// There are 6400 mils in a turn/revolution
     Mils,
+
+    /// Derived unit for Planar Angle - Gradians (gons) - used in surveying
+    /// There are 400 gradians in a turn/revolution
+    Gradians,
 }
 
 macro_rules! from_units_angle {
     ($type:ident) => {
         impl crate::units::FromUnits<$type> for AngleUnits {
             fn from(&self, value: $type, units: Self) -> $type {
+                if *self == units {
+                    return value;
+                }
+                // convert through degrees, where all the factors except radians are exact.
+                let degrees = match units {
+                    AngleUnits::Radians => value * RAD_2_DEG as $type,
+                    AngleUnits::Degrees => value,
+                    AngleUnits::Minutes => value / DEG_2_MIN as $type,
+                    AngleUnits::Seconds => value / DEG_2_SEC as $type,
+                    AngleUnits::Revolutions => value * REV_2_DEG as $type,
+                    AngleUnits::Mils => value / DEG_2_MIL as $type,
+                    AngleUnits::Gradians => value / DEG_2_GRAD as $type,
+                };
                 match self {
-                    AngleUnits::Degrees => match units {
-                        AngleUnits::Radians => value * RAD_2_DEG as $type,
-                        AngleUnits::Degrees => value as $type,
-
-                        AngleUnits::Minutes => value * RAD_2_DEG as $type * DEG_2_MIN as $type,
-                        AngleUnits::Seconds => value * RAD_2_DEG as $type * DEG_2_SEC as $type,
-                        AngleUnits::Revolutions => value / REV_2_RAD as $type,
-                        AngleUnits::Mils => value * RAD_2_MIL as $type,
-                    },
-                    AngleUnits::Radians => match units {
-                        AngleUnits::Degrees => value * DEG_2_RAD as $type,
-                        AngleUnits::Radians => value as $type,
-
-                        AngleUnits::Minutes => value * DEG_2_MIN as $type,
-                        AngleUnits::Seconds => value * DEG_2_SEC as $type,
-                        AngleUnits::Revolutions => value / REV_2_DEG as $type,
-                        AngleUnits::Mils => value * DEG_2_MIL as $type,
-                    },
-                    _ => todo!(),
+                    AngleUnits::Radians => degrees * DEG_2_RAD as $type,
+                    AngleUnits::Degrees => degrees,
+                    AngleUnits::Minutes => degrees * DEG_2_MIN as $type,
+                    AngleUnits::Seconds => degrees * DEG_2_SEC as $type,
+                    AngleUnits::Revolutions => degrees / REV_2_DEG as $type,
+                    AngleUnits::Mils => degrees * DEG_2_MIL as $type,
+                    AngleUnits::Gradians => degrees * DEG_2_GRAD as $type,
                 }
             }
         }
@@ -96,6 +101,31 @@ impl Angle {
         Self::new(value, AngleUnits::Degrees)
     }
 
+    #[must_use]
+    pub const fn new_minutes(value: f64) -> Angle {
+        Self::new(value, AngleUnits::Minutes)
+    }
+
+    #[must_use]
+    pub const fn new_seconds(value: f64) -> Angle {
+        Self::new(value, AngleUnits::Seconds)
+    }
+
+    #[must_use]
+    pub const fn new_revolutions(value: f64) -> Angle {
+        Self::new(value, AngleUnits::Revolutions)
+    }
+
+    #[must_use]
+    pub const fn new_mils(value: f64) -> Angle {
+        Self::new(value, AngleUnits::Mils)
+    }
+
+    #[must_use]
+    pub const fn new_gradians(value: f64) -> Angle {
+        Self::new(value, AngleUnits::Gradians)
+    }
+
     #[must_use]
     pub fn new_dms(degrees: i16, minutes: u8, seconds: f64) -> Angle {
         let mult: f64 = match degrees {
@@ -118,6 +148,31 @@ impl Angle {
         self.as_unit(AngleUnits::Radians)
     }
 
+    #[must_use]
+    pub fn as_minutes(&self) -> Angle {
+        self.as_unit(AngleUnits::Minutes)
+    }
+
+    #[must_use]
+    pub fn as_seconds(&self) -> Angle {
+        self.as_unit(AngleUnits::Seconds)
+    }
+
+    #[must_use]
+    pub fn as_revolutions(&self) -> Angle {
+        self.as_unit(AngleUnits::Revolutions)
+    }
+
+    #[must_use]
+    pub fn as_mils(&self) -> Angle {
+        self.as_unit(AngleUnits::Mils)
+    }
+
+    #[must_use]
+    pub fn as_gradians(&self) -> Angle {
+        self.as_unit(AngleUnits::Gradians)
+    }
+
     #[must_use]
     pub fn as_dms(&self) -> (i16, u8, f64) {
         let (deg, val) = self.as_deg_min();
@@ -139,6 +194,52 @@ impl Angle {
         let min = (val - deg as f64) * 60.;
         (deg * sign, min)
     }
+
+    ///
+    /// Returns the signed shortest angular distance from this angle to the other angle, wrapped
+    /// into `(-180, 180]` degrees, so 350 to 10 degrees is +20 degrees, rather than -340.  The
+    /// result is in the units of this angle.
+    #[must_use]
+    pub fn shortest_difference(&self, other: &Angle) -> Angle {
+        let diff = (other.as_degrees().value - self.as_degrees().value) % REV_2_DEG;
+        let diff = if diff > 180. {
+            diff - REV_2_DEG
+        } else if diff <= -180. {
+            diff + REV_2_DEG
+        } else {
+            diff
+        };
+        Angle::new_degrees(diff).as_unit(self.units)
+    }
+
+    ///
+    /// Wraps the angle into `[0, 360)` degrees, so -90 degrees becomes 270 and 450 becomes 90.
+    /// The result is in the units of this angle, wrapped to the equivalent range - `[0, tau)` for
+    /// radians, `[0, 400)` for gradians, etc.
+    #[must_use]
+    pub fn normalized_0_360(&self) -> Angle {
+        let turn = self.units.from(1., AngleUnits::Revolutions);
+        let mut value = self.value % turn;
+        if value < 0. {
+            value += turn;
+        }
+        if value >= turn {
+            // a tiny negative value rounds up to a full turn when it's added.
+            value = 0.;
+        }
+        Angle::new(value, self.units)
+    }
+
+    ///
+    /// Wraps the angle into `[-180, 180)` degrees, so 270 degrees becomes -90 and -540 becomes
+    /// -180.  The result is in the units of this angle, wrapped to the equivalent range -
+    /// `[-pi, pi)` for radians, `[-200, 200)` for gradians, etc.
+    #[must_use]
+    pub fn normalized_pm_180(&self) -> Angle {
+        let half = self.units.from(0.5, AngleUnits::Revolutions);
+        let shifted = Angle::new(self.value + half, self.units).normalized_0_360();
+        Angle::new(shifted.value - half, self.units)
+    }
 }
 
 impl Display for Angle {
@@ -166,3 +267,162 @@ pub const DEG_2_SEC: f64 = DEG_2_MIN * MIN_2_SEC;
 /// Degrees to Mils factor
 pub const DEG_2_MIL: f64 = MIL_2_REV / REV_2_DEG;
 pub const RAD_2_MIL: f64 = MIL_2_REV / REV_2_RAD;
+/// Gradians to Revolutions factor
+pub const GRAD_2_REV: f64 = 400.;
+/// Degrees to Gradians factor
+pub const DEG_2_GRAD: f64 = GRAD_2_REV / REV_2_DEG;
+
+#[cfg(test)]
+mod tests {
+    use crate::units::angle::{Angle, AngleUnits};
+    use crate::units::{FromUnits, Unit};
+
+    fn assert_near(expected: f64, actual: Angle) {
+        let diff = (expected - actual.value()).abs();
+        assert!(
+            diff < 1e-9,
+            "expected {expected} but was {}",
+            actual.value()
+        );
+    }
+
+    #[test]
+    pub fn mils_revolutions_degrees() {
+        let mils = Angle::new_mils(6400.);
+        let rev = Angle::new_revolutions(1.);
+        let deg = Angle::new_degrees(360.);
+
+        assert_near(1., mils.as_revolutions());
+        assert_near(360., mils.as_degrees());
+        assert_near(6400., rev.as_mils());
+        assert_near(360., rev.as_degrees());
+        assert_near(6400., deg.as_mils());
+        assert_near(1., deg.as_revolutions());
+        assert_near(core::f64::consts::TAU, mils.as_radians());
+        assert_near(6400., Angle::new_radians(core::f64::consts::TAU).as_mils());
+    }
+
+    #[test]
+    pub fn minutes_seconds() {
+        let deg = Angle::new_degrees(1.5);
+        assert_near(90., deg.as_minutes());
+        assert_near(5400., deg.as_seconds());
+        assert_near(1.5, Angle::new_minutes(90.).as_degrees());
+        assert_near(90., Angle::new_seconds(5400.).as_minutes());
+        assert_near(21600., Angle::new_revolutions(1.).as_minutes());
+        assert_near(1., Angle::new_seconds(1_296_000.).as_revolutions());
+    }
+
+    #[test]
+    pub fn shortest_difference() {
+        let a = Angle::new_degrees(350.);
+        let b = Angle::new_degrees(10.);
+        assert_near(20., a.shortest_difference(&b));
+        assert_near(-20., b.shortest_difference(&a));
+        assert_near(0., a.shortest_difference(&Angle::new_degrees(-10.)));
+        assert_near(
+            180.,
+            Angle::new_degrees(0.).shortest_difference(&Angle::new_degrees(180.)),
+        );
+        assert_near(
+            180.,
+            Angle::new_degrees(180.).shortest_difference(&Angle::new_degrees(0.)),
+        );
+        assert_near(
+            -90.,
+            Angle::new_degrees(45.).shortest_difference(&Angle::new_degrees(-765.)),
+        );
+
+        // mixed units, result is in the units of self
+        let diff = Angle::new_revolutions(0.75).shortest_difference(&Angle::new_degrees(10.));
+        assert_eq!(AngleUnits::Revolutions, diff.units());
+        assert_near(100. / 360., diff);
+        let diff = Angle::new_mils(6300.).shortest_difference(&Angle::new_radians(0.));
+        assert_near(100., diff);
+    }
+
+    #[test]
+    pub fn conversion_matrix() {
+        // 30 degrees, in each unit
+        let units = [
+            (AngleUnits::Radians, core::f64::consts::PI / 6.),
+            (AngleUnits::Degrees, 30.),
+            (AngleUnits::Minutes, 1800.),
+            (AngleUnits::Seconds, 108_000.),
+            (AngleUnits::Revolutions, 1. / 12.),
+            (AngleUnits::Mils, 6400. / 12.),
+            (AngleUnits::Gradians, 400. / 12.),
+        ];
+        for (from, from_value) in units {
+            let angle = Angle::new(from_value, from);
+            for (to, to_value) in units {
+                let converted = angle.as_unit(to);
+                assert_eq!(to, converted.units());
+                assert!(
+                    (to_value - converted.value()).abs() < 1e-9 * to_value,
+                    "{from:?} to {to:?}: expected {to_value} but was {}",
+                    converted.value()
+                );
+                let back = converted.as_unit(from);
+                assert!(
+                    (from_value - back.value()).abs() < 1e-9 * from_value,
+                    "{from:?} to {to:?} and back: expected {from_value} but was {}",
+                    back.value()
+                );
+            }
+        }
+        assert_near(0.5, Angle::new(30.0, AngleUnits::Minutes).as_degrees());
+    }
+
+    #[test]
+    pub fn gradians() {
+        let grad = Angle::new_gradians(400.);
+        assert_near(360., grad.as_degrees());
+        assert_near(1., grad.as_revolutions());
+        assert_near(6400., grad.as_mils());
+        assert_near(core::f64::consts::TAU, grad.as_radians());
+        assert_near(100., Angle::new_degrees(90.).as_gradians());
+        assert_near(
+            200.,
+            Angle::new_radians(core::f64::consts::PI).as_gradians(),
+        );
+        assert_near(1., Angle::new_seconds(3240.).as_gradians());
+
+        let grad: f32 = AngleUnits::Gradians.from(90., AngleUnits::Degrees);
+        assert!((grad - 100.).abs() < 1e-4, "{grad}");
+    }
+
+    #[test]
+    pub fn normalized() {
+        for (input, wrapped, pm) in [
+            (0., 0., 0.),
+            (90., 90., 90.),
+            (180., 180., -180.),
+            (270., 270., -90.),
+            (360., 0., 0.),
+            (450., 90., 90.),
+            (1081., 1., 1.),
+            (-90., 270., -90.),
+            (-180., 180., -180.),
+            (-360., 0., 0.),
+            (-540., 180., -180.),
+            (-721., 359., -1.),
+        ] {
+            let angle = Angle::new_degrees(input);
+            assert_near(wrapped, angle.normalized_0_360());
+            assert_near(pm, angle.normalized_pm_180());
+
+            // same results in other units
+            let angle = angle.as_gradians();
+            assert_near(wrapped * 400. / 360., angle.normalized_0_360());
+            assert_near(pm * 400. / 360., angle.normalized_pm_180());
+            assert_eq!(AngleUnits::Gradians, angle.normalized_0_360().units());
+            assert_near(
+                wrapped.to_radians(),
+                Angle::new_degrees(input).as_radians().normalized_0_360(),
+            );
+        }
+        let tiny = Angle::new_degrees(-1e-20).normalized_0_360();
+        assert!(tiny.value() >= 0. && tiny.value() < 360.);
+    }
+}
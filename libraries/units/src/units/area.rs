@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the SI "Area" quantity
+use core::fmt::{Display, Formatter};
+
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific area unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AreaUnits {
+    /// SI Derived Unit for Area - Square Meters
+    #[default]
+    SquareMeters,
+
+    /// Square Kilometers, 1e6 square meters
+    SquareKilometers,
+
+    /// Square US Imperial "Foot"
+    SquareFeet,
+
+    /// Hectare, 1e4 square meters
+    Hectares,
+
+    /// International Acre, 43560 square feet
+    Acres,
+}
+
+impl AreaUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            AreaUnits::SquareMeters => "m^2",
+            AreaUnits::SquareKilometers => "km^2",
+            AreaUnits::SquareFeet => "ft^2",
+            AreaUnits::Hectares => "ha",
+            AreaUnits::Acres => "ac",
+        }
+    }
+
+    /// The number of square meters in one of these units
+    const fn square_meters(self) -> f64 {
+        match self {
+            AreaUnits::SquareMeters => 1.0,
+            AreaUnits::SquareKilometers => SQUARE_KILOMETERS_TO_SQUARE_METERS,
+            AreaUnits::SquareFeet => SQUARE_FEET_TO_SQUARE_METERS,
+            AreaUnits::Hectares => HECTARES_TO_SQUARE_METERS,
+            AreaUnits::Acres => ACRES_TO_SQUARE_METERS,
+        }
+    }
+}
+
+macro_rules! from_units_area {
+    ($type:ident) => {
+        impl crate::units::FromUnits<$type> for AreaUnits {
+            fn from(&self, value: $type, units: Self) -> $type {
+                value * (units.square_meters() / self.square_meters()) as $type
+            }
+        }
+    };
+}
+basic_unit!(Area, AreaUnits, SquareMeters);
+from_units_area!(f32);
+from_units_area!(f64);
+
+impl Unit<AreaUnits> for Area {
+    fn as_unit(&self, units: AreaUnits) -> Self {
+        Area {
+            value: units.from(self.value, self.units),
+            units,
+        }
+    }
+}
+
+impl Area {
+    #[must_use]
+    pub const fn new_square_meters(value: f64) -> Area {
+        Self {
+            value,
+            units: AreaUnits::SquareMeters,
+        }
+    }
+
+    #[must_use]
+    pub fn as_square_meters(&self) -> Area {
+        self.as_unit(AreaUnits::SquareMeters)
+    }
+}
+
+impl Display for Area {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+pub const SQUARE_KILOMETERS_TO_SQUARE_METERS: f64 = 1e6;
+pub const SQUARE_FEET_TO_SQUARE_METERS: f64 = 0.092_903_04;
+pub const HECTARES_TO_SQUARE_METERS: f64 = 1e4;
+pub const ACRES_TO_SQUARE_METERS: f64 = 4_046.856_422_4;
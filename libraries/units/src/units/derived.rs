@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Multiplication and division between quantities, producing the correctly derived quantity.
+//!
+//! | Operation             | Result     |
+//! |-----------------------|------------|
+//! | `Length * Length`     | `Area`     |
+//! | `Area * Length`       | `Volume`   |
+//! | `Length * Area`       | `Volume`   |
+//! | `Area / Length`       | `Length`   |
+//! | `Volume / Length`     | `Area`     |
+//! | `Volume / Area`       | `Length`   |
+//! | `Length / Duration`   | `Speed`    |
+//! | `Speed * Duration`    | `Length`   |
+//! | `Duration * Speed`    | `Length`   |
+//! | `Length / Speed`      | `Duration` |
+//!
+//! The results are in the base SI units of the derived quantity.  Any combination not listed
+//! (like `Angle * Length`) does not compile.
+
+use core::ops::{Div, Mul};
+
+use crate::units::area::Area;
+use crate::units::duration::Duration;
+use crate::units::length::Length;
+use crate::units::speed::Speed;
+use crate::units::volume::Volume;
+use crate::units::Quantity;
+
+macro_rules! derived {
+    ($lhs:ident * $rhs:ident = $out:ident) => {
+        impl Mul<$rhs> for $lhs {
+            type Output = $out;
+
+            fn mul(self, rhs: $rhs) -> Self::Output {
+                $out::new(
+                    self.base_value() * rhs.base_value(),
+                    <$out as Quantity>::BASE_UNITS,
+                )
+            }
+        }
+    };
+    ($lhs:ident / $rhs:ident = $out:ident) => {
+        impl Div<$rhs> for $lhs {
+            type Output = $out;
+
+            fn div(self, rhs: $rhs) -> Self::Output {
+                $out::new(
+                    self.base_value() / rhs.base_value(),
+                    <$out as Quantity>::BASE_UNITS,
+                )
+            }
+        }
+    };
+}
+
+derived!(Length * Length = Area);
+derived!(Area * Length = Volume);
+derived!(Length * Area = Volume);
+derived!(Area / Length = Length);
+derived!(Volume / Length = Area);
+derived!(Volume / Area = Length);
+derived!(Length / Duration = Speed);
+derived!(Speed * Duration = Length);
+derived!(Duration * Speed = Length);
+derived!(Length / Speed = Duration);
+
+#[cfg(test)]
+mod tests {
+    use irox_tools::assert_quantity_eq;
+
+    use crate::units::area::{Area, AreaUnits};
+    use crate::units::duration::{Duration, DurationUnit};
+    use crate::units::length::{Length, LengthUnits};
+    use crate::units::speed::{Speed, SpeedUnits};
+    use crate::units::volume::{Volume, VolumeUnits};
+
+    #[test]
+    pub fn geometric() {
+        let area: Area = Length::new_meters(3.0) * Length::new(2.0, LengthUnits::Kilometers);
+        assert_eq!(AreaUnits::SquareMeters, area.units());
+        assert_quantity_eq!(
+            Area::new(0.6, AreaUnits::Hectares),
+            area,
+            Area::new_square_meters(1e-9)
+        );
+
+        let acre = Length::new_feet(66.0) * Length::new_feet(660.0);
+        assert_quantity_eq!(
+            Area::new(1.0, AreaUnits::Acres),
+            acre,
+            Area::new_square_meters(1e-9)
+        );
+
+        let volume: Volume = area * Length::new_meters(0.5);
+        assert_eq!(VolumeUnits::CubicMeters, volume.units());
+        assert_quantity_eq!(
+            Volume::new_cubic_meters(3000.0),
+            volume,
+            Volume::new_cubic_meters(1e-9)
+        );
+        let volume: Volume =
+            Length::new(10.0, LengthUnits::Meters) * Area::new(1.0, AreaUnits::SquareFeet);
+        assert_quantity_eq!(
+            Volume::new(0.929_030_4, VolumeUnits::CubicMeters),
+            volume,
+            Volume::new_cubic_meters(1e-9)
+        );
+        let gallon =
+            Length::new_feet(1.0) * Length::new_feet(1.0) * Length::new_feet(231.0 / 1728.0);
+        assert_quantity_eq!(
+            Volume::new(1.0, VolumeUnits::USGallons),
+            gallon,
+            Volume::new_cubic_meters(1e-12)
+        );
+
+        let side: Length = Area::new_square_meters(12.0) / Length::new_meters(4.0);
+        assert_quantity_eq!(Length::new_meters(3.0), side, Length::new_meters(1e-12));
+        let base: Area = Volume::new(1.0, VolumeUnits::Liters) / Length::new_meters(0.1);
+        assert_quantity_eq!(
+            Area::new_square_meters(0.01),
+            base,
+            Area::new_square_meters(1e-12)
+        );
+        let height: Length = Volume::new_cubic_meters(8.0) / Area::new_square_meters(4.0);
+        assert_quantity_eq!(Length::new_meters(2.0), height, Length::new_meters(1e-12));
+    }
+
+    #[test]
+    pub fn kinematic() {
+        let speed: Speed = Length::new(36.0, LengthUnits::Kilometers) / Duration::from_hours(1);
+        assert_eq!(SpeedUnits::MetersPerSecond, speed.units());
+        assert_quantity_eq!(
+            Speed::new_meters_per_second(10.0),
+            speed,
+            Speed::new_meters_per_second(1e-9)
+        );
+
+        let distance: Length = Speed::new(1.0, SpeedUnits::Knots) * Duration::from_hours(2);
+        assert_quantity_eq!(
+            Length::new(2.0, LengthUnits::NauticalMile),
+            distance,
+            Length::new_meters(1e-3)
+        );
+        let distance: Length = Duration::new(30.0, DurationUnit::Second) * speed;
+        assert_quantity_eq!(
+            Length::new_meters(300.0),
+            distance,
+            Length::new_meters(1e-9)
+        );
+
+        let time: Duration = Length::new_meters(100.0) / speed;
+        assert_eq!(DurationUnit::Second, time.units());
+        assert_quantity_eq!(
+            Duration::new_seconds(10.0),
+            time,
+            Duration::new_seconds(1e-9)
+        );
+    }
+}
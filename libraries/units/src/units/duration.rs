@@ -213,12 +213,51 @@ impl From<core::time::Duration> for Duration {
     }
 }
 
-impl From<Duration> for core::time::Duration {
-    fn from(value: Duration) -> Self {
-        let secs = value.as_seconds();
-        let frac_sec = value.as_seconds_f64() - secs as f64;
-        let nanos = DurationUnit::Second.as_nanos(frac_sec) as u32;
-        core::time::Duration::new(secs, nanos)
+///
+/// Error returned when a [`Duration`] can't be represented as a [`core::time::Duration`], which
+/// is unsigned and finite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DurationConversionError {
+    /// The duration was less than zero
+    Negative(f64),
+    /// The duration was `NaN` or infinite
+    NotFinite(f64),
+    /// The duration was larger than [`core::time::Duration::MAX`]
+    Overflow(f64),
+}
+
+impl Display for DurationConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DurationConversionError::Negative(v) => {
+                f.write_fmt(format_args!("duration {v} seconds is negative"))
+            }
+            DurationConversionError::NotFinite(v) => {
+                f.write_fmt(format_args!("duration {v} seconds is not finite"))
+            }
+            DurationConversionError::Overflow(v) => {
+                f.write_fmt(format_args!("duration {v} seconds is too large"))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationConversionError {}
+
+impl TryFrom<Duration> for core::time::Duration {
+    type Error = DurationConversionError;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        let secs = value.as_seconds_f64();
+        if !secs.is_finite() {
+            return Err(DurationConversionError::NotFinite(secs));
+        }
+        if secs < 0.0 {
+            return Err(DurationConversionError::Negative(secs));
+        }
+        core::time::Duration::try_from_secs_f64(secs)
+            .map_err(|_| DurationConversionError::Overflow(secs))
     }
 }
 
@@ -321,6 +360,245 @@ impl Duration {
     pub fn as_years(&self) -> u64 {
         self.as_unit(DurationUnit::Year).value() as u64
     }
+
+    ///
+    /// Returns the value of this duration as signed nanoseconds, rounded to the nearest nanosecond.
+    #[allow(unused_imports)]
+    pub fn as_nanos_rounded(&self) -> i128 {
+        use irox_tools::f64::FloatExt;
+        (self.as_seconds_f64() * SEC_TO_NANOS).round() as i128
+    }
+
+    ///
+    /// Returns this duration broken down into (Days, Hours, Minutes, Seconds, Nanoseconds), with
+    /// the duration rounded to the nearest nanosecond.  If the duration is negative, all of the
+    /// components are negative (or zero).
+    pub fn to_components(&self) -> (i64, i8, i8, i8, i32) {
+        let nanos = self.as_nanos_rounded();
+        let (secs, nanos) = (nanos / 1_000_000_000, nanos % 1_000_000_000);
+        let (mins, secs) = (secs / 60, secs % 60);
+        let (hours, mins) = (mins / 60, mins % 60);
+        let (days, hours) = (hours / 24, hours % 24);
+        (
+            days as i64,
+            hours as i8,
+            mins as i8,
+            secs as i8,
+            nanos as i32,
+        )
+    }
+
+    ///
+    /// Returns a [`Display`] wrapper that prints this duration in a human-readable form, as whole
+    /// weeks, days, hours, minutes, seconds, milliseconds, microseconds and nanoseconds (see
+    /// [`HUMAN_UNITS`]), skipping the zero components, like `2h 30m 15s` or `1w 3d 250ms`.
+    /// Negative durations start with `-`, and zero is `0s`.  The output can be parsed with
+    /// [`Duration::parse_human`].
+    pub fn humanize(&self) -> HumanizedDuration {
+        HumanizedDuration(*self)
+    }
+}
+
+///
+/// Prints a [`Duration`] in a human-readable form.  See [`Duration::humanize`]
+#[derive(Debug, Copy, Clone)]
+pub struct HumanizedDuration(pub Duration);
+
+impl Display for HumanizedDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let nanos = self.0.as_nanos_rounded();
+        if nanos == 0 {
+            return f.write_str("0s");
+        }
+        if nanos < 0 {
+            f.write_str("-")?;
+        }
+        let mut rem = nanos.unsigned_abs();
+        let mut sep = "";
+        for (unit_nanos, unit) in HUMAN_UNITS {
+            let value = rem / unit_nanos;
+            rem %= unit_nanos;
+            if value != 0 {
+                write!(f, "{sep}{value}{unit}")?;
+                sep = " ";
+            }
+        }
+        Ok(())
+    }
+}
+
+///
+/// Error returned by [`Duration::parse_human`].  The positions are byte offsets into the input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DurationParseError {
+    /// The input was empty, or only a sign
+    Empty,
+    /// Expecting a number like `5` or `1.5` at the position
+    InvalidNumber(usize),
+    /// The number at the position wasn't followed by a unit
+    MissingUnit(usize),
+    /// The unit at the position isn't recognized
+    UnknownUnit(usize),
+    /// The duration is too large to represent
+    Overflow,
+}
+
+impl Display for DurationParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DurationParseError::Empty => f.write_str("duration is empty"),
+            DurationParseError::InvalidNumber(p) => {
+                f.write_fmt(format_args!("expecting a number at position {p}"))
+            }
+            DurationParseError::MissingUnit(p) => {
+                f.write_fmt(format_args!("expecting a unit at position {p}"))
+            }
+            DurationParseError::UnknownUnit(p) => {
+                f.write_fmt(format_args!("unknown duration unit at position {p}"))
+            }
+            DurationParseError::Overflow => f.write_str("duration is too large"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationParseError {}
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+///
+/// The units of [`Duration::humanize`] as the number of nanoseconds in the unit and its symbol,
+/// largest first.
+pub const HUMAN_UNITS: [(u128, &str); 8] = [
+    (604_800 * NANOS_PER_SECOND, "w"),
+    (86_400 * NANOS_PER_SECOND, "d"),
+    (3_600 * NANOS_PER_SECOND, "h"),
+    (60 * NANOS_PER_SECOND, "m"),
+    (NANOS_PER_SECOND, "s"),
+    (1_000_000, "ms"),
+    (1_000, "us"),
+    (1, "ns"),
+];
+
+/// Returns the number of nanoseconds in one of the units accepted by [`Duration::parse_human`]
+fn human_unit_nanos(unit: &str) -> Option<u128> {
+    let idx = match unit {
+        "w" | "wk" | "wks" | "week" | "weeks" => 0,
+        "d" | "day" | "days" => 1,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 2,
+        "m" | "min" | "mins" | "minute" | "minutes" => 3,
+        "s" | "sec" | "secs" | "second" | "seconds" => 4,
+        "ms" | "msec" | "millis" | "millisecond" | "milliseconds" => 5,
+        "us" | "u" | "\u{b5}s" | "\u{b5}" | "usec" | "micros" | "microsecond" | "microseconds" => 6,
+        "ns" | "nsec" | "nanos" | "nanosecond" | "nanoseconds" => 7,
+        _ => return None,
+    };
+    HUMAN_UNITS.get(idx).map(|(nanos, _)| *nanos)
+}
+
+/// Parses `number` (like `5` or `1.5`) of the unit into nanoseconds, truncating any fraction of
+/// a nanosecond.  `position` is reported if the number is invalid.
+fn parse_human_component(
+    number: &str,
+    unit_nanos: u128,
+    position: usize,
+) -> Result<u128, DurationParseError> {
+    let invalid = DurationParseError::InvalidNumber(position);
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid);
+    }
+    let whole = match whole {
+        "" => 0,
+        w => w.parse::<u128>().map_err(|_| invalid)?,
+    };
+    let mut nanos = whole
+        .checked_mul(unit_nanos)
+        .ok_or(DurationParseError::Overflow)?;
+    // beyond 18 digits is well below a nanosecond, even for weeks.
+    let fraction = fraction.get(..18).unwrap_or(fraction);
+    if !fraction.is_empty() {
+        let scale = 10_u128.pow(fraction.len() as u32);
+        let fraction = fraction.parse::<u128>().map_err(|_| invalid)?;
+        nanos = nanos
+            .checked_add(fraction * unit_nanos / scale)
+            .ok_or(DurationParseError::Overflow)?;
+    }
+    Ok(nanos)
+}
+
+impl Duration {
+    ///
+    /// Formats this duration with [`Duration::humanize`] into a new string.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn format_human(&self) -> alloc::string::String {
+        use alloc::string::ToString;
+        self.humanize().to_string()
+    }
+
+    ///
+    /// Parses a human-readable duration, a sequence of numbers each followed by a unit, like
+    /// `1w2d3h4m5s`, `90min`, `1.5h` or `2 hours 30 minutes`, optionally starting with `-`.
+    /// Whitespace is allowed between the components and between a number and its unit.  The
+    /// units (lowercase) are:
+    ///
+    /// * Weeks: `w`, `wk`, `wks`, `week`, `weeks`
+    /// * Days: `d`, `day`, `days`
+    /// * Hours: `h`, `hr`, `hrs`, `hour`, `hours`
+    /// * Minutes: `m`, `min`, `mins`, `minute`, `minutes`
+    /// * Seconds: `s`, `sec`, `secs`, `second`, `seconds`
+    /// * Milliseconds: `ms`, `msec`, `millis`, `millisecond`, `milliseconds`
+    /// * Microseconds: `us`, `u`, `µs`, `µ`, `usec`, `micros`, `microsecond`, `microseconds`
+    /// * Nanoseconds: `ns`, `nsec`, `nanos`, `nanosecond`, `nanoseconds`
+    ///
+    /// This includes the InfluxDB duration literals, like `1w2d` or `10u`.
+    pub fn parse_human(value: &str) -> Result<Duration, DurationParseError> {
+        let trimmed = value.trim();
+        let (negative, mut rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            return Err(DurationParseError::Empty);
+        }
+        let position = |rest: &str| value.len() - rest.len();
+        let mut nanos: u128 = 0;
+        while !rest.is_empty() {
+            let number_len = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(rest.len());
+            let (number, tail) = rest.split_at(number_len);
+            let tail = tail.trim_start();
+            let unit_start = position(tail);
+            let unit_len = tail
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(tail.len());
+            let (unit, tail) = tail.split_at(unit_len);
+            if number.is_empty() {
+                return Err(DurationParseError::InvalidNumber(position(rest)));
+            }
+            if unit.is_empty() {
+                return Err(DurationParseError::MissingUnit(unit_start));
+            }
+            let Some(unit_nanos) = human_unit_nanos(unit) else {
+                return Err(DurationParseError::UnknownUnit(unit_start));
+            };
+            let component = parse_human_component(number, unit_nanos, position(rest))?;
+            nanos = nanos
+                .checked_add(component)
+                .ok_or(DurationParseError::Overflow)?;
+            rest = tail.trim_start();
+        }
+        // a single rounding, so formatted durations parse back to exactly the same value.
+        let seconds = nanos as f64 / NANOS_PER_SECOND as f64;
+        Ok(Duration::new_seconds(if negative {
+            -seconds
+        } else {
+            seconds
+        }))
+    }
 }
 
 // Backwards compatibility for [`core::time::Duration`] drop-in creation
@@ -550,3 +828,172 @@ pub const YEAR_TO_NANOS: f64 = YEAR_TO_MICROS * MICROS_TO_NANOS;
 
 // going up septs
 pub const NANOS_TO_YEAR: f64 = NANOS_TO_DAY * DAY_TO_YEAR;
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use crate::units::duration::{Duration, DurationConversionError, DurationParseError};
+    use alloc::string::ToString;
+
+    #[test]
+    pub fn std_round_trip() {
+        let std = core::time::Duration::new(1_234, 567_891_000);
+        let dur: Duration = std.into();
+        assert_eq!(1_234, dur.as_seconds());
+        assert_eq!(1_234_567, dur.as_millis());
+        assert_eq!(Ok(std), core::time::Duration::try_from(dur));
+
+        let dur = Duration::from_nanos(1_500_000_250);
+        let std = core::time::Duration::try_from(dur);
+        assert_eq!(Ok(core::time::Duration::new(1, 500_000_250)), std);
+    }
+
+    #[test]
+    pub fn std_negative_fails() {
+        let dur = Duration::from_seconds_f64(-1.5);
+        assert_eq!(
+            Err(DurationConversionError::Negative(-1.5)),
+            core::time::Duration::try_from(dur)
+        );
+        assert!(core::time::Duration::try_from(Duration::from_seconds_f64(f64::NAN)).is_err());
+        assert_eq!(
+            Err(DurationConversionError::NotFinite(f64::INFINITY)),
+            core::time::Duration::try_from(Duration::from_seconds_f64(f64::INFINITY))
+        );
+        assert_eq!(
+            Err(DurationConversionError::Overflow(1e30)),
+            core::time::Duration::try_from(Duration::from_seconds_f64(1e30))
+        );
+    }
+
+    #[test]
+    pub fn components() {
+        let dur = Duration::from_seconds(90061);
+        assert_eq!((1, 1, 1, 1, 0), dur.to_components());
+        assert_eq!("1d 1h 1m 1s", dur.humanize().to_string());
+
+        let dur = Duration::from_seconds_f64(-90061.25);
+        assert_eq!((-1, -1, -1, -1, -250_000_000), dur.to_components());
+        assert_eq!("-1d 1h 1m 1s 250ms", dur.humanize().to_string());
+
+        let dur = Duration::from_seconds_f64(-0.5);
+        assert_eq!((0, 0, 0, 0, -500_000_000), dur.to_components());
+        assert_eq!("-500ms", dur.humanize().to_string());
+
+        assert_eq!(
+            (3, 0, 0, 5, 0),
+            Duration::from_seconds(3 * 86400 + 5).to_components()
+        );
+        assert_eq!(
+            "3d 5s",
+            Duration::from_seconds(3 * 86400 + 5).humanize().to_string()
+        );
+        assert_eq!("2h", Duration::from_hours(2).humanize().to_string());
+        assert_eq!("0s", Duration::default().humanize().to_string());
+        assert_eq!(
+            "1ms 500us",
+            Duration::from_micros(1500).humanize().to_string()
+        );
+        assert_eq!("250us", Duration::from_micros(250).humanize().to_string());
+        assert_eq!("15ns", Duration::from_nanos(15).humanize().to_string());
+    }
+
+    #[test]
+    pub fn humanize_round_trip() {
+        for seconds in [
+            0.0,
+            1.0,
+            -0.5,
+            0.000_000_015,
+            90_061.25,
+            -90_061.25,
+            788_645.0,
+            1_209_600.001_5,
+            -3_600.000_001,
+        ] {
+            let dur = Duration::from_seconds_f64(seconds);
+            let humanized = dur.humanize().to_string();
+            assert_eq!(Ok(dur), Duration::parse_human(&humanized), "{humanized}");
+        }
+    }
+
+    #[test]
+    pub fn parse_human() {
+        let components = |seconds: f64| Duration::from_seconds_f64(seconds).to_components();
+        for (input, seconds) in [
+            ("1w2d3h4m5s", 788_645.0),
+            ("90min", 5_400.0),
+            ("1.5h", 5_400.0),
+            ("2h 30m 15s", 9_015.0),
+            ("2 hours 30 minutes", 9_000.0),
+            ("-2h30m", -9_000.0),
+            (" 250ms ", 0.25),
+            ("10u", 0.000_01),
+            ("1.5\u{b5}s", 0.000_001_5),
+            ("15ns", 0.000_000_015),
+            (".5d", 43_200.0),
+            ("1.000000001s", 1.000_000_001),
+            ("0s", 0.0),
+        ] {
+            assert_eq!(
+                Ok(components(seconds)),
+                Duration::parse_human(input).map(|d| d.to_components()),
+                "{input}"
+            );
+        }
+
+        for (input, err) in [
+            ("", DurationParseError::Empty),
+            (" - ", DurationParseError::Empty),
+            ("5", DurationParseError::MissingUnit(1)),
+            ("h", DurationParseError::InvalidNumber(0)),
+            ("5x", DurationParseError::UnknownUnit(1)),
+            ("1h 5 years", DurationParseError::UnknownUnit(5)),
+            ("1..5h", DurationParseError::InvalidNumber(0)),
+            ("1h-3m", DurationParseError::InvalidNumber(2)),
+            ("1h .m", DurationParseError::InvalidNumber(3)),
+            (
+                "99999999999999999999999999999w",
+                DurationParseError::Overflow,
+            ),
+        ] {
+            assert_eq!(Err(err), Duration::parse_human(input), "{input}");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    pub fn format_human() {
+        for (seconds, expected) in [
+            (9_015.0, "2h 30m 15s"),
+            (788_645.0, "1w 2d 3h 4m 5s"),
+            (-5_400.25, "-1h 30m 250ms"),
+            (0.0, "0s"),
+            (1_209_600.000_001_5, "2w 1us 500ns"),
+            (0.000_000_001, "1ns"),
+        ] {
+            assert_eq!(
+                expected,
+                Duration::from_seconds_f64(seconds).format_human(),
+                "{seconds}"
+            );
+        }
+
+        for nanos in [
+            1_i64,
+            -999,
+            1_000_001,
+            86_400_000_000_123,
+            -(3 * 604_800_000_000_000 + 7_000_000_000),
+            999_999_999_999_999,
+        ] {
+            let dur = Duration::from_seconds_f64(nanos as f64 / 1e9);
+            let formatted = dur.format_human();
+            assert_eq!(
+                Ok(dur.to_components()),
+                Duration::parse_human(&formatted).map(|d| d.to_components()),
+                "{formatted}"
+            );
+        }
+    }
+}
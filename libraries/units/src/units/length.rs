@@ -51,15 +51,15 @@ macro_rules! from_units_length {
                         LengthUnits::Feet => value as $type,
                         LengthUnits::Kilometers => {
                             FromUnits::<$type>::from(&LengthUnits::Meters, value, source_unit)
-                                * METERS_TO_KILOMETERS as $type
+                                * METERS_TO_FEET as $type
                         }
                         LengthUnits::Mile => {
                             FromUnits::<$type>::from(&LengthUnits::Meters, value, source_unit)
-                                * METERS_TO_MILES as $type
+                                * METERS_TO_FEET as $type
                         }
                         LengthUnits::NauticalMile => {
                             FromUnits::<$type>::from(&LengthUnits::Meters, value, source_unit)
-                                * METERS_TO_NAUTICAL_MILE as $type
+                                * METERS_TO_FEET as $type
                         }
                         LengthUnits::USSurveyFoot => {
                             value * (SURVEYFOOT_TO_METER * METERS_TO_FEET) as $type
@@ -177,6 +177,14 @@ impl Length {
         }
     }
 
+    #[must_use]
+    pub const fn new_nautical_miles(value: f64) -> Length {
+        Self {
+            value,
+            units: LengthUnits::NauticalMile,
+        }
+    }
+
     #[must_use]
     pub fn as_meters(&self) -> Length {
         self.as_unit(LengthUnits::Meters)
@@ -186,15 +194,23 @@ impl Length {
     pub fn as_feet(&self) -> Length {
         self.as_unit(LengthUnits::Feet)
     }
+
+    #[must_use]
+    pub fn as_nautical_miles(&self) -> Length {
+        self.as_unit(LengthUnits::NauticalMile)
+    }
 }
 
 impl Display for Length {
+    ///
+    /// Writes the value and the short unit name, like `1234.5 m`.  The precision of the value can
+    /// be set with the format specifier, `{:.2}` writes `1234.50 m`.
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.write_fmt(format_args!(
-            "{:02.3}{}",
-            self.value,
-            self.units.short_name()
-        ))
+        let units = self.units.short_name();
+        match f.precision() {
+            Some(frac) => write!(f, "{:.frac$} {units}", self.value),
+            None => write!(f, "{} {units}", self.value),
+        }
     }
 }
 
@@ -204,7 +220,7 @@ pub const MILES_TO_METERS: f64 = 1.609_344E3; // Exact, as per NIST 811.2008
 pub const METERS_TO_MILES: f64 = 1. / MILES_TO_METERS;
 pub const KILOMETERS_TO_METERS: f64 = 1000.;
 pub const METERS_TO_KILOMETERS: f64 = 1. / KILOMETERS_TO_METERS;
-pub const NAUTICAL_MILES_TO_METERS: f64 = 1.852E3;
+pub const NAUTICAL_MILES_TO_METERS: f64 = 1.852E3; // Exact, as per NIST 811.2008
 pub const METERS_TO_NAUTICAL_MILE: f64 = 1. / NAUTICAL_MILES_TO_METERS;
 
 pub const SURVEYFOOT_TO_METER: f64 = 3.048006E-1;
@@ -212,8 +228,11 @@ pub const METER_TO_SURVEYFOOT: f64 = 1. / SURVEYFOOT_TO_METER;
 
 #[cfg(test)]
 mod tests {
-    use crate::units::length::LengthUnits;
-    use crate::units::FromUnits;
+    extern crate alloc;
+    use crate::units::length::{Length, LengthUnits};
+    use crate::units::{FromUnits, Unit};
+    use alloc::format;
+    use alloc::string::ToString;
 
     #[test]
     pub fn test_feet_meters() {
@@ -235,4 +254,55 @@ mod tests {
             1.
         );
     }
+
+    fn assert_near(expected: f64, actual: Length) {
+        let diff = (expected - actual.value()).abs();
+        assert!(
+            diff <= 1e-12 * expected.abs().max(1.),
+            "expected {expected} but was {}",
+            actual.value()
+        );
+    }
+
+    #[test]
+    pub fn nautical_miles_feet() {
+        let nmi = Length::new_nautical_miles(1.);
+        assert_near(1852., nmi.as_meters());
+        assert_near(1852. / 0.3048, nmi.as_feet());
+        assert_near(1.852, nmi.as_unit(LengthUnits::Kilometers));
+        assert_near(1., Length::new_meters(1852.).as_nautical_miles());
+        assert_near(
+            1.,
+            Length::new_feet(6_076.115_485_564_304).as_nautical_miles(),
+        );
+        assert_near(0.3048, Length::new_feet(1.).as_meters());
+        assert_near(5280., Length::new(1., LengthUnits::Mile).as_feet());
+
+        // round trips through every other unit
+        let units = [
+            LengthUnits::Meters,
+            LengthUnits::Kilometers,
+            LengthUnits::Feet,
+            LengthUnits::Mile,
+            LengthUnits::NauticalMile,
+            LengthUnits::USSurveyFoot,
+        ];
+        for from in units {
+            for to in units {
+                let length = Length::new(1234.5, from);
+                assert_near(1234.5, length.as_unit(to).as_unit(from));
+            }
+        }
+    }
+
+    #[test]
+    pub fn display() {
+        assert_eq!("1234.5 m", Length::new_meters(1234.5).to_string());
+        assert_eq!("1234.50 m", format!("{:.2}", Length::new_meters(1234.5)));
+        assert_eq!("10 ft", Length::new_feet(10.).to_string());
+        assert_eq!(
+            "0.540 nmi",
+            format!("{:.3}", Length::new_meters(1000.).as_nautical_miles())
+        );
+    }
 }
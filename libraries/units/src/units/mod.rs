@@ -179,6 +179,37 @@ macro_rules! basic_unit {
             }
         }
 
+        impl $crate::units::Quantity for $struct_type {
+            type Units = $units_type;
+            const BASE_UNITS: $units_type = $units_type::$default_units;
+
+            fn value(&self) -> f64 {
+                self.value
+            }
+
+            fn unit(&self) -> $units_type {
+                self.units
+            }
+
+            fn as_unit(&self, units: $units_type) -> Self {
+                $crate::units::Unit::<$units_type>::as_unit(self, units)
+            }
+        }
+
+        impl irox_tools::assert::ApproxEq for $struct_type {
+            fn approx_eq(&self, other: &Self, tolerance: &Self) -> bool {
+                let other =
+                    $crate::units::Unit::<$units_type>::as_unit(other, self.units()).value();
+                // the tolerance is a difference, so any offset between the units (like celsius
+                // and kelvin) must not be applied, only the scale.
+                let zero = $struct_type::new(0.0, tolerance.units());
+                let tolerance =
+                    $crate::units::Unit::<$units_type>::as_unit(tolerance, self.units()).value()
+                        - $crate::units::Unit::<$units_type>::as_unit(&zero, self.units()).value();
+                irox_tools::assert::ApproxEq::approx_eq(&self.value(), &other, &tolerance)
+            }
+        }
+
         pub const ZERO: $struct_type = $struct_type::new(0.0, $units_type::$default_units);
     };
 }
@@ -214,10 +245,118 @@ pub trait Unit<T> {
         Self: Sized;
 }
 
+///
+/// A value paired with its units, implemented for all the quantity types (like [`angle::Angle`]
+/// and [`length::Length`]), so generic code can accept any quantity.
+pub trait Quantity: Copy {
+    /// The units type of this quantity
+    type Units: Copy;
+
+    /// The base (default) units of this quantity, that all values can be converted to
+    const BASE_UNITS: Self::Units;
+
+    /// Returns the value of this quantity, in its current units
+    #[must_use]
+    fn value(&self) -> f64;
+
+    /// Returns the units of this quantity
+    #[must_use]
+    fn unit(&self) -> Self::Units;
+
+    /// Converts this quantity into the provided units
+    #[must_use]
+    fn as_unit(&self, units: Self::Units) -> Self;
+
+    /// Converts this quantity into the [`Quantity::BASE_UNITS`]
+    #[must_use]
+    fn as_base(&self) -> Self {
+        self.as_unit(Self::BASE_UNITS)
+    }
+
+    /// Returns the value of this quantity in the [`Quantity::BASE_UNITS`]
+    #[must_use]
+    fn base_value(&self) -> f64 {
+        self.as_base().value()
+    }
+}
+
 pub mod angle;
+pub mod area;
 pub mod compass;
 pub mod datasize;
+pub mod derived;
 pub mod duration;
 pub mod length;
 pub mod speed;
 pub mod temperature;
+pub mod volume;
+
+#[cfg(test)]
+mod tests {
+    use crate::units::angle::Angle;
+    use crate::units::duration::Duration;
+    use crate::units::length::{Length, LengthUnits};
+    use crate::units::temperature::Temperature;
+    use crate::units::Quantity;
+    use irox_tools::{assert_eq_eps, assert_quantity_eq};
+
+    fn ratio<Q: Quantity>(numerator: &Q, denominator: &Q) -> f64 {
+        numerator.base_value() / denominator.base_value()
+    }
+
+    #[test]
+    pub fn generic_ratio() {
+        let km = Length::new(1.5, LengthUnits::Kilometers);
+        let m = Length::new_meters(500.0);
+        assert_eq_eps!(3.0, ratio(&km, &m), 1e-12);
+        assert_eq!(LengthUnits::Kilometers, km.unit());
+        assert_eq_eps!(1500.0, km.as_base().value(), 1e-12);
+        assert_eq!(LengthUnits::Meters, km.as_base().unit());
+
+        let hour = Duration::from_hours(1);
+        let mins = Duration::from_minutes(15);
+        assert_eq_eps!(4.0, ratio(&hour, &mins), 1e-12);
+        assert_eq_eps!(0.25, ratio(&mins, &hour), 1e-12);
+    }
+
+    #[test]
+    pub fn quantity_eq_across_units() {
+        assert_quantity_eq!(
+            Angle::new_degrees(180.0),
+            Angle::new_radians(core::f64::consts::PI),
+            Angle::new_seconds(1e-6)
+        );
+        assert_quantity_eq!(
+            Length::new(1.0, LengthUnits::Kilometers),
+            Length::new_meters(1000.4),
+            Length::new(0.5, LengthUnits::Meters)
+        );
+        // a tolerance of 1 kelvin is 1.8 fahrenheit, not -457.87
+        assert_quantity_eq!(
+            Temperature::new_fahrenheit(212.0),
+            Temperature::new_celsius(99.5),
+            Temperature::new_kelvin(1.0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "within")]
+    pub fn quantity_eq_close_angles() {
+        // 1 arcsecond apart, with a tolerance of half an arcsecond.
+        assert_quantity_eq!(
+            Angle::new_degrees(45.0),
+            Angle::new_minutes(45.0 * 60.0 + 1.0 / 60.0),
+            Angle::new_seconds(0.5)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "within")]
+    pub fn quantity_eq_different_lengths() {
+        assert_quantity_eq!(
+            Length::new(1.0, LengthUnits::Kilometers),
+            Length::new(1.0, LengthUnits::Mile),
+            Length::new_meters(1.0)
+        );
+    }
+}
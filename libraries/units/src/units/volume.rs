@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the SI "Volume" quantity
+use core::fmt::{Display, Formatter};
+
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific volume unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VolumeUnits {
+    /// SI Derived Unit for Volume - Cubic Meters
+    #[default]
+    CubicMeters,
+
+    /// Liter, 1e-3 cubic meters
+    Liters,
+
+    /// Cubic US Imperial "Foot"
+    CubicFeet,
+
+    /// US Liquid Gallon, 231 cubic inches
+    USGallons,
+}
+
+impl VolumeUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            VolumeUnits::CubicMeters => "m^3",
+            VolumeUnits::Liters => "L",
+            VolumeUnits::CubicFeet => "ft^3",
+            VolumeUnits::USGallons => "gal",
+        }
+    }
+
+    /// The number of cubic meters in one of these units
+    const fn cubic_meters(self) -> f64 {
+        match self {
+            VolumeUnits::CubicMeters => 1.0,
+            VolumeUnits::Liters => LITERS_TO_CUBIC_METERS,
+            VolumeUnits::CubicFeet => CUBIC_FEET_TO_CUBIC_METERS,
+            VolumeUnits::USGallons => US_GALLONS_TO_CUBIC_METERS,
+        }
+    }
+}
+
+macro_rules! from_units_volume {
+    ($type:ident) => {
+        impl crate::units::FromUnits<$type> for VolumeUnits {
+            fn from(&self, value: $type, units: Self) -> $type {
+                value * (units.cubic_meters() / self.cubic_meters()) as $type
+            }
+        }
+    };
+}
+basic_unit!(Volume, VolumeUnits, CubicMeters);
+from_units_volume!(f32);
+from_units_volume!(f64);
+
+impl Unit<VolumeUnits> for Volume {
+    fn as_unit(&self, units: VolumeUnits) -> Self {
+        Volume {
+            value: units.from(self.value, self.units),
+            units,
+        }
+    }
+}
+
+impl Volume {
+    #[must_use]
+    pub const fn new_cubic_meters(value: f64) -> Volume {
+        Self {
+            value,
+            units: VolumeUnits::CubicMeters,
+        }
+    }
+
+    #[must_use]
+    pub fn as_cubic_meters(&self) -> Volume {
+        self.as_unit(VolumeUnits::CubicMeters)
+    }
+}
+
+impl Display for Volume {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+pub const LITERS_TO_CUBIC_METERS: f64 = 1e-3;
+pub const CUBIC_FEET_TO_CUBIC_METERS: f64 = 0.028_316_846_592;
+pub const US_GALLONS_TO_CUBIC_METERS: f64 = 0.003_785_411_784;
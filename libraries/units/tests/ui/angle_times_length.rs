@@ -0,0 +1,6 @@
+use irox_units::units::angle::Angle;
+use irox_units::units::length::Length;
+
+fn main() {
+    let _ = Angle::new_degrees(90.0) * Length::new_meters(1.0);
+}
@@ -7,6 +7,9 @@ use clap_verbosity_flag::Verbosity;
 pub enum Transport {
     Serial(crate::transport::serial::SerialConfig),
 
+    /// Relay the reports from an upstream GPSd instance
+    Gpsd(crate::transport::client::ClientConfig),
+
     #[cfg(target_os = "windows")]
     WindowsLocation,
 }
@@ -27,4 +30,8 @@ pub struct GPSdConfig {
     /// TCP listen port
     #[arg(short='l', long, default_value_t=2947, value_parser=clap::value_parser!(u16).range(1..))]
     pub listen_port: u16,
+
+    /// Also request the PPS and TOFF time-pulse reports from an upstream GPSd
+    #[arg(long)]
+    pub pps: bool,
 }
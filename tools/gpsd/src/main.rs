@@ -4,12 +4,13 @@ use std::time::Duration;
 
 use clap::Parser;
 use human_panic::setup_panic;
-use log::{error, info};
+use log::{debug, error, info, warn};
 
 use config::{GPSdConfig, Transport};
 use error::GPSdError;
 use irox_bits::BitsWrapper;
 use output::FrameGenerator;
+use transport::client::{ClientConfig, ClientEvent, GPSdClient};
 use transport::serial::SerialConfig;
 use transport::{ListenSettings, TCPServer};
 
@@ -47,6 +48,9 @@ fn main() -> Result<(), GPSdError> {
 
     if let Err(e) = match config.source {
         Transport::Serial(e) => start_serial(server, &term, &e),
+        Transport::Gpsd(e) => e
+            .with_pps(config.pps)
+            .and_then(|e| start_client(server, &term, e)),
 
         #[cfg(target_os = "windows")]
         Transport::WindowsLocation => windows::start_windows(server, &term),
@@ -97,6 +101,35 @@ pub fn start_serial(
     Ok(())
 }
 
+pub fn start_client(
+    mut server: TCPServer,
+    shouldquit: &Arc<AtomicBool>,
+    config: ClientConfig,
+) -> Result<(), GPSdError> {
+    info!("Relaying from upstream GPSd at {}", config.address);
+    let client = GPSdClient::new(config, shouldquit.clone());
+    for event in client {
+        match event {
+            ClientEvent::Message(report) => {
+                if let Err(e) = server.send_raw(&report) {
+                    error!("Error sending report: {e:?}");
+                }
+            }
+            ClientEvent::Connected => info!("Connected to upstream GPSd"),
+            ClientEvent::Disconnected { reason } => {
+                warn!("Disconnected from upstream GPSd: {reason}");
+            }
+            ClientEvent::Reconnecting { attempt, delay } => {
+                info!("Reconnecting to upstream GPSd, attempt {attempt} in {delay:?}");
+            }
+            ClientEvent::Pps(pps) => debug!("PPS offset from system clock: {}", pps.offset()),
+            ClientEvent::Toff(toff) => debug!("TOFF offset from system clock: {}", toff.offset()),
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(target_os = "windows")]
 mod windows {
     use std::sync::atomic::{AtomicBool, Ordering};
@@ -11,6 +11,7 @@ use irox_nmea0183::MessageType;
 use irox_sirf::error::ErrorType;
 use irox_tools::options::MaybeInto;
 use irox_tools::packetio::{Packet, PacketBuilder};
+pub use pps::*;
 pub use sky::*;
 pub use tpv::*;
 
@@ -21,6 +22,7 @@ pub mod att;
 pub mod device;
 pub mod gst;
 pub mod poll;
+pub mod pps;
 pub mod sky;
 pub mod tpv;
 pub mod version;
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 IROX Contributors
+//
+
+//!
+//! Structs around the PPS and TOFF time-pulse messages
+
+use serde::Deserialize;
+
+use irox_time::datetime::UTCDateTime;
+use irox_units::units::duration::{Duration, DurationUnit};
+
+/// The PPS message reports the GPS time at the PPS (Pulse Per Second) edge, and the time the
+/// system clock thought it was at that moment.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Pps {
+    /// Name of the originating device
+    pub device: Option<String>,
+
+    /// Seconds from the PPS source
+    pub real_sec: i64,
+    /// Nanoseconds from the PPS source
+    pub real_nsec: i64,
+
+    /// Seconds from the system clock
+    pub clock_sec: i64,
+    /// Nanoseconds from the system clock
+    pub clock_nsec: i64,
+
+    /// NTP style estimate of the PPS precision, as a power of 2 seconds
+    pub precision: Option<i32>,
+
+    /// shm key of this PPS
+    pub shm: Option<String>,
+
+    /// Quantization ("sawtooth") error of the PPS, in picoseconds
+    #[serde(rename = "qErr")]
+    pub q_err: Option<i64>,
+}
+
+/// The TOFF message mirrors the [`Pps`] message, but reports the GPS time as derived from the
+/// serial data stream at the start of the reporting cycle.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Toff {
+    /// Name of the originating device
+    pub device: Option<String>,
+
+    /// Seconds from the GPS clock
+    pub real_sec: i64,
+    /// Nanoseconds from the GPS clock
+    pub real_nsec: i64,
+
+    /// Seconds from the system clock
+    pub clock_sec: i64,
+    /// Nanoseconds from the system clock
+    pub clock_nsec: i64,
+
+    /// NTP style estimate of the precision, as a power of 2 seconds
+    pub precision: Option<i32>,
+}
+
+fn to_nanos(sec: i64, nsec: i64) -> i128 {
+    i128::from(sec) * 1_000_000_000 + i128::from(nsec)
+}
+
+fn offset(real: i128, clock: i128) -> Duration {
+    Duration::new((real - clock) as f64, DurationUnit::Nanosecond)
+}
+
+///
+/// Implements the time accessors shared by the [`Pps`] and [`Toff`] reports, `$when` describes
+/// the instant the report is for.
+macro_rules! impl_time_pulse {
+    ($name:ident, $when:literal) => {
+        impl $name {
+            #[doc = concat!("Returns the GPS time at ", $when)]
+            #[must_use]
+            pub fn real_time(&self) -> Option<UTCDateTime> {
+                UTCDateTime::from_unix_nanos(to_nanos(self.real_sec, self.real_nsec))
+            }
+
+            #[doc = concat!("Returns the system clock time at ", $when)]
+            #[must_use]
+            pub fn clock_time(&self) -> Option<UTCDateTime> {
+                UTCDateTime::from_unix_nanos(to_nanos(self.clock_sec, self.clock_nsec))
+            }
+
+            ///
+            /// Returns the offset of the GPS time from the system clock (`real - clock`), the
+            /// correction to add to the system clock.  Negative if the system clock is ahead.
+            #[must_use]
+            pub fn offset(&self) -> Duration {
+                offset(
+                    to_nanos(self.real_sec, self.real_nsec),
+                    to_nanos(self.clock_sec, self.clock_nsec),
+                )
+            }
+        }
+    };
+}
+impl_time_pulse!(Pps, "the PPS edge");
+impl_time_pulse!(Toff, "the start of the reporting cycle");
+
+#[cfg(test)]
+mod tests {
+    use irox_time::datetime::UTCDateTime;
+    use irox_units::units::duration::DurationUnit;
+    use irox_units::units::Unit;
+
+    use crate::error::GPSdError;
+    use crate::output::pps::{Pps, Toff};
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn parse_pps() -> Result<(), GPSdError> {
+        let pps: Pps = serde_json::from_str(
+            r#"{"class":"PPS","device":"/dev/ttyUSB0",
+                "real_sec":1330212592, "real_nsec":0,
+                "clock_sec":1330212591,"clock_nsec":999656818,
+                "precision":-3,"shm":"NTP2","qErr":-1453}"#,
+        )?;
+        assert_eq!(Some("/dev/ttyUSB0"), pps.device.as_deref());
+        assert_eq!(Some(-3), pps.precision);
+        assert_eq!(Some("NTP2"), pps.shm.as_deref());
+        assert_eq!(Some(-1453), pps.q_err);
+
+        let offset = pps.offset().as_unit(DurationUnit::Nanosecond).value();
+        assert_eq!(343_182, offset as i64);
+
+        let expected = UTCDateTime::try_from_values(2012, 2, 25, 23, 29, 52).ok();
+        assert_eq!(expected, pps.real_time());
+        assert_eq!(
+            Some(1_330_212_591_999_656_818),
+            pps.clock_time().map(|t| t.as_unix_nanos())
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn parse_toff() -> Result<(), GPSdError> {
+        let toff: Toff = serde_json::from_str(
+            r#"{"class":"TOFF","device":"/dev/ttyUSB0",
+                "real_sec":1330212592, "real_nsec":343182,
+                "clock_sec":1330212592,"clock_nsec":343184,
+                "precision":-2}"#,
+        )?;
+        assert_eq!(Some(-2), toff.precision);
+        // the system clock is 2ns ahead
+        let offset = toff.offset().as_unit(DurationUnit::Nanosecond).value();
+        assert_eq!(-2, offset as i64);
+        assert_eq!(
+            Some(1_330_212_592_000_343_182),
+            toff.real_time().map(|t| t.as_unix_nanos())
+        );
+
+        // the required fields are required.
+        assert!(serde_json::from_str::<Toff>(r#"{"class":"TOFF","real_sec":1}"#).is_err());
+        Ok(())
+    }
+}
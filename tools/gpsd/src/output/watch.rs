@@ -1,7 +1,12 @@
 //!
 //! Structs around the watch command
 
+use serde::{Serialize, Serializer};
+
+use crate::error::GPSdError;
+
 /// Controls 'raw' mode
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum RawMode {
     /// In NMEA mode, returns the raw strings
     /// In binary mode, provides hex encoded strings
@@ -11,14 +16,69 @@ pub enum RawMode {
     RawBinary = 2,
 }
 
+impl Serialize for RawMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
 /// This command sets watcher mode.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 pub struct Watch {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub json: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nmea: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub raw: Option<RawMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub scaled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub split24: Option<bool>,
+    /// If true, the PPS and TOFF time-pulse messages are also reported
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pps: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remote: Option<String>,
 }
+
+impl Watch {
+    ///
+    /// Returns the `?WATCH={...};` command that requests this watcher mode
+    pub fn to_command(&self) -> Result<String, GPSdError> {
+        Ok(format!("?WATCH={};", serde_json::to_string(self)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::GPSdError;
+    use crate::output::watch::Watch;
+    use crate::transport::client::DEFAULT_WATCH;
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn pps_command() -> Result<(), GPSdError> {
+        let watch = Watch {
+            enable: Some(true),
+            json: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(DEFAULT_WATCH, watch.to_command()?);
+
+        let watch = Watch {
+            pps: Some(true),
+            ..watch
+        };
+        assert_eq!(
+            "?WATCH={\"enable\":true,\"json\":true,\"pps\":true};",
+            watch.to_command()?
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 pub use tcp::*;
 
+pub mod client;
 pub mod serial;
 pub mod tcp;
@@ -0,0 +1,400 @@
+//!
+//! GPSd Client Transport - connects to an upstream GPSd instance, and reconnects with an
+//! exponential backoff if the connection is lost or goes silent.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use log::warn;
+
+use crate::error::GPSdError;
+use crate::output::{Pps, Toff};
+
+/// The default command sent to the upstream server to start the stream of JSON reports
+pub const DEFAULT_WATCH: &str = "?WATCH={\"enable\":true,\"json\":true};";
+
+#[derive(Debug, Clone, Parser)]
+#[command(author, version, about)]
+pub struct ClientConfig {
+    /// Upstream GPSd address, like ("localhost:2947")
+    #[arg(default_value = "localhost:2947")]
+    pub address: String,
+
+    /// Command sent to the upstream server on every (re)connection
+    #[arg(short = 'w', long, default_value = DEFAULT_WATCH)]
+    pub watch: String,
+
+    /// Delay before the first reconnection attempt, in milliseconds.  Doubles on each
+    /// subsequent failed attempt.
+    #[arg(short = 'i', long, default_value_t = 1000)]
+    pub initial_backoff_ms: u64,
+
+    /// Maximum delay between reconnection attempts, in milliseconds
+    #[arg(short = 'm', long, default_value_t = 30000)]
+    pub max_backoff_ms: u64,
+
+    /// If no data is received from the upstream server within this time, in milliseconds, the
+    /// connection is treated as lost.
+    #[arg(short = 't', long, default_value_t = 10000)]
+    pub heartbeat_timeout_ms: u64,
+}
+
+impl ClientConfig {
+    /// Returns the delay before the provided reconnection attempt (starting at 1)
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let factor = 1_u64
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u64::MAX);
+        let delay = self.initial_backoff_ms.saturating_mul(factor);
+        Duration::from_millis(delay.min(self.max_backoff_ms))
+    }
+
+    ///
+    /// If `pps` is set, adds `"pps":true` to the watch command, so the upstream server also sends
+    /// the PPS and TOFF time-pulse reports.  Errors if the watch command isn't a
+    /// `?WATCH={...};` JSON object.
+    pub fn with_pps(mut self, pps: bool) -> Result<ClientConfig, GPSdError> {
+        if !pps {
+            return Ok(self);
+        }
+        let Some(json) = self
+            .watch
+            .trim()
+            .strip_prefix("?WATCH=")
+            .and_then(|w| w.strip_suffix(';'))
+        else {
+            return Err(GPSdError::new_str(format!(
+                "Unable to enable PPS in the watch command: {}",
+                self.watch
+            )));
+        };
+        let mut watch: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)?;
+        watch.insert("pps".to_string(), serde_json::Value::Bool(true));
+        self.watch = format!("?WATCH={};", serde_json::Value::Object(watch));
+        Ok(self)
+    }
+}
+
+///
+/// Events emitted by the client as the connection changes state or data arrives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientEvent {
+    /// Connected (or reconnected) to the upstream server, and the watch command was sent.
+    Connected,
+    /// The connection to the upstream server was lost
+    Disconnected { reason: String },
+    /// The client will attempt to reconnect after the delay
+    Reconnecting { attempt: u32, delay: Duration },
+    /// A line of data was received from the upstream server
+    Message(String),
+    /// The [`ClientEvent::Message`] just received was a PPS time-pulse report
+    Pps(Pps),
+    /// The [`ClientEvent::Message`] just received was a TOFF time-offset report
+    Toff(Toff),
+}
+
+impl ClientEvent {
+    ///
+    /// Parses a PPS or TOFF report into the matching event, returns [`None`] for any other
+    /// message, or if the report is invalid.
+    pub fn time_pulse(report: &str) -> Option<ClientEvent> {
+        let value: serde_json::Value = serde_json::from_str(report).ok()?;
+        match value.get("class")?.as_str()? {
+            "PPS" => serde_json::from_value(value).ok().map(ClientEvent::Pps),
+            "TOFF" => serde_json::from_value(value).ok().map(ClientEvent::Toff),
+            _ => None,
+        }
+    }
+}
+
+///
+/// A client for an upstream GPSd instance.  Iterating the client blocks for the next
+/// [`ClientEvent`], reconnecting as needed, and ends once the `shouldquit` flag is set.  PPS
+/// and TOFF reports (requested with [`crate::output::watch::Watch::pps`]) are followed by the
+/// parsed [`ClientEvent::Pps`] or [`ClientEvent::Toff`] event.
+pub struct GPSdClient {
+    config: ClientConfig,
+    shouldquit: Arc<AtomicBool>,
+    reader: Option<BufReader<TcpStream>>,
+    attempt: u32,
+    delay: Option<Duration>,
+    pending: VecDeque<ClientEvent>,
+}
+
+impl GPSdClient {
+    pub fn new(config: ClientConfig, shouldquit: Arc<AtomicBool>) -> GPSdClient {
+        GPSdClient {
+            config,
+            shouldquit,
+            reader: None,
+            attempt: 0,
+            delay: None,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn connect(&self) -> Result<BufReader<TcpStream>, GPSdError> {
+        let mut last_err = GPSdError::new_str(format!(
+            "Unable to resolve address: {}",
+            self.config.address
+        ));
+        for addr in self.config.address.to_socket_addrs()? {
+            let timeout = Duration::from_millis(self.config.heartbeat_timeout_ms.max(1));
+            match TcpStream::connect_timeout(&addr, timeout) {
+                Ok(mut stream) => {
+                    stream.set_read_timeout(Some(timeout))?;
+                    stream.write_all(self.config.watch.as_bytes())?;
+                    stream.write_all(b"\n")?;
+                    return Ok(BufReader::new(stream));
+                }
+                Err(e) => last_err = e.into(),
+            }
+        }
+        Err(last_err)
+    }
+
+    /// Drops the connection, and schedules the next reconnection attempt.
+    fn disconnected(&mut self, reason: String) -> ClientEvent {
+        self.reader = None;
+        let reconnect = self.next_attempt();
+        self.pending.push_back(reconnect);
+        ClientEvent::Disconnected { reason }
+    }
+
+    fn next_attempt(&mut self) -> ClientEvent {
+        self.attempt = self.attempt.saturating_add(1);
+        let delay = self.config.backoff_delay(self.attempt);
+        self.delay = Some(delay);
+        ClientEvent::Reconnecting {
+            attempt: self.attempt,
+            delay,
+        }
+    }
+
+    /// Sleeps for the duration, returning early if the quit flag is set.
+    fn sleep(&self, delay: Duration) {
+        let end = Instant::now() + delay;
+        while !self.shouldquit.load(Ordering::Relaxed) {
+            let now = Instant::now();
+            if now >= end {
+                return;
+            }
+            std::thread::sleep((end - now).min(Duration::from_millis(100)));
+        }
+    }
+}
+
+impl Iterator for GPSdClient {
+    type Item = ClientEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.shouldquit.load(Ordering::Relaxed) {
+                return None;
+            }
+            if let Some(event) = self.pending.pop_front() {
+                return Some(event);
+            }
+            let Some(reader) = self.reader.as_mut() else {
+                if let Some(delay) = self.delay.take() {
+                    self.sleep(delay);
+                    if self.shouldquit.load(Ordering::Relaxed) {
+                        return None;
+                    }
+                }
+                return match self.connect() {
+                    Ok(reader) => {
+                        self.reader = Some(reader);
+                        self.attempt = 0;
+                        Some(ClientEvent::Connected)
+                    }
+                    Err(e) => {
+                        warn!("Unable to connect to {}: {e:?}", self.config.address);
+                        Some(self.next_attempt())
+                    }
+                };
+            };
+            let mut line = String::new();
+            return match reader.read_line(&mut line) {
+                Ok(0) => Some(self.disconnected("Connection closed".to_string())),
+                Ok(_) => {
+                    let line = line.trim();
+                    if line.is_empty() {
+                        continue;
+                    }
+                    if let Some(event) = ClientEvent::time_pulse(line) {
+                        self.pending.push_back(event);
+                    }
+                    Some(ClientEvent::Message(line.to_string()))
+                }
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    Some(self.disconnected("Heartbeat timeout".to_string()))
+                }
+                Err(e) => Some(self.disconnected(e.to_string())),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc::channel;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use clap::Parser;
+
+    use crate::error::GPSdError;
+    use crate::output::{Pps, Toff};
+    use crate::transport::client::{ClientConfig, ClientEvent, GPSdClient, DEFAULT_WATCH};
+
+    #[test]
+    pub fn backoff_is_capped() {
+        let config = ClientConfig::parse_from(["client", "-i", "100", "-m", "1000"]);
+        let delays: Vec<u64> = (1..=6)
+            .map(|a| config.backoff_delay(a).as_millis() as u64)
+            .collect();
+        assert_eq!(vec![100, 200, 400, 800, 1000, 1000], delays);
+        assert_eq!(Duration::from_secs(1), config.backoff_delay(u32::MAX));
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn with_pps() -> Result<(), GPSdError> {
+        let config = ClientConfig::parse_from(["client"]);
+        assert_eq!(DEFAULT_WATCH, config.clone().with_pps(false)?.watch);
+        assert_eq!(
+            "?WATCH={\"enable\":true,\"json\":true,\"pps\":true};",
+            config.with_pps(true)?.watch
+        );
+
+        let config = ClientConfig::parse_from(["client", "-w", "?WATCH={\"enable\":false};"]);
+        assert_eq!(
+            "?WATCH={\"enable\":false,\"pps\":true};",
+            config.with_pps(true)?.watch
+        );
+        let config = ClientConfig::parse_from(["client", "-w", "?POLL;"]);
+        assert!(config.with_pps(true).is_err());
+        Ok(())
+    }
+
+    #[allow(clippy::panic_in_result_fn)]
+    #[test]
+    pub fn reconnects_after_drop_and_silence() -> Result<(), GPSdError> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?.to_string();
+        let (watches_tx, watches) = channel();
+        let server = std::thread::spawn(move || -> Result<(), std::io::Error> {
+            for report in [
+                "{\"class\":\"TPV\",\"mode\":1}",
+                "{\"class\":\"TPV\",\"mode\":3}",
+            ] {
+                let (stream, _) = listener.accept()?;
+                let mut reader = BufReader::new(stream.try_clone()?);
+                let mut watch = String::new();
+                reader.read_line(&mut watch)?;
+                let _ = watches_tx.send(watch);
+                writeln!(&stream, "{report}")?;
+                // first connection is dropped, the second goes silent.
+                if report.contains("\"mode\":3") {
+                    std::thread::sleep(Duration::from_millis(500));
+                }
+            }
+            Ok(())
+        });
+
+        let quit = Arc::new(AtomicBool::new(false));
+        let config = ClientConfig::parse_from([
+            "client",
+            address.as_str(),
+            "-i",
+            "10",
+            "-m",
+            "50",
+            "-t",
+            "200",
+        ]);
+        let mut client = GPSdClient::new(config, quit.clone());
+        let mut events = Vec::new();
+        for event in client.by_ref() {
+            let done = matches!(&event, ClientEvent::Disconnected { reason } if reason.contains("Heartbeat"));
+            events.push(event);
+            if done {
+                break;
+            }
+        }
+        assert_eq!(
+            vec![
+                ClientEvent::Connected,
+                ClientEvent::Message("{\"class\":\"TPV\",\"mode\":1}".to_string()),
+                ClientEvent::Disconnected {
+                    reason: "Connection closed".to_string()
+                },
+                ClientEvent::Reconnecting {
+                    attempt: 1,
+                    delay: Duration::from_millis(10)
+                },
+                ClientEvent::Connected,
+                ClientEvent::Message("{\"class\":\"TPV\",\"mode\":3}".to_string()),
+                ClientEvent::Disconnected {
+                    reason: "Heartbeat timeout".to_string()
+                },
+            ],
+            events
+        );
+        // the watch command is re-sent on reconnect
+        let watches: Vec<String> = watches.try_iter().collect();
+        assert_eq!(vec![format!("{DEFAULT_WATCH}\n"); 2], watches);
+
+        quit.store(true, Ordering::Relaxed);
+        assert_eq!(None, client.next());
+        let _ = server.join();
+        Ok(())
+    }
+
+    #[test]
+    pub fn time_pulse_events() {
+        let pps = "{\"class\":\"PPS\",\"device\":\"/dev/ttyUSB0\",\"real_sec\":1330212592,\
+            \"real_nsec\":0,\"clock_sec\":1330212591,\"clock_nsec\":999656818,\"precision\":-3}";
+        assert_eq!(
+            Some(ClientEvent::Pps(Pps {
+                device: Some("/dev/ttyUSB0".to_string()),
+                real_sec: 1330212592,
+                real_nsec: 0,
+                clock_sec: 1330212591,
+                clock_nsec: 999656818,
+                precision: Some(-3),
+                shm: None,
+                q_err: None,
+            })),
+            ClientEvent::time_pulse(pps)
+        );
+        let toff = "{\"class\":\"TOFF\",\"real_sec\":1330212592,\"real_nsec\":343182,\
+            \"clock_sec\":1330212592,\"clock_nsec\":343184}";
+        assert_eq!(
+            Some(ClientEvent::Toff(Toff {
+                device: None,
+                real_sec: 1330212592,
+                real_nsec: 343182,
+                clock_sec: 1330212592,
+                clock_nsec: 343184,
+                precision: None,
+            })),
+            ClientEvent::time_pulse(toff)
+        );
+        assert_eq!(
+            None,
+            ClientEvent::time_pulse("{\"class\":\"TPV\",\"mode\":1}")
+        );
+        assert_eq!(None, ClientEvent::time_pulse("{\"class\":\"PPS\"}"));
+        assert_eq!(None, ClientEvent::time_pulse("not json"));
+    }
+}
@@ -65,6 +65,11 @@ impl TCPServer {
 
     pub fn send(&mut self, frame: &Frame) -> Result<(), GPSdError> {
         let data = frame.to_json()?;
+        self.send_raw(&data)
+    }
+
+    /// Sends an already encoded report to all the connected clients
+    pub fn send_raw(&mut self, data: &str) -> Result<(), GPSdError> {
         let mut buf: Vec<u8> = Vec::new();
         buf.write_fmt(format_args!("{data}\r\n"))?;
 